@@ -0,0 +1,6 @@
+-- ============================================================
+-- ユーザー・セッションテーブルを削除
+-- ============================================================
+
+DROP TABLE IF EXISTS sessions;
+DROP TABLE IF EXISTS users;
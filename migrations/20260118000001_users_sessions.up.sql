@@ -0,0 +1,45 @@
+-- ============================================================
+-- ユーザー・セッションテーブル（ログイン・権限管理）
+-- ============================================================
+
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+
+    -- ログイン情報
+    username VARCHAR(50) NOT NULL UNIQUE, -- ログインID
+    password_hash VARCHAR(255) NOT NULL, -- パスワードハッシュ（Argon2）
+    display_name VARCHAR(100) NOT NULL, -- 表示名
+
+    -- 権限
+    role VARCHAR(30) NOT NULL DEFAULT 'READ_ONLY'
+        CHECK (role IN ('ADMIN', 'OFFICE_MANAGER', 'OPERATION_MANAGER', 'READ_ONLY')), -- ロール
+
+    -- 紐付け（任意）
+    employee_id INTEGER REFERENCES employees(id) ON DELETE SET NULL, -- 対応する従業員
+    office_id INTEGER REFERENCES offices(id) ON DELETE SET NULL, -- 担当営業所
+
+    -- ステータス・メタ情報
+    is_active BOOLEAN DEFAULT TRUE NOT NULL, -- 有効なユーザーかどうか
+    last_login_at TIMESTAMPTZ, -- 最終ログイン日時
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE sessions (
+    id SERIAL PRIMARY KEY,
+    token VARCHAR(128) NOT NULL UNIQUE, -- セッショントークン（Cookieの値）
+    user_id INTEGER NOT NULL
+        REFERENCES users(id) ON DELETE CASCADE, -- ユーザー
+    expires_at TIMESTAMPTZ NOT NULL, -- 有効期限
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- インデックス
+CREATE INDEX idx_users_role ON users(role) WHERE is_active = TRUE;
+CREATE INDEX idx_sessions_user_id ON sessions(user_id);
+CREATE INDEX idx_sessions_expires_at ON sessions(expires_at);
+
+-- コメント追加
+COMMENT ON TABLE users IS 'システム利用者';
+COMMENT ON COLUMN users.role IS 'ロール: ADMIN（システム管理者）, OFFICE_MANAGER（事務管理者）, OPERATION_MANAGER（運行管理者）, READ_ONLY（閲覧のみ）';
+COMMENT ON TABLE sessions IS 'ログインセッション';
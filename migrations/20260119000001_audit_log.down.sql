@@ -0,0 +1,5 @@
+-- ============================================================
+-- 監査ログテーブルを削除
+-- ============================================================
+
+DROP TABLE IF EXISTS audit_log;
@@ -0,0 +1,34 @@
+-- ============================================================
+-- 監査ログテーブル（データ変更履歴）
+-- ============================================================
+
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+
+    -- 操作者（ユーザー削除後も記録を残すため名前も保持）
+    user_id INTEGER REFERENCES users(id) ON DELETE SET NULL, -- 操作ユーザー
+    actor_name VARCHAR(100), -- 操作者名（記録時点）
+
+    -- 操作内容
+    action VARCHAR(10) NOT NULL
+        CHECK (action IN ('CREATE', 'UPDATE', 'DELETE')), -- 操作種別
+    table_name VARCHAR(100) NOT NULL, -- 対象テーブル
+    row_id INTEGER NOT NULL, -- 対象行ID
+    employee_id INTEGER, -- 対象従業員ID（従業員削除後も検索できるよう外部キーは張らない）
+
+    -- 変更前後のスナップショット
+    old_data JSONB, -- 変更前（作成時はNULL）
+    new_data JSONB, -- 変更後（削除時はNULL）
+
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- インデックス
+CREATE INDEX idx_audit_log_employee_id ON audit_log(employee_id, created_at DESC);
+CREATE INDEX idx_audit_log_table_row ON audit_log(table_name, row_id);
+CREATE INDEX idx_audit_log_created_at ON audit_log(created_at DESC);
+
+-- コメント追加
+COMMENT ON TABLE audit_log IS '監査ログ（すべてのデータ変更の前後スナップショット）';
+COMMENT ON COLUMN audit_log.old_data IS '変更前の行データ（JSON）';
+COMMENT ON COLUMN audit_log.new_data IS '変更後の行データ（JSON）';
@@ -0,0 +1,8 @@
+-- ============================================================
+-- s3_urlカラムを復元（値はs3_keyから再生成しない）
+-- ============================================================
+
+ALTER TABLE employee_documents
+ADD COLUMN IF NOT EXISTS s3_url VARCHAR(1000);
+
+COMMENT ON COLUMN employee_documents.s3_url IS 'S3オブジェクトのURL';
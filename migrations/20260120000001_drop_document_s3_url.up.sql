@@ -0,0 +1,7 @@
+-- ============================================================
+-- ドキュメントの公開URLを保持しない
+-- 閲覧は署名付きURL（有効期限付き）または認証付きの配信エンドポイント経由とする
+-- ============================================================
+
+ALTER TABLE employee_documents
+DROP COLUMN IF EXISTS s3_url;
@@ -0,0 +1,6 @@
+-- ============================================================
+-- サムネイルのカラムを削除
+-- ============================================================
+
+ALTER TABLE employee_documents
+DROP COLUMN IF EXISTS thumbnail_key;
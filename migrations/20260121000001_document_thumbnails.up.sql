@@ -0,0 +1,9 @@
+-- ============================================================
+-- ドキュメントのサムネイル
+-- 画像はアップロード時にEXIFを除去・縮小し、一覧用のサムネイルを別オブジェクトとして保存する
+-- ============================================================
+
+ALTER TABLE employee_documents
+ADD COLUMN IF NOT EXISTS thumbnail_key VARCHAR(500);
+
+COMMENT ON COLUMN employee_documents.thumbnail_key IS 'サムネイル画像のオブジェクトキー（画像のみ）';
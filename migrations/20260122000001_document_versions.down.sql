@@ -0,0 +1,12 @@
+-- ============================================================
+-- ドキュメントの版管理を削除（履歴の版も現在の版に戻る）
+-- ============================================================
+
+DROP INDEX IF EXISTS idx_employee_documents_versions;
+
+UPDATE employee_documents SET is_active = TRUE WHERE is_active = FALSE;
+
+ALTER TABLE employee_documents
+DROP COLUMN IF EXISTS superseded_at,
+DROP COLUMN IF EXISTS superseded_by,
+DROP COLUMN IF EXISTS version;
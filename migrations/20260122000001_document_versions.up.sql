@@ -0,0 +1,44 @@
+-- ============================================================
+-- ドキュメントの版管理
+-- 同じ従業員・種類・関連IDで新しいファイルをアップロードすると以前の版を置き換え、
+-- 以前の版は is_active = FALSE の履歴として残す（「その他」は対象外）
+-- ============================================================
+
+ALTER TABLE employee_documents
+ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN IF NOT EXISTS superseded_by INTEGER
+    REFERENCES employee_documents(id) ON DELETE SET NULL,
+ADD COLUMN IF NOT EXISTS superseded_at TIMESTAMPTZ;
+
+-- 既存データのマイグレーション（アップロード順に版番号を振り、最新以外を履歴にする）
+WITH ordered AS (
+    SELECT
+        id,
+        ROW_NUMBER() OVER w AS version,
+        LEAD(id) OVER w AS next_id,
+        LEAD(uploaded_at) OVER w AS next_uploaded_at
+    FROM employee_documents
+    WHERE category IS NOT NULL AND category <> 'OTHER'
+    WINDOW w AS (
+        PARTITION BY employee_id, category, related_id
+        ORDER BY uploaded_at, id
+    )
+)
+UPDATE employee_documents d
+SET
+    version = o.version,
+    is_active = (o.next_id IS NULL),
+    superseded_by = o.next_id,
+    superseded_at = o.next_uploaded_at
+FROM ordered o
+WHERE d.id = o.id;
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_employee_documents_versions
+ON employee_documents(employee_id, category, related_id, version);
+
+-- コメント追加
+COMMENT ON COLUMN employee_documents.version IS '版番号（同じ従業員・種類・関連IDの中で1から連番）';
+COMMENT ON COLUMN employee_documents.is_active IS '現在の版かどうか（FALSEは置き換えられた履歴）';
+COMMENT ON COLUMN employee_documents.superseded_by IS 'この版を置き換えたドキュメントのID';
+COMMENT ON COLUMN employee_documents.superseded_at IS '新しい版に置き換えられた日時';
@@ -0,0 +1,15 @@
+-- ============================================================
+-- 保管期限管理・廃棄の保留を削除
+-- ============================================================
+
+DROP INDEX IF EXISTS idx_employees_end_date;
+
+ALTER TABLE employees
+DROP COLUMN IF EXISTS legal_hold_reason,
+DROP COLUMN IF EXISTS legal_hold;
+
+ALTER TABLE employee_documents
+DROP COLUMN IF EXISTS legal_hold_reason,
+DROP COLUMN IF EXISTS legal_hold;
+
+COMMENT ON COLUMN document_types.retention_years IS NULL;
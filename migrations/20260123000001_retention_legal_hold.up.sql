@@ -0,0 +1,48 @@
+-- ============================================================
+-- 保管期限管理・廃棄の保留（リーガルホールド）
+-- ============================================================
+
+-- アップロード書類の種類に対応する書類種別を追加（保管年数の判定用）
+INSERT INTO document_types (name, is_mandatory, retention_years) VALUES
+('顔写真', FALSE, NULL),
+('車検証', FALSE, NULL),
+('健康診断書', FALSE, 5),
+('適性診断書', FALSE, 3)
+ON CONFLICT (name) DO NOTHING;
+
+-- 既存データのマイグレーション（カテゴリから書類種別を設定）
+UPDATE employee_documents d
+SET document_type_id = t.id
+FROM document_types t
+WHERE d.document_type_id IS NULL
+  AND t.name = CASE d.category
+    WHEN 'FACE_PHOTO' THEN '顔写真'
+    WHEN 'LICENSE_FRONT' THEN '免許証コピー'
+    WHEN 'LICENSE_BACK' THEN '免許証コピー'
+    WHEN 'QUALIFICATION_CERT' THEN '資格証コピー'
+    WHEN 'VEHICLE_INSPECTION' THEN '車検証'
+    WHEN 'HEALTH_CHECKUP' THEN '健康診断書'
+    WHEN 'APTITUDE_CHECKUP' THEN '適性診断書'
+    ELSE 'その他'
+  END;
+
+-- 廃棄の保留
+ALTER TABLE employee_documents
+ADD COLUMN IF NOT EXISTS legal_hold BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN IF NOT EXISTS legal_hold_reason TEXT;
+
+ALTER TABLE employees
+ADD COLUMN IF NOT EXISTS legal_hold BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN IF NOT EXISTS legal_hold_reason TEXT;
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_employees_end_date
+ON employees(end_date)
+WHERE end_date IS NOT NULL;
+
+-- コメント追加
+COMMENT ON COLUMN document_types.retention_years IS '保管期間（年）。退職日・選任解除日、または新しい版に置き換えられた日から起算。未設定は3年';
+COMMENT ON COLUMN employee_documents.legal_hold IS '廃棄の保留（訴訟・調査などのため期限後も保管）';
+COMMENT ON COLUMN employee_documents.legal_hold_reason IS '保留の理由';
+COMMENT ON COLUMN employees.legal_hold IS '廃棄の保留（従業員の記録・書類すべてを期限後も保管）';
+COMMENT ON COLUMN employees.legal_hold_reason IS '保留の理由';
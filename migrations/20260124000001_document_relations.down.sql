@@ -0,0 +1,27 @@
+-- ============================================================
+-- ドキュメントの添付先の種類を削除（related_id は残す）
+-- ============================================================
+
+DROP TRIGGER IF EXISTS trg_vehicles_clear_document_relation ON vehicles;
+DROP TRIGGER IF EXISTS trg_residence_cards_clear_document_relation ON residence_cards;
+DROP TRIGGER IF EXISTS trg_aptitude_checkup_history_clear_document_relation ON aptitude_checkup_history;
+DROP TRIGGER IF EXISTS trg_health_checkup_history_clear_document_relation ON health_checkup_history;
+DROP TRIGGER IF EXISTS trg_qualifications_clear_document_relation ON qualifications;
+DROP TRIGGER IF EXISTS trg_licenses_clear_document_relation ON licenses;
+DROP FUNCTION IF EXISTS clear_employee_document_relation();
+
+DROP TRIGGER IF EXISTS trg_employee_documents_relation ON employee_documents;
+DROP FUNCTION IF EXISTS check_employee_document_relation();
+
+DROP INDEX IF EXISTS idx_employee_documents_relation;
+DROP INDEX IF EXISTS idx_employee_documents_versions;
+CREATE INDEX IF NOT EXISTS idx_employee_documents_versions
+ON employee_documents(employee_id, category, related_id, version);
+
+ALTER TABLE employee_documents
+DROP CONSTRAINT IF EXISTS chk_employee_documents_relation,
+DROP CONSTRAINT IF EXISTS chk_employee_documents_related_type,
+DROP COLUMN IF EXISTS related_type;
+
+COMMENT ON COLUMN employee_documents.related_id IS '関連ID（資格IDや車両IDなど）';
+COMMENT ON COLUMN employee_documents.version IS '版番号（同じ従業員・種類・関連IDの中で1から連番）';
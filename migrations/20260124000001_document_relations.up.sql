@@ -0,0 +1,139 @@
+-- ============================================================
+-- ドキュメントの添付先を種類付きにする
+-- related_id だけでは何のIDか分からないため related_type を追加し、
+-- 添付先の記録の存在（従業員の記録は同じ従業員か）をトリガーで検査する
+-- ============================================================
+
+ALTER TABLE employee_documents
+ADD COLUMN IF NOT EXISTS related_type VARCHAR(30);
+
+-- 既存データのマイグレーション（カテゴリから添付先の種類を推定し、存在しない記録への参照は外す）
+UPDATE employee_documents
+SET related_type = CASE category
+    WHEN 'LICENSE_FRONT' THEN 'LICENSE'
+    WHEN 'LICENSE_BACK' THEN 'LICENSE'
+    WHEN 'QUALIFICATION_CERT' THEN 'QUALIFICATION'
+    WHEN 'HEALTH_CHECKUP' THEN 'HEALTH_CHECKUP'
+    WHEN 'APTITUDE_CHECKUP' THEN 'APTITUDE_CHECKUP'
+    WHEN 'VEHICLE_INSPECTION' THEN 'VEHICLE'
+END
+WHERE related_id IS NOT NULL;
+
+UPDATE employee_documents d
+SET related_type = NULL, related_id = NULL
+WHERE d.related_id IS NOT NULL
+  AND NOT CASE d.related_type
+    WHEN 'LICENSE' THEN EXISTS (
+        SELECT 1 FROM licenses r WHERE r.id = d.related_id AND r.employee_id = d.employee_id)
+    WHEN 'QUALIFICATION' THEN EXISTS (
+        SELECT 1 FROM qualifications r WHERE r.id = d.related_id AND r.employee_id = d.employee_id)
+    WHEN 'HEALTH_CHECKUP' THEN EXISTS (
+        SELECT 1 FROM health_checkup_history r WHERE r.id = d.related_id AND r.employee_id = d.employee_id)
+    WHEN 'APTITUDE_CHECKUP' THEN EXISTS (
+        SELECT 1 FROM aptitude_checkup_history r WHERE r.id = d.related_id AND r.employee_id = d.employee_id)
+    WHEN 'VEHICLE' THEN EXISTS (SELECT 1 FROM vehicles r WHERE r.id = d.related_id)
+    ELSE FALSE
+  END;
+
+ALTER TABLE employee_documents
+ADD CONSTRAINT chk_employee_documents_related_type CHECK (
+    related_type IN (
+        'LICENSE', 'QUALIFICATION', 'HEALTH_CHECKUP',
+        'APTITUDE_CHECKUP', 'RESIDENCE_CARD', 'VEHICLE'
+    )
+),
+ADD CONSTRAINT chk_employee_documents_relation CHECK (
+    (related_type IS NULL) = (related_id IS NULL)
+);
+
+-- 添付先の記録の存在を検査
+CREATE OR REPLACE FUNCTION check_employee_document_relation()
+RETURNS TRIGGER AS $$
+DECLARE
+    owner_id INTEGER;
+BEGIN
+    IF NEW.related_type IS NULL THEN
+        RETURN NEW;
+    END IF;
+
+    IF NEW.related_type = 'VEHICLE' THEN
+        IF NOT EXISTS (SELECT 1 FROM vehicles WHERE id = NEW.related_id) THEN
+            RAISE EXCEPTION '添付先の車両 (id=%) が存在しません', NEW.related_id
+                USING ERRCODE = 'foreign_key_violation';
+        END IF;
+        RETURN NEW;
+    END IF;
+
+    CASE NEW.related_type
+        WHEN 'LICENSE' THEN
+            SELECT employee_id INTO owner_id FROM licenses WHERE id = NEW.related_id;
+        WHEN 'QUALIFICATION' THEN
+            SELECT employee_id INTO owner_id FROM qualifications WHERE id = NEW.related_id;
+        WHEN 'HEALTH_CHECKUP' THEN
+            SELECT employee_id INTO owner_id FROM health_checkup_history WHERE id = NEW.related_id;
+        WHEN 'APTITUDE_CHECKUP' THEN
+            SELECT employee_id INTO owner_id FROM aptitude_checkup_history WHERE id = NEW.related_id;
+        WHEN 'RESIDENCE_CARD' THEN
+            SELECT employee_id INTO owner_id FROM residence_cards WHERE id = NEW.related_id;
+    END CASE;
+
+    IF owner_id IS DISTINCT FROM NEW.employee_id THEN
+        RAISE EXCEPTION '添付先の記録 (%, id=%) が存在しないか、別の従業員の記録です',
+            NEW.related_type, NEW.related_id
+            USING ERRCODE = 'foreign_key_violation';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_employee_documents_relation
+BEFORE INSERT OR UPDATE OF related_type, related_id, employee_id ON employee_documents
+FOR EACH ROW EXECUTE FUNCTION check_employee_document_relation();
+
+-- 添付先の記録を削除したら、ドキュメントは残して添付を外す
+CREATE OR REPLACE FUNCTION clear_employee_document_relation()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE employee_documents
+    SET related_type = NULL, related_id = NULL, updated_at = CURRENT_TIMESTAMP
+    WHERE related_type = TG_ARGV[0] AND related_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_licenses_clear_document_relation
+AFTER DELETE ON licenses
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('LICENSE');
+
+CREATE TRIGGER trg_qualifications_clear_document_relation
+AFTER DELETE ON qualifications
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('QUALIFICATION');
+
+CREATE TRIGGER trg_health_checkup_history_clear_document_relation
+AFTER DELETE ON health_checkup_history
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('HEALTH_CHECKUP');
+
+CREATE TRIGGER trg_aptitude_checkup_history_clear_document_relation
+AFTER DELETE ON aptitude_checkup_history
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('APTITUDE_CHECKUP');
+
+CREATE TRIGGER trg_residence_cards_clear_document_relation
+AFTER DELETE ON residence_cards
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('RESIDENCE_CARD');
+
+CREATE TRIGGER trg_vehicles_clear_document_relation
+AFTER DELETE ON vehicles
+FOR EACH ROW EXECUTE FUNCTION clear_employee_document_relation('VEHICLE');
+
+-- インデックスを置き換え（版のまとまりと添付先からの検索用）
+DROP INDEX IF EXISTS idx_employee_documents_versions;
+CREATE INDEX IF NOT EXISTS idx_employee_documents_versions
+ON employee_documents(employee_id, category, related_type, related_id, version);
+CREATE INDEX IF NOT EXISTS idx_employee_documents_relation
+ON employee_documents(related_type, related_id)
+WHERE related_type IS NOT NULL;
+
+-- コメント追加
+COMMENT ON COLUMN employee_documents.related_type IS '添付先の記録の種類（LICENSE, QUALIFICATION, HEALTH_CHECKUP, APTITUDE_CHECKUP, RESIDENCE_CARD, VEHICLE）';
+COMMENT ON COLUMN employee_documents.related_id IS '添付先の記録のID（related_type のテーブルのID）';
+COMMENT ON COLUMN employee_documents.version IS '版番号（同じ従業員・種類・添付先の中で1から連番）';
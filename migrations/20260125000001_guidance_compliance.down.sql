@@ -0,0 +1,9 @@
+-- 指導教育の実施状況の判定を削除
+DROP INDEX IF EXISTS idx_guidance_education_history_employee_type_date;
+DROP TABLE IF EXISTS driver_accidents;
+
+ALTER TABLE guidance_education_types
+DROP CONSTRAINT IF EXISTS chk_guidance_education_types_trigger_kind;
+
+ALTER TABLE guidance_education_types
+DROP COLUMN IF EXISTS trigger_kind;
@@ -0,0 +1,38 @@
+-- ============================================================
+-- 指導教育の実施状況の判定（初任運転者・事故惹起運転者）
+-- ============================================================
+
+-- 特別な指導の契機（初任運転者・事故惹起運転者）を指導教育種別に設定
+ALTER TABLE guidance_education_types
+ADD COLUMN IF NOT EXISTS trigger_kind VARCHAR(30);
+
+ALTER TABLE guidance_education_types
+ADD CONSTRAINT chk_guidance_education_types_trigger_kind CHECK (
+    trigger_kind IS NULL OR trigger_kind IN ('INITIAL_DRIVER', 'ACCIDENT')
+);
+
+UPDATE guidance_education_types SET trigger_kind = 'INITIAL_DRIVER' WHERE name = '初任運転者講習';
+UPDATE guidance_education_types SET trigger_kind = 'ACCIDENT' WHERE name = '事故惹起者講習';
+
+-- 事故歴テーブル（事故惹起運転者の判定用）
+CREATE TABLE IF NOT EXISTS driver_accidents (
+    id SERIAL PRIMARY KEY,
+    employee_id INTEGER NOT NULL
+        REFERENCES employees(id) ON DELETE CASCADE,
+    accident_date DATE NOT NULL, -- 事故の発生日
+    is_injury_accident BOOLEAN NOT NULL DEFAULT TRUE, -- 死者又は負傷者が生じた事故か
+    summary TEXT, -- 事故の概要
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_driver_accidents_employee_id
+ON driver_accidents(employee_id, accident_date);
+CREATE INDEX IF NOT EXISTS idx_guidance_education_history_employee_type_date
+ON guidance_education_history(employee_id, guidance_education_type_id, education_date);
+
+-- コメント追加
+COMMENT ON COLUMN guidance_education_types.trigger_kind IS '特別な指導の契機（INITIAL_DRIVER: 初任運転者, ACCIDENT: 事故惹起運転者）';
+COMMENT ON TABLE driver_accidents IS '事故歴（死傷事故は事故惹起運転者への特別な指導の契機になる）';
+COMMENT ON COLUMN driver_accidents.is_injury_accident IS '死者又は負傷者が生じた事故か';
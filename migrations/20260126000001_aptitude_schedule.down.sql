@@ -0,0 +1,10 @@
+-- 適性診断の受診計画を削除
+DROP INDEX IF EXISTS idx_aptitude_checkup_history_employee_type_date;
+
+UPDATE aptitude_checkup_types
+SET required_frequency_years = 1
+WHERE name = '適齢診断（65歳以上）';
+
+ALTER TABLE aptitude_checkup_types
+DROP COLUMN IF EXISTS is_initial,
+DROP COLUMN IF EXISTS is_mandatory;
@@ -0,0 +1,23 @@
+-- ============================================================
+-- 適性診断の受診計画（適齢診断・初任診断）
+-- ============================================================
+
+-- 義務の診断と、選任前に受診する初任診断を区別
+ALTER TABLE aptitude_checkup_types
+ADD COLUMN IF NOT EXISTS is_mandatory BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN IF NOT EXISTS is_initial BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE aptitude_checkup_types SET is_mandatory = TRUE, is_initial = TRUE WHERE name = '初任診断';
+
+-- 適齢診断は65歳に達した日以後1年以内に1回、その後3年以内ごとに1回
+UPDATE aptitude_checkup_types
+SET is_mandatory = TRUE, required_frequency_years = 3
+WHERE name = '適齢診断（65歳以上）';
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_aptitude_checkup_history_employee_type_date
+ON aptitude_checkup_history(employee_id, aptitude_checkup_type_id, checkup_date);
+
+-- コメント追加
+COMMENT ON COLUMN aptitude_checkup_types.is_mandatory IS '受診計画の対象とする義務の診断か';
+COMMENT ON COLUMN aptitude_checkup_types.is_initial IS '初任運転者が選任前に受診する診断か（初任診断）';
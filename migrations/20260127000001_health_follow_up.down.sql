@@ -0,0 +1,6 @@
+-- 健康診断の受診計画と有所見者の事後措置を削除
+DROP INDEX IF EXISTS idx_health_checkup_history_employee_type_date;
+DROP TABLE IF EXISTS health_checkup_follow_ups;
+
+ALTER TABLE health_checkup_types DROP COLUMN IF EXISTS is_night_work;
+ALTER TABLE employees DROP COLUMN IF EXISTS is_night_worker;
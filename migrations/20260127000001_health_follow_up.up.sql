@@ -0,0 +1,55 @@
+-- ============================================================
+-- 健康診断の受診計画と有所見者の事後措置
+-- ============================================================
+
+-- 深夜業に従事する従業員（6か月以内ごとに1回の健康診断の対象）
+ALTER TABLE employees
+ADD COLUMN IF NOT EXISTS is_night_worker BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- 深夜業従事者のみが対象の健康診断種別
+ALTER TABLE health_checkup_types
+ADD COLUMN IF NOT EXISTS is_night_work BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE health_checkup_types SET is_night_work = TRUE WHERE name = '深夜業健康診断';
+
+-- 事後措置テーブル（要精密検査・要治療などの健康診断1件につき1件）
+CREATE TABLE IF NOT EXISTS health_checkup_follow_ups (
+    id SERIAL PRIMARY KEY,
+    health_checkup_history_id INTEGER NOT NULL UNIQUE
+        REFERENCES health_checkup_history(id) ON DELETE CASCADE,
+    employee_id INTEGER NOT NULL
+        REFERENCES employees(id) ON DELETE CASCADE,
+    referral_date DATE, -- 受診勧奨日
+    re_exam_date DATE, -- 精密検査・再検査の受診日
+    re_exam_institution VARCHAR(200), -- 精密検査・再検査の医療機関
+    re_exam_result TEXT, -- 精密検査・再検査の結果
+    doctor_opinion TEXT, -- 乗務の可否についての医師の意見
+    work_decision VARCHAR(30), -- 就業判定
+    decision_date DATE, -- 就業判定日
+    notes TEXT,
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    updated_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    CONSTRAINT chk_health_checkup_follow_ups_work_decision CHECK (
+        work_decision IS NULL OR work_decision IN ('NORMAL', 'RESTRICTED', 'SUSPENDED')
+    ),
+    -- 就業判定には判定日が必要
+    CONSTRAINT chk_health_checkup_follow_ups_decision_date CHECK (
+        work_decision IS NULL OR decision_date IS NOT NULL
+    ),
+    CONSTRAINT chk_health_checkup_follow_ups_dates CHECK (
+        referral_date IS NULL OR re_exam_date IS NULL OR referral_date <= re_exam_date
+    )
+);
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_health_checkup_follow_ups_employee_id
+ON health_checkup_follow_ups(employee_id);
+CREATE INDEX IF NOT EXISTS idx_health_checkup_history_employee_type_date
+ON health_checkup_history(employee_id, health_checkup_type_id, checkup_date);
+
+-- コメント追加
+COMMENT ON COLUMN employees.is_night_worker IS '深夜業に従事するか（健康診断を6か月以内ごとに実施）';
+COMMENT ON COLUMN health_checkup_types.is_night_work IS '深夜業従事者のみが対象の健康診断か';
+COMMENT ON TABLE health_checkup_follow_ups IS '健康診断の事後措置（受診勧奨・精密検査・医師の意見・就業判定）';
+COMMENT ON COLUMN health_checkup_follow_ups.work_decision IS '就業判定（NORMAL: 通常勤務, RESTRICTED: 就業制限, SUSPENDED: 乗務禁止）';
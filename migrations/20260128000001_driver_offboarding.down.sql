@@ -0,0 +1,3 @@
+-- 運転者の選任解除の記録を削除
+DROP INDEX IF EXISTS idx_driver_deselections_employee_id;
+DROP TABLE IF EXISTS driver_deselections;
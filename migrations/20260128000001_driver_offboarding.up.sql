@@ -0,0 +1,29 @@
+-- ============================================================
+-- 運転者の選任解除・退職の手続き
+-- ============================================================
+
+-- 選任解除の記録（運転者台帳は解任後3年間保存する）
+CREATE TABLE IF NOT EXISTS driver_deselections (
+    id SERIAL PRIMARY KEY,
+    employee_id INTEGER NOT NULL
+        REFERENCES employees(id) ON DELETE CASCADE,
+    kind VARCHAR(20) NOT NULL
+        CHECK (kind IN ('DESELECTION', 'RETIREMENT')), -- 選任解除のみ・退職
+    driver_start_date DATE NOT NULL, -- 選任開始日
+    driver_end_date DATE NOT NULL, -- 選任終了日（解任日）
+    reason TEXT NOT NULL, -- 解任の理由
+    created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    -- CHECK制約: 選任開始日 <= 選任終了日
+    CONSTRAINT chk_driver_deselections_dates CHECK (
+        driver_start_date <= driver_end_date
+    )
+);
+
+-- インデックスを追加
+CREATE INDEX IF NOT EXISTS idx_driver_deselections_employee_id
+ON driver_deselections(employee_id, driver_end_date);
+
+-- コメント追加
+COMMENT ON TABLE driver_deselections IS '運転者の選任解除の記録（運転者台帳の保存期間の起算日）';
+COMMENT ON COLUMN driver_deselections.kind IS '手続きの種類（DESELECTION: 選任解除のみ、RETIREMENT: 退職）';
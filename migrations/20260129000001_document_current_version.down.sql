@@ -0,0 +1,2 @@
+-- ドキュメントの現在の版の一意制約を削除
+DROP INDEX IF EXISTS idx_employee_documents_current_version;
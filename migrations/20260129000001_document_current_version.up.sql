@@ -0,0 +1,33 @@
+-- ============================================================
+-- ドキュメントの現在の版の一意制約
+-- 同じ従業員・種類・添付先の現在の版（is_active = TRUE）を1件に限る（「その他」は対象外）
+-- 現在の版がまだない状態で同時にアップロードされた場合も、2件目は一意制約で失敗させる
+-- ============================================================
+
+-- 既存データで現在の版が重複している場合は、最新の版以外を履歴にする
+WITH ranked AS (
+    SELECT
+        id,
+        FIRST_VALUE(id) OVER w AS latest_id,
+        FIRST_VALUE(uploaded_at) OVER w AS latest_uploaded_at,
+        ROW_NUMBER() OVER w AS rank
+    FROM employee_documents
+    WHERE is_active = TRUE AND category IS NOT NULL AND category <> 'OTHER'
+    WINDOW w AS (
+        PARTITION BY employee_id, category, related_type, related_id
+        ORDER BY version DESC, uploaded_at DESC, id DESC
+    )
+)
+UPDATE employee_documents d
+SET
+    is_active = FALSE,
+    superseded_by = r.latest_id,
+    superseded_at = r.latest_uploaded_at,
+    updated_at = CURRENT_TIMESTAMP
+FROM ranked r
+WHERE d.id = r.id AND r.rank > 1;
+
+-- 添付先のない書類（related_type / related_id が NULL）同士も重複とみなす
+CREATE UNIQUE INDEX IF NOT EXISTS idx_employee_documents_current_version
+ON employee_documents(employee_id, category, related_type, related_id) NULLS NOT DISTINCT
+WHERE is_active = TRUE AND category IS NOT NULL AND category <> 'OTHER';
@@ -0,0 +1,10 @@
+-- 添付先の記録を削除したら、ドキュメントは残して添付を外す（版の扱いを元に戻す）
+CREATE OR REPLACE FUNCTION clear_employee_document_relation()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE employee_documents
+    SET related_type = NULL, related_id = NULL, updated_at = CURRENT_TIMESTAMP
+    WHERE related_type = TG_ARGV[0] AND related_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
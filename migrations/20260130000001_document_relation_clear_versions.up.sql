@@ -0,0 +1,59 @@
+-- ============================================================
+-- 添付先の記録を削除したときの版の扱い
+-- 添付を外した書類は、同じ従業員・種類の添付先のない書類の版に合流させる。
+-- 現在の版が2件にならないよう、添付先のない書類に現在の版があれば外した側の現在の版は履歴にし、
+-- 版番号はアップロード順に振り直す（「その他」は版管理の対象外のため添付を外すのみ）
+-- ============================================================
+
+CREATE OR REPLACE FUNCTION clear_employee_document_relation()
+RETURNS TRIGGER AS $$
+DECLARE
+    chain RECORD;
+BEGIN
+    FOR chain IN
+        SELECT DISTINCT employee_id, category
+        FROM employee_documents
+        WHERE related_type = TG_ARGV[0] AND related_id = OLD.id
+          AND category IS NOT NULL AND category <> 'OTHER'
+    LOOP
+        -- 添付先のない書類に現在の版があれば、そちらを現在の版として残す
+        UPDATE employee_documents d
+        SET
+            is_active = FALSE,
+            superseded_by = current_version.id,
+            superseded_at = CURRENT_TIMESTAMP,
+            updated_at = CURRENT_TIMESTAMP
+        FROM (
+            SELECT id FROM employee_documents
+            WHERE employee_id = chain.employee_id AND category = chain.category
+              AND related_type IS NULL AND related_id IS NULL AND is_active = TRUE
+        ) current_version
+        WHERE d.employee_id = chain.employee_id AND d.category = chain.category
+          AND d.related_type = TG_ARGV[0] AND d.related_id = OLD.id
+          AND d.is_active = TRUE;
+
+        UPDATE employee_documents
+        SET related_type = NULL, related_id = NULL, updated_at = CURRENT_TIMESTAMP
+        WHERE employee_id = chain.employee_id AND category = chain.category
+          AND related_type = TG_ARGV[0] AND related_id = OLD.id;
+
+        -- 合流した版のまとまりの版番号をアップロード順に振り直す
+        WITH ordered AS (
+            SELECT id, ROW_NUMBER() OVER (ORDER BY uploaded_at, id) AS version
+            FROM employee_documents
+            WHERE employee_id = chain.employee_id AND category = chain.category
+              AND related_type IS NULL AND related_id IS NULL
+        )
+        UPDATE employee_documents d
+        SET version = o.version, updated_at = CURRENT_TIMESTAMP
+        FROM ordered o
+        WHERE d.id = o.id AND d.version <> o.version;
+    END LOOP;
+
+    -- 版管理の対象外の書類は添付を外すのみ
+    UPDATE employee_documents
+    SET related_type = NULL, related_id = NULL, updated_at = CURRENT_TIMESTAMP
+    WHERE related_type = TG_ARGV[0] AND related_id = OLD.id;
+    RETURN OLD;
+END;
+$$ LANGUAGE plpgsql;
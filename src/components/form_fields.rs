@@ -0,0 +1,156 @@
+//! 入力フォーム・詳細表示の共通部品
+//!
+//! 入力欄のクラス、空欄・数値・日付の変換、金額の表示と、ラベル付きの入力欄・詳細表示のコンポーネント
+
+use crate::modules::money;
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use std::fmt::Display;
+
+/// 入力欄の共通クラス
+pub const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-amber-400 focus:border-transparent transition-colors";
+
+/// 空文字をNoneとして扱う
+pub fn opt_string(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// 日付入力（YYYY-MM-DD）を変換
+pub fn opt_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// 数値入力を変換（空欄・不正値はNone）
+pub fn opt_number<T: std::str::FromStr>(value: &str) -> Option<T> {
+    value.trim().parse().ok()
+}
+
+/// 金額入力の表示値（未設定は空文字）
+pub fn amount_text(amount: Option<&impl Display>) -> String {
+    amount.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// 金額の表示（3桁区切り）
+pub fn format_yen(amount: &impl Display) -> String {
+    let rounded = money::to_f64(amount).round() as i64;
+    let digits = rounded.abs().to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if rounded < 0 {
+        format!("-{}円", grouped)
+    } else {
+        format!("{}円", grouped)
+    }
+}
+
+/// ラベル付きの入力欄
+#[component]
+pub fn InputLabelField(
+    label: String,
+    #[props(default)] required: bool,
+    children: Element,
+) -> Element {
+    rsx! {
+        div { class: "space-y-2",
+            label { class: "block text-sm font-medium text-gray-700",
+                "{label}"
+                if required {
+                    span { class: "text-red-500 ml-1", "*" }
+                }
+            }
+            {children}
+        }
+    }
+}
+
+/// テキスト入力
+#[component]
+pub fn TextField(
+    label: String,
+    value: String,
+    #[props(default)] required: bool,
+    #[props(default)] placeholder: String,
+    on_input: EventHandler<String>,
+) -> Element {
+    rsx! {
+        InputLabelField { label, required,
+            input {
+                class: INPUT_CLASS,
+                r#type: "text",
+                placeholder: "{placeholder}",
+                value: "{value}",
+                oninput: move |evt| on_input.call(evt.value()),
+            }
+        }
+    }
+}
+
+/// 日付入力
+#[component]
+pub fn DateField(
+    label: String,
+    value: Option<NaiveDate>,
+    #[props(default)] required: bool,
+    on_input: EventHandler<Option<NaiveDate>>,
+) -> Element {
+    rsx! {
+        InputLabelField { label, required,
+            input {
+                class: INPUT_CLASS,
+                r#type: "date",
+                value: "{value.map(|d| d.to_string()).unwrap_or_default()}",
+                oninput: move |evt| on_input.call(opt_date(&evt.value())),
+            }
+        }
+    }
+}
+
+/// 数値入力（金額・年式など）
+///
+/// 金額の型はサーバーとクライアントで異なるため、入力値は文字列のまま渡し、`opt_number`で変換する
+#[component]
+pub fn NumberField(
+    label: String,
+    value: String,
+    #[props(default = "1".to_string())] step: String,
+    on_input: EventHandler<String>,
+) -> Element {
+    rsx! {
+        InputLabelField { label,
+            input {
+                class: INPUT_CLASS,
+                r#type: "number",
+                min: "0",
+                step: "{step}",
+                value: "{value}",
+                oninput: move |evt| on_input.call(evt.value()),
+            }
+        }
+    }
+}
+
+/// 詳細表示用フィールド
+#[component]
+pub fn DetailField(label: String, value: String) -> Element {
+    rsx! {
+        div { class: "space-y-1.5",
+            p { class: "text-sm text-gray-500 font-semibold uppercase tracking-wide", "{label}" }
+            p { class: "text-base text-gray-800",
+                if value.is_empty() {
+                    span { class: "text-gray-400", "未設定" }
+                } else {
+                    "{value}"
+                }
+            }
+        }
+    }
+}
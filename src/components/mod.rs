@@ -1,10 +1,15 @@
 // 共通コンポーネントを配置するモジュール
 // 今後、複数のモジュールで使用される共通のUIコンポーネントを追加可能
 
+pub mod form_fields;
 pub mod icon;
 pub mod nav;
 pub mod search_bar;
 
+pub use form_fields::{
+    amount_text, format_yen, opt_number, opt_string, DateField, DetailField, InputLabelField,
+    NumberField, TextField, INPUT_CLASS,
+};
 pub use icon::{Icon, IconType};
 pub use nav::Navbar;
 pub use search_bar::SearchBar;
@@ -1,8 +1,24 @@
 use crate::components::{Icon, IconType};
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::{logout, use_current_user, use_permission};
 use dioxus::prelude::*;
 
 #[component]
 pub fn Navbar(show_menu: Signal<bool>, on_toggle: EventHandler<()>) -> Element {
+    let current_user = use_current_user();
+    let is_admin = use_permission(Permission::Admin);
+    let can_audit = use_permission(Permission::Audit);
+    let can_export = use_permission(Permission::EditEmployee);
+
+    // ログアウトしてログイン画面へ
+    let handle_logout = move |_| {
+        spawn(async move {
+            if logout().await.is_ok() {
+                navigator().replace(crate::routes::Route::LoginPage {});
+            }
+        });
+    };
+
     rsx! {
         nav { class: "flex flex-col h-full",
             // ヘッダー部分（トグルボタン）- メインヘッダーと高さを統一（h-14）
@@ -24,9 +40,9 @@ pub fn Navbar(show_menu: Signal<bool>, on_toggle: EventHandler<()>) -> Element {
             // ナビゲーションリンク
             div { class: "flex-1 px-3 py-4 space-y-1",
                 NavLink {
-                    to: crate::routes::Route::Homepage {},
+                    to: crate::routes::Route::ExpiryDashboardPage {},
                     icon_type: IconType::Home,
-                    label: "ホーム",
+                    label: "ダッシュボード",
                     show_label: show_menu()
                 }
                 NavLink {
@@ -35,6 +51,100 @@ pub fn Navbar(show_menu: Signal<bool>, on_toggle: EventHandler<()>) -> Element {
                     label: "従業員記録簿",
                     show_label: show_menu()
                 }
+                NavLink {
+                    to: crate::routes::Route::VehiclesPage {},
+                    icon_type: IconType::Truck,
+                    label: "車両管理",
+                    show_label: show_menu()
+                }
+                NavLink {
+                    to: crate::routes::Route::GuidanceCompliancePage {},
+                    icon_type: IconType::Checklist,
+                    label: "指導教育",
+                    show_label: show_menu()
+                }
+                NavLink {
+                    to: crate::routes::Route::AptitudeSchedulePage {},
+                    icon_type: IconType::Calendar,
+                    label: "適性診断",
+                    show_label: show_menu()
+                }
+                NavLink {
+                    to: crate::routes::Route::HealthFollowUpPage {},
+                    icon_type: IconType::Heart,
+                    label: "健康管理",
+                    show_label: show_menu()
+                }
+                NavLink {
+                    to: crate::routes::Route::ClientsPage {},
+                    icon_type: IconType::Building,
+                    label: "取引先",
+                    show_label: show_menu()
+                }
+                if can_export() {
+                    NavLink {
+                        to: crate::routes::Route::ExportPage {},
+                        icon_type: IconType::Download,
+                        label: "データ出力",
+                        show_label: show_menu()
+                    }
+                }
+                if can_audit() {
+                    NavLink {
+                        to: crate::routes::Route::AuditLogPage {},
+                        icon_type: IconType::History,
+                        label: "監査ログ",
+                        show_label: show_menu()
+                    }
+                    NavLink {
+                        to: crate::routes::Route::RetentionPage {},
+                        icon_type: IconType::Archive,
+                        label: "保管期限",
+                        show_label: show_menu()
+                    }
+                }
+                if is_admin() {
+                    NavLink {
+                        to: crate::routes::Route::UsersPage {},
+                        icon_type: IconType::Lock,
+                        label: "ユーザー管理",
+                        show_label: show_menu()
+                    }
+                    NavLink {
+                        to: crate::routes::Route::SettingsPage {},
+                        icon_type: IconType::Settings,
+                        label: "組織設定",
+                        show_label: show_menu()
+                    }
+                }
+            }
+
+            // ログインユーザー・ログアウト
+            div { class: "px-3 py-3 border-t border-gray-200",
+                if show_menu() {
+                    if let Some(user) = current_user() {
+                        div { class: "px-3 pb-2",
+                            p { class: "text-sm font-medium text-gray-800 truncate", "{user.display_name}" }
+                            p { class: "text-xs text-gray-500", "{user.role.display_name()}" }
+                        }
+                    }
+                }
+                button {
+                    class: if show_menu() {
+                        "group flex items-center w-full px-3 py-2 rounded-lg transition-colors hover:bg-amber-200"
+                    } else {
+                        "group flex items-center justify-center w-full px-3 py-2 rounded-lg transition-colors hover:bg-amber-200"
+                    },
+                    title: "ログアウト",
+                    onclick: handle_logout,
+                    Icon {
+                        icon_type: IconType::Logout,
+                        class: Some("size-5 shrink-0 text-gray-600 group-hover:text-amber-700".to_string())
+                    }
+                    if show_menu() {
+                        span { class: "ml-3 text-sm font-medium text-gray-700 group-hover:text-amber-900 whitespace-nowrap", "ログアウト" }
+                    }
+                }
             }
 
             // フッター（オプション）
@@ -1,8 +1,35 @@
 use dioxus::prelude::*;
 
+/// 検索ボックス
+/// 入力が`debounce_ms`ミリ秒止まった時点の値で`on_search`を呼ぶ
 #[component]
-pub fn SearchBar() -> Element {
+pub fn SearchBar(
+    #[props(default)] value: String,
+    #[props(default = "検索...".to_string())] placeholder: String,
+    #[props(default = 300)] debounce_ms: u32,
+    on_search: EventHandler<String>,
+) -> Element {
     const SEARCH_ICON: &str = "M15.5 14h-.79l-.28-.27A6.47 6.47 0 0 0 16 9.5A6.5 6.5 0 1 0 9.5 16c1.61 0 3.09-.59 4.23-1.57l.27.28v.79l5 4.99L20.49 19zm-6 0C7.01 14 5 11.99 5 9.5S7.01 5 9.5 5S14 7.01 14 9.5S11.99 14 9.5 14";
+    let mut text = use_signal(|| value.clone());
+    // 入力のたびに増やし、待機後も変わっていなければ最新の入力とみなす
+    let mut generation = use_signal(|| 0u32);
+
+    let handle_input = move |evt: FormEvent| {
+        let query = evt.value();
+        text.set(query.clone());
+        *generation.write() += 1;
+        let current = generation();
+        spawn(async move {
+            #[cfg(feature = "web")]
+            gloo_timers::future::TimeoutFuture::new(debounce_ms).await;
+            #[cfg(not(feature = "web"))]
+            let _ = debounce_ms;
+            if generation() == current {
+                on_search.call(query);
+            }
+        });
+    };
+
     rsx! {
         div { class: "col-span-1 col-start-2 flex items-center gap-2 border border-gray-300 rounded p-2",
             label { r#for: "search-input",
@@ -14,10 +41,12 @@ pub fn SearchBar() -> Element {
                 }
             }
             input {
-                class: "outline-none",
+                class: "outline-none w-72",
                 id: "search-input",
                 r#type: "search",
-                placeholder: "Search employees...",
+                placeholder: "{placeholder}",
+                value: "{text}",
+                oninput: handle_input,
             }
         }
     }
@@ -24,26 +24,66 @@ async fn main() {
     use axum::extract::DefaultBodyLimit;
     use dioxus_server::DioxusRouterExt;
     use tower::ServiceBuilder;
+    use tower_cookies::CookieManagerLayer;
 
     // 環境変数を読み込み
     dotenvy::dotenv().ok();
     println!("🚀 Starting server with lazy database initialization...");
 
-    // S3クライアントを初期化
-    let s3_bucket =
-        std::env::var("S3_BUCKET_NAME").unwrap_or_else(|_| "rust-ws-app-documents".to_string());
+    // ファイル保存先を初期化（STORAGE_BACKENDで S3 / S3互換 / ローカル を切り替え）
+    // 設定の誤りや初期化の失敗はファイルの保存・取得がすべて失敗するため、起動を中止する
+    let storage_config = match modules::s3_service::StorageConfig::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("❌ Invalid storage configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = modules::s3_service::init_storage(storage_config).await {
+        eprintln!("❌ Failed to initialize storage: {}", e);
+        std::process::exit(1);
+    }
+
+    // 保存先とドキュメントの突き合わせ（`reconcile-storage [--repair]`）を実行して終了
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some(modules::employee_documents::reconcile::COMMAND) {
+        let repair = args.iter().any(|a| a == "--repair");
+        std::process::exit(modules::employee_documents::reconcile::run_command(repair).await);
+    }
 
-    modules::s3_service::init_s3_client(s3_bucket).await;
-    println!("📦 S3 client initialized");
+    // 初期管理者ユーザーを作成（INITIAL_ADMIN_PASSWORDが設定されている場合のみ）
+    if let Err(e) = modules::auth::session::ensure_initial_admin().await {
+        eprintln!("⚠️ Failed to create initial admin user: {}", e);
+    }
 
     // サーバーアドレスを決定
     let address = dioxus_cli_config::fullstack_address_or_localhost();
 
-    // Axumルーターをセットアップ（ボディサイズ制限を20MBに設定）
+    // Axumルーターをセットアップ（サーバー関数のボディサイズ制限を10MBに設定、セッションCookieを管理）
     let router = axum::Router::new()
+        .route(
+            modules::employee_documents::proxy::ROUTE,
+            axum::routing::get(modules::employee_documents::proxy::serve_document),
+        )
+        .route(
+            modules::employee_documents::archive::ROUTE,
+            axum::routing::get(modules::employee_documents::archive::serve_archive),
+        )
+        .route(
+            modules::exports::download::ROUTE,
+            axum::routing::get(modules::exports::download::serve_export),
+        )
+        // 書類のアップロードはファイルを種類別、その他の項目を項目ごとの上限で打ち切るため、全体の上限は外す
+        .route(
+            modules::employee_documents::upload::ROUTE,
+            axum::routing::post(modules::employee_documents::upload::upload_documents)
+                .layer(DefaultBodyLimit::disable()),
+        )
         .serve_dioxus_application(dioxus_server::ServeConfig::new(), App)
         .layer(
-            ServiceBuilder::new().layer(DefaultBodyLimit::max(20 * 1024 * 1024)), // 20MB
+            ServiceBuilder::new()
+                .layer(DefaultBodyLimit::max(10 * 1024 * 1024)) // 10MB（CSV取込のBase64を含む）
+                .layer(CookieManagerLayer::new()),
         );
 
     // サーバーを起動
@@ -3,9 +3,14 @@ use super::models::{Address, CreateAddress, UpdateAddress};
 use super::repository::AddressRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の住所一覧を取得
 #[server]
 pub async fn get_addresses_by_employee(employee_id: i32) -> Result<Vec<Address>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     AddressRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -14,6 +19,8 @@ pub async fn get_addresses_by_employee(employee_id: i32) -> Result<Vec<Address>,
 /// 従業員の現住所を取得
 #[server]
 pub async fn get_current_address(employee_id: i32) -> Result<Option<Address>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     AddressRepository::get_current_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -22,6 +29,8 @@ pub async fn get_current_address(employee_id: i32) -> Result<Option<Address>, Se
 /// 住所を作成
 #[server]
 pub async fn create_address(data: CreateAddress) -> Result<Address, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     AddressRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -30,6 +39,8 @@ pub async fn create_address(data: CreateAddress) -> Result<Address, ServerFnErro
 /// 住所を更新
 #[server]
 pub async fn update_address(data: UpdateAddress) -> Result<Address, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     AddressRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -38,6 +49,8 @@ pub async fn update_address(data: UpdateAddress) -> Result<Address, ServerFnErro
 /// 住所を削除
 #[server]
 pub async fn delete_address(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     AddressRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -2,6 +2,8 @@
 use super::models::{Address, CreateAddress, UpdateAddress};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct AddressRepository;
@@ -73,7 +75,7 @@ impl AddressRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let address = sqlx::query_as!(
             Address,
             r#"INSERT INTO addresses (employee_id, postal_code, prefecture, city, street, building, start_date, is_current, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -88,12 +90,18 @@ impl AddressRepository {
             data.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create("addresses", address.id, Some(address.employee_id), &address)
+            .await;
+
+        Ok(address)
     }
 
     /// 住所を更新
     pub async fn update(data: UpdateAddress) -> Result<Address, sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(data.id).await?;
 
         // 現住所として更新する場合、他の現住所を解除
         if data.is_current {
@@ -109,7 +117,7 @@ impl AddressRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let address = sqlx::query_as!(
             Address,
             r#"UPDATE addresses
                SET postal_code = $2, prefecture = $3, city = $4, street = $5, building = $6,
@@ -127,15 +135,36 @@ impl AddressRepository {
             data.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "addresses",
+            address.id,
+            Some(address.employee_id),
+            old.as_ref(),
+            &address,
+        )
+        .await;
+
+        Ok(address)
     }
 
     /// 住所を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM addresses WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "addresses",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
@@ -6,11 +6,16 @@ use super::models::{
 use super::repository::AptitudeCheckupHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の適性診断履歴を取得
 #[server]
 pub async fn get_aptitude_checkup_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<AptitudeCheckupHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     AptitudeCheckupHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -21,6 +26,8 @@ pub async fn get_aptitude_checkup_history_by_employee(
 pub async fn get_active_aptitude_checkup_history(
     employee_id: i32,
 ) -> Result<Vec<AptitudeCheckupHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     AptitudeCheckupHistoryRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -31,6 +38,8 @@ pub async fn get_active_aptitude_checkup_history(
 pub async fn create_aptitude_checkup_history(
     data: CreateAptitudeCheckupHistory,
 ) -> Result<AptitudeCheckupHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.checkup_date >= expiration_date {
@@ -50,6 +59,8 @@ pub async fn create_aptitude_checkup_history(
 pub async fn update_aptitude_checkup_history(
     data: UpdateAptitudeCheckupHistory,
 ) -> Result<AptitudeCheckupHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.checkup_date >= expiration_date {
@@ -67,6 +78,8 @@ pub async fn update_aptitude_checkup_history(
 /// 適性診断履歴を削除
 #[server]
 pub async fn delete_aptitude_checkup_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     AptitudeCheckupHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -75,6 +88,8 @@ pub async fn delete_aptitude_checkup_history(id: i32) -> Result<(), ServerFnErro
 /// 全適性診断種別を取得
 #[server]
 pub async fn get_all_aptitude_checkup_types() -> Result<Vec<AptitudeCheckupType>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     AptitudeCheckupHistoryRepository::get_all_aptitude_checkup_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
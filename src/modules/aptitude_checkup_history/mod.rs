@@ -1,7 +1,7 @@
 pub mod handlers;
 pub mod models;
 #[cfg(feature = "server")]
-mod repository;
+pub mod repository;
 
 pub use handlers::{
     create_aptitude_checkup_history, delete_aptitude_checkup_history,
@@ -55,6 +55,10 @@ pub struct AptitudeCheckupType {
     pub target_age_min: Option<i32>,
     pub target_age_max: Option<i32>,
     pub required_frequency_years: Option<i32>,
+    /// 受診計画の対象とする義務の診断か
+    pub is_mandatory: bool,
+    /// 初任運転者が選任前に受診する診断か（初任診断）
+    pub is_initial: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
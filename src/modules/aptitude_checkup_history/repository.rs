@@ -5,6 +5,8 @@ use super::models::{
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct AptitudeCheckupHistoryRepository;
@@ -75,7 +77,7 @@ impl AptitudeCheckupHistoryRepository {
         data: CreateAptitudeCheckupHistory,
     ) -> Result<AptitudeCheckupHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             AptitudeCheckupHistory,
             r#"INSERT INTO aptitude_checkup_history
                (employee_id, aptitude_checkup_type_id, checkup_date, expiration_date,
@@ -94,7 +96,17 @@ impl AptitudeCheckupHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "aptitude_checkup_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 適性診断履歴を更新
@@ -102,7 +114,8 @@ impl AptitudeCheckupHistoryRepository {
         data: UpdateAptitudeCheckupHistory,
     ) -> Result<AptitudeCheckupHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let history = sqlx::query_as!(
             AptitudeCheckupHistory,
             r#"UPDATE aptitude_checkup_history
                SET aptitude_checkup_type_id = $2, checkup_date = $3, expiration_date = $4,
@@ -122,15 +135,36 @@ impl AptitudeCheckupHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "aptitude_checkup_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 適性診断履歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM aptitude_checkup_history WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "aptitude_checkup_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -140,7 +174,8 @@ impl AptitudeCheckupHistoryRepository {
         sqlx::query_as!(
             AptitudeCheckupType,
             r#"SELECT id, name, description, target_age_min, target_age_max,
-                      required_frequency_years, created_at, updated_at
+                      required_frequency_years, is_mandatory, is_initial,
+                      created_at, updated_at
                FROM aptitude_checkup_types
                ORDER BY id"#
         )
@@ -0,0 +1,216 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::aptitude_schedule::handlers::get_aptitude_schedule;
+use crate::modules::aptitude_schedule::models::{
+    AptitudeRequirement, AptitudeScheduleItem, DriverAptitudeSchedule,
+};
+use crate::modules::guidance_compliance::ComplianceStatus;
+use crate::modules::organization::handlers::get_offices;
+use crate::Route;
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str = "flex items-center h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+
+/// 適性診断の受診計画（全社の運転者を対応が必要な順に一覧）
+#[component]
+pub fn AptitudeSchedulePage() -> Element {
+    let mut office_id = use_signal(|| None::<i32>);
+    let mut action_only = use_signal(|| true);
+
+    let offices = use_resource(|| async move { get_offices().await });
+    let schedule =
+        use_resource(
+            move || async move { get_aptitude_schedule(office_id(), action_only()).await },
+        );
+
+    rsx! {
+        document::Title { "適性診断の受診計画" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "適性診断の受診計画" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-7xl mx-auto space-y-6",
+                    // 抽出条件
+                    div { class: SECTION_CLASS,
+                        div { class: "grid grid-cols-1 md:grid-cols-4 gap-4 items-end",
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "営業所" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| office_id.set(evt.value().parse().ok()),
+                                    option { value: "", "すべて" }
+                                    if let Some(Ok(offices)) = &*offices.read_unchecked() {
+                                        for office in offices.iter() {
+                                            option {
+                                                value: "{office.id}",
+                                                selected: office_id() == Some(office.id),
+                                                "{office.name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            label { class: "flex items-center gap-2 text-sm text-gray-700 md:col-span-3",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: action_only(),
+                                    onchange: move |evt| action_only.set(evt.checked()),
+                                }
+                                "受診が必要な運転者のみ表示"
+                            }
+                        }
+                    }
+
+                    match &*schedule.read_unchecked() {
+                        Some(Ok(list)) => rsx! {
+                            ScheduleSummary { drivers: list.drivers.clone() }
+                            if list.requirements.is_empty() {
+                                div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                                    "義務の適性診断種別が設定されていません"
+                                }
+                            } else if list.drivers.is_empty() {
+                                div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                                    "該当する運転者はいません"
+                                }
+                            } else {
+                                ScheduleTable {
+                                    requirements: list.requirements.clone(),
+                                    drivers: list.drivers.clone(),
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700",
+                                "エラーが発生しました: {e}"
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex justify-center py-12",
+                                div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 状況ごとの人数
+#[component]
+fn ScheduleSummary(drivers: Vec<DriverAptitudeSchedule>) -> Element {
+    let statuses = [
+        ComplianceStatus::Overdue,
+        ComplianceStatus::NeverTaken,
+        ComplianceStatus::DueSoon,
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for status in statuses {
+                {
+                    let count = drivers.iter().filter(|d| d.worst_status() == status).count();
+                    rsx! {
+                        div { class: "rounded-xl p-5 {status.badge_class()}",
+                            p { class: "text-sm font-medium", "{status.display_name()}" }
+                            p { class: "text-3xl font-bold mt-1", "{count}名" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ScheduleTable(
+    requirements: Vec<AptitudeRequirement>,
+    drivers: Vec<DriverAptitudeSchedule>,
+) -> Element {
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "overflow-x-auto",
+                table { class: "min-w-full text-sm",
+                    thead {
+                        tr { class: "text-left text-gray-600 border-b border-gray-200",
+                            th { class: "py-2 px-3 whitespace-nowrap", "運転者" }
+                            th { class: "py-2 px-3 whitespace-nowrap", "営業所" }
+                            th { class: "py-2 px-3 whitespace-nowrap", "年齢" }
+                            th { class: "py-2 px-3 whitespace-nowrap", "次回期限" }
+                            for requirement in requirements.iter() {
+                                th { key: "{requirement.type_id}", class: "py-2 px-3 whitespace-nowrap",
+                                    "{requirement.name}"
+                                    if let Some(years) = requirement.required_frequency_years {
+                                        span { class: "block text-xs font-normal text-gray-400", "{years}年ごと" }
+                                    } else if requirement.is_initial {
+                                        span { class: "block text-xs font-normal text-gray-400", "選任前" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for driver in drivers {
+                            DriverScheduleRow { key: "{driver.employee_id}", driver }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DriverScheduleRow(driver: DriverAptitudeSchedule) -> Element {
+    let age = driver.age.map(|a| format!("{}歳", a)).unwrap_or_default();
+    let next_due = driver
+        .next_due_date()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "-".to_string());
+    let office = driver.office_name.clone().unwrap_or_default();
+
+    rsx! {
+        tr { class: "border-b border-gray-100 align-top",
+            td { class: "py-2 px-3 whitespace-nowrap",
+                Link {
+                    to: Route::EmployeeDetailPage { id: driver.employee_id },
+                    class: "text-amber-700 hover:underline",
+                    "{driver.employee_code} {driver.employee_name}"
+                }
+            }
+            td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{office}" }
+            td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{age}" }
+            td { class: "py-2 px-3 text-gray-700 whitespace-nowrap font-semibold", "{next_due}" }
+            for item in driver.items {
+                td { key: "{item.type_id}", class: "py-2 px-3",
+                    ScheduleCell { item }
+                }
+            }
+        }
+    }
+}
+
+/// 1つの適性診断の状況（状況・最終受診日・期限）
+#[component]
+pub(super) fn ScheduleCell(item: AptitudeScheduleItem) -> Element {
+    let format = |d: chrono::NaiveDate| d.format("%Y-%m-%d").to_string();
+
+    rsx! {
+        div { class: "space-y-0.5 whitespace-nowrap",
+            span { class: "px-2 py-0.5 rounded text-xs font-semibold {item.status.badge_class()}",
+                "{item.status.display_name()}"
+            }
+            if let Some(last) = item.last_date {
+                p { class: "text-xs text-gray-500", "受診 {format(last)}" }
+            }
+            if let Some(due) = item.due_date {
+                p { class: "text-xs text-gray-500", "期限 {format(due)}" }
+            }
+            if let Some(reason) = &item.reason {
+                p { class: "text-xs text-gray-400", "{reason}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::aptitude_schedule_page::ScheduleCell;
+use crate::modules::aptitude_schedule::handlers::get_employee_aptitude_schedule;
+use dioxus::prelude::*;
+
+/// 適性診断の受診計画（従業員詳細用）
+#[component]
+pub fn DriverAptitudeSection(employee_id: i32) -> Element {
+    let schedule =
+        use_resource(move || async move { get_employee_aptitude_schedule(employee_id).await });
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                "適性診断の受診計画"
+            }
+
+            match &*schedule.read_unchecked() {
+                Some(Ok(Some(result))) => rsx! {
+                    if let Some(age) = result.driver.age {
+                        p { class: "text-sm text-gray-600 mb-3", "現在 {age}歳" }
+                    }
+                    div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                        for item in result.driver.items.iter() {
+                            div { key: "{item.type_id}", class: "border border-gray-200 rounded-lg p-3",
+                                p { class: "text-xs text-gray-500 font-semibold mb-1", "{item.type_name}" }
+                                ScheduleCell { item: item.clone() }
+                            }
+                        }
+                    }
+                },
+                Some(Ok(None)) => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "選任中の運転者ではないため判定していません" }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "受診計画の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
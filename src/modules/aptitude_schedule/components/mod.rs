@@ -0,0 +1,7 @@
+// 適性診断の受診計画のコンポーネント
+
+mod aptitude_schedule_page;
+mod driver_aptitude_section;
+
+pub use aptitude_schedule_page::AptitudeSchedulePage;
+pub use driver_aptitude_section::DriverAptitudeSection;
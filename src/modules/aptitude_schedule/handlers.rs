@@ -0,0 +1,90 @@
+//! 適性診断の受診計画のハンドラー層（サーバー関数）
+
+use super::models::{AptitudeScheduleList, EmployeeAptitudeSchedule};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{
+    schedule_driver, sort_by_urgency, AptitudeRequirement, DriverAptitudeSchedule,
+};
+#[cfg(feature = "server")]
+use super::repository::AptitudeScheduleRepository;
+#[cfg(feature = "server")]
+use crate::modules::aptitude_checkup_history::repository::AptitudeCheckupHistoryRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// 全社の適性診断の受診計画を取得（営業所で絞り込み可、対応が必要な順）
+#[server]
+pub async fn get_aptitude_schedule(
+    office_id: Option<i32>,
+    action_only: bool,
+) -> Result<AptitudeScheduleList, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let (requirements, mut drivers) = load_schedule(office_id, None).await?;
+    if action_only {
+        drivers.retain(|d| d.worst_status().needs_action());
+    }
+    sort_by_urgency(&mut drivers);
+    Ok(AptitudeScheduleList {
+        as_of: chrono::Local::now().date_naive(),
+        requirements,
+        drivers,
+    })
+}
+
+/// 従業員1人の適性診断の受診計画を取得（運転者でない場合はNone）
+#[server]
+pub async fn get_employee_aptitude_schedule(
+    employee_id: i32,
+) -> Result<Option<EmployeeAptitudeSchedule>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let (requirements, drivers) = load_schedule(None, Some(employee_id)).await?;
+    Ok(drivers
+        .into_iter()
+        .next()
+        .map(|driver| EmployeeAptitudeSchedule {
+            requirements,
+            driver,
+        }))
+}
+
+/// 義務の適性診断と運転者ごとの受診計画を読み込む
+#[cfg(feature = "server")]
+async fn load_schedule(
+    office_id: Option<i32>,
+    employee_id: Option<i32>,
+) -> Result<(Vec<AptitudeRequirement>, Vec<DriverAptitudeSchedule>), ServerFnError> {
+    let to_error = |e: sqlx::Error| ServerFnError::new(e.to_string());
+
+    let requirements: Vec<AptitudeRequirement> =
+        AptitudeCheckupHistoryRepository::get_all_aptitude_checkup_types()
+            .await
+            .map_err(to_error)?
+            .iter()
+            .filter_map(AptitudeRequirement::from_type)
+            .collect();
+    let drivers = AptitudeScheduleRepository::get_drivers(office_id, employee_id)
+        .await
+        .map_err(to_error)?;
+    let employee_ids: Vec<i32> = drivers.iter().map(|d| d.employee_id).collect();
+    let records = AptitudeScheduleRepository::get_records(&employee_ids)
+        .await
+        .map_err(to_error)?;
+
+    let today = chrono::Local::now().date_naive();
+    let drivers = drivers
+        .into_iter()
+        .map(|driver| {
+            let own: Vec<_> = records
+                .iter()
+                .filter(|r| r.employee_id == driver.employee_id)
+                .copied()
+                .collect();
+            schedule_driver(&requirements, driver, &own, today)
+        })
+        .collect();
+    Ok((requirements, drivers))
+}
@@ -0,0 +1,14 @@
+//! 適性診断の受診計画モジュール
+//! 生年月日と受診履歴から、初任診断・適齢診断など義務の適性診断の次回期限を求め、
+//! 受診が遅れている運転者を抽出する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::{AptitudeSchedulePage, DriverAptitudeSection};
+pub use handlers::*;
+pub use models::{AptitudeScheduleList, DriverAptitudeSchedule, EmployeeAptitudeSchedule};
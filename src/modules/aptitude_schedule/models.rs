@@ -0,0 +1,410 @@
+//! 適性診断の受診計画のモデル定義
+
+use crate::modules::aptitude_checkup_history::AptitudeCheckupType;
+use crate::modules::guidance_compliance::models::add_months;
+use crate::modules::guidance_compliance::ComplianceStatus;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 次回の受診期限が近い場合に「期限間近」とみなす日数
+pub const DUE_SOON_DAYS: i64 = 60;
+
+/// 対象年齢に達してから初回の受診までの猶予（年）
+pub const FIRST_CHECKUP_GRACE_YEARS: u32 = 1;
+
+/// 受診計画の対象とする診断（義務の診断のみ）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AptitudeRequirement {
+    pub type_id: i32,
+    pub name: String,
+    pub target_age_min: Option<i32>,
+    pub target_age_max: Option<i32>,
+    pub required_frequency_years: Option<i32>,
+    /// 初任診断（選任前に受診）
+    pub is_initial: bool,
+}
+
+impl AptitudeRequirement {
+    /// 適性診断種別から受診計画の対象を作る（義務でない種別はNone）
+    pub fn from_type(t: &AptitudeCheckupType) -> Option<Self> {
+        t.is_mandatory.then(|| Self {
+            type_id: t.id,
+            name: t.name.clone(),
+            target_age_min: t.target_age_min,
+            target_age_max: t.target_age_max,
+            required_frequency_years: t.required_frequency_years.filter(|y| *y > 0),
+            is_initial: t.is_initial,
+        })
+    }
+}
+
+/// 適性診断の受診記録
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct AptitudeRecord {
+    pub employee_id: i32,
+    pub aptitude_checkup_type_id: i32,
+    pub checkup_date: NaiveDate,
+}
+
+/// 受診計画の対象の運転者（選任中・選任予定）
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct AptitudeDriverRow {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    pub driver_start_date: Option<NaiveDate>,
+}
+
+/// 1つの診断の受診状況
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AptitudeScheduleItem {
+    pub type_id: i32,
+    pub type_name: String,
+    pub status: ComplianceStatus,
+    /// 最後に受診した日
+    pub last_date: Option<NaiveDate>,
+    /// 次回の受診期限
+    pub due_date: Option<NaiveDate>,
+    /// 判定の根拠（対象年齢に達する日・選任日など）
+    pub reason: Option<String>,
+}
+
+/// 運転者1人の受診計画
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriverAptitudeSchedule {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub birth_date: Option<NaiveDate>,
+    /// 判定日時点の年齢
+    pub age: Option<u32>,
+    pub driver_start_date: Option<NaiveDate>,
+    pub items: Vec<AptitudeScheduleItem>,
+}
+
+impl DriverAptitudeSchedule {
+    /// 最も対応が必要な状況
+    pub fn worst_status(&self) -> ComplianceStatus {
+        self.items
+            .iter()
+            .map(|i| i.status)
+            .min()
+            .unwrap_or(ComplianceStatus::NotRequired)
+    }
+
+    /// 対応が必要な診断のうち最も早い期限
+    pub fn next_due_date(&self) -> Option<NaiveDate> {
+        self.items
+            .iter()
+            .filter(|i| i.status != ComplianceStatus::NotRequired)
+            .filter_map(|i| i.due_date)
+            .min()
+    }
+}
+
+/// 全社の受診計画
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AptitudeScheduleList {
+    /// 判定日
+    pub as_of: NaiveDate,
+    pub requirements: Vec<AptitudeRequirement>,
+    /// 対応が必要な順（状況→次回期限→社員コード）
+    pub drivers: Vec<DriverAptitudeSchedule>,
+}
+
+/// 従業員1人の受診計画
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeAptitudeSchedule {
+    pub requirements: Vec<AptitudeRequirement>,
+    pub driver: DriverAptitudeSchedule,
+}
+
+fn add_years(date: NaiveDate, years: u32) -> NaiveDate {
+    add_months(date, years * 12)
+}
+
+/// 運転者1人・診断1つの受診状況を判定する
+///
+/// * 初任診断: 受診歴がなければ選任日を期限とする
+/// * 対象年齢の下限がある診断（適齢診断）: 下限の年齢に達した日から1年以内に初回、
+///   その後は最後の受診日から受診間隔ごと。年齢に達する前の受診は数えない
+/// * 対象年齢の上限を超えた場合は対象外
+/// * その他: 最後の受診日から受診間隔ごと（受診間隔がなければ1回で足りる）
+pub fn evaluate(
+    requirement: &AptitudeRequirement,
+    birth_date: Option<NaiveDate>,
+    driver_start_date: Option<NaiveDate>,
+    records: &[AptitudeRecord],
+    today: NaiveDate,
+) -> AptitudeScheduleItem {
+    let item = |status, last_date, due_date, reason: Option<String>| AptitudeScheduleItem {
+        type_id: requirement.type_id,
+        type_name: requirement.name.clone(),
+        status,
+        last_date,
+        due_date,
+        reason,
+    };
+    let format = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    let is_age_limited =
+        requirement.target_age_min.is_some() || requirement.target_age_max.is_some();
+    if is_age_limited && birth_date.is_none() {
+        return item(
+            ComplianceStatus::NotRequired,
+            None,
+            None,
+            Some("生年月日が未登録のため判定できません".to_string()),
+        );
+    }
+    let age = birth_date.map(|b| today.years_since(b).unwrap_or(0) as i32);
+    if let (Some(max), Some(age)) = (requirement.target_age_max, age) {
+        if age > max {
+            return item(ComplianceStatus::NotRequired, None, None, None);
+        }
+    }
+
+    // 対象年齢に達した日（以後の受診のみ数える）
+    let eligible_from = match (requirement.target_age_min, birth_date) {
+        (Some(min), Some(birth)) => Some(add_years(birth, min.max(0) as u32)),
+        _ => None,
+    };
+    let last_date = records
+        .iter()
+        .filter(|r| r.aptitude_checkup_type_id == requirement.type_id)
+        .map(|r| r.checkup_date)
+        .filter(|d| eligible_from.is_none_or(|from| *d >= from))
+        .max();
+
+    if requirement.is_initial {
+        let Some(start) = driver_start_date else {
+            return item(ComplianceStatus::NotRequired, last_date, None, None);
+        };
+        let reason = Some(format!("選任日 {}", format(start)));
+        if last_date.is_some() {
+            return item(ComplianceStatus::Compliant, last_date, None, reason);
+        }
+        return item(
+            ComplianceStatus::by_due(start, false, today),
+            None,
+            Some(start),
+            reason,
+        );
+    }
+
+    if let Some(from) = eligible_from {
+        let reason = Some(format!(
+            "{}歳到達 {}",
+            requirement.target_age_min.unwrap_or_default(),
+            format(from)
+        ));
+        if from > today {
+            return item(ComplianceStatus::NotRequired, None, Some(from), reason);
+        }
+        if last_date.is_none() {
+            let due = add_years(from, FIRST_CHECKUP_GRACE_YEARS);
+            return item(
+                ComplianceStatus::by_due(due, false, today),
+                None,
+                Some(due),
+                reason,
+            );
+        }
+    }
+
+    let Some(last) = last_date else {
+        return item(ComplianceStatus::NeverTaken, None, None, None);
+    };
+    let Some(years) = requirement.required_frequency_years else {
+        return item(ComplianceStatus::Compliant, Some(last), None, None);
+    };
+    let due = add_years(last, years as u32);
+    item(
+        ComplianceStatus::by_next_due(due, today, DUE_SOON_DAYS),
+        Some(last),
+        Some(due),
+        None,
+    )
+}
+
+/// 運転者1人の受診計画
+pub fn schedule_driver(
+    requirements: &[AptitudeRequirement],
+    driver: AptitudeDriverRow,
+    records: &[AptitudeRecord],
+    today: NaiveDate,
+) -> DriverAptitudeSchedule {
+    let items = requirements
+        .iter()
+        .map(|r| {
+            evaluate(
+                r,
+                driver.birth_date,
+                driver.driver_start_date,
+                records,
+                today,
+            )
+        })
+        .collect();
+    DriverAptitudeSchedule {
+        employee_id: driver.employee_id,
+        employee_code: driver.employee_code,
+        employee_name: driver.employee_name,
+        office_id: driver.office_id,
+        office_name: driver.office_name,
+        birth_date: driver.birth_date,
+        age: driver.birth_date.and_then(|b| today.years_since(b)),
+        driver_start_date: driver.driver_start_date,
+        items,
+    }
+}
+
+/// 対応が必要な順に並べる（状況→次回期限→社員コード）
+pub fn sort_by_urgency(drivers: &mut [DriverAptitudeSchedule]) {
+    drivers.sort_by(|a, b| {
+        a.worst_status()
+            .cmp(&b.worst_status())
+            .then_with(|| match (a.next_due_date(), b.next_due_date()) {
+                (Some(x), Some(y)) => x.cmp(&y),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            })
+            .then_with(|| a.employee_code.cmp(&b.employee_code))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn elderly() -> AptitudeRequirement {
+        AptitudeRequirement {
+            type_id: 3,
+            name: "適齢診断（65歳以上）".to_string(),
+            target_age_min: Some(65),
+            target_age_max: None,
+            required_frequency_years: Some(3),
+            is_initial: false,
+        }
+    }
+
+    fn initial() -> AptitudeRequirement {
+        AptitudeRequirement {
+            type_id: 2,
+            name: "初任診断".to_string(),
+            target_age_min: None,
+            target_age_max: None,
+            required_frequency_years: None,
+            is_initial: true,
+        }
+    }
+
+    fn record(type_id: i32, checkup_date: NaiveDate) -> AptitudeRecord {
+        AptitudeRecord {
+            employee_id: 1,
+            aptitude_checkup_type_id: type_id,
+            checkup_date,
+        }
+    }
+
+    #[test]
+    fn test_elderly_checkup_schedule() {
+        let birth = Some(date(1961, 4, 10));
+        let req = elderly();
+
+        // 65歳到達前は対象外（到達日を表示）
+        let item = evaluate(&req, birth, None, &[], date(2026, 1, 1));
+        assert_eq!(item.status, ComplianceStatus::NotRequired);
+        assert_eq!(item.due_date, Some(date(2026, 4, 10)));
+
+        // 到達後1年以内は期限間近、過ぎたら未受診（到達前の受診は数えない）
+        let early = [record(3, date(2026, 3, 1))];
+        let item = evaluate(&req, birth, None, &early, date(2026, 6, 1));
+        assert_eq!(item.status, ComplianceStatus::DueSoon);
+        assert_eq!(item.due_date, Some(date(2027, 4, 10)));
+        let item = evaluate(&req, birth, None, &early, date(2027, 5, 1));
+        assert_eq!(item.status, ComplianceStatus::NeverTaken);
+
+        // 受診後は3年ごと
+        let taken = [record(3, date(2026, 9, 1))];
+        let item = evaluate(&req, birth, None, &taken, date(2027, 5, 1));
+        assert_eq!(item.status, ComplianceStatus::Compliant);
+        assert_eq!(item.due_date, Some(date(2029, 9, 1)));
+        let item = evaluate(&req, birth, None, &taken, date(2029, 10, 1));
+        assert_eq!(item.status, ComplianceStatus::Overdue);
+
+        // 生年月日が未登録なら判定しない
+        let item = evaluate(&req, None, None, &taken, date(2027, 5, 1));
+        assert_eq!(item.status, ComplianceStatus::NotRequired);
+        assert!(item.reason.is_some());
+    }
+
+    #[test]
+    fn test_initial_checkup_before_selection() {
+        let req = initial();
+        let start = Some(date(2026, 4, 1));
+
+        let item = evaluate(&req, None, start, &[], date(2026, 3, 20));
+        assert_eq!(item.status, ComplianceStatus::DueSoon);
+        assert_eq!(item.due_date, start);
+        let item = evaluate(&req, None, start, &[], date(2026, 4, 2));
+        assert_eq!(item.status, ComplianceStatus::NeverTaken);
+
+        let taken = [record(2, date(2026, 3, 15))];
+        let item = evaluate(&req, None, start, &taken, date(2026, 4, 2));
+        assert_eq!(item.status, ComplianceStatus::Compliant);
+
+        let item = evaluate(&req, None, None, &[], date(2026, 4, 2));
+        assert_eq!(item.status, ComplianceStatus::NotRequired);
+    }
+
+    #[test]
+    fn test_age_limit_and_next_due_date() {
+        let general = AptitudeRequirement {
+            type_id: 1,
+            name: "一般診断".to_string(),
+            target_age_min: None,
+            target_age_max: Some(64),
+            required_frequency_years: Some(5),
+            is_initial: false,
+        };
+        let item = evaluate(
+            &general,
+            Some(date(1950, 1, 1)),
+            None,
+            &[],
+            date(2026, 1, 1),
+        );
+        assert_eq!(item.status, ComplianceStatus::NotRequired);
+
+        let driver = AptitudeDriverRow {
+            employee_id: 1,
+            employee_code: "001".to_string(),
+            employee_name: "山田 太郎".to_string(),
+            office_id: None,
+            office_name: None,
+            birth_date: Some(date(1961, 4, 10)),
+            driver_start_date: Some(date(2020, 4, 1)),
+        };
+        let schedule = schedule_driver(
+            &[initial(), elderly()],
+            driver,
+            &[record(2, date(2020, 3, 1))],
+            date(2026, 6, 1),
+        );
+        assert_eq!(schedule.age, Some(65));
+        assert_eq!(schedule.worst_status(), ComplianceStatus::DueSoon);
+        assert_eq!(schedule.next_due_date(), Some(date(2027, 4, 10)));
+    }
+}
@@ -0,0 +1,64 @@
+//! 適性診断の受診計画のリポジトリ層
+
+use super::models::{AptitudeDriverRow, AptitudeRecord};
+use crate::db;
+
+/// 適性診断の受診計画リポジトリ
+pub struct AptitudeScheduleRepository;
+
+impl AptitudeScheduleRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 判定対象の運転者（在籍中で、選任中または選任予定）を取得
+    pub async fn get_drivers(
+        office_id: Option<i32>,
+        employee_id: Option<i32>,
+    ) -> Result<Vec<AptitudeDriverRow>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            AptitudeDriverRow,
+            r#"
+            SELECT
+                e.id AS employee_id,
+                e.employee_code,
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                e.office_id,
+                o.name AS "office_name?",
+                e.birth_date,
+                e.driver_start_date
+            FROM employees e
+            LEFT JOIN offices o ON o.id = e.office_id
+            WHERE e.is_active = TRUE
+              AND e.driver_start_date IS NOT NULL
+              AND (e.driver_end_date IS NULL OR e.driver_end_date > CURRENT_DATE)
+              AND ($1::INT IS NULL OR e.office_id = $1)
+              AND ($2::INT IS NULL OR e.id = $2)
+            ORDER BY e.employee_code
+            "#,
+            office_id,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 指定した従業員の適性診断の受診記録を取得
+    pub async fn get_records(employee_ids: &[i32]) -> Result<Vec<AptitudeRecord>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            AptitudeRecord,
+            r#"
+            SELECT employee_id, aptitude_checkup_type_id, checkup_date
+            FROM aptitude_checkup_history
+            WHERE employee_id = ANY($1)
+            ORDER BY employee_id, checkup_date
+            "#,
+            employee_ids
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,197 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::audit_log::handlers::search_audit_logs;
+use crate::modules::audit_log::models::{
+    table_display_name, AuditAction, AuditLogEntry, AuditLogFilter, AUDITED_TABLES,
+};
+use crate::modules::employees::handlers::get_employees;
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str = "flex items-center h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+
+/// 監査ログ画面（従業員・テーブル・期間で絞り込み）
+#[component]
+pub fn AuditLogPage() -> Element {
+    let employees = use_resource(|| async move { get_employees().await });
+    let mut employee_id = use_signal(|| None::<i32>);
+    let mut table_name = use_signal(|| None::<String>);
+    let mut date_from = use_signal(|| None::<NaiveDate>);
+    let mut date_to = use_signal(|| None::<NaiveDate>);
+
+    // 条件が変わるたびに再検索
+    let logs = use_resource(move || async move {
+        let filter = AuditLogFilter {
+            employee_id: employee_id(),
+            table_name: table_name(),
+            date_from: date_from(),
+            date_to: date_to(),
+        };
+        search_audit_logs(filter).await
+    });
+
+    rsx! {
+        document::Title { "監査ログ" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "監査ログ" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-6xl mx-auto space-y-6",
+                    // 検索条件
+                    div { class: SECTION_CLASS,
+                        div { class: "grid grid-cols-1 md:grid-cols-4 gap-4",
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "従業員" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| employee_id.set(evt.value().parse().ok()),
+                                    option { value: "", "すべて" }
+                                    if let Some(Ok(list)) = &*employees.read_unchecked() {
+                                        for emp in list.iter() {
+                                            option {
+                                                value: "{emp.id}",
+                                                "{emp.employee_code} {emp.last_name} {emp.first_name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "対象" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| {
+                                        let v = evt.value();
+                                        table_name.set(if v.is_empty() { None } else { Some(v) });
+                                    },
+                                    option { value: "", "すべて" }
+                                    for (name, label) in AUDITED_TABLES.iter() {
+                                        option { value: *name, "{label}" }
+                                    }
+                                }
+                            }
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "期間（開始）" }
+                                input {
+                                    r#type: "date",
+                                    class: INPUT_CLASS,
+                                    oninput: move |evt| date_from.set(NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()),
+                                }
+                            }
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "期間（終了）" }
+                                input {
+                                    r#type: "date",
+                                    class: INPUT_CLASS,
+                                    oninput: move |evt| date_to.set(NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()),
+                                }
+                            }
+                        }
+                    }
+
+                    // 検索結果
+                    div { class: SECTION_CLASS,
+                        h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400", "変更履歴" }
+                        match &*logs.read_unchecked() {
+                            Some(Ok(list)) if list.is_empty() => rsx! {
+                                p { class: "text-gray-500 text-center py-6", "該当する記録はありません" }
+                            },
+                            Some(Ok(list)) => rsx! {
+                                p { class: "text-sm text-gray-600 mb-4", "{list.len()} 件" }
+                                div { class: "space-y-3",
+                                    for entry in list.iter() {
+                                        AuditLogRow { key: "{entry.log.id}", entry: entry.clone() }
+                                    }
+                                }
+                            },
+                            Some(Err(e)) => rsx! {
+                                div { class: "text-center py-6",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "エラーが発生しました" }
+                                    p { class: "text-gray-600 text-sm", "{e}" }
+                                }
+                            },
+                            None => rsx! {
+                                div { class: "text-center py-6",
+                                    div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500 mb-4" }
+                                    p { class: "text-gray-600", "読み込み中..." }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 監査ログ1件（クリックで変更内容を表示）
+#[component]
+fn AuditLogRow(entry: AuditLogEntry) -> Element {
+    let mut expanded = use_signal(|| false);
+    let log = &entry.log;
+
+    let badge_class = match log.action {
+        AuditAction::Create => "bg-green-100 text-green-700",
+        AuditAction::Update => "bg-amber-100 text-amber-800",
+        AuditAction::Delete => "bg-red-100 text-red-700",
+    };
+    let created_at = log
+        .created_at
+        .with_timezone(&chrono::Local)
+        .format("%Y/%m/%d %H:%M:%S")
+        .to_string();
+    let actor = log
+        .actor_name
+        .clone()
+        .unwrap_or_else(|| "システム".to_string());
+    let employee = log.employee_name.clone().unwrap_or_default();
+
+    rsx! {
+        div { class: "border border-gray-200 rounded-lg",
+            button {
+                class: "w-full flex items-center gap-4 px-4 py-3 text-left text-sm hover:bg-gray-50",
+                onclick: move |_| expanded.toggle(),
+                span { class: "text-gray-500 font-mono w-40 shrink-0", "{created_at}" }
+                span { class: "px-2 py-0.5 rounded text-xs font-semibold {badge_class}", "{log.action.display_name()}" }
+                span { class: "font-medium text-gray-800 w-28 shrink-0", "{table_display_name(&log.table_name)}" }
+                span { class: "text-gray-500 w-16 shrink-0", "#{log.row_id}" }
+                span { class: "text-gray-800 flex-1 truncate", "{employee}" }
+                span { class: "text-gray-600", "{actor}" }
+            }
+            if expanded() {
+                div { class: "border-t border-gray-200 px-4 py-3",
+                    if entry.changes.is_empty() {
+                        p { class: "text-sm text-gray-500", "変更された項目はありません" }
+                    } else {
+                        table { class: "w-full text-sm",
+                            thead {
+                                tr { class: "text-left text-gray-500",
+                                    th { class: "py-1 w-1/4", "項目" }
+                                    th { class: "py-1", "変更前" }
+                                    th { class: "py-1", "変更後" }
+                                }
+                            }
+                            tbody {
+                                for change in entry.changes.iter() {
+                                    {
+                                        let old_value = change.old_value.as_deref().unwrap_or("-");
+                                        let new_value = change.new_value.as_deref().unwrap_or("-");
+                                        rsx! {
+                                            tr { class: "border-t border-gray-100",
+                                                td { class: "py-1 font-mono text-gray-600", "{change.field}" }
+                                                td { class: "py-1 text-red-700 break-all", "{old_value}" }
+                                                td { class: "py-1 text-green-700 break-all", "{new_value}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod audit_log_page;
+
+pub use audit_log_page::AuditLogPage;
@@ -0,0 +1,44 @@
+//! 監査ログのハンドラー層（サーバー関数）
+
+use super::models::{AuditLogEntry, AuditLogFilter};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::recorder::diff_json;
+#[cfg(feature = "server")]
+use super::repository::AuditLogRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// 1回の検索で返す最大件数
+#[cfg(feature = "server")]
+const SEARCH_LIMIT: i64 = 500;
+
+/// 監査ログを検索
+#[server]
+pub async fn search_audit_logs(
+    filter: AuditLogFilter,
+) -> Result<Vec<AuditLogEntry>, ServerFnError> {
+    require_permission(Permission::Audit).await?;
+
+    // バリデーション
+    if let (Some(from), Some(to)) = (filter.date_from, filter.date_to) {
+        if from > to {
+            return Err(ServerFnError::new(
+                "期間の開始日は終了日以前の日付を指定してください".to_string(),
+            ));
+        }
+    }
+
+    let logs = AuditLogRepository::search(&filter, SEARCH_LIMIT)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(logs
+        .into_iter()
+        .map(|log| {
+            let changes = diff_json(log.old_data.as_deref(), log.new_data.as_deref());
+            AuditLogEntry { log, changes }
+        })
+        .collect())
+}
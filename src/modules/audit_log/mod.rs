@@ -0,0 +1,17 @@
+//! 監査ログモジュール
+//! すべてのデータ変更について操作者・対象・変更前後のスナップショットを記録する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod recorder;
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::*;
+pub use handlers::*;
+pub use models::*;
+#[cfg(feature = "server")]
+pub use recorder::{record_create, record_delete, record_update};
@@ -0,0 +1,129 @@
+//! 監査ログのモデル定義
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 操作種別
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum AuditAction {
+    /// 作成
+    #[serde(rename = "CREATE")]
+    Create,
+    /// 更新
+    #[serde(rename = "UPDATE")]
+    Update,
+    /// 削除
+    #[serde(rename = "DELETE")]
+    Delete,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "CREATE",
+            AuditAction::Update => "UPDATE",
+            AuditAction::Delete => "DELETE",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AuditAction::Create => "作成",
+            AuditAction::Update => "更新",
+            AuditAction::Delete => "削除",
+        }
+    }
+}
+
+/// 監査対象テーブルと表示名
+pub const AUDITED_TABLES: &[(&str, &str)] = &[
+    ("employees", "従業員"),
+    ("addresses", "住所"),
+    ("emergency_contacts", "緊急連絡先"),
+    ("education_history", "学歴"),
+    ("employment_history", "職歴"),
+    ("employee_bank_accounts", "銀行口座"),
+    ("department_position_history", "配属履歴"),
+    ("residence_cards", "在留カード"),
+    ("licenses", "運転免許証"),
+    ("qualifications", "資格"),
+    ("insurance_history", "保険"),
+    ("health_checkup_history", "健康診断"),
+    ("health_checkup_follow_ups", "健康診断の事後措置"),
+    ("aptitude_checkup_history", "適性診断"),
+    ("guidance_education_history", "指導教育"),
+    ("driver_accidents", "事故歴"),
+    ("driver_deselections", "運転者の選任解除"),
+    ("employee_documents", "書類"),
+    ("vehicles", "車両"),
+    ("vehicle_inspection_history", "車両点検"),
+    ("vehicle_repair_history", "車両修理"),
+    ("clients", "取引先"),
+    ("client_offices", "取引先営業所"),
+    ("client_contacts", "取引先担当者"),
+    ("companies", "会社"),
+    ("offices", "営業所"),
+    ("departments", "部署"),
+    ("positions", "役職"),
+    ("users", "ユーザー"),
+];
+
+/// テーブル名の表示名を取得
+pub fn table_display_name(table_name: &str) -> &str {
+    AUDITED_TABLES
+        .iter()
+        .find(|(name, _)| *name == table_name)
+        .map(|(_, label)| *label)
+        .unwrap_or(table_name)
+}
+
+/// 監査ログ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct AuditLog {
+    pub id: i64,
+    pub user_id: Option<i32>,
+    pub actor_name: Option<String>,
+    pub action: AuditAction,
+    pub table_name: String,
+    pub row_id: i32,
+    pub employee_id: Option<i32>,
+    /// 対象従業員の氏名（一覧表示用）
+    pub employee_name: Option<String>,
+    /// 変更前（JSON文字列）
+    pub old_data: Option<String>,
+    /// 変更後（JSON文字列）
+    pub new_data: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 監査ログ検索条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct AuditLogFilter {
+    pub employee_id: Option<i32>,
+    pub table_name: Option<String>,
+    /// 期間（開始日）
+    pub date_from: Option<NaiveDate>,
+    /// 期間（終了日、当日を含む）
+    pub date_to: Option<NaiveDate>,
+}
+
+/// 項目ごとの変更内容
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditFieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+/// 変更内容を含む監査ログ（画面表示用）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntry {
+    pub log: AuditLog,
+    pub changes: Vec<AuditFieldChange>,
+}
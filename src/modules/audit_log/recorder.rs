@@ -0,0 +1,170 @@
+//! リポジトリの書き込み処理から監査ログを記録するヘルパー
+//!
+//! 操作者はリクエストのセッションから取得する。監査ログの書き込みに失敗しても
+//! 本来の更新処理は既に完了しているため、エラーはログ出力のみとする。
+
+use super::models::{AuditAction, AuditFieldChange};
+use super::repository::{AuditLogRepository, NewAuditLog};
+use crate::modules::auth::session;
+use serde::Serialize;
+
+/// 行データをJSON文字列に変換
+fn to_json(value: &impl Serialize) -> Option<String> {
+    serde_json::to_string(value).ok()
+}
+
+async fn record(
+    action: AuditAction,
+    table_name: &str,
+    row_id: i32,
+    employee_id: Option<i32>,
+    old_data: Option<String>,
+    new_data: Option<String>,
+) {
+    // リクエスト外（起動時処理など）ではセッションが無いため操作者なしで記録
+    let actor = session::current_user().await.ok().flatten();
+
+    let entry = NewAuditLog {
+        user_id: actor.as_ref().map(|u| u.id),
+        actor_name: actor.map(|u| u.display_name),
+        action,
+        table_name,
+        row_id,
+        employee_id,
+        old_data,
+        new_data,
+    };
+
+    if let Err(e) = AuditLogRepository::insert(entry).await {
+        eprintln!(
+            "⚠️ Failed to write audit log ({} {} #{}): {}",
+            action.as_str(),
+            table_name,
+            row_id,
+            e
+        );
+    }
+}
+
+/// 作成を記録
+pub async fn record_create(
+    table_name: &str,
+    row_id: i32,
+    employee_id: Option<i32>,
+    new: &impl Serialize,
+) {
+    record(
+        AuditAction::Create,
+        table_name,
+        row_id,
+        employee_id,
+        None,
+        to_json(new),
+    )
+    .await;
+}
+
+/// 更新を記録
+pub async fn record_update(
+    table_name: &str,
+    row_id: i32,
+    employee_id: Option<i32>,
+    old: Option<&impl Serialize>,
+    new: &impl Serialize,
+) {
+    record(
+        AuditAction::Update,
+        table_name,
+        row_id,
+        employee_id,
+        old.and_then(to_json),
+        to_json(new),
+    )
+    .await;
+}
+
+/// 削除を記録
+pub async fn record_delete(
+    table_name: &str,
+    row_id: i32,
+    employee_id: Option<i32>,
+    old: Option<&impl Serialize>,
+) {
+    record(
+        AuditAction::Delete,
+        table_name,
+        row_id,
+        employee_id,
+        old.and_then(to_json),
+        None,
+    )
+    .await;
+}
+
+/// 変更前後のJSONを比較して項目ごとの差分を作成
+pub fn diff_json(old_data: Option<&str>, new_data: Option<&str>) -> Vec<AuditFieldChange> {
+    use serde_json::{Map, Value};
+
+    let parse = |data: Option<&str>| -> Map<String, Value> {
+        data.and_then(|s| serde_json::from_str::<Value>(s).ok())
+            .and_then(|v| match v {
+                Value::Object(map) => Some(map),
+                _ => None,
+            })
+            .unwrap_or_default()
+    };
+    let display = |v: Option<&Value>| -> Option<String> {
+        match v {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => Some(other.to_string()),
+        }
+    };
+
+    let old_map = parse(old_data);
+    let new_map = parse(new_data);
+
+    let mut fields: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        // タイムスタンプのみの変更は表示しない
+        .filter(|field| field.as_str() != "updated_at")
+        .filter_map(|field| {
+            let old_value = display(old_map.get(field));
+            let new_value = display(new_map.get(field));
+            (old_value != new_value).then(|| AuditFieldChange {
+                field: field.clone(),
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_json_update() {
+        let changes = diff_json(
+            Some(r#"{"id":1,"city":"港区","building":null,"updated_at":"a"}"#),
+            Some(r#"{"id":1,"city":"中央区","building":"ビル","updated_at":"b"}"#),
+        );
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "building");
+        assert_eq!(changes[0].old_value, None);
+        assert_eq!(changes[1].old_value.as_deref(), Some("港区"));
+        assert_eq!(changes[1].new_value.as_deref(), Some("中央区"));
+    }
+
+    #[test]
+    fn test_diff_json_delete() {
+        let changes = diff_json(Some(r#"{"id":3,"is_active":true}"#), None);
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|c| c.new_value.is_none()));
+    }
+}
@@ -0,0 +1,74 @@
+//! 監査ログのリポジトリ層
+
+use super::models::{AuditAction, AuditLog, AuditLogFilter};
+use crate::db;
+
+/// 監査ログ1件分の書き込みデータ
+pub struct NewAuditLog<'a> {
+    pub user_id: Option<i32>,
+    pub actor_name: Option<String>,
+    pub action: AuditAction,
+    pub table_name: &'a str,
+    pub row_id: i32,
+    pub employee_id: Option<i32>,
+    pub old_data: Option<String>,
+    pub new_data: Option<String>,
+}
+
+/// 監査ログリポジトリ
+pub struct AuditLogRepository;
+
+impl AuditLogRepository {
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 監査ログを記録
+    pub async fn insert(entry: NewAuditLog<'_>) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!(
+            r#"INSERT INTO audit_log
+               (user_id, actor_name, action, table_name, row_id, employee_id, old_data, new_data)
+               VALUES ($1, $2, $3, $4, $5, $6, $7::TEXT::JSONB, $8::TEXT::JSONB)"#,
+            entry.user_id,
+            entry.actor_name,
+            entry.action.as_str(),
+            entry.table_name,
+            entry.row_id,
+            entry.employee_id,
+            entry.old_data,
+            entry.new_data
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 条件で監査ログを検索（新しい順）
+    pub async fn search(filter: &AuditLogFilter, limit: i64) -> Result<Vec<AuditLog>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            AuditLog,
+            r#"SELECT a.id, a.user_id, a.actor_name, a.action as "action: _",
+                      a.table_name, a.row_id, a.employee_id,
+                      (e.last_name || ' ' || e.first_name) as "employee_name?",
+                      a.old_data::TEXT as "old_data?", a.new_data::TEXT as "new_data?",
+                      a.created_at
+               FROM audit_log a
+               LEFT JOIN employees e ON e.id = a.employee_id
+               WHERE ($1::INT IS NULL OR a.employee_id = $1)
+                 AND ($2::VARCHAR IS NULL OR a.table_name = $2)
+                 AND ($3::DATE IS NULL OR a.created_at >= $3::DATE)
+                 AND ($4::DATE IS NULL OR a.created_at < $4::DATE + 1)
+               ORDER BY a.created_at DESC, a.id DESC
+               LIMIT $5"#,
+            filter.employee_id,
+            filter.table_name,
+            filter.date_from,
+            filter.date_to,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
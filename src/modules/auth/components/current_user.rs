@@ -0,0 +1,13 @@
+use crate::modules::auth::models::{AuthUser, Permission};
+use dioxus::prelude::*;
+
+/// ログイン中のユーザー（Baseレイアウトで提供される）
+pub fn use_current_user() -> Signal<Option<AuthUser>> {
+    use_context::<Signal<Option<AuthUser>>>()
+}
+
+/// ログイン中のユーザーが権限を持つかどうか
+pub fn use_permission(permission: Permission) -> Memo<bool> {
+    let user = use_current_user();
+    use_memo(move || user().is_some_and(|u| u.can(permission)))
+}
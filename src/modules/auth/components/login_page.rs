@@ -0,0 +1,81 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::handlers::login;
+use crate::modules::auth::models::LoginRequest;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// ログイン画面
+#[component]
+pub fn LoginPage() -> Element {
+    let mut username = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut err_msg = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        is_submitting.set(true);
+        err_msg.set(String::new());
+        spawn(async move {
+            let data = LoginRequest {
+                username: username(),
+                password: password(),
+            };
+            match login(data).await {
+                Ok(_) => {
+                    navigator().replace(Route::ExpiryDashboardPage {});
+                }
+                Err(e) => {
+                    err_msg.set(format!("ログインに失敗しました: {}", e));
+                    password.set(String::new());
+                }
+            }
+            is_submitting.set(false);
+        });
+    };
+
+    rsx! {
+        document::Title { "ログイン" }
+        div { class: "flex items-center justify-center min-h-screen bg-gray-50",
+            form {
+                class: "w-full max-w-sm bg-white rounded-xl shadow-sm border border-gray-200 p-8 space-y-5",
+                onsubmit: handle_submit,
+                div { class: "text-center",
+                    h1 { class: "text-xl font-bold text-gray-800", "和清商事" }
+                    p { class: "text-sm text-gray-500 mt-1", "従業員管理システム" }
+                }
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "ログインID" }
+                    input {
+                        r#type: "text",
+                        class: INPUT_CLASS,
+                        autocomplete: "username",
+                        value: "{username}",
+                        oninput: move |evt| username.set(evt.value()),
+                    }
+                }
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "パスワード" }
+                    input {
+                        r#type: "password",
+                        class: INPUT_CLASS,
+                        autocomplete: "current-password",
+                        value: "{password}",
+                        oninput: move |evt| password.set(evt.value()),
+                    }
+                }
+                if !err_msg().is_empty() {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded text-sm",
+                        "{err_msg}"
+                    }
+                }
+                button {
+                    r#type: "submit",
+                    class: "w-full px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting() || username().trim().is_empty() || password().is_empty(),
+                    if is_submitting() { "ログイン中..." } else { "ログイン" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod current_user;
+mod login_page;
+mod users_page;
+
+pub use current_user::{use_current_user, use_permission};
+pub use login_page::LoginPage;
+pub use users_page::UsersPage;
@@ -0,0 +1,255 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::handlers::{create_user, get_users, reset_user_password, update_user};
+use crate::modules::auth::models::{CreateUser, Role, UpdateUser, User};
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str =
+    "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+const BUTTON_PRIMARY_CLASS: &str = "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed";
+
+/// ユーザー管理画面（システム管理者のみ）
+#[component]
+pub fn UsersPage() -> Element {
+    let mut users = use_resource(|| async move { get_users().await });
+    let mut message = use_signal(String::new);
+
+    rsx! {
+        document::Title { "ユーザー管理" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "ユーザー管理" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-5xl mx-auto space-y-6",
+                    if !message().is_empty() {
+                        div { class: "bg-amber-50 border border-amber-300 text-gray-800 px-4 py-3 rounded text-sm",
+                            "{message}"
+                        }
+                    }
+
+                    UserCreateForm {
+                        on_created: move |_| {
+                            message.set("ユーザーを作成しました".to_string());
+                            users.restart();
+                        },
+                        on_error: move |e: String| message.set(e),
+                    }
+
+                    div { class: SECTION_CLASS,
+                        h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400", "ユーザー一覧" }
+                        match &*users.read_unchecked() {
+                            Some(Ok(list)) => rsx! {
+                                table { class: "w-full text-sm",
+                                    thead {
+                                        tr { class: "text-left text-gray-500 border-b border-gray-200",
+                                            th { class: "py-2", "ログインID" }
+                                            th { class: "py-2", "表示名" }
+                                            th { class: "py-2", "ロール" }
+                                            th { class: "py-2", "状態" }
+                                            th { class: "py-2", "最終ログイン" }
+                                            th { class: "py-2", "" }
+                                        }
+                                    }
+                                    tbody {
+                                        for user in list.iter() {
+                                            UserRow {
+                                                key: "{user.id}",
+                                                user: user.clone(),
+                                                on_changed: move |msg: String| {
+                                                    message.set(msg);
+                                                    users.restart();
+                                                },
+                                            }
+                                        }
+                                    }
+                                }
+                            },
+                            Some(Err(e)) => rsx! {
+                                div { class: "text-center py-6",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "エラーが発生しました" }
+                                    p { class: "text-gray-600 text-sm", "{e}" }
+                                }
+                            },
+                            None => rsx! {
+                                div { class: "text-center py-6",
+                                    div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500 mb-4" }
+                                    p { class: "text-gray-600", "読み込み中..." }
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// ロール選択
+#[component]
+fn RoleSelect(value: Role, onchange: EventHandler<Role>) -> Element {
+    rsx! {
+        select {
+            class: INPUT_CLASS,
+            value: value.as_str(),
+            onchange: move |evt| {
+                if let Some(role) = Role::from_code(&evt.value()) {
+                    onchange.call(role);
+                }
+            },
+            for role in Role::ALL {
+                option { value: role.as_str(), selected: role == value, "{role.display_name()}" }
+            }
+        }
+    }
+}
+
+/// ユーザー作成フォーム
+#[component]
+fn UserCreateForm(on_created: EventHandler<()>, on_error: EventHandler<String>) -> Element {
+    let mut username = use_signal(String::new);
+    let mut display_name = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut role = use_signal(|| Role::ReadOnly);
+
+    let handle_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        spawn(async move {
+            let data = CreateUser {
+                username: username().trim().to_string(),
+                display_name: display_name().trim().to_string(),
+                password: password(),
+                role: role(),
+                employee_id: None,
+                office_id: None,
+            };
+            match create_user(data).await {
+                Ok(_) => {
+                    username.set(String::new());
+                    display_name.set(String::new());
+                    password.set(String::new());
+                    role.set(Role::ReadOnly);
+                    on_created.call(());
+                }
+                Err(e) => on_error.call(format!("ユーザーの作成に失敗しました: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        form { class: SECTION_CLASS, onsubmit: handle_submit,
+            h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400", "ユーザー追加" }
+            div { class: "grid grid-cols-1 md:grid-cols-4 gap-4 items-end",
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "ログインID" }
+                    input { class: INPUT_CLASS, value: "{username}", oninput: move |evt| username.set(evt.value()) }
+                }
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "表示名" }
+                    input { class: INPUT_CLASS, value: "{display_name}", oninput: move |evt| display_name.set(evt.value()) }
+                }
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "初期パスワード" }
+                    input { r#type: "password", class: INPUT_CLASS, autocomplete: "new-password", value: "{password}", oninput: move |evt| password.set(evt.value()) }
+                }
+                div { class: "space-y-2",
+                    label { class: "block text-sm font-medium text-gray-700", "ロール" }
+                    RoleSelect { value: role(), onchange: move |r| role.set(r) }
+                }
+            }
+            div { class: "flex justify-end mt-4",
+                button {
+                    r#type: "submit",
+                    class: BUTTON_PRIMARY_CLASS,
+                    disabled: username().trim().is_empty() || display_name().trim().is_empty() || password().is_empty(),
+                    "追加"
+                }
+            }
+        }
+    }
+}
+
+/// ユーザー一覧の1行（ロール変更・有効/無効・パスワード再設定）
+#[component]
+fn UserRow(user: User, on_changed: EventHandler<String>) -> Element {
+    let mut new_password = use_signal(String::new);
+    let user_id = user.id;
+    let last_login = user
+        .last_login_at
+        .map(|d| d.format("%Y/%m/%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string());
+
+    let save = move |data: UpdateUser| {
+        spawn(async move {
+            match update_user(data).await {
+                Ok(u) => on_changed.call(format!("{} を更新しました", u.username)),
+                Err(e) => on_changed.call(format!("更新に失敗しました: {}", e)),
+            }
+        });
+    };
+
+    let base = UpdateUser {
+        id: user.id,
+        display_name: user.display_name.clone(),
+        role: user.role,
+        employee_id: user.employee_id,
+        office_id: user.office_id,
+        is_active: user.is_active,
+    };
+
+    rsx! {
+        tr { class: "border-b border-gray-100",
+            td { class: "py-2 font-mono", "{user.username}" }
+            td { class: "py-2", "{user.display_name}" }
+            td { class: "py-2 pr-2",
+                RoleSelect {
+                    value: user.role,
+                    onchange: {
+                        let base = base.clone();
+                        move |role| save(UpdateUser { role, ..base.clone() })
+                    },
+                }
+            }
+            td { class: "py-2",
+                button {
+                    class: if user.is_active { "px-3 py-1 rounded-lg text-xs font-semibold bg-green-100 text-green-700" } else { "px-3 py-1 rounded-lg text-xs font-semibold bg-gray-200 text-gray-600" },
+                    onclick: {
+                        let base = base.clone();
+                        move |_| save(UpdateUser { is_active: !base.is_active, ..base.clone() })
+                    },
+                    if user.is_active { "有効" } else { "無効" }
+                }
+            }
+            td { class: "py-2 text-gray-600", "{last_login}" }
+            td { class: "py-2",
+                div { class: "flex gap-2",
+                    input {
+                        r#type: "password",
+                        class: "px-2 py-1 border border-gray-300 rounded-lg text-xs w-32",
+                        placeholder: "新しいパスワード",
+                        autocomplete: "new-password",
+                        value: "{new_password}",
+                        oninput: move |evt| new_password.set(evt.value()),
+                    }
+                    button {
+                        class: "px-3 py-1 text-xs border border-gray-300 rounded-lg hover:bg-gray-50 disabled:opacity-50",
+                        disabled: new_password().is_empty(),
+                        onclick: move |_| {
+                            spawn(async move {
+                                match reset_user_password(user_id, new_password()).await {
+                                    Ok(_) => {
+                                        new_password.set(String::new());
+                                        on_changed.call("パスワードを再設定しました".to_string());
+                                    }
+                                    Err(e) => on_changed.call(format!("パスワードの再設定に失敗しました: {}", e)),
+                                }
+                            });
+                        },
+                        "再設定"
+                    }
+                }
+            }
+        }
+    }
+}
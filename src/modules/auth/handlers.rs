@@ -0,0 +1,120 @@
+//! 認証・ユーザー管理のハンドラー層（サーバー関数）
+
+use super::models::{AuthUser, CreateUser, LoginRequest, UpdateUser, User};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::Permission;
+#[cfg(feature = "server")]
+use super::repository::{SessionRepository, UserRepository};
+#[cfg(feature = "server")]
+use super::session;
+
+/// ログイン
+#[server]
+pub async fn login(data: LoginRequest) -> Result<AuthUser, ServerFnError> {
+    let credentials = UserRepository::get_credentials(data.username.trim())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    // ユーザーの存在有無を区別しないエラーメッセージにする
+    let credentials = match credentials {
+        Some(c) if c.is_active && session::verify_password(&data.password, &c.password_hash) => c,
+        _ => {
+            return Err(ServerFnError::new(
+                "ログインIDまたはパスワードが正しくありません",
+            ))
+        }
+    };
+
+    session::start_session(credentials.user.id).await?;
+    UserRepository::touch_last_login(credentials.user.id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(credentials.user)
+}
+
+/// ログアウト
+#[server]
+pub async fn logout() -> Result<(), ServerFnError> {
+    session::end_session().await
+}
+
+/// ログイン中のユーザーを取得
+#[server]
+pub async fn get_current_user() -> Result<Option<AuthUser>, ServerFnError> {
+    session::current_user().await
+}
+
+/// ユーザー一覧を取得
+#[server]
+pub async fn get_users() -> Result<Vec<User>, ServerFnError> {
+    session::require_permission(Permission::Admin).await?;
+
+    UserRepository::get_all()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// ユーザーを作成
+#[server]
+pub async fn create_user(data: CreateUser) -> Result<User, ServerFnError> {
+    session::require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    if data.username.trim().is_empty() {
+        return Err(ServerFnError::new("ログインIDは必須です"));
+    }
+    if data.display_name.trim().is_empty() {
+        return Err(ServerFnError::new("表示名は必須です"));
+    }
+    session::validate_password(&data.password).map_err(ServerFnError::new)?;
+
+    let password_hash = session::hash_password(&data.password)?;
+    UserRepository::create(&data, &password_hash)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// ユーザーを更新（無効化した場合はセッションも破棄）
+#[server]
+pub async fn update_user(data: UpdateUser) -> Result<User, ServerFnError> {
+    let current = session::require_permission(Permission::Admin).await?;
+
+    // 自分自身の管理者権限を外してしまうことを防ぐ
+    if current.id == data.id && (!data.is_active || !data.role.allows(Permission::Admin)) {
+        return Err(ServerFnError::new(
+            "自分自身の管理者権限や有効状態は変更できません",
+        ));
+    }
+
+    let user = UserRepository::update(&data)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    if !user.is_active {
+        SessionRepository::delete_by_user_id(user.id)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    Ok(user)
+}
+
+/// ユーザーのパスワードを再設定
+#[server]
+pub async fn reset_user_password(id: i32, password: String) -> Result<(), ServerFnError> {
+    session::require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    session::validate_password(&password).map_err(ServerFnError::new)?;
+
+    let password_hash = session::hash_password(&password)?;
+    UserRepository::update_password(id, &password_hash)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    SessionRepository::delete_by_user_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,17 @@
+//! 認証・権限管理モジュール
+//! ログイン・セッション（Cookie）・ロールによるアクセス制御
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+#[cfg(feature = "server")]
+pub mod session;
+
+pub use components::*;
+pub use handlers::*;
+pub use models::*;
+#[cfg(feature = "server")]
+pub use session::require_permission;
@@ -0,0 +1,182 @@
+//! 認証・権限管理のモデル定義
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// ユーザーロール
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum Role {
+    /// システム管理者
+    #[serde(rename = "ADMIN")]
+    Admin,
+    /// 事務管理者
+    #[serde(rename = "OFFICE_MANAGER")]
+    OfficeManager,
+    /// 運行管理者
+    #[serde(rename = "OPERATION_MANAGER")]
+    OperationManager,
+    /// 閲覧のみ
+    #[serde(rename = "READ_ONLY")]
+    ReadOnly,
+}
+
+impl Role {
+    pub const ALL: [Role; 4] = [
+        Role::Admin,
+        Role::OfficeManager,
+        Role::OperationManager,
+        Role::ReadOnly,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "ADMIN",
+            Role::OfficeManager => "OFFICE_MANAGER",
+            Role::OperationManager => "OPERATION_MANAGER",
+            Role::ReadOnly => "READ_ONLY",
+        }
+    }
+
+    pub fn from_code(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.as_str() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Role::Admin => "システム管理者",
+            Role::OfficeManager => "事務管理者",
+            Role::OperationManager => "運行管理者",
+            Role::ReadOnly => "閲覧のみ",
+        }
+    }
+
+    /// ロールが指定された権限を持つかどうか
+    pub fn allows(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::View => true,
+            Permission::EditDriver => matches!(
+                self,
+                Role::Admin | Role::OfficeManager | Role::OperationManager
+            ),
+            Permission::EditEmployee | Permission::Delete | Permission::Audit => {
+                matches!(self, Role::Admin | Role::OfficeManager)
+            }
+            Permission::Admin => matches!(self, Role::Admin),
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// サーバー関数ごとに要求する権限
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Permission {
+    /// 閲覧
+    View,
+    /// 乗務員関連（免許・診断・指導教育・書類）・車両の登録・更新
+    EditDriver,
+    /// 従業員情報（個人情報・口座・保険など）・取引先の登録・更新、データ出力
+    EditEmployee,
+    /// 削除
+    Delete,
+    /// 監査ログ・保管期限の閲覧
+    Audit,
+    /// ユーザー・マスタ管理、保管期限を過ぎた記録の廃棄・保留
+    Admin,
+}
+
+/// ログイン中のユーザー（クライアントへ返す情報）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct AuthUser {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub role: Role,
+    pub employee_id: Option<i32>,
+    pub office_id: Option<i32>,
+}
+
+impl AuthUser {
+    pub fn can(&self, permission: Permission) -> bool {
+        self.role.allows(permission)
+    }
+}
+
+/// システム利用者
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct User {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub role: Role,
+    pub employee_id: Option<i32>,
+    pub office_id: Option<i32>,
+    pub is_active: bool,
+    pub last_login_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// ログイン入力
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoginRequest {
+    pub username: String,
+    pub password: String,
+}
+
+/// ユーザー作成用の入力データ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateUser {
+    pub username: String,
+    pub display_name: String,
+    pub password: String,
+    pub role: Role,
+    pub employee_id: Option<i32>,
+    pub office_id: Option<i32>,
+}
+
+/// ユーザー更新用の入力データ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateUser {
+    pub id: i32,
+    pub display_name: String,
+    pub role: Role,
+    pub employee_id: Option<i32>,
+    pub office_id: Option<i32>,
+    pub is_active: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_permissions() {
+        assert!(Role::Admin.allows(Permission::Admin));
+        assert!(Role::OfficeManager.allows(Permission::EditEmployee));
+        assert!(!Role::OfficeManager.allows(Permission::Admin));
+        assert!(Role::OperationManager.allows(Permission::EditDriver));
+        assert!(!Role::OperationManager.allows(Permission::Delete));
+        assert!(Role::ReadOnly.allows(Permission::View));
+        assert!(!Role::ReadOnly.allows(Permission::EditDriver));
+    }
+
+    #[test]
+    fn test_role_as_str_round_trip() {
+        for role in Role::ALL {
+            assert_eq!(Role::from_code(role.as_str()), Some(role));
+        }
+        assert_eq!(Role::from_code("UNKNOWN"), None);
+    }
+}
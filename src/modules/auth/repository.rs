@@ -0,0 +1,242 @@
+//! 認証・権限管理のリポジトリ層
+
+use super::models::{AuthUser, CreateUser, Role, UpdateUser, User};
+use crate::db;
+use crate::modules::audit_log;
+use chrono::{DateTime, Utc};
+
+/// パスワード照合用のユーザー情報（サーバー内部でのみ使用）
+pub struct UserCredentials {
+    pub user: AuthUser,
+    pub password_hash: String,
+    pub is_active: bool,
+}
+
+/// ユーザーリポジトリ
+pub struct UserRepository;
+
+impl UserRepository {
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// ユーザー一覧を取得
+    pub async fn get_all() -> Result<Vec<User>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            User,
+            r#"SELECT id, username, display_name, role as "role: _", employee_id, office_id,
+                      is_active, last_login_at, created_at, updated_at
+               FROM users
+               ORDER BY username"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDでユーザーを取得
+    pub async fn get_by_id(id: i32) -> Result<Option<User>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            User,
+            r#"SELECT id, username, display_name, role as "role: _", employee_id, office_id,
+                      is_active, last_login_at, created_at, updated_at
+               FROM users
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// ログインIDで認証情報を取得
+    pub async fn get_credentials(username: &str) -> Result<Option<UserCredentials>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let row = sqlx::query!(
+            r#"SELECT id, username, display_name, role as "role: Role",
+                      employee_id, office_id, password_hash, is_active
+               FROM users
+               WHERE username = $1"#,
+            username
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|r| UserCredentials {
+            user: AuthUser {
+                id: r.id,
+                username: r.username,
+                display_name: r.display_name,
+                role: r.role,
+                employee_id: r.employee_id,
+                office_id: r.office_id,
+            },
+            password_hash: r.password_hash,
+            is_active: r.is_active,
+        }))
+    }
+
+    /// 登録ユーザー数を取得
+    pub async fn count() -> Result<i64, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let count = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM users"#)
+            .fetch_one(pool)
+            .await?;
+        Ok(count)
+    }
+
+    /// ユーザーを作成（password_hashはハッシュ化済みの値）
+    pub async fn create(data: &CreateUser, password_hash: &str) -> Result<User, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let user = sqlx::query_as!(
+            User,
+            r#"INSERT INTO users (username, password_hash, display_name, role, employee_id, office_id)
+               VALUES ($1, $2, $3, $4, $5, $6)
+               RETURNING id, username, display_name, role as "role: _", employee_id, office_id,
+                         is_active, last_login_at, created_at, updated_at"#,
+            data.username,
+            password_hash,
+            data.display_name,
+            data.role.as_str(),
+            data.employee_id,
+            data.office_id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("users", user.id, None, &user).await;
+
+        Ok(user)
+    }
+
+    /// ユーザーを更新
+    pub async fn update(data: &UpdateUser) -> Result<User, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(data.id).await?;
+        let user = sqlx::query_as!(
+            User,
+            r#"UPDATE users
+               SET display_name = $2, role = $3, employee_id = $4, office_id = $5,
+                   is_active = $6, updated_at = CURRENT_TIMESTAMP
+               WHERE id = $1
+               RETURNING id, username, display_name, role as "role: _", employee_id, office_id,
+                         is_active, last_login_at, created_at, updated_at"#,
+            data.id,
+            data.display_name,
+            data.role.as_str(),
+            data.employee_id,
+            data.office_id,
+            data.is_active
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update("users", user.id, None, old.as_ref(), &user).await;
+
+        Ok(user)
+    }
+
+    /// パスワードを更新（password_hashはハッシュ化済みの値）
+    pub async fn update_password(id: i32, password_hash: &str) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!(
+            r#"UPDATE users
+               SET password_hash = $2, updated_at = CURRENT_TIMESTAMP
+               WHERE id = $1"#,
+            id,
+            password_hash
+        )
+        .execute(pool)
+        .await?;
+
+        // ハッシュ値は記録せず、再設定した事実のみを残す
+        let marker = serde_json::json!({ "password": "再設定" });
+        audit_log::record_update("users", id, None, None::<&User>, &marker).await;
+
+        Ok(())
+    }
+
+    /// 最終ログイン日時を記録
+    pub async fn touch_last_login(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!(
+            "UPDATE users SET last_login_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// セッションリポジトリ
+pub struct SessionRepository;
+
+impl SessionRepository {
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// セッションを作成
+    pub async fn create(
+        token: &str,
+        user_id: i32,
+        expires_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!(
+            "INSERT INTO sessions (token, user_id, expires_at) VALUES ($1, $2, $3)",
+            token,
+            user_id,
+            expires_at
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 有効なセッションからユーザーを取得
+    pub async fn get_user_by_token(token: &str) -> Result<Option<AuthUser>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            AuthUser,
+            r#"SELECT u.id, u.username, u.display_name, u.role as "role: _",
+                      u.employee_id, u.office_id
+               FROM sessions s
+               INNER JOIN users u ON u.id = s.user_id
+               WHERE s.token = $1
+                 AND s.expires_at > CURRENT_TIMESTAMP
+                 AND u.is_active = TRUE"#,
+            token
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// セッションを削除
+    pub async fn delete(token: &str) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!("DELETE FROM sessions WHERE token = $1", token)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// ユーザーの全セッションを削除（無効化・パスワード変更時）
+    pub async fn delete_by_user_id(user_id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!("DELETE FROM sessions WHERE user_id = $1", user_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 期限切れセッションを削除
+    pub async fn delete_expired() -> Result<u64, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let result = sqlx::query!("DELETE FROM sessions WHERE expires_at <= CURRENT_TIMESTAMP")
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,182 @@
+//! セッション管理と権限チェック（サーバー専用）
+//!
+//! セッションIDはCookieに保存し、`sessions`テーブルで有効期限を管理する。
+//! 各サーバー関数の先頭で`require_permission`を呼び出してロールを検証する。
+
+use super::models::{AuthUser, CreateUser, Permission, Role};
+use super::repository::{SessionRepository, UserRepository};
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, SaltString};
+use argon2::{Argon2, PasswordVerifier};
+use dioxus::fullstack::FullstackContext;
+use dioxus::prelude::ServerFnError;
+use tower_cookies::cookie::{time::Duration, SameSite};
+use tower_cookies::{Cookie, Cookies};
+
+/// セッションCookie名
+pub const SESSION_COOKIE: &str = "ws_session";
+
+/// セッションの有効日数
+const SESSION_DAYS: i64 = 7;
+
+/// パスワードの最小文字数
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// 未ログイン時のエラー（401）
+fn unauthorized() -> ServerFnError {
+    ServerFnError::ServerError {
+        message: "ログインが必要です".to_string(),
+        code: 401,
+        details: None,
+    }
+}
+
+/// 権限不足時のエラー（403）
+fn forbidden() -> ServerFnError {
+    ServerFnError::ServerError {
+        message: "この操作を行う権限がありません".to_string(),
+        code: 403,
+        details: None,
+    }
+}
+
+/// パスワードの形式チェック
+pub fn validate_password(password: &str) -> Result<(), String> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "パスワードは{}文字以上で入力してください",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+/// パスワードをArgon2でハッシュ化
+pub fn hash_password(password: &str) -> Result<String, ServerFnError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// パスワードを検証
+pub fn verify_password(password: &str, password_hash: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|parsed| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &parsed)
+                .is_ok()
+        })
+        .unwrap_or(false)
+}
+
+/// 推測困難なセッショントークンを生成
+fn generate_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// リクエストのCookieを取得
+async fn cookies() -> Result<Cookies, ServerFnError> {
+    FullstackContext::extract::<Cookies, _>().await
+}
+
+/// セッションを開始してCookieを発行
+///
+/// あわせて期限切れのセッションを削除する（失敗してもログインは続ける）
+pub async fn start_session(user_id: i32) -> Result<(), ServerFnError> {
+    if let Err(e) = SessionRepository::delete_expired().await {
+        eprintln!("⚠️ Failed to delete expired sessions: {}", e);
+    }
+
+    let token = generate_token();
+    let expires_at = chrono::Utc::now() + chrono::Duration::days(SESSION_DAYS);
+
+    SessionRepository::create(&token, user_id, expires_at)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let cookie = Cookie::build((SESSION_COOKIE, token))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .max_age(Duration::days(SESSION_DAYS))
+        .build();
+    cookies().await?.add(cookie);
+
+    Ok(())
+}
+
+/// セッションを終了してCookieを削除
+pub async fn end_session() -> Result<(), ServerFnError> {
+    let cookies = cookies().await?;
+    if let Some(cookie) = cookies.get(SESSION_COOKIE) {
+        SessionRepository::delete(cookie.value())
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+    cookies.remove(Cookie::build(SESSION_COOKIE).path("/").build());
+    Ok(())
+}
+
+/// ログイン中のユーザーを取得（未ログインならNone）
+pub async fn current_user() -> Result<Option<AuthUser>, ServerFnError> {
+    user_from_cookies(&cookies().await?)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// Cookieのセッションからユーザーを取得（サーバー関数以外のAxumハンドラー用）
+pub async fn user_from_cookies(cookies: &Cookies) -> Result<Option<AuthUser>, sqlx::Error> {
+    let Some(cookie) = cookies.get(SESSION_COOKIE) else {
+        return Ok(None);
+    };
+    SessionRepository::get_user_by_token(cookie.value()).await
+}
+
+/// 指定された権限を持つログインユーザーを要求
+pub async fn require_permission(permission: Permission) -> Result<AuthUser, ServerFnError> {
+    let user = current_user().await?.ok_or_else(unauthorized)?;
+    if !user.can(permission) {
+        return Err(forbidden());
+    }
+    Ok(user)
+}
+
+/// ユーザーが1人もいない場合に初期管理者を作成
+///
+/// 環境変数 `INITIAL_ADMIN_USERNAME`（省略時は admin）と
+/// `INITIAL_ADMIN_PASSWORD` が設定されているときのみ作成する。
+pub async fn ensure_initial_admin() -> Result<(), ServerFnError> {
+    let Ok(password) = std::env::var("INITIAL_ADMIN_PASSWORD") else {
+        return Ok(());
+    };
+    validate_password(&password)
+        .map_err(|e| ServerFnError::new(format!("INITIAL_ADMIN_PASSWORD: {}", e)))?;
+
+    let count = UserRepository::count()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if count > 0 {
+        return Ok(());
+    }
+
+    let username = std::env::var("INITIAL_ADMIN_USERNAME").unwrap_or_else(|_| "admin".to_string());
+    let data = CreateUser {
+        username: username.clone(),
+        display_name: "システム管理者".to_string(),
+        password: String::new(),
+        role: Role::Admin,
+        employee_id: None,
+        office_id: None,
+    };
+    UserRepository::create(&data, &hash_password(&password)?)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    println!("👤 Initial admin user '{}' created", username);
+    Ok(())
+}
@@ -3,11 +3,16 @@ use super::models::{BankAccount, CreateBankAccount, UpdateBankAccount};
 use super::repository::BankAccountRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の銀行口座一覧を取得
 #[server]
 pub async fn get_bank_accounts_by_employee(
     employee_id: i32,
 ) -> Result<Vec<BankAccount>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     BankAccountRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -16,6 +21,8 @@ pub async fn get_bank_accounts_by_employee(
 /// 有効な銀行口座のみを取得
 #[server]
 pub async fn get_active_bank_accounts(employee_id: i32) -> Result<Vec<BankAccount>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     BankAccountRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -26,6 +33,8 @@ pub async fn get_active_bank_accounts(employee_id: i32) -> Result<Vec<BankAccoun
 pub async fn get_primary_bank_account(
     employee_id: i32,
 ) -> Result<Option<BankAccount>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     BankAccountRepository::get_primary_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -34,6 +43,8 @@ pub async fn get_primary_bank_account(
 /// 銀行口座を作成
 #[server]
 pub async fn create_bank_account(data: CreateBankAccount) -> Result<BankAccount, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     BankAccountRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -42,6 +53,8 @@ pub async fn create_bank_account(data: CreateBankAccount) -> Result<BankAccount,
 /// 銀行口座を更新
 #[server]
 pub async fn update_bank_account(data: UpdateBankAccount) -> Result<BankAccount, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     BankAccountRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -50,6 +63,8 @@ pub async fn update_bank_account(data: UpdateBankAccount) -> Result<BankAccount,
 /// 銀行口座を削除
 #[server]
 pub async fn delete_bank_account(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     BankAccountRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -2,6 +2,8 @@
 use super::models::{BankAccount, CreateBankAccount, UpdateBankAccount};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct BankAccountRepository;
@@ -95,7 +97,7 @@ impl BankAccountRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let account = sqlx::query_as!(
             BankAccount,
             r#"INSERT INTO employee_bank_accounts (employee_id, bank_code, bank_name, branch_code, branch_name, account_type, account_number, account_holder_name, is_primary, is_active, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -111,12 +113,23 @@ impl BankAccountRepository {
             data.is_primary
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "employee_bank_accounts",
+            account.id,
+            Some(account.employee_id),
+            &account,
+        )
+        .await;
+
+        Ok(account)
     }
 
     /// 銀行口座を更新
     pub async fn update(data: UpdateBankAccount) -> Result<BankAccount, sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(data.id).await?;
 
         // プライマリ口座として更新する場合、他のプライマリ口座を解除
         if data.is_primary && data.is_active {
@@ -133,7 +146,7 @@ impl BankAccountRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let account = sqlx::query_as!(
             BankAccount,
             r#"UPDATE employee_bank_accounts
                SET bank_code = $2, bank_name = $3, branch_code = $4, branch_name = $5,
@@ -153,15 +166,36 @@ impl BankAccountRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "employee_bank_accounts",
+            account.id,
+            Some(account.employee_id),
+            old.as_ref(),
+            &account,
+        )
+        .await;
+
+        Ok(account)
     }
 
     /// 銀行口座を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM employee_bank_accounts WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "employee_bank_accounts",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
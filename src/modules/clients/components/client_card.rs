@@ -0,0 +1,39 @@
+use crate::modules::clients::models::ClientSummary;
+use dioxus::prelude::*;
+
+#[component]
+pub fn ClientCard(client: ClientSummary, on_click: EventHandler<MouseEvent>) -> Element {
+    let contact = client
+        .primary_contact_name
+        .clone()
+        .unwrap_or_else(|| "担当者未設定".to_string());
+
+    rsx! {
+        div {
+            key: "{client.id}",
+            class: "bg-white rounded-lg border border-gray-200 shadow-sm hover:shadow-md transition-all cursor-pointer p-4",
+            onclick: move |evt| on_click.call(evt),
+
+            div { class: "flex items-start justify-between mb-2",
+                div {
+                    if let Some(kana) = &client.name_kana {
+                        p { class: "text-xs text-gray-500", "{kana}" }
+                    }
+                    p { class: "text-lg font-bold text-gray-800", "{client.name}" }
+                }
+                span { class: "shrink-0 inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-800",
+                    "{client.client_type_name}"
+                }
+            }
+            ul { class: "grid gap-0.5 text-xs",
+                if let Some(phone) = &client.phone {
+                    li { class: "text-gray-700", "TEL {phone}" }
+                }
+                if let Some(address) = &client.address {
+                    li { class: "text-gray-600 truncate", "{address}" }
+                }
+                li { class: "text-gray-600", "メイン担当: {contact}" }
+            }
+        }
+    }
+}
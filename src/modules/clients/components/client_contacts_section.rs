@@ -0,0 +1,334 @@
+use crate::components::{opt_string, InputLabelField, TextField, INPUT_CLASS};
+use crate::modules::clients::handlers::{
+    create_client_contact, delete_client_contact, get_client_contacts, set_primary_client_contact,
+    update_client_contact,
+};
+use crate::modules::clients::models::{
+    ClientContact, ClientOffice, CreateClientContact, UpdateClientContact,
+};
+use dioxus::prelude::*;
+
+/// 取引先担当者セクション
+#[component]
+pub fn ClientContactsSection(
+    client_id: i32,
+    offices: Resource<Result<Vec<ClientOffice>, ServerFnError>>,
+) -> Element {
+    let mut contacts = use_resource(move || async move { get_client_contacts(client_id).await });
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<ClientContact>>);
+    let mut error_message = use_signal(String::new);
+
+    let office_list = match &*offices.read_unchecked() {
+        Some(Ok(list)) => list.clone(),
+        _ => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "担当者" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 担当者を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                ContactForm {
+                    key: "{initial.as_ref().map(|c| c.id).unwrap_or(0)}",
+                    client_id,
+                    offices: office_list.clone(),
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        contacts.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*contacts.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "担当者が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "space-y-3",
+                        for contact in list.iter().cloned() {
+                            {
+                                let id = contact.id;
+                                let edit_item = contact.clone();
+                                let office_name = contact
+                                    .client_office_id
+                                    .and_then(|oid| office_list.iter().find(|o| o.id == oid))
+                                    .map(|o| o.name.clone());
+                                let affiliation = [office_name, contact.department.clone(), contact.position.clone()]
+                                    .into_iter()
+                                    .flatten()
+                                    .collect::<Vec<_>>()
+                                    .join(" / ");
+                                rsx! {
+                                    div {
+                                        key: "{id}",
+                                        class: "border rounded-lg p-4",
+                                        class: if contact.is_primary { "border-amber-400 bg-amber-50" } else { "border-gray-200" },
+                                        class: if !contact.is_active { "opacity-60" },
+                                        div { class: "flex items-start justify-between",
+                                            div {
+                                                div { class: "flex items-center gap-2",
+                                                    p { class: "font-semibold text-gray-800", "{contact.name}" }
+                                                    if let Some(kana) = &contact.name_kana {
+                                                        span { class: "text-xs text-gray-500", "{kana}" }
+                                                    }
+                                                    if contact.is_primary {
+                                                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-amber-400 text-gray-800", "★ メイン担当" }
+                                                    }
+                                                    if !contact.is_active {
+                                                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-gray-200 text-gray-600", "無効" }
+                                                    }
+                                                }
+                                                if !affiliation.is_empty() {
+                                                    p { class: "text-sm text-gray-600", "{affiliation}" }
+                                                }
+                                            }
+                                            div { class: "flex gap-2 text-sm",
+                                                if !contact.is_primary {
+                                                    button {
+                                                        class: "text-amber-700 hover:underline",
+                                                        onclick: move |_| async move {
+                                                            match set_primary_client_contact(id).await {
+                                                                Ok(_) => contacts.restart(),
+                                                                Err(e) => error_message.set(format!("更新に失敗しました: {}", e)),
+                                                            }
+                                                        },
+                                                        "メイン担当に設定"
+                                                    }
+                                                }
+                                                button {
+                                                    class: "text-amber-700 hover:underline",
+                                                    onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                    "編集"
+                                                }
+                                                button {
+                                                    class: "text-red-600 hover:underline",
+                                                    onclick: move |_| async move {
+                                                        match delete_client_contact(id).await {
+                                                            Ok(_) => contacts.restart(),
+                                                            Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                        }
+                                                    },
+                                                    "削除"
+                                                }
+                                            }
+                                        }
+                                        ul { class: "grid grid-cols-3 gap-2 mt-2 text-sm text-gray-700",
+                                            if let Some(phone) = &contact.phone {
+                                                li { "TEL {phone}" }
+                                            }
+                                            if let Some(mobile) = &contact.mobile {
+                                                li { "携帯 {mobile}" }
+                                            }
+                                            if let Some(email) = &contact.email {
+                                                li { class: "truncate", "{email}" }
+                                            }
+                                        }
+                                        if let Some(notes) = &contact.notes {
+                                            p { class: "mt-2 text-sm text-gray-600 whitespace-pre-wrap", "{notes}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "担当者の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 担当者の入力フォーム（新規・編集兼用）
+#[component]
+fn ContactForm(
+    client_id: i32,
+    offices: Vec<ClientOffice>,
+    initial: Option<ClientContact>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record_id = initial.as_ref().map(|c| c.id);
+    let text =
+        |f: fn(&ClientContact) -> Option<String>| initial.as_ref().and_then(f).unwrap_or_default();
+    let mut name = use_signal(|| text(|c| Some(c.name.clone())));
+    let mut name_kana = use_signal(|| text(|c| c.name_kana.clone()));
+    let mut department = use_signal(|| text(|c| c.department.clone()));
+    let mut position = use_signal(|| text(|c| c.position.clone()));
+    let mut phone = use_signal(|| text(|c| c.phone.clone()));
+    let mut mobile = use_signal(|| text(|c| c.mobile.clone()));
+    let mut email = use_signal(|| text(|c| c.email.clone()));
+    let mut notes = use_signal(|| text(|c| c.notes.clone()));
+    let mut client_office_id = use_signal(|| initial.as_ref().and_then(|c| c.client_office_id));
+    let mut is_primary = use_signal(|| initial.as_ref().is_some_and(|c| c.is_primary));
+    let mut is_active = use_signal(|| initial.as_ref().is_none_or(|c| c.is_active));
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| async move {
+        is_submitting.set(true);
+        let result = match record_id {
+            Some(id) => update_client_contact(UpdateClientContact {
+                id,
+                client_id,
+                client_office_id: client_office_id(),
+                name: name(),
+                name_kana: opt_string(name_kana()),
+                department: opt_string(department()),
+                position: opt_string(position()),
+                phone: opt_string(phone()),
+                mobile: opt_string(mobile()),
+                email: opt_string(email()),
+                is_primary: is_primary(),
+                is_active: is_active(),
+                notes: opt_string(notes()),
+            })
+            .await
+            .map(|_| ()),
+            None => create_client_contact(CreateClientContact {
+                client_id,
+                client_office_id: client_office_id(),
+                name: name(),
+                name_kana: opt_string(name_kana()),
+                department: opt_string(department()),
+                position: opt_string(position()),
+                phone: opt_string(phone()),
+                mobile: opt_string(mobile()),
+                email: opt_string(email()),
+                is_primary: is_primary(),
+                notes: opt_string(notes()),
+            })
+            .await
+            .map(|_| ()),
+        };
+        is_submitting.set(false);
+
+        match result {
+            Ok(_) => on_saved.call(()),
+            Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                TextField {
+                    label: "担当者名".to_string(),
+                    value: name(),
+                    required: true,
+                    on_input: move |v| name.set(v),
+                }
+                TextField {
+                    label: "担当者名カナ".to_string(),
+                    value: name_kana(),
+                    on_input: move |v| name_kana.set(v),
+                }
+                InputLabelField { label: "所属営業所".to_string(),
+                    select {
+                        class: INPUT_CLASS,
+                        onchange: move |evt| client_office_id.set(evt.value().parse().ok()),
+                        option { value: "", "未設定" }
+                        for office in offices.iter() {
+                            option {
+                                value: "{office.id}",
+                                selected: client_office_id() == Some(office.id),
+                                "{office.name}"
+                            }
+                        }
+                    }
+                }
+                TextField {
+                    label: "部署".to_string(),
+                    value: department(),
+                    on_input: move |v| department.set(v),
+                }
+                TextField {
+                    label: "役職".to_string(),
+                    value: position(),
+                    on_input: move |v| position.set(v),
+                }
+                TextField {
+                    label: "電話番号".to_string(),
+                    value: phone(),
+                    on_input: move |v| phone.set(v),
+                }
+                TextField {
+                    label: "携帯電話".to_string(),
+                    value: mobile(),
+                    on_input: move |v| mobile.set(v),
+                }
+                TextField {
+                    label: "メールアドレス".to_string(),
+                    value: email(),
+                    on_input: move |v| email.set(v),
+                }
+            }
+            InputLabelField { label: "備考".to_string(),
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "2",
+                    value: "{notes}",
+                    oninput: move |evt| notes.set(evt.value()),
+                }
+            }
+            div { class: "flex gap-6 text-sm text-gray-700",
+                label { class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: is_primary(),
+                        onchange: move |evt| is_primary.set(evt.checked()),
+                    }
+                    "メイン担当者"
+                }
+                if record_id.is_some() {
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            checked: is_active(),
+                            onchange: move |evt| is_active.set(evt.checked()),
+                        }
+                        "有効"
+                    }
+                }
+            }
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm", "{error_message}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting() || name().trim().is_empty(),
+                    onclick: handle_submit,
+                    if record_id.is_some() { "更新" } else { "追加" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::components::{opt_string, InputLabelField, TextField, INPUT_CLASS};
+use crate::modules::clients::handlers::{
+    check_client_name_available, create_client, get_client_masters,
+};
+use crate::modules::clients::models::{ClientSummary, CreateClient};
+use crate::modules::clients::validation::{validate_client_name, validate_phone};
+use dioxus::prelude::*;
+
+#[component]
+pub fn ClientCreateForm(
+    mut clients_list: Resource<Result<Vec<ClientSummary>, ServerFnError>>,
+    on_close: EventHandler<MouseEvent>,
+) -> Element {
+    let masters = use_resource(|| async move { get_client_masters().await });
+
+    let mut name = use_signal(String::new);
+    let mut name_kana = use_signal(String::new);
+    let mut phone = use_signal(String::new);
+    let mut company_id = use_signal(|| None::<i32>);
+    let mut client_type_id = use_signal(|| None::<i32>);
+    let mut err_msg = use_signal(String::new);
+    let mut is_name_duplicate = use_signal(|| false);
+
+    // 会社が1社のみの場合は自動選択
+    use_effect(move || {
+        if let Some(Ok(m)) = &*masters.read() {
+            if company_id.peek().is_none() && m.companies.len() == 1 {
+                company_id.set(Some(m.companies[0].id));
+            }
+        }
+    });
+
+    let err_name = use_memo(move || {
+        let v = name();
+        if v.is_empty() {
+            None
+        } else {
+            validate_client_name(&v).err()
+        }
+    });
+    let err_phone = use_memo(move || validate_phone(&phone(), "電話番号").err());
+
+    // 取引先名の重複チェック（同一会社内）
+    use_effect(move || {
+        let value = name();
+        let Some(company) = company_id() else {
+            is_name_duplicate.set(false);
+            return;
+        };
+        if validate_client_name(&value).is_err() {
+            is_name_duplicate.set(false);
+            return;
+        }
+        spawn(async move {
+            let available = check_client_name_available(company, value, None)
+                .await
+                .unwrap_or(true);
+            is_name_duplicate.set(!available);
+        });
+    });
+
+    let is_valid = use_memo(move || {
+        !name().is_empty()
+            && err_name().is_none()
+            && err_phone().is_none()
+            && company_id().is_some()
+            && client_type_id().is_some()
+            && !is_name_duplicate()
+    });
+
+    let handle_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let (Some(company), Some(client_type)) = (company_id(), client_type_id()) else {
+            return;
+        };
+        spawn(async move {
+            let data = CreateClient {
+                company_id: company,
+                client_type_id: client_type,
+                name: name(),
+                name_kana: opt_string(name_kana()),
+                phone: opt_string(phone()),
+            };
+            match create_client(data).await {
+                Ok(_) => {
+                    clients_list.restart();
+                    name.set(String::new());
+                    name_kana.set(String::new());
+                    phone.set(String::new());
+                    err_msg.set(String::new());
+                }
+                Err(e) => err_msg.set(format!("取引先の登録に失敗しました: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col h-full bg-white",
+            // ヘッダー
+            div { class: "flex items-center justify-between h-14 px-4 border-b border-gray-200",
+                h3 { class: "text-lg font-semibold text-gray-800", "新規取引先登録" }
+                button {
+                    class: "text-gray-500 hover:text-gray-700 transition-colors",
+                    onclick: move |evt| on_close.call(evt),
+                    "✕"
+                }
+            }
+
+            // フォーム
+            form {
+                id: "client-create-form",
+                class: "flex-1 overflow-y-auto p-4 space-y-4",
+                onsubmit: handle_submit,
+
+                match &*masters.read_unchecked() {
+                    Some(Ok(m)) => rsx! {
+                        TextField {
+                            label: "取引先名".to_string(),
+                            value: name(),
+                            required: true,
+                            placeholder: "例: 株式会社〇〇物流".to_string(),
+                            on_input: move |v| name.set(v),
+                        }
+                        if let Some(err) = err_name() {
+                            p { class: "text-red-500 text-sm -mt-2", "{err}" }
+                        }
+                        if is_name_duplicate() {
+                            p { class: "text-red-500 text-sm -mt-2", "この取引先名は既に登録されています" }
+                        }
+                        TextField {
+                            label: "取引先名カナ".to_string(),
+                            value: name_kana(),
+                            placeholder: "例: マルマルブツリュウ".to_string(),
+                            on_input: move |v| name_kana.set(v),
+                        }
+                        InputLabelField { label: "取引先種別".to_string(), required: true,
+                            select {
+                                class: INPUT_CLASS,
+                                onchange: move |evt| client_type_id.set(evt.value().parse().ok()),
+                                option { value: "", "選択してください" }
+                                for t in m.client_types.iter() {
+                                    option { value: "{t.id}", selected: client_type_id() == Some(t.id), "{t.name}" }
+                                }
+                            }
+                        }
+                        if m.companies.len() > 1 {
+                            InputLabelField { label: "会社".to_string(), required: true,
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| company_id.set(evt.value().parse().ok()),
+                                    option { value: "", "選択してください" }
+                                    for c in m.companies.iter() {
+                                        option { value: "{c.id}", selected: company_id() == Some(c.id), "{c.name}" }
+                                    }
+                                }
+                            }
+                        }
+                        TextField {
+                            label: "電話番号".to_string(),
+                            value: phone(),
+                            placeholder: "例: 03-1234-5678".to_string(),
+                            on_input: move |v| phone.set(v),
+                        }
+                        if let Some(err) = err_phone() {
+                            p { class: "text-red-500 text-sm -mt-2", "{err}" }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-red-500 text-sm", "マスタの読み込みに失敗しました: {e}" }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center py-4",
+                            div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+
+                if !err_msg().is_empty() {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded",
+                        "{err_msg}"
+                    }
+                }
+            }
+
+            // フッター
+            div { class: "flex justify-end gap-2 p-4 border-t border-gray-200",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |evt| on_close.call(evt),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    r#type: "submit",
+                    form: "client-create-form",
+                    disabled: !is_valid(),
+                    "登録"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::components::{format_yen, DetailField, Icon, IconType};
+use crate::modules::clients::components::{
+    ClientContactsSection, ClientEditForm, ClientOfficesSection,
+};
+use crate::modules::clients::handlers::{
+    delete_client, get_client, get_client_masters, get_client_offices,
+};
+use crate::modules::clients::models::Client;
+use crate::Route;
+
+use dioxus::prelude::*;
+
+#[component]
+pub fn ClientDetailPage(id: i32) -> Element {
+    let mut client_resource = use_resource(move || async move { get_client(id).await });
+    let mut edit_mode = use_signal(|| false);
+    let mut show_delete_confirm = use_signal(|| false);
+    let mut is_deleting = use_signal(|| false);
+    let mut delete_error = use_signal(String::new);
+    let nav = navigator();
+
+    rsx! {
+        document::Title { "取引先詳細" }
+        div { class: "flex h-full bg-gray-50",
+            div { class: "flex-1 flex flex-col overflow-hidden",
+                // ヘッダー
+                div { class: "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white shadow-sm",
+                    div { class: "flex items-center gap-3",
+                        button {
+                            class: "p-2 hover:bg-gray-100 rounded-lg transition-colors",
+                            onclick: move |_| nav.go_back(),
+                            title: "一覧に戻る",
+                            Icon {
+                                icon_type: IconType::ArrowLeft,
+                                class: Some("size-5".to_string())
+                            }
+                        }
+                        h1 { class: "text-lg font-semibold text-gray-800", "取引先詳細" }
+                    }
+                    div { class: "flex items-center gap-3",
+                        if !edit_mode() {
+                            button {
+                                class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm bg-red-500 text-white hover:bg-red-600",
+                                onclick: move |_| show_delete_confirm.set(true),
+                                "🗑 削除"
+                            }
+                        }
+                        button {
+                            class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm",
+                            class: if edit_mode() {
+                                "bg-gray-200 text-gray-700 hover:bg-gray-300"
+                            } else {
+                                "bg-amber-400 text-gray-800 hover:bg-amber-500"
+                            },
+                            onclick: move |_| edit_mode.set(!edit_mode()),
+                            if edit_mode() { "✕ キャンセル" } else { "✎ 編集" }
+                        }
+                    }
+                }
+
+                // コンテンツ
+                div { class: "flex-1 overflow-auto",
+                    match &*client_resource.read_unchecked() {
+                        Some(Ok(Some(client))) => {
+                            if edit_mode() {
+                                rsx! {
+                                    ClientEditForm {
+                                        client: client.clone(),
+                                        on_close: move |_| {
+                                            edit_mode.set(false);
+                                            client_resource.restart();
+                                        },
+                                    }
+                                }
+                            } else {
+                                rsx! {
+                                    ClientDetailView { client: client.clone() }
+                                }
+                            }
+                        },
+                        Some(Ok(None)) => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "取引先が見つかりませんでした" }
+                                    p { class: "text-gray-600 text-sm", "指定されたIDの取引先は存在しません。" }
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "エラーが発生しました" }
+                                    p { class: "text-gray-600 text-sm", "{e}" }
+                                }
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500 mb-4" }
+                                    p { class: "text-gray-600", "読み込み中..." }
+                                }
+                            }
+                        },
+                    }
+                }
+
+                // 削除確認ダイアログ
+                if show_delete_confirm() {
+                    div {
+                        class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+                        onclick: move |_| {
+                            if !is_deleting() {
+                                show_delete_confirm.set(false);
+                            }
+                        },
+                        div {
+                            class: "bg-white rounded-xl shadow-2xl p-6 max-w-md w-full mx-4",
+                            onclick: move |e| e.stop_propagation(),
+                            div { class: "flex items-start gap-4 mb-6",
+                                div { class: "shrink-0 w-12 h-12 bg-red-100 rounded-full flex items-center justify-center",
+                                    svg {
+                                        class: "w-6 h-6 text-red-600",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        path { d: "M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z" }
+                                    }
+                                }
+                                div { class: "flex-1",
+                                    h3 { class: "text-lg font-bold text-gray-900 mb-2", "取引先を削除しますか？" }
+                                    p { class: "text-sm text-gray-600",
+                                        "この操作は取り消せません。営業所・担当者も含めて削除され、この取引先をリース会社に指定している車両は未設定に戻ります。取引を終了した場合は編集画面でステータスを変更してください。"
+                                    }
+                                    if !delete_error().is_empty() {
+                                        p { class: "text-sm text-red-600 mt-2", "{delete_error}" }
+                                    }
+                                }
+                            }
+                            div { class: "flex gap-3 justify-end",
+                                button {
+                                    class: "px-4 py-2 text-gray-700 bg-gray-100 rounded-lg hover:bg-gray-200 transition-colors font-semibold",
+                                    disabled: is_deleting(),
+                                    onclick: move |_| show_delete_confirm.set(false),
+                                    "キャンセル"
+                                }
+                                button {
+                                    class: "px-4 py-2 text-white bg-red-600 rounded-lg hover:bg-red-700 transition-colors font-semibold disabled:opacity-50 disabled:cursor-not-allowed",
+                                    disabled: is_deleting(),
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            is_deleting.set(true);
+                                            match delete_client(id).await {
+                                                Ok(_) => {
+                                                    nav.push(Route::ClientsPage {});
+                                                }
+                                                Err(e) => {
+                                                    delete_error.set(format!("削除に失敗しました: {}", e));
+                                                    is_deleting.set(false);
+                                                }
+                                            }
+                                        });
+                                    },
+                                    if is_deleting() {
+                                        span { class: "flex items-center gap-2",
+                                            div { class: "inline-block animate-spin rounded-full h-4 w-4 border-b-2 border-white" }
+                                            "削除中..."
+                                        }
+                                    } else {
+                                        "削除する"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ClientDetailView(client: Client) -> Element {
+    let masters_resource = use_resource(|| async move { get_client_masters().await });
+    let offices_resource = use_resource(move || async move { get_client_offices(client.id).await });
+
+    // マスタ名称（読み込み前・失敗時は空表示）
+    let (company_name, client_type_name) = match &*masters_resource.read_unchecked() {
+        Some(Ok(m)) => (
+            m.companies
+                .iter()
+                .find(|c| c.id == client.company_id)
+                .map(|c| c.name.clone())
+                .unwrap_or_default(),
+            m.client_types
+                .iter()
+                .find(|t| t.id == client.client_type_id)
+                .map(|t| t.name.clone())
+                .unwrap_or_default(),
+        ),
+        _ => (String::new(), String::new()),
+    };
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto p-6 space-y-6",
+            // 基本情報セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "基本情報"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField { label: "取引先名".to_string(), value: client.name.clone() }
+                    DetailField {
+                        label: "取引先名カナ".to_string(),
+                        value: client.name_kana.clone().unwrap_or_default()
+                    }
+                    DetailField { label: "取引先種別".to_string(), value: client_type_name }
+                    DetailField { label: "会社".to_string(), value: company_name }
+                    DetailField {
+                        label: "法人番号".to_string(),
+                        value: client.corporate_number.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "インボイス登録番号".to_string(),
+                        value: client.invoice_registration_number.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "ステータス".to_string(),
+                        value: if client.is_active { "取引中".to_string() } else { "取引停止".to_string() }
+                    }
+                }
+            }
+
+            // 所在地・連絡先セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "所在地・連絡先"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField {
+                        label: "郵便番号".to_string(),
+                        value: client.postal_code.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "住所".to_string(),
+                        value: client.address.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "電話番号".to_string(),
+                        value: client.phone.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "FAX番号".to_string(),
+                        value: client.fax.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "メールアドレス".to_string(),
+                        value: client.email.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "ウェブサイト".to_string(),
+                        value: client.website_url.clone().unwrap_or_default()
+                    }
+                }
+            }
+
+            // 取引条件セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "取引条件"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField {
+                        label: "支払条件".to_string(),
+                        value: client.payment_terms.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "与信限度額".to_string(),
+                        value: client.credit_limit.as_ref().map(format_yen).unwrap_or_default()
+                    }
+                    div { class: "col-span-2",
+                        DetailField {
+                            label: "備考".to_string(),
+                            value: client.notes.clone().unwrap_or_default()
+                        }
+                    }
+                }
+            }
+
+            ClientOfficesSection { client_id: client.id, offices: offices_resource }
+            ClientContactsSection { client_id: client.id, offices: offices_resource }
+
+            // メタ情報セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "メタ情報"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField {
+                        label: "作成日時".to_string(),
+                        value: client.created_at.format("%Y-%m-%d %H:%M:%S").to_string()
+                    }
+                    DetailField {
+                        label: "更新日時".to_string(),
+                        value: client.updated_at.format("%Y-%m-%d %H:%M:%S").to_string()
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use crate::components::{
+    amount_text, opt_number, opt_string, InputLabelField, NumberField, TextField, INPUT_CLASS,
+};
+use crate::modules::clients::handlers::{
+    check_client_name_available, get_client_masters, update_client,
+};
+use crate::modules::clients::models::Client;
+use crate::modules::clients::validation::{
+    validate_client_name, validate_corporate_number, validate_credit_limit, validate_email,
+    validate_invoice_registration_number,
+};
+use dioxus::prelude::*;
+
+/// フォームセクション
+#[component]
+fn FormSection(title: String, children: Element) -> Element {
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-4 space-y-3",
+            h4 { class: "font-bold text-md border-b pb-2", "{title}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+pub fn ClientEditForm(client: Client, on_close: EventHandler<()>) -> Element {
+    let masters = use_resource(|| async move { get_client_masters().await });
+    let mut data = use_signal(|| client.clone());
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    // 入力値をまとめて更新するヘルパー
+    let mut edit = move |f: &dyn Fn(&mut Client)| {
+        let mut c = data();
+        f(&mut c);
+        data.set(c);
+    };
+
+    let handle_submit = move |_| {
+        spawn(async move {
+            is_submitting.set(true);
+            error_message.set(String::new());
+            let c = data();
+
+            // バリデーション
+            let checks = validate_client_name(&c.name)
+                .and_then(|_| {
+                    validate_corporate_number(c.corporate_number.as_deref().unwrap_or(""))
+                })
+                .and_then(|_| {
+                    validate_invoice_registration_number(
+                        c.invoice_registration_number.as_deref().unwrap_or(""),
+                    )
+                })
+                .and_then(|_| validate_email(c.email.as_deref().unwrap_or("")))
+                .and_then(|_| validate_credit_limit(c.credit_limit.as_ref()));
+            if let Err(e) = checks {
+                error_message.set(e);
+                is_submitting.set(false);
+                return;
+            }
+
+            // 取引先名の重複チェック（自分以外）
+            match check_client_name_available(c.company_id, c.name.clone(), Some(c.id)).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    error_message.set("この取引先名は既に登録されています".to_string());
+                    is_submitting.set(false);
+                    return;
+                }
+                Err(e) => {
+                    error_message.set(format!("バリデーションエラー: {}", e));
+                    is_submitting.set(false);
+                    return;
+                }
+            }
+
+            match update_client(c).await {
+                Ok(_) => {
+                    is_submitting.set(false);
+                    on_close.call(());
+                }
+                Err(e) => {
+                    error_message.set(format!("更新エラー: {}", e));
+                    is_submitting.set(false);
+                }
+            }
+        });
+    };
+
+    let c = data();
+
+    rsx! {
+        div { class: "flex flex-col h-full bg-gray-50",
+            // ヘッダー
+            div { class: "flex items-center justify-between h-14 px-4 border-b border-gray-300 bg-white",
+                h3 { class: "text-lg font-semibold", "取引先編集" }
+                button {
+                    class: "text-gray-500 hover:text-gray-700 transition-colors",
+                    onclick: move |_| on_close.call(()),
+                    "✕"
+                }
+            }
+
+            div { class: "flex-1 overflow-y-auto p-4 space-y-4 max-w-5xl mx-auto w-full",
+                if !error_message().is_empty() {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded",
+                        "{error_message}"
+                    }
+                }
+
+                match &*masters.read_unchecked() {
+                    Some(Ok(m)) => rsx! {
+                        // 基本情報
+                        FormSection { title: "基本情報".to_string(),
+                            div { class: "grid grid-cols-2 gap-3",
+                                TextField {
+                                    label: "取引先名".to_string(),
+                                    value: c.name.clone(),
+                                    required: true,
+                                    on_input: move |val: String| edit(&|x| x.name = val.clone()),
+                                }
+                                TextField {
+                                    label: "取引先名カナ".to_string(),
+                                    value: c.name_kana.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.name_kana = opt_string(val.clone())),
+                                }
+                                InputLabelField { label: "取引先種別".to_string(), required: true,
+                                    select {
+                                        class: INPUT_CLASS,
+                                        onchange: move |evt| {
+                                            if let Ok(id) = evt.value().parse::<i32>() {
+                                                edit(&|x| x.client_type_id = id);
+                                            }
+                                        },
+                                        for t in m.client_types.iter() {
+                                            option { value: "{t.id}", selected: c.client_type_id == t.id, "{t.name}" }
+                                        }
+                                    }
+                                }
+                                InputLabelField { label: "会社".to_string(), required: true,
+                                    select {
+                                        class: INPUT_CLASS,
+                                        onchange: move |evt| {
+                                            if let Ok(id) = evt.value().parse::<i32>() {
+                                                edit(&|x| x.company_id = id);
+                                            }
+                                        },
+                                        for co in m.companies.iter() {
+                                            option { value: "{co.id}", selected: c.company_id == co.id, "{co.name}" }
+                                        }
+                                    }
+                                }
+                                TextField {
+                                    label: "法人番号".to_string(),
+                                    value: c.corporate_number.clone().unwrap_or_default(),
+                                    placeholder: "13桁の数字".to_string(),
+                                    on_input: move |val: String| edit(&|x| x.corporate_number = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "インボイス登録番号".to_string(),
+                                    value: c.invoice_registration_number.clone().unwrap_or_default(),
+                                    placeholder: "例: T1234567890123".to_string(),
+                                    on_input: move |val: String| edit(&|x| x.invoice_registration_number = opt_string(val.clone())),
+                                }
+                            }
+                        }
+
+                        // 連絡先
+                        FormSection { title: "所在地・連絡先".to_string(),
+                            div { class: "grid grid-cols-2 gap-3",
+                                TextField {
+                                    label: "郵便番号".to_string(),
+                                    value: c.postal_code.clone().unwrap_or_default(),
+                                    placeholder: "例: 1000001".to_string(),
+                                    on_input: move |val: String| edit(&|x| x.postal_code = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "住所".to_string(),
+                                    value: c.address.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.address = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "電話番号".to_string(),
+                                    value: c.phone.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.phone = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "FAX番号".to_string(),
+                                    value: c.fax.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.fax = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "メールアドレス".to_string(),
+                                    value: c.email.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.email = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "ウェブサイト".to_string(),
+                                    value: c.website_url.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.website_url = opt_string(val.clone())),
+                                }
+                            }
+                        }
+
+                        // 取引条件
+                        FormSection { title: "取引条件".to_string(),
+                            div { class: "grid grid-cols-2 gap-3",
+                                TextField {
+                                    label: "支払条件".to_string(),
+                                    value: c.payment_terms.clone().unwrap_or_default(),
+                                    placeholder: "例: 月末締め翌月末払い".to_string(),
+                                    on_input: move |val: String| edit(&|x| x.payment_terms = opt_string(val.clone())),
+                                }
+                                NumberField {
+                                    label: "与信限度額（円）".to_string(),
+                                    value: amount_text(c.credit_limit.as_ref()),
+                                    on_input: move |val: String| edit(&|x| x.credit_limit = opt_number(&val)),
+                                }
+                                InputLabelField { label: "ステータス".to_string(),
+                                    select {
+                                        class: INPUT_CLASS,
+                                        value: if c.is_active { "true" } else { "false" },
+                                        onchange: move |evt| {
+                                            let active = evt.value() == "true";
+                                            edit(&|x| x.is_active = active);
+                                        },
+                                        option { value: "true", "取引中" }
+                                        option { value: "false", "取引停止" }
+                                    }
+                                }
+                                div { class: "col-span-2",
+                                    InputLabelField { label: "備考".to_string(),
+                                        textarea {
+                                            class: INPUT_CLASS,
+                                            rows: "3",
+                                            value: "{c.notes.clone().unwrap_or_default()}",
+                                            oninput: move |evt| {
+                                                let notes = opt_string(evt.value());
+                                                edit(&|x| x.notes = notes.clone());
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-red-500 text-sm", "マスタの読み込みに失敗しました: {e}" }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center py-4",
+                            div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+            }
+
+            // フッター
+            div { class: "flex justify-end gap-2 p-4 border-t border-gray-300 bg-white",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_close.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting(),
+                    onclick: handle_submit,
+                    if is_submitting() { "保存中..." } else { "保存" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::components::{opt_string, TextField};
+use crate::modules::clients::handlers::{
+    create_client_office, delete_client_office, update_client_office,
+};
+use crate::modules::clients::models::{ClientOffice, CreateClientOffice, UpdateClientOffice};
+use dioxus::prelude::*;
+
+/// 取引先営業所セクション
+#[component]
+pub fn ClientOfficesSection(
+    client_id: i32,
+    mut offices: Resource<Result<Vec<ClientOffice>, ServerFnError>>,
+) -> Element {
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<ClientOffice>>);
+    let mut error_message = use_signal(String::new);
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "営業所" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 営業所を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                OfficeForm {
+                    key: "{initial.as_ref().map(|o| o.id).unwrap_or(0)}",
+                    client_id,
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        offices.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*offices.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "営業所が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "grid grid-cols-2 gap-4",
+                        for office in list.iter().cloned() {
+                            {
+                                let id = office.id;
+                                let edit_item = office.clone();
+                                rsx! {
+                                    div {
+                                        key: "{id}",
+                                        class: "border border-gray-200 rounded-lg p-4",
+                                        class: if !office.is_active { "opacity-60" },
+                                        div { class: "flex items-start justify-between mb-2",
+                                            div { class: "flex items-center gap-2",
+                                                p { class: "font-semibold text-gray-800", "{office.name}" }
+                                                if office.is_main_office {
+                                                    span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-800", "本社" }
+                                                }
+                                                if !office.is_active {
+                                                    span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-gray-200 text-gray-600", "閉鎖" }
+                                                }
+                                            }
+                                            div { class: "flex gap-2 text-sm",
+                                                button {
+                                                    class: "text-amber-700 hover:underline",
+                                                    onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                    "編集"
+                                                }
+                                                button {
+                                                    class: "text-red-600 hover:underline",
+                                                    onclick: move |_| async move {
+                                                        match delete_client_office(id).await {
+                                                            Ok(_) => offices.restart(),
+                                                            Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                        }
+                                                    },
+                                                    "削除"
+                                                }
+                                            }
+                                        }
+                                        ul { class: "text-sm text-gray-700 space-y-0.5",
+                                            if office.postal_code.is_some() || office.address.is_some() {
+                                                li {
+                                                    "〒{office.postal_code.clone().unwrap_or_default()} {office.address.clone().unwrap_or_default()}"
+                                                }
+                                            }
+                                            if let Some(phone) = &office.phone {
+                                                li { "TEL {phone}" }
+                                            }
+                                            if let Some(fax) = &office.fax {
+                                                li { "FAX {fax}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "営業所の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 営業所の入力フォーム（新規・編集兼用）
+#[component]
+fn OfficeForm(
+    client_id: i32,
+    initial: Option<ClientOffice>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record_id = initial.as_ref().map(|o| o.id);
+    let text =
+        |f: fn(&ClientOffice) -> Option<String>| initial.as_ref().and_then(f).unwrap_or_default();
+    let mut name = use_signal(|| text(|o| Some(o.name.clone())));
+    let mut postal_code = use_signal(|| text(|o| o.postal_code.clone()));
+    let mut address = use_signal(|| text(|o| o.address.clone()));
+    let mut phone = use_signal(|| text(|o| o.phone.clone()));
+    let mut fax = use_signal(|| text(|o| o.fax.clone()));
+    let mut is_main_office = use_signal(|| initial.as_ref().is_some_and(|o| o.is_main_office));
+    let mut is_active = use_signal(|| initial.as_ref().is_none_or(|o| o.is_active));
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| async move {
+        is_submitting.set(true);
+        let result = match record_id {
+            Some(id) => update_client_office(UpdateClientOffice {
+                id,
+                client_id,
+                name: name(),
+                postal_code: opt_string(postal_code()),
+                address: opt_string(address()),
+                phone: opt_string(phone()),
+                fax: opt_string(fax()),
+                is_main_office: is_main_office(),
+                is_active: is_active(),
+            })
+            .await
+            .map(|_| ()),
+            None => create_client_office(CreateClientOffice {
+                client_id,
+                name: name(),
+                postal_code: opt_string(postal_code()),
+                address: opt_string(address()),
+                phone: opt_string(phone()),
+                fax: opt_string(fax()),
+                is_main_office: is_main_office(),
+            })
+            .await
+            .map(|_| ()),
+        };
+        is_submitting.set(false);
+
+        match result {
+            Ok(_) => on_saved.call(()),
+            Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                TextField {
+                    label: "営業所名".to_string(),
+                    value: name(),
+                    required: true,
+                    on_input: move |v| name.set(v),
+                }
+                TextField {
+                    label: "郵便番号".to_string(),
+                    value: postal_code(),
+                    on_input: move |v| postal_code.set(v),
+                }
+                div { class: "col-span-2",
+                    TextField {
+                        label: "住所".to_string(),
+                        value: address(),
+                        on_input: move |v| address.set(v),
+                    }
+                }
+                TextField {
+                    label: "電話番号".to_string(),
+                    value: phone(),
+                    on_input: move |v| phone.set(v),
+                }
+                TextField {
+                    label: "FAX番号".to_string(),
+                    value: fax(),
+                    on_input: move |v| fax.set(v),
+                }
+            }
+            div { class: "flex gap-6 text-sm text-gray-700",
+                label { class: "flex items-center gap-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: is_main_office(),
+                        onchange: move |evt| is_main_office.set(evt.checked()),
+                    }
+                    "本社"
+                }
+                if record_id.is_some() {
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            checked: is_active(),
+                            onchange: move |evt| is_active.set(evt.checked()),
+                        }
+                        "稼働中"
+                    }
+                }
+            }
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm", "{error_message}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting() || name().trim().is_empty(),
+                    onclick: handle_submit,
+                    if record_id.is_some() { "更新" } else { "追加" }
+                }
+            }
+        }
+    }
+}
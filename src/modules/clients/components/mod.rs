@@ -0,0 +1,15 @@
+// 取引先関連のコンポーネント
+
+mod client_card;
+mod client_contacts_section;
+mod client_create_form;
+mod client_detail_page;
+mod client_edit_form;
+mod client_offices_section;
+
+pub use client_card::ClientCard;
+pub use client_contacts_section::ClientContactsSection;
+pub use client_create_form::ClientCreateForm;
+pub use client_detail_page::ClientDetailPage;
+pub use client_edit_form::ClientEditForm;
+pub use client_offices_section::ClientOfficesSection;
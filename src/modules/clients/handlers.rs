@@ -0,0 +1,292 @@
+//! 取引先管理のハンドラー層（サーバー関数）
+
+use super::models::{
+    Client, ClientContact, ClientMasters, ClientOffice, ClientSummary, CreateClient,
+    CreateClientContact, CreateClientOffice, UpdateClientContact, UpdateClientOffice,
+};
+#[cfg(feature = "server")]
+use super::repository::ClientRepository;
+use super::validation::{
+    validate_client_name, validate_corporate_number, validate_credit_limit, validate_email,
+    validate_invoice_registration_number, validate_phone,
+};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// データベースエラーを`ServerFnError`に変換するヘルパー関数
+#[cfg(feature = "server")]
+fn db_error_to_server_error(error: sqlx::Error) -> ServerFnError {
+    let error_msg = error.to_string();
+
+    // 重複・制約エラーの場合、わかりやすいメッセージに変換
+    if error_msg.contains("uq_clients_company_name") {
+        ServerFnError::new("この取引先名は既に登録されています".to_string())
+    } else if error_msg.contains("uq_client_offices_name") {
+        ServerFnError::new("この営業所名は既に登録されています".to_string())
+    } else {
+        ServerFnError::new(error_msg)
+    }
+}
+
+/// 取引先の全項目をバリデーション
+fn validate_client(client: &Client) -> Result<(), ServerFnError> {
+    validate_client_name(&client.name).map_err(ServerFnError::new)?;
+    validate_corporate_number(client.corporate_number.as_deref().unwrap_or(""))
+        .map_err(ServerFnError::new)?;
+    validate_invoice_registration_number(
+        client.invoice_registration_number.as_deref().unwrap_or(""),
+    )
+    .map_err(ServerFnError::new)?;
+    validate_phone(client.phone.as_deref().unwrap_or(""), "電話番号")
+        .map_err(ServerFnError::new)?;
+    validate_phone(client.fax.as_deref().unwrap_or(""), "FAX番号").map_err(ServerFnError::new)?;
+    validate_email(client.email.as_deref().unwrap_or("")).map_err(ServerFnError::new)?;
+    validate_credit_limit(client.credit_limit.as_ref()).map_err(ServerFnError::new)?;
+    Ok(())
+}
+
+/// 営業所の入力チェック
+fn validate_office_fields(
+    name: &str,
+    phone: Option<&str>,
+    fax: Option<&str>,
+) -> Result<(), ServerFnError> {
+    if name.trim().is_empty() {
+        return Err(ServerFnError::new("営業所名を入力してください".to_string()));
+    }
+    validate_phone(phone.unwrap_or(""), "電話番号").map_err(ServerFnError::new)?;
+    validate_phone(fax.unwrap_or(""), "FAX番号").map_err(ServerFnError::new)?;
+    Ok(())
+}
+
+/// 担当者の入力チェック
+fn validate_contact_fields(
+    name: &str,
+    phone: Option<&str>,
+    mobile: Option<&str>,
+    email: Option<&str>,
+) -> Result<(), ServerFnError> {
+    if name.trim().is_empty() {
+        return Err(ServerFnError::new("担当者名を入力してください".to_string()));
+    }
+    validate_phone(phone.unwrap_or(""), "電話番号").map_err(ServerFnError::new)?;
+    validate_phone(mobile.unwrap_or(""), "携帯電話").map_err(ServerFnError::new)?;
+    validate_email(email.unwrap_or("")).map_err(ServerFnError::new)?;
+    Ok(())
+}
+
+// ========================================
+// 取引先
+// ========================================
+
+/// 全取引先の一覧を取得
+#[server]
+pub async fn get_clients() -> Result<Vec<ClientSummary>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::get_all_summaries()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// IDで取引先を取得
+#[server]
+pub async fn get_client(id: i32) -> Result<Option<Client>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::get_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 取引先入力フォーム用のマスタを取得
+#[server]
+pub async fn get_client_masters() -> Result<ClientMasters, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::get_masters()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 取引先名が使用可能かチェック
+#[server]
+pub async fn check_client_name_available(
+    company_id: i32,
+    name: String,
+    exclude_id: Option<i32>,
+) -> Result<bool, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::is_name_available(company_id, &name, exclude_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 取引先の作成
+#[server]
+pub async fn create_client(data: CreateClient) -> Result<Client, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_client_name(&data.name).map_err(ServerFnError::new)?;
+    validate_phone(data.phone.as_deref().unwrap_or(""), "電話番号").map_err(ServerFnError::new)?;
+
+    ClientRepository::create(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 取引先の全項目を更新
+#[server]
+pub async fn update_client(client: Client) -> Result<Client, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_client(&client)?;
+
+    ClientRepository::update(client)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 取引先の削除
+#[server]
+pub async fn delete_client(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    ClientRepository::delete(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+// ========================================
+// 営業所
+// ========================================
+
+/// 取引先の営業所一覧を取得
+#[server]
+pub async fn get_client_offices(client_id: i32) -> Result<Vec<ClientOffice>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::get_offices(client_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 営業所の作成
+#[server]
+pub async fn create_client_office(data: CreateClientOffice) -> Result<ClientOffice, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_office_fields(&data.name, data.phone.as_deref(), data.fax.as_deref())?;
+
+    ClientRepository::create_office(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 営業所の更新
+#[server]
+pub async fn update_client_office(data: UpdateClientOffice) -> Result<ClientOffice, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_office_fields(&data.name, data.phone.as_deref(), data.fax.as_deref())?;
+
+    ClientRepository::update_office(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 営業所の削除
+#[server]
+pub async fn delete_client_office(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    ClientRepository::delete_office(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+// ========================================
+// 担当者
+// ========================================
+
+/// 取引先の担当者一覧を取得
+#[server]
+pub async fn get_client_contacts(client_id: i32) -> Result<Vec<ClientContact>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    ClientRepository::get_contacts(client_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 担当者の作成
+#[server]
+pub async fn create_client_contact(
+    data: CreateClientContact,
+) -> Result<ClientContact, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_contact_fields(
+        &data.name,
+        data.phone.as_deref(),
+        data.mobile.as_deref(),
+        data.email.as_deref(),
+    )?;
+
+    ClientRepository::create_contact(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 担当者の更新
+#[server]
+pub async fn update_client_contact(
+    data: UpdateClientContact,
+) -> Result<ClientContact, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    // バリデーション
+    validate_contact_fields(
+        &data.name,
+        data.phone.as_deref(),
+        data.mobile.as_deref(),
+        data.email.as_deref(),
+    )?;
+    if data.is_primary && !data.is_active {
+        return Err(ServerFnError::new(
+            "無効の担当者をメイン担当者にすることはできません".to_string(),
+        ));
+    }
+
+    ClientRepository::update_contact(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 担当者をメイン担当者に設定
+#[server]
+pub async fn set_primary_client_contact(id: i32) -> Result<ClientContact, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    ClientRepository::set_primary_contact(id)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 担当者の削除
+#[server]
+pub async fn delete_client_contact(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    ClientRepository::delete_contact(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,19 @@
+//! 取引先管理モジュール
+//! 荷主・協力会社・リース会社などの取引先と、その営業所・担当者の管理
+
+mod components;
+pub mod handlers;
+pub mod models;
+mod page;
+
+#[cfg(feature = "server")]
+pub mod repository;
+pub mod validation;
+
+pub use components::ClientDetailPage;
+pub use models::{
+    Client, ClientContact, ClientMasters, ClientOffice, ClientSummary, ClientType, CreateClient,
+    CreateClientContact, CreateClientOffice, UpdateClientContact, UpdateClientOffice,
+    LEASE_COMPANY_TYPE_NAME,
+};
+pub use page::ClientsPage;
@@ -0,0 +1,185 @@
+//! 取引先管理のモデル定義
+
+#[cfg(feature = "server")]
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// リース会社として扱う取引先種別の名称
+pub const LEASE_COMPANY_TYPE_NAME: &str = "リース会社";
+
+/// 取引先種別マスタ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ClientType {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// 取引先（全項目）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct Client {
+    pub id: i32,
+    pub company_id: i32,
+    pub client_type_id: i32,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub corporate_number: Option<String>,
+    pub invoice_registration_number: Option<String>,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub email: Option<String>,
+    pub website_url: Option<String>,
+    pub payment_terms: Option<String>,
+    #[cfg(feature = "server")]
+    pub credit_limit: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    #[serde(default, deserialize_with = "crate::modules::money::deserialize_opt")]
+    pub credit_limit: Option<f64>,
+    pub is_active: bool,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 取引先一覧用（種別名・メイン担当者付き）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ClientSummary {
+    pub id: i32,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub client_type_id: i32,
+    pub client_type_name: String,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub primary_contact_name: Option<String>,
+    pub is_active: bool,
+}
+
+/// 取引先の新規作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateClient {
+    pub company_id: i32,
+    pub client_type_id: i32,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub phone: Option<String>,
+}
+
+/// 取引先営業所
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ClientOffice {
+    pub id: i32,
+    pub client_id: i32,
+    pub name: String,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub is_main_office: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 取引先営業所の作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateClientOffice {
+    pub client_id: i32,
+    pub name: String,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub is_main_office: bool,
+}
+
+/// 取引先営業所の更新
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateClientOffice {
+    pub id: i32,
+    pub client_id: i32,
+    pub name: String,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub is_main_office: bool,
+    pub is_active: bool,
+}
+
+/// 取引先担当者
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ClientContact {
+    pub id: i32,
+    pub client_id: i32,
+    pub client_office_id: Option<i32>,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub department: Option<String>,
+    pub position: Option<String>,
+    pub phone: Option<String>,
+    pub mobile: Option<String>,
+    pub email: Option<String>,
+    pub is_primary: bool,
+    pub is_active: bool,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 取引先担当者の作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateClientContact {
+    pub client_id: i32,
+    pub client_office_id: Option<i32>,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub department: Option<String>,
+    pub position: Option<String>,
+    pub phone: Option<String>,
+    pub mobile: Option<String>,
+    pub email: Option<String>,
+    pub is_primary: bool,
+    pub notes: Option<String>,
+}
+
+/// 取引先担当者の更新
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateClientContact {
+    pub id: i32,
+    pub client_id: i32,
+    pub client_office_id: Option<i32>,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub department: Option<String>,
+    pub position: Option<String>,
+    pub phone: Option<String>,
+    pub mobile: Option<String>,
+    pub email: Option<String>,
+    pub is_primary: bool,
+    pub is_active: bool,
+    pub notes: Option<String>,
+}
+
+/// 自社（会社マスタ）の選択肢
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct CompanyOption {
+    pub id: i32,
+    pub name: String,
+}
+
+/// 取引先の入力フォームで使うマスタ一式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientMasters {
+    pub companies: Vec<CompanyOption>,
+    pub client_types: Vec<ClientType>,
+}
@@ -0,0 +1,129 @@
+use dioxus::prelude::*;
+
+use crate::modules::clients::components::{ClientCard, ClientCreateForm};
+use crate::modules::clients::handlers::{get_client_masters, get_clients};
+use crate::Route;
+
+// 共通のCSSクラス定数
+const HEADER_CLASS: &str =
+    "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const BUTTON_PRIMARY_CLASS: &str =
+    "bg-amber-400 font-semibold py-2 px-6 rounded-lg hover:bg-amber-500 transition-all shadow-sm";
+const BUTTON_TOGGLE_ACTIVE_CLASS: &str = "bg-amber-400 text-gray-800 hover:bg-amber-500";
+const BUTTON_TOGGLE_INACTIVE_CLASS: &str = "bg-gray-200 text-gray-700 hover:bg-gray-300";
+const BUTTON_TOGGLE_BASE_CLASS: &str =
+    "px-4 py-2 rounded-lg text-sm font-semibold transition-all shadow-sm";
+const PANEL_CLASS: &str =
+    "border-l border-gray-200 bg-white transition-all duration-300 ease-in-out shadow-xl";
+const GRID_CLASS: &str = "grid grid-cols-[repeat(auto-fill,minmax(300px,1fr))] gap-5";
+
+#[component]
+pub fn ClientsPage() -> Element {
+    let clients_list = use_resource(|| async move { get_clients().await });
+    let masters = use_resource(|| async move { get_client_masters().await });
+    let mut create_panel = use_signal(|| false);
+    let mut show_active_only = use_signal(|| true); // true: 取引中, false: 取引停止
+    let mut type_filter = use_signal(|| None::<i32>);
+
+    rsx! {
+        document::Title { "取引先一覧" }
+        div { class: "flex h-full",
+            div { class: "flex-1 flex flex-col overflow-hidden",
+                // ヘッダー
+                div { class: HEADER_CLASS,
+                    h1 { class: "text-lg font-semibold text-gray-800", "取引先一覧" }
+                    button {
+                        class: format!("{} {}", BUTTON_PRIMARY_CLASS, if create_panel() { "opacity-0 pointer-events-none" } else { "opacity-100" }),
+                        onclick: move |_| create_panel.set(true),
+                        "＋ 新規登録"
+                    }
+                }
+
+                // コンテンツ
+                match &*clients_list.read_unchecked() {
+                    Some(Ok(list)) => {
+                        let filtered: Vec<_> = list
+                            .iter()
+                            .filter(|c| c.is_active == show_active_only())
+                            .filter(|c| type_filter().is_none_or(|t| c.client_type_id == t))
+                            .cloned()
+                            .collect();
+                        rsx! {
+                            div { class: CONTENT_CLASS,
+                                div { class: "mb-6 flex items-center gap-3",
+                                    p { class: "text-gray-700 font-semibold text-lg", "全 {filtered.len()} 件" }
+                                    div { class: "h-6 w-px bg-gray-300" }
+                                    button {
+                                        class: format!("{} {}", BUTTON_TOGGLE_BASE_CLASS, if show_active_only() {
+                                            BUTTON_TOGGLE_ACTIVE_CLASS
+                                        } else {
+                                            BUTTON_TOGGLE_INACTIVE_CLASS
+                                        }),
+                                        onclick: move |_| show_active_only.set(!show_active_only()),
+                                        if show_active_only() { "取引中のみ" } else { "取引停止のみ" }
+                                    }
+                                    if let Some(Ok(m)) = &*masters.read_unchecked() {
+                                        select {
+                                            class: "px-3 py-2 border border-gray-300 rounded-lg text-sm bg-white focus:outline-none focus:ring-2 focus:ring-amber-400",
+                                            onchange: move |evt| type_filter.set(evt.value().parse().ok()),
+                                            option { value: "", "すべての種別" }
+                                            for t in m.client_types.iter() {
+                                                option { value: "{t.id}", selected: type_filter() == Some(t.id), "{t.name}" }
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: GRID_CLASS,
+                                    for client in filtered {
+                                        {
+                                            let id = client.id;
+                                            let nav = navigator();
+                                            rsx! {
+                                                ClientCard {
+                                                    client,
+                                                    on_click: move |_| {
+                                                        if !create_panel() {
+                                                            nav.push(Route::ClientDetailPage { id });
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Some(Err(e)) => rsx! {
+                        div { class: "flex-1 flex items-center justify-center p-6",
+                            div { class: "text-center",
+                                p { class: "text-red-500 font-semibold text-lg mb-2", "エラーが発生しました" }
+                                p { class: "text-gray-600 text-sm", "{e}" }
+                            }
+                        }
+                    },
+                    None => rsx! {
+                        div { class: "flex-1 flex items-center justify-center p-6",
+                            div { class: "text-center",
+                                div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500 mb-4" }
+                                p { class: "text-gray-600", "読み込み中..." }
+                            }
+                        }
+                    },
+                }
+            }
+
+            // CreateForm 開閉可能なパネル
+            div {
+                class: format!("{} {}", PANEL_CLASS, if create_panel() { "w-[480px]" } else { "w-0 overflow-hidden" }),
+                if create_panel() {
+                    ClientCreateForm {
+                        clients_list,
+                        on_close: move |_| create_panel.set(false),
+                    }
+                }
+            }
+        }
+    }
+}
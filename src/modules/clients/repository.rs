@@ -0,0 +1,524 @@
+#[cfg(feature = "server")]
+use super::models::{
+    Client, ClientContact, ClientMasters, ClientOffice, ClientSummary, ClientType, CompanyOption,
+    CreateClient, CreateClientContact, CreateClientOffice, UpdateClientContact, UpdateClientOffice,
+};
+#[cfg(feature = "server")]
+use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
+
+#[cfg(feature = "server")]
+pub struct ClientRepository;
+
+#[cfg(feature = "server")]
+impl ClientRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    // ========================================
+    // 取引先
+    // ========================================
+
+    /// 全取引先の一覧を取得（種別名・メイン担当者付き）
+    pub async fn get_all_summaries() -> Result<Vec<ClientSummary>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ClientSummary,
+            r#"SELECT
+                c.id,
+                c.name,
+                c.name_kana,
+                c.client_type_id,
+                t.name AS client_type_name,
+                c.phone,
+                c.address,
+                pc.name AS "primary_contact_name?",
+                c.is_active
+            FROM clients c
+            JOIN client_types t ON t.id = c.client_type_id
+            LEFT JOIN client_contacts pc
+                ON pc.client_id = c.id AND pc.is_primary = TRUE AND pc.is_active = TRUE
+            ORDER BY c.name_kana NULLS LAST, c.name"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで取引先を取得
+    pub async fn get_by_id(id: i32) -> Result<Option<Client>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Client,
+            r#"SELECT
+                id, company_id, client_type_id, name, name_kana,
+                corporate_number, invoice_registration_number,
+                postal_code, address, phone, fax, email, website_url,
+                payment_terms, credit_limit,
+                is_active, notes, created_at, updated_at
+            FROM clients WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 取引先名が使用可能かチェック（同一会社内で重複不可）
+    pub async fn is_name_available(
+        company_id: i32,
+        name: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<bool, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM clients
+                WHERE company_id = $1 AND name = $2
+                  AND ($3::INT IS NULL OR id <> $3)
+            ) AS "exists!""#,
+            company_id,
+            name,
+            exclude_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(!exists)
+    }
+
+    /// 取引先の作成
+    pub async fn create(data: CreateClient) -> Result<Client, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let client = sqlx::query_as!(
+            Client,
+            r#"INSERT INTO clients
+               (company_id, client_type_id, name, name_kana, phone,
+                is_active, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING
+                id, company_id, client_type_id, name, name_kana,
+                corporate_number, invoice_registration_number,
+                postal_code, address, phone, fax, email, website_url,
+                payment_terms, credit_limit,
+                is_active, notes, created_at, updated_at"#,
+            data.company_id,
+            data.client_type_id,
+            data.name,
+            data.name_kana,
+            data.phone
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("clients", client.id, None, &client).await;
+
+        Ok(client)
+    }
+
+    /// 取引先の全項目を更新
+    pub async fn update(client: Client) -> Result<Client, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(client.id).await?;
+        let updated = sqlx::query_as!(
+            Client,
+            r#"UPDATE clients SET
+                company_id = $2,
+                client_type_id = $3,
+                name = $4,
+                name_kana = $5,
+                corporate_number = $6,
+                invoice_registration_number = $7,
+                postal_code = $8,
+                address = $9,
+                phone = $10,
+                fax = $11,
+                email = $12,
+                website_url = $13,
+                payment_terms = $14,
+                credit_limit = $15,
+                is_active = $16,
+                notes = $17,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING
+                id, company_id, client_type_id, name, name_kana,
+                corporate_number, invoice_registration_number,
+                postal_code, address, phone, fax, email, website_url,
+                payment_terms, credit_limit,
+                is_active, notes, created_at, updated_at"#,
+            client.id,
+            client.company_id,
+            client.client_type_id,
+            client.name,
+            client.name_kana,
+            client.corporate_number,
+            client.invoice_registration_number,
+            client.postal_code,
+            client.address,
+            client.phone,
+            client.fax,
+            client.email,
+            client.website_url,
+            client.payment_terms,
+            client.credit_limit,
+            client.is_active,
+            client.notes
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update("clients", updated.id, None, old.as_ref(), &updated).await;
+
+        Ok(updated)
+    }
+
+    /// 取引先の削除（営業所・担当者も削除される）
+    pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
+        sqlx::query!("DELETE FROM clients WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("clients", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 入力フォーム用のマスタを取得
+    pub async fn get_masters() -> Result<ClientMasters, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let companies = sqlx::query_as!(
+            CompanyOption,
+            r#"SELECT id, name FROM companies WHERE is_active = TRUE ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let client_types = sqlx::query_as!(
+            ClientType,
+            r#"SELECT id, name, description FROM client_types ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(ClientMasters {
+            companies,
+            client_types,
+        })
+    }
+
+    // ========================================
+    // 営業所
+    // ========================================
+
+    /// 取引先の営業所一覧を取得（本社が先頭）
+    pub async fn get_offices(client_id: i32) -> Result<Vec<ClientOffice>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ClientOffice,
+            r#"SELECT id, client_id, name, postal_code, address, phone, fax,
+                      is_main_office, is_active, created_at, updated_at
+               FROM client_offices
+               WHERE client_id = $1
+               ORDER BY is_main_office DESC, is_active DESC, name"#,
+            client_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで営業所を取得
+    pub async fn get_office_by_id(id: i32) -> Result<Option<ClientOffice>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ClientOffice,
+            r#"SELECT id, client_id, name, postal_code, address, phone, fax,
+                      is_main_office, is_active, created_at, updated_at
+               FROM client_offices WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 営業所の作成（本社に指定した場合は他の営業所の本社指定を外す）
+    pub async fn create_office(data: CreateClientOffice) -> Result<ClientOffice, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+        let office = sqlx::query_as!(
+            ClientOffice,
+            r#"INSERT INTO client_offices
+               (client_id, name, postal_code, address, phone, fax, is_main_office,
+                is_active, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, client_id, name, postal_code, address, phone, fax,
+                         is_main_office, is_active, created_at, updated_at"#,
+            data.client_id,
+            data.name,
+            data.postal_code,
+            data.address,
+            data.phone,
+            data.fax,
+            data.is_main_office
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if office.is_main_office {
+            Self::clear_main_office(&mut tx, office.client_id, office.id).await?;
+        }
+        tx.commit().await?;
+
+        audit_log::record_create("client_offices", office.id, None, &office).await;
+
+        Ok(office)
+    }
+
+    /// 営業所の更新
+    pub async fn update_office(data: UpdateClientOffice) -> Result<ClientOffice, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_office_by_id(data.id).await?;
+        let mut tx = pool.begin().await?;
+        let office = sqlx::query_as!(
+            ClientOffice,
+            r#"UPDATE client_offices SET
+                name = $3,
+                postal_code = $4,
+                address = $5,
+                phone = $6,
+                fax = $7,
+                is_main_office = $8,
+                is_active = $9,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND client_id = $2
+            RETURNING id, client_id, name, postal_code, address, phone, fax,
+                      is_main_office, is_active, created_at, updated_at"#,
+            data.id,
+            data.client_id,
+            data.name,
+            data.postal_code,
+            data.address,
+            data.phone,
+            data.fax,
+            data.is_main_office,
+            data.is_active
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if office.is_main_office {
+            Self::clear_main_office(&mut tx, office.client_id, office.id).await?;
+        }
+        tx.commit().await?;
+
+        audit_log::record_update("client_offices", office.id, None, old.as_ref(), &office).await;
+
+        Ok(office)
+    }
+
+    /// 営業所の削除（所属していた担当者は営業所未設定になる）
+    pub async fn delete_office(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_office_by_id(id).await?;
+        sqlx::query!("DELETE FROM client_offices WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("client_offices", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 指定した営業所以外の本社指定を外す
+    async fn clear_main_office(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        client_id: i32,
+        keep_id: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE client_offices
+               SET is_main_office = FALSE, updated_at = CURRENT_TIMESTAMP
+               WHERE client_id = $1 AND id <> $2 AND is_main_office = TRUE"#,
+            client_id,
+            keep_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+
+    // ========================================
+    // 担当者
+    // ========================================
+
+    /// 取引先の担当者一覧を取得（メイン担当者が先頭）
+    pub async fn get_contacts(client_id: i32) -> Result<Vec<ClientContact>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ClientContact,
+            r#"SELECT id, client_id, client_office_id, name, name_kana, department, position,
+                      phone, mobile, email, is_primary, is_active, notes, created_at, updated_at
+               FROM client_contacts
+               WHERE client_id = $1
+               ORDER BY is_primary DESC, is_active DESC, name_kana NULLS LAST, name"#,
+            client_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで担当者を取得
+    pub async fn get_contact_by_id(id: i32) -> Result<Option<ClientContact>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ClientContact,
+            r#"SELECT id, client_id, client_office_id, name, name_kana, department, position,
+                      phone, mobile, email, is_primary, is_active, notes, created_at, updated_at
+               FROM client_contacts WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 担当者の作成（メイン担当者に指定した場合は他の担当者の指定を外す）
+    pub async fn create_contact(data: CreateClientContact) -> Result<ClientContact, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+        let contact = sqlx::query_as!(
+            ClientContact,
+            r#"INSERT INTO client_contacts
+               (client_id, client_office_id, name, name_kana, department, position,
+                phone, mobile, email, is_primary, is_active, notes, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, TRUE, $11,
+                       CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, client_id, client_office_id, name, name_kana, department, position,
+                         phone, mobile, email, is_primary, is_active, notes,
+                         created_at, updated_at"#,
+            data.client_id,
+            data.client_office_id,
+            data.name,
+            data.name_kana,
+            data.department,
+            data.position,
+            data.phone,
+            data.mobile,
+            data.email,
+            data.is_primary,
+            data.notes
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if contact.is_primary {
+            Self::clear_primary_contact(&mut tx, contact.client_id, contact.id).await?;
+        }
+        tx.commit().await?;
+
+        audit_log::record_create("client_contacts", contact.id, None, &contact).await;
+
+        Ok(contact)
+    }
+
+    /// 担当者の更新
+    pub async fn update_contact(data: UpdateClientContact) -> Result<ClientContact, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_contact_by_id(data.id).await?;
+        let mut tx = pool.begin().await?;
+        let contact = sqlx::query_as!(
+            ClientContact,
+            r#"UPDATE client_contacts SET
+                client_office_id = $3,
+                name = $4,
+                name_kana = $5,
+                department = $6,
+                position = $7,
+                phone = $8,
+                mobile = $9,
+                email = $10,
+                is_primary = $11,
+                is_active = $12,
+                notes = $13,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1 AND client_id = $2
+            RETURNING id, client_id, client_office_id, name, name_kana, department, position,
+                      phone, mobile, email, is_primary, is_active, notes,
+                      created_at, updated_at"#,
+            data.id,
+            data.client_id,
+            data.client_office_id,
+            data.name,
+            data.name_kana,
+            data.department,
+            data.position,
+            data.phone,
+            data.mobile,
+            data.email,
+            data.is_primary,
+            data.is_active,
+            data.notes
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if contact.is_primary {
+            Self::clear_primary_contact(&mut tx, contact.client_id, contact.id).await?;
+        }
+        tx.commit().await?;
+
+        audit_log::record_update("client_contacts", contact.id, None, old.as_ref(), &contact).await;
+
+        Ok(contact)
+    }
+
+    /// 担当者をメイン担当者に設定（無効の担当者は有効に戻す）
+    pub async fn set_primary_contact(id: i32) -> Result<ClientContact, sqlx::Error> {
+        let Some(contact) = Self::get_contact_by_id(id).await? else {
+            return Err(sqlx::Error::RowNotFound);
+        };
+        Self::update_contact(UpdateClientContact {
+            id: contact.id,
+            client_id: contact.client_id,
+            client_office_id: contact.client_office_id,
+            name: contact.name,
+            name_kana: contact.name_kana,
+            department: contact.department,
+            position: contact.position,
+            phone: contact.phone,
+            mobile: contact.mobile,
+            email: contact.email,
+            is_primary: true,
+            is_active: true,
+            notes: contact.notes,
+        })
+        .await
+    }
+
+    /// 担当者の削除
+    pub async fn delete_contact(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_contact_by_id(id).await?;
+        sqlx::query!("DELETE FROM client_contacts WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("client_contacts", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 指定した担当者以外のメイン担当者指定を外す
+    async fn clear_primary_contact(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        client_id: i32,
+        keep_id: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE client_contacts
+               SET is_primary = FALSE, updated_at = CURRENT_TIMESTAMP
+               WHERE client_id = $1 AND id <> $2 AND is_primary = TRUE"#,
+            client_id,
+            keep_id
+        )
+        .execute(&mut **tx)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use std::cmp::Ordering;
+
+/// 取引先名のバリデーション
+///
+/// ルール:
+/// - 空文字列は不可
+/// - 前後の空白は不可
+/// - 200文字以内
+pub fn validate_client_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("取引先名を入力してください".to_string());
+    }
+
+    if name != name.trim() {
+        return Err("取引先名の前後に空白を含めることはできません".to_string());
+    }
+
+    if name.chars().count() > 200 {
+        return Err("取引先名は200文字以内で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 法人番号のバリデーション
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 13桁の数字のみ許可
+pub fn validate_corporate_number(number: &str) -> Result<(), String> {
+    if number.is_empty() {
+        return Ok(());
+    }
+
+    if number.len() != 13 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return Err("法人番号は13桁の数字で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 適格請求書発行事業者登録番号のバリデーション
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 「T」+ 13桁の数字
+pub fn validate_invoice_registration_number(number: &str) -> Result<(), String> {
+    if number.is_empty() {
+        return Ok(());
+    }
+
+    let Some(digits) = number.strip_prefix('T') else {
+        return Err("登録番号は「T」から始まる形式で入力してください".to_string());
+    };
+
+    if digits.len() != 13 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err("登録番号は「T」+13桁の数字で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 電話・FAX番号のバリデーション
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 数字とハイフンのみ許可
+/// - 20文字以内
+pub fn validate_phone(phone: &str, label: &str) -> Result<(), String> {
+    if phone.is_empty() {
+        return Ok(());
+    }
+
+    if !phone.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(format!("{}は数字とハイフンのみで入力してください", label));
+    }
+
+    if phone.len() > 20 {
+        return Err(format!("{}は20文字以内で入力してください", label));
+    }
+
+    Ok(())
+}
+
+/// メールアドレスのバリデーション（簡易）
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 「@」の前後に文字があり、ドメインに「.」を含む
+pub fn validate_email(email: &str) -> Result<(), String> {
+    if email.is_empty() {
+        return Ok(());
+    }
+
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.contains(char::is_whitespace)
+        }
+        None => false,
+    };
+    if !valid {
+        return Err("メールアドレスの形式が正しくありません".to_string());
+    }
+
+    Ok(())
+}
+
+/// 与信限度額のバリデーション（0以上）
+pub fn validate_credit_limit<T: PartialOrd + Default>(amount: Option<&T>) -> Result<(), String> {
+    if let Some(amount) = amount {
+        match amount.partial_cmp(&T::default()) {
+            Some(Ordering::Equal | Ordering::Greater) => {}
+            _ => return Err("与信限度額は0以上で入力してください".to_string()),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_name() {
+        assert!(validate_client_name("株式会社テスト運輸").is_ok());
+        assert!(validate_client_name("").is_err());
+        assert!(validate_client_name(" 株式会社テスト運輸").is_err());
+    }
+
+    #[test]
+    fn test_corporate_number() {
+        assert!(validate_corporate_number("").is_ok());
+        assert!(validate_corporate_number("1234567890123").is_ok());
+        assert!(validate_corporate_number("123456789012").is_err());
+        assert!(validate_corporate_number("12345678901AB").is_err());
+    }
+
+    #[test]
+    fn test_invoice_registration_number() {
+        assert!(validate_invoice_registration_number("").is_ok());
+        assert!(validate_invoice_registration_number("T1234567890123").is_ok());
+        assert!(validate_invoice_registration_number("1234567890123").is_err());
+        assert!(validate_invoice_registration_number("T123").is_err());
+    }
+
+    #[test]
+    fn test_phone_and_email() {
+        assert!(validate_phone("03-1234-5678", "電話番号").is_ok());
+        assert!(validate_phone("03(1234)5678", "電話番号").is_err());
+        assert!(validate_email("info@example.co.jp").is_ok());
+        assert!(validate_email("info@example").is_err());
+        assert!(validate_email("@example.com").is_err());
+    }
+
+    #[test]
+    fn test_credit_limit() {
+        assert!(validate_credit_limit(None::<&f64>).is_ok());
+        assert!(validate_credit_limit(Some(&1_000_000.0)).is_ok());
+        assert!(validate_credit_limit(Some(&-1.0)).is_err());
+        assert!(validate_credit_limit(Some(&f64::NAN)).is_err());
+    }
+}
@@ -5,11 +5,16 @@ use super::models::{
 use super::repository::DepartmentPositionHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の部署・役職履歴を取得
 #[server]
 pub async fn get_department_position_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<DepartmentPositionHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     DepartmentPositionHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -20,6 +25,8 @@ pub async fn get_department_position_history_by_employee(
 pub async fn get_current_department_position(
     employee_id: i32,
 ) -> Result<Option<DepartmentPositionHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     DepartmentPositionHistoryRepository::get_current_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -30,6 +37,8 @@ pub async fn get_current_department_position(
 pub async fn create_department_position_history(
     data: CreateDepartmentPositionHistory,
 ) -> Result<DepartmentPositionHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     if data.office_id.is_none() && data.department_id.is_none() && data.position_id.is_none() {
         return Err(ServerFnError::new(
@@ -47,6 +56,8 @@ pub async fn create_department_position_history(
 pub async fn update_department_position_history(
     data: UpdateDepartmentPositionHistory,
 ) -> Result<DepartmentPositionHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     if data.office_id.is_none() && data.department_id.is_none() && data.position_id.is_none() {
         return Err(ServerFnError::new(
@@ -62,6 +73,8 @@ pub async fn update_department_position_history(
 /// 部署・役職履歴を削除
 #[server]
 pub async fn delete_department_position_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     DepartmentPositionHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
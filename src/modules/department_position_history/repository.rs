@@ -4,6 +4,8 @@ use super::models::{
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct DepartmentPositionHistoryRepository;
@@ -88,7 +90,7 @@ impl DepartmentPositionHistoryRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             DepartmentPositionHistory,
             r#"INSERT INTO department_position_history
                (employee_id, office_id, department_id, position_id, start_date,
@@ -106,7 +108,17 @@ impl DepartmentPositionHistoryRepository {
             data.change_reason
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "department_position_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 部署・役職履歴を更新
@@ -114,6 +126,7 @@ impl DepartmentPositionHistoryRepository {
         data: UpdateDepartmentPositionHistory,
     ) -> Result<DepartmentPositionHistory, sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(data.id).await?;
 
         // 新しい履歴が現在の配属の場合、既存の現在配属フラグを解除（自分以外）
         if data.is_current {
@@ -128,7 +141,7 @@ impl DepartmentPositionHistoryRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             DepartmentPositionHistory,
             r#"UPDATE department_position_history
                SET office_id = $2, department_id = $3, position_id = $4,
@@ -148,18 +161,38 @@ impl DepartmentPositionHistoryRepository {
             data.change_reason
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "department_position_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 部署・役職履歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(
             r#"DELETE FROM department_position_history WHERE id = $1"#,
             id
         )
         .execute(pool)
         .await?;
+        audit_log::record_delete(
+            "department_position_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
@@ -0,0 +1,162 @@
+//! 運転者台帳に記載する情報の収集
+//! PDF描画は同期処理のため、必要なデータはここで先にすべて取得しておく
+
+use super::repository::DriverLedgerRepository;
+use crate::modules::aptitude_checkup_history::repository::AptitudeCheckupHistoryRepository;
+use crate::modules::aptitude_checkup_history::AptitudeCheckupHistory;
+use crate::modules::employee_documents::repository::EmployeeDocumentRepository;
+use crate::modules::employee_documents::DocumentCategory;
+use crate::modules::employees::repository::EmployeeRepository;
+use crate::modules::employees::{Address, EmployeeFull};
+use crate::modules::guidance_education_history::repository::GuidanceEducationHistoryRepository;
+use crate::modules::guidance_education_history::GuidanceEducationHistory;
+use crate::modules::health_checkup_history::repository::HealthCheckupHistoryRepository;
+use crate::modules::health_checkup_history::HealthCheckupHistory;
+use crate::modules::licenses::repository::LicenseRepository;
+use crate::modules::licenses::License;
+use crate::modules::s3_service;
+use std::collections::HashMap;
+
+/// 種別名を解決済みの履歴レコード
+pub struct Named<T> {
+    pub type_name: String,
+    pub record: T,
+}
+
+/// 運転者台帳の記載内容
+pub struct DriverLedgerData {
+    pub employee: EmployeeFull,
+    pub company_name: Option<String>,
+    pub office_name: Option<String>,
+    pub address: Option<Address>,
+    pub licenses: Vec<Named<License>>,
+    pub health_checkups: Vec<Named<HealthCheckupHistory>>,
+    pub aptitude_checkups: Vec<Named<AptitudeCheckupHistory>>,
+    pub guidance_educations: Vec<Named<GuidanceEducationHistory>>,
+    /// 顔写真（最新のFACE_PHOTO）の画像データ
+    pub face_photo: Option<Vec<u8>>,
+}
+
+/// 従業員IDから台帳データを収集（従業員が存在しない場合はNone）
+pub async fn collect(employee_id: i32) -> Result<Option<DriverLedgerData>, sqlx::Error> {
+    let Some(employee) = EmployeeRepository::get_by_id(employee_id).await? else {
+        return Ok(None);
+    };
+
+    let company_name = match employee.company_id {
+        Some(id) => DriverLedgerRepository::get_company_name(id).await?,
+        None => None,
+    };
+    let office_name = match employee.office_id {
+        Some(id) => DriverLedgerRepository::get_office_name(id).await?,
+        None => None,
+    };
+    let address = EmployeeRepository::get_current_address(employee_id).await?;
+
+    // 運転免許（有効なもののみ）
+    let license_types: HashMap<i32, String> = LicenseRepository::get_all_license_types()
+        .await?
+        .into_iter()
+        .map(|t| (t.id, t.name))
+        .collect();
+    let licenses = LicenseRepository::get_active_by_employee_id(employee_id)
+        .await?
+        .into_iter()
+        .map(|record| Named {
+            type_name: type_name(&license_types, record.license_type_id),
+            record,
+        })
+        .collect();
+
+    // 健康診断
+    let health_types: HashMap<i32, String> =
+        HealthCheckupHistoryRepository::get_all_health_checkup_types()
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+    let health_checkups = HealthCheckupHistoryRepository::get_by_employee_id(employee_id)
+        .await?
+        .into_iter()
+        .map(|record| Named {
+            type_name: type_name(&health_types, record.health_checkup_type_id),
+            record,
+        })
+        .collect();
+
+    // 適性診断
+    let aptitude_types: HashMap<i32, String> =
+        AptitudeCheckupHistoryRepository::get_all_aptitude_checkup_types()
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+    let aptitude_checkups = AptitudeCheckupHistoryRepository::get_by_employee_id(employee_id)
+        .await?
+        .into_iter()
+        .map(|record| Named {
+            type_name: type_name(&aptitude_types, record.aptitude_checkup_type_id),
+            record,
+        })
+        .collect();
+
+    // 指導教育
+    let guidance_types: HashMap<i32, String> =
+        GuidanceEducationHistoryRepository::get_all_guidance_education_types()
+            .await?
+            .into_iter()
+            .map(|t| (t.id, t.name))
+            .collect();
+    let guidance_educations = GuidanceEducationHistoryRepository::get_by_employee_id(employee_id)
+        .await?
+        .into_iter()
+        .map(|record| Named {
+            type_name: type_name(&guidance_types, record.guidance_education_type_id),
+            record,
+        })
+        .collect();
+
+    let face_photo = fetch_face_photo(employee_id).await?;
+
+    Ok(Some(DriverLedgerData {
+        employee,
+        company_name,
+        office_name,
+        address,
+        licenses,
+        health_checkups,
+        aptitude_checkups,
+        guidance_educations,
+        face_photo,
+    }))
+}
+
+/// 種別IDから名称を引く（見つからない場合はIDを表示）
+fn type_name(types: &HashMap<i32, String>, id: i32) -> String {
+    types
+        .get(&id)
+        .cloned()
+        .unwrap_or_else(|| format!("種別ID: {}", id))
+}
+
+/// 最新の顔写真を取得
+/// S3からの取得に失敗した場合は写真なしとして台帳を作成する
+async fn fetch_face_photo(employee_id: i32) -> Result<Option<Vec<u8>>, sqlx::Error> {
+    let documents = EmployeeDocumentRepository::get_by_category(
+        employee_id,
+        DocumentCategory::FacePhoto.as_str(),
+    )
+    .await?;
+
+    let Some(s3_key) = documents.into_iter().find_map(|d| d.s3_key) else {
+        return Ok(None);
+    };
+
+    match s3_service::download_file(&s3_key).await {
+        Ok(bytes) => Ok(Some(bytes.to_vec())),
+        Err(e) => {
+            eprintln!("顔写真の取得に失敗しました ({}): {}", s3_key, e);
+            Ok(None)
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::modules::driver_ledger::handlers::generate_driver_ledger;
+use crate::modules::driver_ledger::models::DriverLedgerPdf;
+use dioxus::prelude::*;
+
+/// 運転者台帳PDFの作成・ダウンロードボタン
+#[component]
+pub fn DriverLedgerButton(employee_id: i32) -> Element {
+    let mut is_generating = use_signal(|| false);
+    let mut ledger = use_signal(|| None::<DriverLedgerPdf>);
+    let mut error_message = use_signal(|| None::<String>);
+
+    let on_generate = move |_| async move {
+        is_generating.set(true);
+        error_message.set(None);
+        match generate_driver_ledger(employee_id).await {
+            Ok(pdf) => ledger.set(Some(pdf)),
+            Err(e) => error_message.set(Some(format!("台帳の作成に失敗しました: {}", e))),
+        }
+        is_generating.set(false);
+    };
+
+    rsx! {
+        div { class: "flex items-center gap-3",
+            if let Some(error) = error_message() {
+                span { class: "text-sm text-red-600", "{error}" }
+            }
+            if let Some(pdf) = ledger() {
+                a {
+                    class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm bg-green-600 text-white hover:bg-green-700",
+                    href: pdf.data_url(),
+                    download: pdf.filename.clone(),
+                    "⬇ 台帳をダウンロード"
+                }
+            }
+            button {
+                class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm bg-white border border-gray-300 text-gray-700 hover:bg-gray-50 disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: is_generating(),
+                onclick: on_generate,
+                if is_generating() { "作成中..." } else { "📄 運転者台帳" }
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod driver_ledger_button;
+
+pub use driver_ledger_button::DriverLedgerButton;
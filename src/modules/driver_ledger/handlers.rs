@@ -0,0 +1,44 @@
+//! 運転者台帳のハンドラー層（サーバー関数）
+
+use super::models::DriverLedgerPdf;
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::{collector, pdf};
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// 運転者台帳PDFを生成
+#[server]
+pub async fn generate_driver_ledger(employee_id: i32) -> Result<DriverLedgerPdf, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    build_ledger_pdf(employee_id, None).await
+}
+
+/// 台帳データを収集してPDFを組み立てる（印の有無は呼び出し側で指定）
+#[cfg(feature = "server")]
+pub async fn build_ledger_pdf(
+    employee_id: i32,
+    stamp: Option<&str>,
+) -> Result<DriverLedgerPdf, ServerFnError> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let data = collector::collect(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("従業員が見つかりません".to_string()))?;
+
+    let filename = format!(
+        "運転者台帳_{}_{}{}.pdf",
+        data.employee.employee_code, data.employee.last_name, data.employee.first_name
+    );
+
+    // PDFの描画は同期処理
+    let bytes = pdf::render(&data, stamp).map_err(ServerFnError::new)?;
+
+    Ok(DriverLedgerPdf {
+        filename,
+        data: general_purpose::STANDARD.encode(bytes),
+    })
+}
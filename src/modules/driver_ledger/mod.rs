@@ -0,0 +1,17 @@
+//! 運転者台帳モジュール
+//! 貨物自動車運送事業輸送安全規則 第9条の5 に基づく運転者台帳のPDF出力
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod collector;
+#[cfg(feature = "server")]
+pub mod pdf;
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::DriverLedgerButton;
+pub use handlers::generate_driver_ledger;
+pub use models::DriverLedgerPdf;
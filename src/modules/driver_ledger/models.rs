@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// 生成済みの運転者台帳PDF
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriverLedgerPdf {
+    /// ダウンロード時のファイル名
+    pub filename: String,
+    /// PDF本体（Base64）
+    pub data: String,
+}
+
+impl DriverLedgerPdf {
+    /// ダウンロードリンク用のdata URLを返す
+    pub fn data_url(&self) -> String {
+        format!("data:application/pdf;base64,{}", self.data)
+    }
+}
@@ -0,0 +1,544 @@
+//! 運転者台帳のPDF描画
+//! printpdfのドキュメントはSendではないため、描画は非同期処理を挟まずに行う
+
+use super::collector::DriverLedgerData;
+use chrono::{Local, NaiveDate};
+use printpdf::path::PaintMode;
+use printpdf::{
+    Color, Image, ImageTransform, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point, Rect, Rgb,
+};
+use std::fs::File;
+use std::io::BufReader;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - MARGIN * 2.0;
+const ROW_HEIGHT: f32 = 7.0;
+const FONT_SIZE: f32 = 9.0;
+
+// 写真欄（縦4cm×横3cm）
+const PHOTO_WIDTH: f32 = 30.0;
+const PHOTO_HEIGHT: f32 = 40.0;
+
+const LEGAL_BASIS: &str = "貨物自動車運送事業輸送安全規則 第9条の5 に基づく運転者台帳";
+
+/// 台帳PDFを生成
+/// `stamp` を指定すると表題の横に朱書きの印（例: "解任"）を付ける
+pub fn render(data: &DriverLedgerData, stamp: Option<&str>) -> Result<Vec<u8>, String> {
+    let font_path = std::env::var("LEDGER_FONT_PATH").map_err(|_| {
+        "LEDGER_FONT_PATH が設定されていません（日本語TrueTypeフォントのパスを指定してください）"
+            .to_string()
+    })?;
+    let font_file = File::open(&font_path)
+        .map_err(|e| format!("フォントを開けません ({}): {}", font_path, e))?;
+
+    let (doc, page, layer) =
+        PdfDocument::new("運転者台帳", Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+    let font = doc
+        .add_external_font(BufReader::new(font_file))
+        .map_err(|e| format!("フォントの読み込みに失敗しました: {}", e))?;
+    let layer = doc.get_page(page).get_layer(layer);
+
+    let mut writer = LedgerWriter {
+        doc,
+        layer,
+        font,
+        y: PAGE_HEIGHT - MARGIN,
+    };
+
+    writer.header(data, stamp);
+    writer.basic_info(data);
+    writer.licenses(data);
+    writer.health_checkups(data);
+    writer.aptitude_checkups(data);
+    writer.guidance_educations(data);
+    writer.accidents();
+    writer.footer();
+
+    writer
+        .doc
+        .save_to_bytes()
+        .map_err(|e| format!("PDFの生成に失敗しました: {}", e))
+}
+
+/// ページ送りを管理しながら上から順に描画するライター
+struct LedgerWriter {
+    doc: PdfDocumentReference,
+    layer: PdfLayerReference,
+    font: IndirectFontRef,
+    /// 次に描画する位置（ページ下端からのmm）
+    y: f32,
+}
+
+impl LedgerWriter {
+    // ========================================
+    // 台帳の各セクション
+    // ========================================
+
+    fn header(&mut self, data: &DriverLedgerData, stamp: Option<&str>) {
+        let employee = &data.employee;
+        let today = Local::now().date_naive();
+
+        self.text(MARGIN, self.y - 8.0, 18.0, "運転者台帳");
+        if let Some(stamp) = stamp {
+            self.stamp(MARGIN + 50.0, self.y - 11.0, stamp);
+        }
+
+        let right = PAGE_WIDTH - MARGIN - 70.0;
+        self.text(
+            right,
+            self.y - 4.0,
+            FONT_SIZE,
+            &format!(
+                "作成番号: {}-{}",
+                employee.employee_code,
+                today.format("%Y%m%d")
+            ),
+        );
+        self.text(
+            right,
+            self.y - 9.0,
+            FONT_SIZE,
+            &format!("作成年月日: {}", format_date(today)),
+        );
+        self.text(
+            right,
+            self.y - 14.0,
+            FONT_SIZE,
+            &format!(
+                "事業者: {}",
+                data.company_name.as_deref().unwrap_or("未設定")
+            ),
+        );
+        self.text(
+            right,
+            self.y - 19.0,
+            FONT_SIZE,
+            &format!(
+                "営業所: {}",
+                data.office_name.as_deref().unwrap_or("未設定")
+            ),
+        );
+        self.y -= 26.0;
+    }
+
+    fn basic_info(&mut self, data: &DriverLedgerData) {
+        let employee = &data.employee;
+        self.section_title("1. 運転者");
+
+        let top = self.y;
+        let photo_x = PAGE_WIDTH - MARGIN - PHOTO_WIDTH;
+        self.photo(photo_x, top - PHOTO_HEIGHT, data.face_photo.as_deref());
+
+        let kana = match (&employee.last_name_kana, &employee.first_name_kana) {
+            (Some(last), Some(first)) => format!("{} {}", last, first),
+            (Some(last), None) => last.clone(),
+            (None, Some(first)) => first.clone(),
+            (None, None) => String::new(),
+        };
+        let birth = match employee.birth_date {
+            Some(date) => format!(
+                "{}（{}歳）",
+                format_date(date),
+                age_on(date, Local::now().date_naive())
+            ),
+            None => "未登録".to_string(),
+        };
+        let address = match &data.address {
+            Some(a) => format!(
+                "〒{} {}{}{}{}",
+                a.postal_code,
+                a.prefecture,
+                a.city,
+                a.street,
+                a.building.as_deref().unwrap_or("")
+            ),
+            None => "未登録".to_string(),
+        };
+        let phone = employee
+            .mobile
+            .clone()
+            .or_else(|| employee.phone.clone())
+            .unwrap_or_default();
+
+        let fields = [
+            ("従業員コード", employee.employee_code.clone()),
+            (
+                "氏名",
+                format!("{} {}", employee.last_name, employee.first_name),
+            ),
+            ("フリガナ", kana),
+            ("生年月日", birth),
+            ("住所", address),
+            ("電話番号", phone),
+            ("雇入年月日", format_opt_date(employee.start_date)),
+            ("選任年月日", format_opt_date(employee.driver_start_date)),
+            ("解任年月日", format_opt_date(employee.driver_end_date)),
+            (
+                "解任の理由",
+                employee.driver_end_note.clone().unwrap_or_default(),
+            ),
+        ];
+
+        let width = CONTENT_WIDTH - PHOTO_WIDTH - 5.0;
+        for (label, value) in fields {
+            self.field_row(MARGIN, width, label, &value);
+        }
+        self.y = self.y.min(top - PHOTO_HEIGHT) - 4.0;
+    }
+
+    fn licenses(&mut self, data: &DriverLedgerData) {
+        self.section_title("2. 運転免許");
+        let rows = data
+            .licenses
+            .iter()
+            .map(|l| {
+                vec![
+                    l.type_name.clone(),
+                    l.record.license_number.clone().unwrap_or_default(),
+                    format_opt_date(l.record.issue_date),
+                    format_date(l.record.expiration_date),
+                    l.record.conditions.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        self.table(
+            &["種類", "免許証番号", "取得年月日", "有効期限", "条件"],
+            &[40.0, 40.0, 25.0, 25.0, 50.0],
+            rows,
+        );
+    }
+
+    fn health_checkups(&mut self, data: &DriverLedgerData) {
+        self.section_title("3. 健康状態（健康診断）");
+        let rows = data
+            .health_checkups
+            .iter()
+            .map(|h| {
+                vec![
+                    h.type_name.clone(),
+                    format_date(h.record.checkup_date),
+                    format_opt_date(h.record.expiration_date),
+                    h.record.medical_institution.clone().unwrap_or_default(),
+                    h.record.result.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        self.table(
+            &["種別", "受診日", "有効期限", "医療機関", "結果"],
+            &[40.0, 25.0, 25.0, 45.0, 45.0],
+            rows,
+        );
+    }
+
+    fn aptitude_checkups(&mut self, data: &DriverLedgerData) {
+        self.section_title("4. 適性診断の受診状況");
+        let rows = data
+            .aptitude_checkups
+            .iter()
+            .map(|a| {
+                vec![
+                    a.type_name.clone(),
+                    format_date(a.record.checkup_date),
+                    format_opt_date(a.record.expiration_date),
+                    a.record.testing_organization.clone().unwrap_or_default(),
+                    a.record.result.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        self.table(
+            &["種別", "受診日", "有効期限", "実施機関", "結果"],
+            &[40.0, 25.0, 25.0, 45.0, 45.0],
+            rows,
+        );
+    }
+
+    fn guidance_educations(&mut self, data: &DriverLedgerData) {
+        self.section_title("5. 指導の実施状況");
+        let rows = data
+            .guidance_educations
+            .iter()
+            .map(|g| {
+                vec![
+                    g.type_name.clone(),
+                    format_date(g.record.education_date),
+                    g.record
+                        .duration_hours
+                        .as_ref()
+                        .map(|h| format!("{}h", h))
+                        .unwrap_or_default(),
+                    g.record.instructor_name.clone().unwrap_or_default(),
+                    g.record.content.clone().unwrap_or_default(),
+                ]
+            })
+            .collect();
+        self.table(
+            &["種別", "実施日", "時間", "指導者", "内容"],
+            &[40.0, 25.0, 15.0, 35.0, 65.0],
+            rows,
+        );
+    }
+
+    fn accidents(&mut self) {
+        // 事故・違反はシステムで管理していないため記入欄のみ設ける
+        self.section_title("6. 事故及び道路交通法違反の概要");
+        let height = 25.0;
+        self.ensure_space(height);
+        self.stroke_rect(MARGIN, self.y - height, CONTENT_WIDTH, height);
+        self.text(MARGIN + 2.0, self.y - 5.0, 7.0, "（記入欄）");
+        self.y -= height + 4.0;
+    }
+
+    /// 最終ページの下余白に根拠法令と出力日時を記載
+    fn footer(&mut self) {
+        self.text(MARGIN, MARGIN - 5.0, 7.0, LEGAL_BASIS);
+        let printed = format!("出力日時: {}", Local::now().format("%Y-%m-%d %H:%M"));
+        self.text(PAGE_WIDTH - MARGIN - 40.0, MARGIN - 5.0, 7.0, &printed);
+    }
+
+    // ========================================
+    // 描画ヘルパー
+    // ========================================
+
+    fn new_page(&mut self) {
+        let (page, layer) = self
+            .doc
+            .add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer 1");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// 残りの高さが足りなければ改ページ
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn text(&self, x: f32, y: f32, size: f32, text: &str) {
+        self.layer.use_text(text, size, Mm(x), Mm(y), &self.font);
+    }
+
+    fn stroke_rect(&self, x: f32, y: f32, width: f32, height: f32) {
+        self.layer.set_outline_color(black());
+        self.layer.set_outline_thickness(0.5);
+        self.layer.add_rect(
+            Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + height)).with_mode(PaintMode::Stroke),
+        );
+    }
+
+    fn fill_rect(&self, x: f32, y: f32, width: f32, height: f32) {
+        self.layer.set_fill_color(gray());
+        self.layer.set_outline_color(black());
+        self.layer.set_outline_thickness(0.5);
+        self.layer.add_rect(
+            Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + height)).with_mode(PaintMode::FillStroke),
+        );
+        self.layer.set_fill_color(black());
+    }
+
+    fn section_title(&mut self, title: &str) {
+        self.ensure_space(20.0);
+        self.text(MARGIN, self.y - 5.0, 11.0, title);
+        self.layer.set_outline_color(amber());
+        self.layer.set_outline_thickness(1.0);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(MARGIN), Mm(self.y - 7.0)), false),
+                (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(self.y - 7.0)), false),
+            ],
+            is_closed: false,
+        });
+        self.y -= 10.0;
+    }
+
+    /// 見出しと値の1行
+    fn field_row(&mut self, x: f32, width: f32, label: &str, value: &str) {
+        let label_width = 30.0;
+        let bottom = self.y - ROW_HEIGHT;
+        self.fill_rect(x, bottom, label_width, ROW_HEIGHT);
+        self.stroke_rect(x + label_width, bottom, width - label_width, ROW_HEIGHT);
+        self.text(x + 2.0, bottom + 2.2, FONT_SIZE, label);
+        let value = fit_text(value, width - label_width - 4.0, FONT_SIZE);
+        self.text(x + label_width + 2.0, bottom + 2.2, FONT_SIZE, &value);
+        self.y = bottom;
+    }
+
+    /// 表を描画（改ページ時は見出し行を繰り返す）
+    fn table(&mut self, headers: &[&str], widths: &[f32], rows: Vec<Vec<String>>) {
+        self.ensure_space(ROW_HEIGHT * 2.0);
+        self.table_header(headers, widths);
+
+        if rows.is_empty() {
+            let bottom = self.y - ROW_HEIGHT;
+            self.stroke_rect(MARGIN, bottom, widths.iter().sum(), ROW_HEIGHT);
+            self.text(MARGIN + 2.0, bottom + 2.2, FONT_SIZE, "記録なし");
+            self.y = bottom;
+        }
+
+        for row in rows {
+            if self.y - ROW_HEIGHT < MARGIN {
+                self.new_page();
+                self.table_header(headers, widths);
+            }
+            let bottom = self.y - ROW_HEIGHT;
+            let mut x = MARGIN;
+            for (value, width) in row.iter().zip(widths) {
+                self.stroke_rect(x, bottom, *width, ROW_HEIGHT);
+                let value = fit_text(value, width - 4.0, FONT_SIZE);
+                self.text(x + 2.0, bottom + 2.2, FONT_SIZE, &value);
+                x += width;
+            }
+            self.y = bottom;
+        }
+        self.y -= 4.0;
+    }
+
+    fn table_header(&mut self, headers: &[&str], widths: &[f32]) {
+        let bottom = self.y - ROW_HEIGHT;
+        let mut x = MARGIN;
+        for (header, width) in headers.iter().zip(widths) {
+            self.fill_rect(x, bottom, *width, ROW_HEIGHT);
+            self.text(x + 2.0, bottom + 2.2, FONT_SIZE, header);
+            x += width;
+        }
+        self.y = bottom;
+    }
+
+    /// 写真欄（写真がない・読み込めない場合は枠のみ）
+    fn photo(&self, x: f32, y: f32, photo: Option<&[u8]>) {
+        self.stroke_rect(x, y, PHOTO_WIDTH, PHOTO_HEIGHT);
+
+        let image = photo.and_then(|bytes| printpdf::image_crate::load_from_memory(bytes).ok());
+        let Some(image) = image else {
+            self.text(x + 9.0, y + PHOTO_HEIGHT / 2.0, FONT_SIZE, "写真");
+            return;
+        };
+
+        // 300dpi換算の原寸から、枠に収まる倍率を求める
+        let dpi = 300.0;
+        let native_width = image.width() as f32 / dpi * 25.4;
+        let native_height = image.height() as f32 / dpi * 25.4;
+        let scale = (PHOTO_WIDTH / native_width).min(PHOTO_HEIGHT / native_height);
+        let offset_x = (PHOTO_WIDTH - native_width * scale) / 2.0;
+        let offset_y = (PHOTO_HEIGHT - native_height * scale) / 2.0;
+
+        Image::from_dynamic_image(&image).add_to_layer(
+            self.layer.clone(),
+            ImageTransform {
+                translate_x: Some(Mm(x + offset_x)),
+                translate_y: Some(Mm(y + offset_y)),
+                scale_x: Some(scale),
+                scale_y: Some(scale),
+                dpi: Some(dpi),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// 朱書きの印
+    fn stamp(&self, x: f32, y: f32, text: &str) {
+        let width = text.chars().count() as f32 * 7.0 + 6.0;
+        self.layer.set_outline_color(red());
+        self.layer.set_outline_thickness(1.5);
+        self.layer.add_rect(
+            Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + 11.0)).with_mode(PaintMode::Stroke),
+        );
+        self.layer.set_fill_color(red());
+        self.text(x + 3.0, y + 3.0, 18.0, text);
+        self.layer.set_fill_color(black());
+        self.layer.set_outline_color(black());
+    }
+}
+
+fn black() -> Color {
+    Color::Rgb(Rgb::new(0.0, 0.0, 0.0, None))
+}
+
+fn gray() -> Color {
+    Color::Rgb(Rgb::new(0.93, 0.93, 0.93, None))
+}
+
+fn amber() -> Color {
+    Color::Rgb(Rgb::new(0.98, 0.75, 0.14, None))
+}
+
+fn red() -> Color {
+    Color::Rgb(Rgb::new(0.85, 0.1, 0.1, None))
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn format_opt_date(date: Option<NaiveDate>) -> String {
+    date.map(format_date).unwrap_or_default()
+}
+
+/// 基準日時点の満年齢
+fn age_on(birth_date: NaiveDate, today: NaiveDate) -> u32 {
+    today.years_since(birth_date).unwrap_or(0)
+}
+
+/// セル幅に収まるように文字列を切り詰める
+/// 全角は文字サイズ分、半角はその半分の幅として概算する
+fn fit_text(text: &str, width_mm: f32, font_size: f32) -> String {
+    let full_width = font_size * 0.3528;
+    let char_width = |c: char| {
+        if c.is_ascii() {
+            full_width / 2.0
+        } else {
+            full_width
+        }
+    };
+
+    let total: f32 = text.chars().map(char_width).sum();
+    if total <= width_mm {
+        return text.to_string();
+    }
+
+    let limit = width_mm - full_width;
+    let mut used = 0.0;
+    let mut result = String::new();
+    for c in text.chars() {
+        used += char_width(c);
+        if used > limit {
+            break;
+        }
+        result.push(c);
+    }
+    result.push('…');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_text_keeps_short_text() {
+        assert_eq!(fit_text("山田 太郎", 40.0, 9.0), "山田 太郎");
+    }
+
+    #[test]
+    fn test_fit_text_truncates_long_text() {
+        let fitted = fit_text("あいうえおかきくけこさしすせそ", 20.0, 9.0);
+        assert!(fitted.ends_with('…'));
+        assert!(fitted.chars().count() < 15);
+    }
+
+    #[test]
+    fn test_age_on() {
+        let birth = NaiveDate::from_ymd_opt(1960, 4, 2).unwrap();
+        assert_eq!(
+            age_on(birth, NaiveDate::from_ymd_opt(2025, 4, 1).unwrap()),
+            64
+        );
+        assert_eq!(
+            age_on(birth, NaiveDate::from_ymd_opt(2025, 4, 2).unwrap()),
+            65
+        );
+    }
+}
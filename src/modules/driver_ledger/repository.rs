@@ -0,0 +1,30 @@
+#[cfg(feature = "server")]
+use crate::db;
+
+/// 運転者台帳の見出しに使うマスタ名称の取得
+#[cfg(feature = "server")]
+pub struct DriverLedgerRepository;
+
+#[cfg(feature = "server")]
+impl DriverLedgerRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 会社名を取得
+    pub async fn get_company_name(company_id: i32) -> Result<Option<String>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(r#"SELECT name FROM companies WHERE id = $1"#, company_id)
+            .fetch_optional(pool)
+            .await
+    }
+
+    /// 営業所名を取得
+    pub async fn get_office_name(office_id: i32) -> Result<Option<String>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(r#"SELECT name FROM offices WHERE id = $1"#, office_id)
+            .fetch_optional(pool)
+            .await
+    }
+}
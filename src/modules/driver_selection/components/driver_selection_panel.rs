@@ -0,0 +1,110 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::driver_selection::handlers::{check_driver_selection, select_driver};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 運転者選任（前提条件の確認と選任開始日の設定、選任中でない従業員のみ表示）
+#[component]
+pub fn DriverSelectionPanel(
+    employee_id: i32,
+    is_active: bool,
+    driver_start_date: Option<NaiveDate>,
+    driver_end_date: Option<NaiveDate>,
+    on_selected: EventHandler<()>,
+) -> Element {
+    let today = chrono::Local::now().date_naive();
+    let can_edit = use_permission(Permission::EditDriver);
+    let mut selection_date = use_signal(move || today);
+    let mut is_saving = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+    let check =
+        use_resource(
+            move || async move { check_driver_selection(employee_id, selection_date()).await },
+        );
+
+    let is_selected = driver_start_date.is_some() && driver_end_date.is_none_or(|d| d > today);
+    if !is_active || is_selected {
+        return rsx! {};
+    }
+
+    let handle_select = move |_| async move {
+        is_saving.set(true);
+        error_message.set(String::new());
+        match select_driver(employee_id, selection_date()).await {
+            Ok(_) => on_selected.call(()),
+            Err(e) => error_message.set(format!("選任に失敗しました: {}", e)),
+        }
+        is_saving.set(false);
+    };
+
+    rsx! {
+        div { class: "mt-6 p-4 border border-amber-300 bg-amber-50 rounded-lg space-y-4",
+            div { class: "flex flex-wrap items-end justify-between gap-4",
+                div {
+                    h4 { class: "font-semibold text-gray-800", "運転者選任" }
+                    p { class: "text-xs text-gray-500",
+                        "前提条件をすべて満たすと選任開始日を設定できます"
+                    }
+                }
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "選任日" }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLASS,
+                        value: "{selection_date}",
+                        oninput: move |evt| {
+                            if let Ok(date) = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d") {
+                                selection_date.set(date);
+                            }
+                        },
+                    }
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-sm text-red-700", "❌ {error_message}" }
+            }
+
+            match &*check.read_unchecked() {
+                Some(Ok(result)) => rsx! {
+                    ul { class: "space-y-2 text-sm",
+                        for item in result.checks.iter() {
+                            li { key: "{item.prerequisite}", class: "flex items-start gap-2",
+                                if item.satisfied {
+                                    span { class: "text-green-600 font-bold", "✓" }
+                                } else {
+                                    span { class: "text-red-600 font-bold", "✕" }
+                                }
+                                span { class: "font-semibold text-gray-800 w-28 shrink-0", "{item.prerequisite}" }
+                                span {
+                                    class: if item.satisfied { "text-gray-600" } else { "text-red-700" },
+                                    "{item.detail}"
+                                }
+                            }
+                        }
+                    }
+                    if can_edit() {
+                        div { class: "flex justify-end",
+                            button {
+                                class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50",
+                                disabled: !result.is_ready() || is_saving(),
+                                onclick: handle_select,
+                                if is_saving() { "選任中..." } else { "運転者に選任" }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-sm text-red-500", "前提条件の確認に失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
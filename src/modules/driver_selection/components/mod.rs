@@ -0,0 +1,5 @@
+// 運転者選任のコンポーネント
+
+mod driver_selection_panel;
+
+pub use driver_selection_panel::DriverSelectionPanel;
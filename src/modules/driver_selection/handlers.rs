@@ -0,0 +1,178 @@
+//! 運転者選任のハンドラー層（サーバー関数）
+
+use super::models::SelectionCheck;
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{evaluate, ResidenceCardFact, SelectionCandidate, SelectionFacts};
+#[cfg(feature = "server")]
+use super::repository::DriverSelectionRepository;
+#[cfg(feature = "server")]
+use crate::modules::aptitude_checkup_history::repository::AptitudeCheckupHistoryRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::guidance_education_history::repository::GuidanceEducationHistoryRepository;
+#[cfg(feature = "server")]
+use crate::modules::guidance_education_history::GuidanceTrigger;
+#[cfg(feature = "server")]
+use crate::modules::health_checkup_history::repository::HealthCheckupHistoryRepository;
+#[cfg(feature = "server")]
+use crate::modules::health_follow_up::models::{next_checkup, PeriodicCheckupPlan};
+#[cfg(feature = "server")]
+use crate::modules::health_follow_up::repository::HealthFollowUpRepository;
+#[cfg(feature = "server")]
+use crate::modules::licenses::repository::LicenseRepository;
+#[cfg(feature = "server")]
+use crate::modules::residence_cards::repository::ResidenceCardRepository;
+
+/// 選任日時点で運転者選任の前提条件を満たしているかを確認
+#[server]
+pub async fn check_driver_selection(
+    employee_id: i32,
+    selection_date: NaiveDate,
+) -> Result<SelectionCheck, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let candidate = get_candidate(employee_id).await?;
+    load_check(&candidate, selection_date).await
+}
+
+/// 前提条件をすべて満たしている場合のみ運転者に選任する（選任開始日を設定）
+#[server]
+pub async fn select_driver(
+    employee_id: i32,
+    selection_date: NaiveDate,
+) -> Result<SelectionCheck, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    let candidate = get_candidate(employee_id).await?;
+
+    // バリデーション
+    if !candidate.is_active {
+        return Err(ServerFnError::new(
+            "在籍していない従業員は選任できません".to_string(),
+        ));
+    }
+    if candidate.is_selected(chrono::Local::now().date_naive()) {
+        return Err(ServerFnError::new(
+            "既に運転者に選任されています".to_string(),
+        ));
+    }
+    if let Some(end) = candidate.driver_end_date {
+        if selection_date <= end {
+            return Err(ServerFnError::new(
+                "選任日は前回の選任終了日より後の日付を指定してください".to_string(),
+            ));
+        }
+    }
+    let check = load_check(&candidate, selection_date).await?;
+    if !check.is_ready() {
+        return Err(ServerFnError::new(format!(
+            "選任の前提条件を満たしていません: {}",
+            check.missing_message()
+        )));
+    }
+
+    DriverSelectionRepository::select(employee_id, selection_date)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(check)
+}
+
+#[cfg(feature = "server")]
+async fn get_candidate(employee_id: i32) -> Result<SelectionCandidate, ServerFnError> {
+    DriverSelectionRepository::get_candidate(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("従業員が見つかりません".to_string()))
+}
+
+/// 従業員の記録を集めて前提条件を確認する
+#[cfg(feature = "server")]
+async fn load_check(
+    candidate: &SelectionCandidate,
+    selection_date: NaiveDate,
+) -> Result<SelectionCheck, ServerFnError> {
+    let to_error = |e: sqlx::Error| ServerFnError::new(e.to_string());
+    let employee_id = candidate.employee_id;
+
+    let license_expirations = LicenseRepository::get_active_by_employee_id(employee_id)
+        .await
+        .map_err(to_error)?
+        .into_iter()
+        .map(|l| l.expiration_date)
+        .collect();
+
+    let initial_aptitude_types: Vec<i32> =
+        AptitudeCheckupHistoryRepository::get_all_aptitude_checkup_types()
+            .await
+            .map_err(to_error)?
+            .into_iter()
+            .filter(|t| t.is_initial)
+            .map(|t| t.id)
+            .collect();
+    let initial_aptitude_dates = AptitudeCheckupHistoryRepository::get_by_employee_id(employee_id)
+        .await
+        .map_err(to_error)?
+        .into_iter()
+        .filter(|r| initial_aptitude_types.contains(&r.aptitude_checkup_type_id))
+        .map(|r| r.checkup_date)
+        .collect();
+
+    let initial_guidance_types: Vec<i32> =
+        GuidanceEducationHistoryRepository::get_all_guidance_education_types()
+            .await
+            .map_err(to_error)?
+            .into_iter()
+            .filter(|t| t.trigger_kind == Some(GuidanceTrigger::InitialDriver))
+            .map(|t| t.id)
+            .collect();
+    let initial_guidance_dates =
+        GuidanceEducationHistoryRepository::get_by_employee_id(employee_id)
+            .await
+            .map_err(to_error)?
+            .into_iter()
+            .filter(|r| initial_guidance_types.contains(&r.guidance_education_type_id))
+            .map(|r| r.education_date)
+            .collect();
+
+    // 選任日時点の定期健康診断の状況（選任日より後の受診は数えない）
+    let health_types = HealthCheckupHistoryRepository::get_all_health_checkup_types()
+        .await
+        .map_err(to_error)?;
+    let health_records: Vec<_> = HealthFollowUpRepository::get_records(&[employee_id])
+        .await
+        .map_err(to_error)?
+        .into_iter()
+        .filter(|r| r.checkup_date <= selection_date)
+        .collect();
+    let plan = PeriodicCheckupPlan::from_types(&health_types, candidate.is_night_worker);
+    let health_checkup = next_checkup(plan.as_ref(), &health_records, selection_date);
+
+    let residence_cards = ResidenceCardRepository::get_active_by_employee_id(employee_id)
+        .await
+        .map_err(to_error)?
+        .into_iter()
+        .map(|c| ResidenceCardFact {
+            expiration_date: c.expiration_date,
+            work_restrictions: c.work_restrictions,
+        })
+        .collect();
+
+    let facts = SelectionFacts {
+        is_foreign: candidate.is_foreign(),
+        license_expirations,
+        initial_aptitude_dates,
+        initial_guidance_dates,
+        health_checkup,
+        residence_cards,
+    };
+    Ok(SelectionCheck {
+        employee_id,
+        selection_date,
+        is_foreign: facts.is_foreign,
+        checks: evaluate(&facts, selection_date),
+    })
+}
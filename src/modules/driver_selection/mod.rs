@@ -0,0 +1,14 @@
+//! 運転者選任モジュール
+//! 運転免許証・初任診断・初任運転者講習・健康診断・在留カード（外国籍の従業員）を確認し、
+//! 前提条件をすべて満たした場合のみ選任開始日を設定する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::DriverSelectionPanel;
+pub use handlers::*;
+pub use models::{Prerequisite, PrerequisiteCheck, SelectionCheck};
@@ -0,0 +1,357 @@
+//! 運転者選任のモデル定義
+
+use crate::modules::guidance_compliance::ComplianceStatus;
+use crate::modules::health_follow_up::models::CheckupDue;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 日本国籍の国籍名（これ以外は在留カードの確認が必要）
+pub const JAPANESE_NATIONALITY: &str = "日本";
+
+/// 就労制限にこの語句を含む在留カードは運転業務に就けるとみなす
+/// （特定技能「自動車運送業」分野の指定書の内容を就労制限に記載する）
+pub const DRIVING_WORK_KEYWORD: &str = "自動車運送";
+
+/// 選任の前提条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Prerequisite {
+    /// 有効な運転免許証
+    License,
+    /// 初任診断の受診
+    InitialAptitude,
+    /// 初任運転者講習（特別な指導）の受講
+    InitialGuidance,
+    /// 期限内の定期健康診断
+    HealthCheckup,
+    /// 運転業務に就ける在留カード（外国籍の従業員のみ）
+    ResidenceCard,
+}
+
+impl Prerequisite {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Prerequisite::License => "運転免許証",
+            Prerequisite::InitialAptitude => "初任診断",
+            Prerequisite::InitialGuidance => "初任運転者講習",
+            Prerequisite::HealthCheckup => "健康診断",
+            Prerequisite::ResidenceCard => "在留カード",
+        }
+    }
+}
+
+impl std::fmt::Display for Prerequisite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 前提条件1つの確認結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrerequisiteCheck {
+    pub prerequisite: Prerequisite,
+    pub satisfied: bool,
+    /// 満たしている根拠、または不足している内容
+    pub detail: String,
+}
+
+/// 選任の前提条件の確認結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelectionCheck {
+    pub employee_id: i32,
+    /// 選任日
+    pub selection_date: NaiveDate,
+    pub is_foreign: bool,
+    pub checks: Vec<PrerequisiteCheck>,
+}
+
+impl SelectionCheck {
+    /// すべての前提条件を満たしているか
+    pub fn is_ready(&self) -> bool {
+        self.checks.iter().all(|c| c.satisfied)
+    }
+
+    /// 不足している前提条件
+    pub fn missing(&self) -> Vec<&PrerequisiteCheck> {
+        self.checks.iter().filter(|c| !c.satisfied).collect()
+    }
+
+    /// 不足している前提条件を1行にまとめたメッセージ
+    pub fn missing_message(&self) -> String {
+        self.missing()
+            .iter()
+            .map(|c| format!("{}（{}）", c.prerequisite, c.detail))
+            .collect::<Vec<_>>()
+            .join("、")
+    }
+}
+
+/// 在留カードの確認に使う内容
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResidenceCardFact {
+    pub expiration_date: NaiveDate,
+    pub work_restrictions: Option<String>,
+}
+
+/// 前提条件の確認に使う従業員の記録
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectionFacts {
+    pub is_foreign: bool,
+    /// 有効な運転免許証の有効期限
+    pub license_expirations: Vec<NaiveDate>,
+    /// 初任診断の受診日
+    pub initial_aptitude_dates: Vec<NaiveDate>,
+    /// 初任運転者講習の受講日
+    pub initial_guidance_dates: Vec<NaiveDate>,
+    /// 選任日時点の定期健康診断の状況
+    pub health_checkup: CheckupDue,
+    /// 有効な在留カード
+    pub residence_cards: Vec<ResidenceCardFact>,
+}
+
+/// 選任の前提条件の確認対象の従業員
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct SelectionCandidate {
+    pub employee_id: i32,
+    pub nationality_name: Option<String>,
+    pub is_night_worker: bool,
+    pub is_active: bool,
+    pub driver_start_date: Option<NaiveDate>,
+    pub driver_end_date: Option<NaiveDate>,
+}
+
+impl SelectionCandidate {
+    /// 外国籍か（国籍が未登録の場合は日本国籍とみなす）
+    pub fn is_foreign(&self) -> bool {
+        self.nationality_name
+            .as_deref()
+            .is_some_and(|n| n != JAPANESE_NATIONALITY)
+    }
+
+    /// 選任中か（選任終了日が過ぎていない）
+    pub fn is_selected(&self, today: NaiveDate) -> bool {
+        self.driver_start_date.is_some() && self.driver_end_date.is_none_or(|d| d > today)
+    }
+}
+
+/// 在留カードの就労制限が運転業務を認めているか
+pub fn check_work_restrictions(work_restrictions: Option<&str>) -> Result<(), String> {
+    let Some(text) = work_restrictions.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Err("就労制限が登録されていません".to_string());
+    };
+    if text.contains("就労不可") {
+        Err(format!("就労できません（{}）", text))
+    } else if text.contains("就労制限なし") || text.contains(DRIVING_WORK_KEYWORD) {
+        Ok(())
+    } else {
+        Err(format!(
+            "運転業務に就ける就労活動か確認できません（{}）",
+            text
+        ))
+    }
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+fn check(prerequisite: Prerequisite, result: Result<String, String>) -> PrerequisiteCheck {
+    let (satisfied, detail) = match result {
+        Ok(detail) => (true, detail),
+        Err(detail) => (false, detail),
+    };
+    PrerequisiteCheck {
+        prerequisite,
+        satisfied,
+        detail,
+    }
+}
+
+/// 選任日時点で前提条件を満たしているかを確認する
+pub fn evaluate(facts: &SelectionFacts, selection_date: NaiveDate) -> Vec<PrerequisiteCheck> {
+    let mut checks = Vec::new();
+
+    let license = match facts.license_expirations.iter().max() {
+        Some(exp) if *exp >= selection_date => Ok(format!("有効期限 {}", format_date(*exp))),
+        Some(exp) => Err(format!("有効期限が切れています（{}）", format_date(*exp))),
+        None => Err("有効な運転免許証が登録されていません".to_string()),
+    };
+    checks.push(check(Prerequisite::License, license));
+
+    // 初任診断・初任運転者講習は選任日までに受けていること
+    let taken = |dates: &[NaiveDate], verb: &str| match dates
+        .iter()
+        .filter(|d| **d <= selection_date)
+        .max()
+    {
+        Some(d) => Ok(format!("{} {}", verb, format_date(*d))),
+        None => Err(format!("選任日までの{}の記録がありません", verb)),
+    };
+    checks.push(check(
+        Prerequisite::InitialAptitude,
+        taken(&facts.initial_aptitude_dates, "受診"),
+    ));
+    checks.push(check(
+        Prerequisite::InitialGuidance,
+        taken(&facts.initial_guidance_dates, "受講"),
+    ));
+
+    let health = &facts.health_checkup;
+    let health = match (health.status, health.last_date, health.due_date) {
+        (ComplianceStatus::Compliant | ComplianceStatus::DueSoon, Some(last), Some(due)) => {
+            Ok(format!(
+                "受診 {}（次回期限 {}）",
+                format_date(last),
+                format_date(due)
+            ))
+        }
+        (ComplianceStatus::Overdue, _, Some(due)) => Err(format!(
+            "定期健康診断の期限が切れています（{}）",
+            format_date(due)
+        )),
+        (ComplianceStatus::NotRequired, _, _) => {
+            Err("定期健康診断の種別が設定されていません".to_string())
+        }
+        _ => Err("定期健康診断の受診記録がありません".to_string()),
+    };
+    checks.push(check(Prerequisite::HealthCheckup, health));
+
+    if facts.is_foreign {
+        let valid: Vec<&ResidenceCardFact> = facts
+            .residence_cards
+            .iter()
+            .filter(|c| c.expiration_date >= selection_date)
+            .collect();
+        let residence = if valid.is_empty() {
+            Err("有効な在留カードが登録されていません".to_string())
+        } else {
+            let results: Vec<(NaiveDate, Result<(), String>)> = valid
+                .iter()
+                .map(|c| {
+                    (
+                        c.expiration_date,
+                        check_work_restrictions(c.work_restrictions.as_deref()),
+                    )
+                })
+                .collect();
+            match results.iter().find(|(_, r)| r.is_ok()) {
+                Some((exp, _)) => Ok(format!("有効期限 {}", format_date(*exp))),
+                None => Err(results
+                    .into_iter()
+                    .filter_map(|(_, r)| r.err())
+                    .collect::<Vec<_>>()
+                    .join("、")),
+            }
+        };
+        checks.push(check(Prerequisite::ResidenceCard, residence));
+    }
+
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn facts() -> SelectionFacts {
+        SelectionFacts {
+            is_foreign: false,
+            license_expirations: vec![date(2028, 5, 1)],
+            initial_aptitude_dates: vec![date(2026, 3, 1)],
+            initial_guidance_dates: vec![date(2026, 3, 10)],
+            health_checkup: CheckupDue {
+                status: ComplianceStatus::Compliant,
+                last_date: Some(date(2026, 1, 15)),
+                due_date: Some(date(2027, 1, 15)),
+                frequency_months: Some(12),
+            },
+            residence_cards: vec![],
+        }
+    }
+
+    #[test]
+    fn test_all_prerequisites_satisfied() {
+        let checks = evaluate(&facts(), date(2026, 4, 1));
+        assert_eq!(checks.len(), 4);
+        assert!(checks.iter().all(|c| c.satisfied));
+    }
+
+    #[test]
+    fn test_missing_prerequisites_are_reported() {
+        let facts = SelectionFacts {
+            license_expirations: vec![date(2026, 3, 31)],
+            initial_aptitude_dates: vec![date(2026, 4, 10)],
+            initial_guidance_dates: vec![],
+            health_checkup: CheckupDue {
+                status: ComplianceStatus::NeverTaken,
+                last_date: None,
+                due_date: None,
+                frequency_months: Some(12),
+            },
+            ..facts()
+        };
+        let check = SelectionCheck {
+            employee_id: 1,
+            selection_date: date(2026, 4, 1),
+            is_foreign: false,
+            checks: evaluate(&facts, date(2026, 4, 1)),
+        };
+        assert!(!check.is_ready());
+        let missing: Vec<Prerequisite> = check.missing().iter().map(|c| c.prerequisite).collect();
+        assert_eq!(
+            missing,
+            vec![
+                Prerequisite::License,
+                Prerequisite::InitialAptitude,
+                Prerequisite::InitialGuidance,
+                Prerequisite::HealthCheckup,
+            ]
+        );
+        assert!(check
+            .missing_message()
+            .contains("初任運転者講習（選任日までの受講の記録がありません）"));
+    }
+
+    #[test]
+    fn test_residence_card_for_foreign_staff() {
+        let with_card = |restrictions: Option<&str>| SelectionFacts {
+            is_foreign: true,
+            residence_cards: vec![ResidenceCardFact {
+                expiration_date: date(2027, 1, 1),
+                work_restrictions: restrictions.map(str::to_string),
+            }],
+            ..facts()
+        };
+        let residence = |facts: &SelectionFacts| {
+            evaluate(facts, date(2026, 4, 1))
+                .into_iter()
+                .find(|c| c.prerequisite == Prerequisite::ResidenceCard)
+                .unwrap()
+        };
+
+        assert!(residence(&with_card(Some("就労制限なし"))).satisfied);
+        assert!(
+            residence(&with_card(Some(
+                "指定書により指定された就労活動のみ可（自動車運送業）"
+            )))
+            .satisfied
+        );
+        assert!(!residence(&with_card(Some("就労不可"))).satisfied);
+        assert!(!residence(&with_card(Some("在留資格に基づく就労活動のみ可"))).satisfied);
+        assert!(!residence(&with_card(None)).satisfied);
+
+        let expired = SelectionFacts {
+            is_foreign: true,
+            residence_cards: vec![ResidenceCardFact {
+                expiration_date: date(2026, 3, 1),
+                work_restrictions: Some("就労制限なし".to_string()),
+            }],
+            ..facts()
+        };
+        assert!(!residence(&expired).satisfied);
+    }
+}
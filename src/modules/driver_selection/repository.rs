@@ -0,0 +1,75 @@
+//! 運転者選任のリポジトリ層
+
+use super::models::SelectionCandidate;
+use crate::db;
+use crate::modules::audit_log;
+use crate::modules::employees::repository::EmployeeRepository;
+use chrono::NaiveDate;
+
+/// 運転者選任リポジトリ
+pub struct DriverSelectionRepository;
+
+impl DriverSelectionRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 選任の前提条件の確認対象の従業員を取得
+    pub async fn get_candidate(
+        employee_id: i32,
+    ) -> Result<Option<SelectionCandidate>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            SelectionCandidate,
+            r#"
+            SELECT
+                e.id AS employee_id,
+                n.name AS "nationality_name?",
+                e.is_night_worker,
+                e.is_active,
+                e.driver_start_date,
+                e.driver_end_date
+            FROM employees e
+            LEFT JOIN nationalities n ON n.id = e.nationality_id
+            WHERE e.id = $1
+            "#,
+            employee_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 運転者に選任する（以前の選任終了日・解除理由は消去）
+    pub async fn select(employee_id: i32, selection_date: NaiveDate) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = EmployeeRepository::get_by_id(employee_id).await?;
+        sqlx::query!(
+            r#"
+            UPDATE employees
+            SET driver_start_date = $2,
+                driver_end_date = NULL,
+                driver_end_note = NULL,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            "#,
+            employee_id,
+            selection_date
+        )
+        .execute(pool)
+        .await?;
+
+        if let Some(new) = EmployeeRepository::get_by_id(employee_id).await? {
+            audit_log::record_update(
+                "employees",
+                employee_id,
+                Some(employee_id),
+                old.as_ref(),
+                &new,
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+}
@@ -3,11 +3,16 @@ use super::models::{CreateEducationHistory, EducationHistory, UpdateEducationHis
 use super::repository::EducationHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の学歴一覧を取得
 #[server]
 pub async fn get_education_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<EducationHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EducationHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -18,6 +23,8 @@ pub async fn get_education_history_by_employee(
 pub async fn create_education_history(
     data: CreateEducationHistory,
 ) -> Result<EducationHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EducationHistoryRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -28,6 +35,8 @@ pub async fn create_education_history(
 pub async fn update_education_history(
     data: UpdateEducationHistory,
 ) -> Result<EducationHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EducationHistoryRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -36,6 +45,8 @@ pub async fn update_education_history(
 /// 学歴を削除
 #[server]
 pub async fn delete_education_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     EducationHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -2,6 +2,8 @@
 use super::models::{CreateEducationHistory, EducationHistory, UpdateEducationHistory};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct EducationHistoryRepository;
@@ -44,7 +46,7 @@ impl EducationHistoryRepository {
     /// 学歴を作成
     pub async fn create(data: CreateEducationHistory) -> Result<EducationHistory, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             EducationHistory,
             r#"INSERT INTO education_history (employee_id, school_name, degree, major, start_date, end_date, graduation_status, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, $7, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -58,13 +60,24 @@ impl EducationHistoryRepository {
             data.graduation_status
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "education_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 学歴を更新
     pub async fn update(data: UpdateEducationHistory) -> Result<EducationHistory, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let history = sqlx::query_as!(
             EducationHistory,
             r#"UPDATE education_history
                SET school_name = $2, degree = $3, major = $4, start_date = $5,
@@ -80,15 +93,36 @@ impl EducationHistoryRepository {
             data.graduation_status
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "education_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 学歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM education_history WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "education_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
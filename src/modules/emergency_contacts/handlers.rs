@@ -3,11 +3,16 @@ use super::models::{CreateEmergencyContact, EmergencyContact, UpdateEmergencyCon
 use super::repository::EmergencyContactRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の緊急連絡先一覧を取得
 #[server]
 pub async fn get_emergency_contacts_by_employee(
     employee_id: i32,
 ) -> Result<Vec<EmergencyContact>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmergencyContactRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -18,6 +23,8 @@ pub async fn get_emergency_contacts_by_employee(
 pub async fn get_active_emergency_contacts(
     employee_id: i32,
 ) -> Result<Vec<EmergencyContact>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmergencyContactRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -28,6 +35,8 @@ pub async fn get_active_emergency_contacts(
 pub async fn create_emergency_contact(
     data: CreateEmergencyContact,
 ) -> Result<EmergencyContact, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EmergencyContactRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -38,6 +47,8 @@ pub async fn create_emergency_contact(
 pub async fn update_emergency_contact(
     data: UpdateEmergencyContact,
 ) -> Result<EmergencyContact, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EmergencyContactRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -46,6 +57,8 @@ pub async fn update_emergency_contact(
 /// 緊急連絡先を削除
 #[server]
 pub async fn delete_emergency_contact(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     EmergencyContactRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -2,6 +2,8 @@
 use super::models::{CreateEmergencyContact, EmergencyContact, UpdateEmergencyContact};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct EmergencyContactRepository;
@@ -62,7 +64,7 @@ impl EmergencyContactRepository {
     /// 緊急連絡先を作成
     pub async fn create(data: CreateEmergencyContact) -> Result<EmergencyContact, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let contact = sqlx::query_as!(
             EmergencyContact,
             r#"INSERT INTO emergency_contacts (employee_id, name, relationship, phone, mobile, postal_code, address, priority_order, is_active, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -77,13 +79,24 @@ impl EmergencyContactRepository {
             data.priority_order
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "emergency_contacts",
+            contact.id,
+            Some(contact.employee_id),
+            &contact,
+        )
+        .await;
+
+        Ok(contact)
     }
 
     /// 緊急連絡先を更新
     pub async fn update(data: UpdateEmergencyContact) -> Result<EmergencyContact, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let contact = sqlx::query_as!(
             EmergencyContact,
             r#"UPDATE emergency_contacts
                SET name = $2, relationship = $3, phone = $4, mobile = $5, postal_code = $6,
@@ -101,15 +114,36 @@ impl EmergencyContactRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "emergency_contacts",
+            contact.id,
+            Some(contact.employee_id),
+            old.as_ref(),
+            &contact,
+        )
+        .await;
+
+        Ok(contact)
     }
 
     /// 緊急連絡先を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM emergency_contacts WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "emergency_contacts",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
@@ -0,0 +1,328 @@
+//! 書類の一括ダウンロード（サーバー専用）
+//!
+//! `GET /api/documents/archive?employees=1,2&category=...&related_type=...&related_id=...` で、
+//! 指定した従業員の現在の版の書類を種類ごとのフォルダに分けたZIPとして返す。
+//! ZIPは保存先から1件ずつ読み出しながら順次送信し、最後に一覧（manifest.csv）を加える。
+
+use super::models::{DocumentArchiveRequest, DocumentCategory};
+use super::repository::EmployeeDocumentRepository;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::session::user_from_cookies;
+use crate::modules::exports::writer;
+use crate::modules::exports::ExportFormat;
+use crate::modules::s3_service;
+use async_zip::tokio::write::ZipFileWriter;
+use async_zip::{Compression, ZipEntryBuilder};
+use axum::body::Body;
+use axum::extract::Query;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashSet;
+use tokio::io::AsyncWrite;
+use tower_cookies::Cookies;
+
+/// 一括ダウンロードエンドポイントのパス（Axumのルート定義用）
+pub const ROUTE: &str = "/api/documents/archive";
+
+/// ZIPに加える一覧のファイル名
+pub const MANIFEST_NAME: &str = "manifest.csv";
+
+/// 種類が未設定の書類のフォルダ名
+const UNCATEGORIZED_FOLDER: &str = "未分類";
+
+const MANIFEST_HEADERS: [&str; 10] = [
+    "社員番号",
+    "氏名",
+    "書類の種類",
+    "ファイル名",
+    "ZIP内のパス",
+    "版",
+    "サイズ（バイト）",
+    "説明",
+    "アップロード日時",
+    "備考",
+];
+
+#[derive(Debug, Deserialize)]
+pub struct ArchiveQuery {
+    /// 従業員ID（カンマ区切り）
+    #[serde(default)]
+    employees: String,
+    #[serde(default)]
+    category: Option<String>,
+    #[serde(default)]
+    related_type: Option<String>,
+    #[serde(default)]
+    related_id: Option<i32>,
+}
+
+/// ZIPに含める書類
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveDocument {
+    pub id: i32,
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub category: Option<DocumentCategory>,
+    pub filename: String,
+    pub s3_key: Option<String>,
+    pub file_size: Option<i64>,
+    pub version: i32,
+    pub description: Option<String>,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+/// ZIPのファイル名（1人ならその従業員の社員番号・氏名を付ける）
+pub fn archive_filename(documents: &[ArchiveDocument], employee_count: usize) -> String {
+    let date = chrono::Local::now().format("%Y%m%d");
+    match documents.first() {
+        Some(doc) if employee_count == 1 => format!(
+            "書類_{}_{}_{}.zip",
+            doc.employee_code,
+            doc.employee_name.replace(' ', ""),
+            date
+        ),
+        _ => format!("書類_{}名_{}.zip", employee_count, date),
+    }
+}
+
+/// ZIP内のパスに使えない文字を置き換える
+fn sanitize_path_segment(value: &str) -> String {
+    let sanitized: String = value
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    match sanitized.as_str() {
+        "" | "." | ".." => "_".to_string(),
+        _ => sanitized,
+    }
+}
+
+/// ZIP内のパス（複数人の場合は `社員番号_氏名/種類/ファイル名`、1人の場合は `種類/ファイル名`）
+///
+/// 同じパスが既にある場合は `名前 (2).pdf` のように番号を付ける
+pub fn entry_path(
+    doc: &ArchiveDocument,
+    per_employee_folder: bool,
+    used: &mut HashSet<String>,
+) -> String {
+    let mut folder = String::new();
+    if per_employee_folder {
+        folder.push_str(&sanitize_path_segment(&format!(
+            "{}_{}",
+            doc.employee_code,
+            doc.employee_name.replace(' ', "")
+        )));
+        folder.push('/');
+    }
+    folder.push_str(
+        doc.category
+            .map(|c| c.display_name())
+            .unwrap_or(UNCATEGORIZED_FOLDER),
+    );
+    folder.push('/');
+
+    let filename = sanitize_path_segment(&doc.filename);
+    let (stem, ext) = match filename.rfind('.') {
+        Some(i) if i > 0 => filename.split_at(i),
+        _ => (filename.as_str(), ""),
+    };
+    let mut path = format!("{}{}", folder, filename);
+    let mut n = 2;
+    while !used.insert(path.clone()) {
+        path = format!("{}{} ({}){}", folder, stem, n, ext);
+        n += 1;
+    }
+    path
+}
+
+/// 一覧（manifest.csv）の1行
+fn manifest_row(doc: &ArchiveDocument, path: &str, note: &str) -> Vec<String> {
+    vec![
+        doc.employee_code.clone(),
+        doc.employee_name.clone(),
+        doc.category
+            .map(|c| c.display_name())
+            .unwrap_or(UNCATEGORIZED_FOLDER)
+            .to_string(),
+        doc.filename.clone(),
+        path.to_string(),
+        doc.version.to_string(),
+        doc.file_size.map(|s| s.to_string()).unwrap_or_default(),
+        doc.description.clone().unwrap_or_default(),
+        doc.uploaded_at
+            .with_timezone(&chrono::Local)
+            .format("%Y/%m/%d %H:%M")
+            .to_string(),
+        note.to_string(),
+    ]
+}
+
+/// 書類を1件ずつ読み出してZIPに書き込み、最後に一覧を加える
+///
+/// 送信を始めた後はエラーを返せないため、読み出せなかった書類は一覧の備考に記録して続ける
+async fn write_archive<W>(writer: W, documents: Vec<ArchiveDocument>, per_employee_folder: bool)
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut zip = ZipFileWriter::with_tokio(writer);
+    let mut used = HashSet::new();
+    let mut rows = Vec::with_capacity(documents.len());
+
+    for doc in &documents {
+        let Some(s3_key) = doc.s3_key.as_deref() else {
+            rows.push(manifest_row(doc, "", "ファイルなし"));
+            continue;
+        };
+        let data = match s3_service::download_file(s3_key).await {
+            Ok(data) => data,
+            Err(e) => {
+                rows.push(manifest_row(
+                    doc,
+                    "",
+                    &format!("読み出せませんでした: {}", e),
+                ));
+                continue;
+            }
+        };
+        let path = entry_path(doc, per_employee_folder, &mut used);
+        // 画像・PDFは圧縮済みのため無圧縮で格納する
+        let entry = ZipEntryBuilder::new(path.clone().into(), Compression::Stored);
+        if let Err(e) = zip.write_entry_whole(entry, &data).await {
+            // 受信側が切断した場合など、以降は書き込めない
+            eprintln!("⚠️ Failed to write document archive: {}", e);
+            return;
+        }
+        rows.push(manifest_row(doc, &path, ""));
+    }
+
+    let manifest = match writer::write(ExportFormat::CsvUtf8, "manifest", &MANIFEST_HEADERS, &rows)
+    {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            eprintln!("⚠️ Failed to write document archive manifest: {}", e);
+            return;
+        }
+    };
+    let entry = ZipEntryBuilder::new(MANIFEST_NAME.into(), Compression::Stored);
+    if let Err(e) = zip.write_entry_whole(entry, &manifest).await {
+        eprintln!("⚠️ Failed to write document archive: {}", e);
+        return;
+    }
+    if let Err(e) = zip.close().await {
+        eprintln!("⚠️ Failed to write document archive: {}", e);
+    }
+}
+
+/// 指定した従業員の書類をZIPで返す
+pub async fn serve_archive(cookies: Cookies, Query(query): Query<ArchiveQuery>) -> Response {
+    match user_from_cookies(&cookies).await {
+        Ok(Some(user)) if user.can(Permission::EditDriver) => {}
+        Ok(Some(_)) => return StatusCode::FORBIDDEN.into_response(),
+        Ok(None) => return StatusCode::UNAUTHORIZED.into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+
+    let request = match DocumentArchiveRequest::from_query(
+        &query.employees,
+        query.category.as_deref(),
+        query.related_type.as_deref(),
+        query.related_id,
+    )
+    .and_then(|request| request.validate().map(|_| request))
+    {
+        Ok(request) => request,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+
+    let employee_ids = request.target_employee_ids();
+    let documents = match EmployeeDocumentRepository::list_for_archive(
+        &employee_ids,
+        request.filter.category,
+        request.filter.relation,
+    )
+    .await
+    {
+        Ok(documents) if documents.is_empty() => return StatusCode::NOT_FOUND.into_response(),
+        Ok(documents) => documents,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let filename = archive_filename(&documents, employee_ids.len());
+    let per_employee_folder = employee_ids.len() > 1;
+    let (writer, reader) = tokio::io::duplex(64 * 1024);
+    tokio::spawn(write_archive(writer, documents, per_employee_folder));
+
+    (
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                s3_service::content_disposition(&filename, true),
+            ),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        Body::from_stream(tokio_util::io::ReaderStream::new(reader)),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(
+        employee_code: &str,
+        category: Option<DocumentCategory>,
+        filename: &str,
+    ) -> ArchiveDocument {
+        ArchiveDocument {
+            id: 1,
+            employee_id: 1,
+            employee_code: employee_code.to_string(),
+            employee_name: "山田 太郎".to_string(),
+            category,
+            filename: filename.to_string(),
+            s3_key: Some("employees/1/license/a.jpg".to_string()),
+            file_size: Some(100),
+            version: 1,
+            description: None,
+            uploaded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_entry_path_by_category() {
+        let mut used = HashSet::new();
+        let doc = document("001", Some(DocumentCategory::LicenseFront), "免許証.jpg");
+        assert_eq!(
+            entry_path(&doc, false, &mut used),
+            "免許証（表）/免許証.jpg"
+        );
+        assert_eq!(
+            entry_path(&doc, false, &mut used),
+            "免許証（表）/免許証 (2).jpg"
+        );
+        assert_eq!(
+            entry_path(&doc, true, &mut used),
+            "001_山田太郎/免許証（表）/免許証.jpg"
+        );
+
+        let doc = document("001", None, "../a/b.pdf");
+        assert_eq!(entry_path(&doc, false, &mut used), "未分類/.._a_b.pdf");
+    }
+
+    #[test]
+    fn test_archive_filename() {
+        let docs = vec![document("001", None, "a.pdf")];
+        assert!(archive_filename(&docs, 1).starts_with("書類_001_山田太郎_"));
+        assert!(archive_filename(&docs, 3).starts_with("書類_3名_"));
+    }
+}
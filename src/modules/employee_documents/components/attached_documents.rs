@@ -0,0 +1,99 @@
+//! 記録に添付されたスキャンの表示コンポーネント
+
+use super::DocumentUploadForm;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::employee_documents::get_documents_by_relation;
+use crate::modules::employee_documents::models::DocumentRelation;
+use dioxus::prelude::*;
+
+/// 添付スキャン表示のプロパティ
+#[derive(Props, Clone, PartialEq)]
+pub struct AttachedDocumentsProps {
+    /// 従業員ID
+    pub employee_id: i32,
+    /// 添付先の記録
+    pub relation: DocumentRelation,
+}
+
+/// 記録（運転免許・資格・診断など）に添付されたスキャンを一覧表示し、追加で添付できるようにする
+#[component]
+pub fn AttachedDocuments(props: AttachedDocumentsProps) -> Element {
+    let can_edit = use_permission(Permission::EditDriver);
+    let mut show_form = use_signal(|| false);
+    let relation = props.relation;
+
+    let mut documents = use_resource(use_reactive((&relation,), move |(relation,)| async move {
+        get_documents_by_relation(relation).await
+    }));
+
+    let docs = match &*documents.read() {
+        Some(Ok(docs)) => docs.clone(),
+        Some(Err(e)) => {
+            return rsx! {
+                p { class: "text-xs text-red-600", "❌ 添付書類を読み込めませんでした: {e}" }
+            };
+        }
+        None => Vec::new(),
+    };
+
+    rsx! {
+        div { class: "mt-3 pt-3 border-t border-gray-100",
+            div { class: "flex items-center justify-between mb-2",
+                span { class: "text-xs font-semibold text-gray-500",
+                    "添付書類（{docs.len()}件）"
+                }
+                if can_edit() {
+                    button {
+                        class: "text-xs text-amber-700 hover:text-amber-900 font-semibold",
+                        onclick: move |_| show_form.set(!show_form()),
+                        if show_form() { "閉じる" } else { "＋ スキャンを添付" }
+                    }
+                }
+            }
+
+            if !docs.is_empty() {
+                div { class: "flex flex-wrap gap-2",
+                    for doc in docs.iter() {
+                        a {
+                            key: "{doc.id}",
+                            href: doc.file_url(false),
+                            target: "_blank",
+                            title: "{doc.filename}",
+                            class: "flex items-center gap-2 border border-gray-200 rounded-lg p-1 pr-3 hover:bg-gray-50 transition-colors",
+                            if let Some(thumbnail_url) = doc.thumbnail_url() {
+                                img {
+                                    class: "w-12 h-12 object-cover rounded",
+                                    src: "{thumbnail_url}",
+                                    alt: "{doc.filename}",
+                                    loading: "lazy",
+                                }
+                            } else {
+                                span { class: "w-12 h-12 flex items-center justify-center text-xl", "📄" }
+                            }
+                            div { class: "text-xs",
+                                p { class: "font-medium text-gray-800 max-w-40 truncate", "{doc.filename}" }
+                                if let Some(cat) = &doc.category {
+                                    p { class: "text-gray-500", "{cat.display_name()}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if show_form() {
+                div { class: "mt-3",
+                    DocumentUploadForm {
+                        employee_id: props.employee_id,
+                        relation: Some(relation),
+                        on_upload_success: move |_| {
+                            show_form.set(false);
+                            documents.restart();
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! 書類の一括ダウンロード（ZIP）ボタン
+
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::employee_documents::models::{DocumentArchiveRequest, DocumentArchiveSummary};
+use crate::modules::employee_documents::prepare_document_archive;
+use dioxus::prelude::*;
+
+/// 一括ダウンロードボタンのプロパティ
+#[derive(Props, Clone, PartialEq)]
+pub struct DocumentArchiveButtonProps {
+    /// 対象の従業員と絞り込み条件
+    pub request: DocumentArchiveRequest,
+    /// ボタンの表示名
+    #[props(default = "ZIPで一括ダウンロード".to_string())]
+    pub label: String,
+}
+
+/// 書類の一括ダウンロードボタン
+///
+/// 押すと件数と合計サイズを確認してからZIPをダウンロードする（書類を扱う権限がない場合は表示しない）
+#[component]
+pub fn DocumentArchiveButton(props: DocumentArchiveButtonProps) -> Element {
+    let can_download = use_permission(Permission::EditDriver);
+    let mut summary = use_signal(|| None::<DocumentArchiveSummary>);
+    let mut error = use_signal(|| None::<String>);
+    let mut is_loading = use_signal(|| false);
+
+    // 対象や絞り込みが変わったら確認内容を閉じる
+    use_effect(use_reactive((&props.request,), move |(_request,)| {
+        summary.set(None);
+        error.set(None);
+    }));
+
+    if !can_download() {
+        return rsx! {};
+    }
+
+    let request = props.request.clone();
+    let handle_prepare = move |_| {
+        let request = request.clone();
+        spawn(async move {
+            is_loading.set(true);
+            error.set(None);
+            match prepare_document_archive(request).await {
+                Ok(result) => summary.set(Some(result)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            is_loading.set(false);
+        });
+    };
+
+    let format_size = |bytes: i64| -> String {
+        if bytes < 1024 * 1024 {
+            format!("{:.1} KB", bytes as f64 / 1024.0)
+        } else {
+            format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+        }
+    };
+
+    rsx! {
+        div { class: "relative",
+            button {
+                class: "py-2 px-4 rounded-lg border border-gray-300 text-sm font-semibold text-gray-700 hover:bg-gray-50 transition-colors disabled:opacity-50",
+                disabled: is_loading(),
+                onclick: handle_prepare,
+                if is_loading() { "確認中..." } else { "{props.label}" }
+            }
+
+            if summary().is_some() || error().is_some() {
+                div { class: "absolute right-0 mt-2 w-72 bg-white border border-gray-200 rounded-lg shadow-lg p-4 z-20",
+                    if let Some(err) = error() {
+                        p { class: "text-sm text-red-700 mb-3", "❌ {err}" }
+                    }
+                    if let Some(result) = summary() {
+                        p { class: "text-sm text-gray-700 mb-1",
+                            "{result.employee_count}名・{result.document_count}件（{format_size(result.total_bytes)}）"
+                        }
+                        p { class: "text-xs text-gray-500 mb-3",
+                            "書類の種類ごとのフォルダと一覧（manifest.csv）をまとめたZIPを作成します"
+                        }
+                        a {
+                            class: "block text-center bg-amber-400 font-semibold py-2 px-4 rounded-lg hover:bg-amber-500 transition-all shadow-sm text-sm",
+                            href: "{result.url}",
+                            download: "",
+                            "ダウンロード"
+                        }
+                    }
+                    button {
+                        class: "mt-2 w-full text-sm text-gray-500 hover:text-gray-700",
+                        onclick: move |_| {
+                            summary.set(None);
+                            error.set(None);
+                        },
+                        "閉じる"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! ドキュメント一覧表示コンポーネント
 
-use crate::modules::employee_documents::models::{DocumentCategory, EmployeeDocument};
+use super::{DocumentArchiveButton, DocumentVersions};
+use crate::modules::employee_documents::models::{
+    DocumentArchiveRequest, DocumentCategory, DocumentFilter, EmployeeDocument,
+};
 use crate::modules::employee_documents::{delete_document, get_employee_documents};
 use dioxus::prelude::*;
 
@@ -48,11 +51,16 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
     let mut delete_confirm_id = use_signal(|| None::<i32>);
     let mut is_deleting = use_signal(|| false);
     let mut preview_doc = use_signal(|| None::<PreviewDocument>);
+    // 版の履歴を開いているドキュメント
+    let mut history_doc_id = use_signal(|| None::<i32>);
+    // 版の復元後の再読み込み用
+    let mut reload = use_signal(|| 0);
 
     // 初回読み込みと更新トリガー時にドキュメントを取得
     use_effect(move || {
         let employee_id = props.employee_id;
         let _refresh = props.refresh_trigger; // 依存関係として使用
+        let _reload = reload();
         spawn(async move {
             is_loading.set(true);
             error.set(None);
@@ -77,9 +85,10 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
 
             match delete_document(id).await {
                 Ok(_) => {
-                    // 一覧から削除
-                    documents.write().retain(|doc| doc.id != id);
+                    // 以前の版が現在の版に戻る場合があるため再読み込み
                     delete_confirm_id.set(None);
+                    history_doc_id.set(None);
+                    reload += 1;
                 }
                 Err(e) => {
                     error.set(Some(format!("削除に失敗しました: {}", e)));
@@ -124,34 +133,50 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
             div { class: "flex justify-between items-center mb-4",
                 h3 { class: "text-lg font-semibold", "ドキュメント一覧" }
 
-                // カテゴリフィルター
-                select {
-                    class: "px-3 py-1 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500",
-                    onchange: move |evt| {
-                        let value = evt.value();
-                        let filter = match value.as_str() {
-                            "ALL" => CategoryFilter::All,
-                            "FACE_PHOTO" => CategoryFilter::Category(DocumentCategory::FacePhoto),
-                            "LICENSE_FRONT" => CategoryFilter::Category(DocumentCategory::LicenseFront),
-                            "LICENSE_BACK" => CategoryFilter::Category(DocumentCategory::LicenseBack),
-                            "QUALIFICATION_CERT" => CategoryFilter::Category(DocumentCategory::QualificationCert),
-                            "VEHICLE_INSPECTION" => CategoryFilter::Category(DocumentCategory::VehicleInspection),
-                            "HEALTH_CHECKUP" => CategoryFilter::Category(DocumentCategory::HealthCheckup),
-                            "APTITUDE_CHECKUP" => CategoryFilter::Category(DocumentCategory::AptitudeCheckup),
-                            "OTHER" => CategoryFilter::Category(DocumentCategory::Other),
-                            _ => CategoryFilter::All,
-                        };
-                        category_filter.set(filter);
-                    },
-                    option { value: "ALL", "すべて" }
-                    option { value: "FACE_PHOTO", "顔写真" }
-                    option { value: "LICENSE_FRONT", "免許証（表）" }
-                    option { value: "LICENSE_BACK", "免許証（裏）" }
-                    option { value: "QUALIFICATION_CERT", "資格証明書" }
-                    option { value: "VEHICLE_INSPECTION", "車検証" }
-                    option { value: "HEALTH_CHECKUP", "健康診断書" }
-                    option { value: "APTITUDE_CHECKUP", "適性診断書" }
-                    option { value: "OTHER", "その他" }
+                div { class: "flex items-center gap-3",
+                    // 表示中の種類の書類をまとめてダウンロード
+                    DocumentArchiveButton {
+                        request: DocumentArchiveRequest {
+                            employee_ids: vec![props.employee_id],
+                            filter: DocumentFilter {
+                                category: match *category_filter.read() {
+                                    CategoryFilter::All => None,
+                                    CategoryFilter::Category(cat) => Some(cat),
+                                },
+                                ..Default::default()
+                            },
+                        },
+                    }
+
+                    // カテゴリフィルター
+                    select {
+                        class: "px-3 py-1 border border-gray-300 rounded-md text-sm focus:outline-none focus:ring-2 focus:ring-blue-500",
+                        onchange: move |evt| {
+                            let value = evt.value();
+                            let filter = match value.as_str() {
+                                "ALL" => CategoryFilter::All,
+                                "FACE_PHOTO" => CategoryFilter::Category(DocumentCategory::FacePhoto),
+                                "LICENSE_FRONT" => CategoryFilter::Category(DocumentCategory::LicenseFront),
+                                "LICENSE_BACK" => CategoryFilter::Category(DocumentCategory::LicenseBack),
+                                "QUALIFICATION_CERT" => CategoryFilter::Category(DocumentCategory::QualificationCert),
+                                "VEHICLE_INSPECTION" => CategoryFilter::Category(DocumentCategory::VehicleInspection),
+                                "HEALTH_CHECKUP" => CategoryFilter::Category(DocumentCategory::HealthCheckup),
+                                "APTITUDE_CHECKUP" => CategoryFilter::Category(DocumentCategory::AptitudeCheckup),
+                                "OTHER" => CategoryFilter::Category(DocumentCategory::Other),
+                                _ => CategoryFilter::All,
+                            };
+                            category_filter.set(filter);
+                        },
+                        option { value: "ALL", "すべて" }
+                        option { value: "FACE_PHOTO", "顔写真" }
+                        option { value: "LICENSE_FRONT", "免許証（表）" }
+                        option { value: "LICENSE_BACK", "免許証（裏）" }
+                        option { value: "QUALIFICATION_CERT", "資格証明書" }
+                        option { value: "VEHICLE_INSPECTION", "車検証" }
+                        option { value: "HEALTH_CHECKUP", "健康診断書" }
+                        option { value: "APTITUDE_CHECKUP", "適性診断書" }
+                        option { value: "OTHER", "その他" }
+                    }
                 }
             }
 
@@ -181,6 +206,15 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                             class: "border border-gray-200 rounded-lg p-4 hover:bg-gray-50 transition-colors",
 
                             div { class: "flex items-start justify-between",
+                                // サムネイル（画像のみ、原本は読み込まない）
+                                if let Some(thumbnail_url) = doc.thumbnail_url() {
+                                    img {
+                                        class: "w-16 h-16 object-cover rounded mr-4 border border-gray-200 shrink-0",
+                                        src: "{thumbnail_url}",
+                                        alt: "{doc.filename}",
+                                        loading: "lazy",
+                                    }
+                                }
                                 // ドキュメント情報
                                 div { class: "flex-1",
                                     div { class: "flex items-center gap-2 mb-1",
@@ -211,7 +245,17 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                                                 "{cat.display_name()}"
                                             }
                                         }
+                                        if let Some(relation) = doc.relation() {
+                                            span { class: "bg-green-100 text-green-800 px-2 py-0.5 rounded-full text-xs",
+                                                "{relation.record_type.display_name()}に添付"
+                                            }
+                                        }
                                         span { "{format_date(doc.uploaded_at)}" }
+                                        if doc.has_history() {
+                                            span { class: "bg-amber-100 text-amber-800 px-2 py-0.5 rounded-full text-xs",
+                                                "第{doc.version}版"
+                                            }
+                                        }
                                     }
 
                                     // 説明
@@ -227,12 +271,12 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                                 // アクションボタン
                                 div { class: "flex items-center gap-2 ml-4",
                                     // プレビューボタン（画像・PDF対応）
-                                    if let Some(url) = &doc.s3_url {
+                                    if doc.s3_key.is_some() {
                                         if doc.mime_type.as_ref().map(|m| m.starts_with("image/") || m == "application/pdf").unwrap_or(false) {
                                             button {
                                                 class: "px-3 py-1 text-sm bg-blue-100 text-blue-700 rounded hover:bg-blue-200 transition-colors",
                                                 onclick: {
-                                                    let url = url.clone();
+                                                    let url = doc.file_url(false);
                                                     let filename = doc.filename.clone();
                                                     let mime_type = doc.mime_type.clone().unwrap_or_default();
                                                     move |_| {
@@ -249,15 +293,32 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                                     }
 
                                     // ダウンロードボタン
-                                    if let Some(url) = &doc.s3_url {
+                                    if doc.s3_key.is_some() {
                                         a {
-                                            href: "{url}",
-                                            target: "_blank",
+                                            href: doc.file_url(true),
                                             class: "px-3 py-1 text-sm bg-gray-100 text-gray-700 rounded hover:bg-gray-200 transition-colors",
                                             "ダウンロード"
                                         }
                                     }
 
+                                    // 版の履歴
+                                    if doc.has_history() {
+                                        button {
+                                            class: "px-3 py-1 text-sm bg-amber-100 text-amber-800 rounded hover:bg-amber-200 transition-colors",
+                                            onclick: {
+                                                let doc_id = doc.id;
+                                                move |_| {
+                                                    if history_doc_id() == Some(doc_id) {
+                                                        history_doc_id.set(None);
+                                                    } else {
+                                                        history_doc_id.set(Some(doc_id));
+                                                    }
+                                                }
+                                            },
+                                            "履歴"
+                                        }
+                                    }
+
                                     // 削除ボタン
                                     button {
                                         class: "px-3 py-1 text-sm bg-red-100 text-red-700 rounded hover:bg-red-200 transition-colors",
@@ -269,6 +330,17 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                                     }
                                 }
                             }
+
+                            // 版の一覧
+                            if history_doc_id() == Some(doc.id) {
+                                DocumentVersions {
+                                    document_id: doc.id,
+                                    on_restored: move |_| {
+                                        history_doc_id.set(None);
+                                        reload += 1;
+                                    },
+                                }
+                            }
                         }
                     }
                 }
@@ -288,7 +360,7 @@ pub fn DocumentList(props: DocumentListProps) -> Element {
                 div { class: "bg-white rounded-lg p-6 max-w-sm w-full mx-4 shadow-xl",
                     h4 { class: "text-lg font-semibold mb-4", "削除の確認" }
                     p { class: "text-gray-600 mb-6",
-                        "このドキュメントを削除しますか？この操作は取り消せません。"
+                        "このドキュメントを削除しますか？この操作は取り消せません。以前の版がある場合は直前の版が現在の版になります。"
                     }
                     div { class: "flex justify-end gap-3",
                         button {
@@ -1,43 +1,95 @@
 //! ドキュメントアップロードフォームコンポーネント
 
-use crate::modules::employee_documents::models::{DocumentCategory, FileUpload};
+use super::upload_request::UploadFile;
+use crate::modules::employee_documents::models::{
+    is_allowed_upload, DocumentCategory, DocumentRelation, DocumentUploadResult,
+    MAX_FILES_PER_UPLOAD,
+};
 use dioxus::prelude::*;
 
-/// ファイル選択状態を表す構造体
-#[derive(Debug, Clone, Default)]
-struct SelectedFile {
-    name: String,
-    size: u64,
-    data: Option<String>, // Base64エンコードされたデータ
-}
-
 /// ドキュメントアップロードフォームのプロパティ
 #[derive(Props, Clone, PartialEq)]
 pub struct DocumentUploadFormProps {
     /// 従業員ID
     pub employee_id: i32,
+    /// 添付先の記録（運転免許・資格など。指定時は種類の初期値もそれに合わせる）
+    #[props(default)]
+    pub relation: Option<DocumentRelation>,
     /// アップロード成功時のコールバック
     pub on_upload_success: EventHandler<()>,
 }
 
-/// Base64エンコード関数（web feature用）
+/// ファイルサイズをフォーマット
+fn format_size(size: u64) -> String {
+    if size < 1024 {
+        format!("{} B", size)
+    } else if size < 1024 * 1024 {
+        format!("{:.1} KB", size as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
+    }
+}
+
+/// 拡張子からMIMEタイプを推測（送信時のContent-Type用）
 #[cfg(feature = "web")]
-fn encode_base64(data: &[u8]) -> String {
-    use base64::{engine::general_purpose, Engine as _};
-    general_purpose::STANDARD.encode(data)
+fn mime_type_for(filename: &str) -> &'static str {
+    let lower = filename.to_lowercase();
+    if lower.ends_with(".jpg") || lower.ends_with(".jpeg") {
+        "image/jpeg"
+    } else if lower.ends_with(".png") {
+        "image/png"
+    } else if lower.ends_with(".gif") {
+        "image/gif"
+    } else if lower.ends_with(".pdf") {
+        "application/pdf"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+/// 選択中の種類で送信できないファイルのエラーメッセージ（件数・サイズの上限）
+fn oversized_files(files: &[UploadFile], category: DocumentCategory) -> Option<String> {
+    if category.is_versioned() && files.len() > 1 {
+        return Some(format!(
+            "{}は1件ずつアップロードしてください",
+            category.display_name()
+        ));
+    }
+    let max_bytes = category.max_bytes();
+    let names: Vec<&str> = files
+        .iter()
+        .filter(|f| f.size() > max_bytes)
+        .map(|f| f.name.as_str())
+        .collect();
+    if names.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "{}は{}以下にしてください: {}",
+            category.display_name(),
+            format_size(max_bytes),
+            names.join("、")
+        ))
+    }
 }
 
 /// ドキュメントアップロードフォームコンポーネント
 #[component]
 pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
-    let mut selected_category = use_signal(|| DocumentCategory::FacePhoto);
+    let relation = props.relation;
+    let mut selected_category = use_signal(move || {
+        relation
+            .map(|r| r.record_type.default_category())
+            .unwrap_or(DocumentCategory::FacePhoto)
+    });
     let mut description = use_signal(|| String::new());
-    let mut selected_file = use_signal(SelectedFile::default);
+    let mut selected_files = use_signal(Vec::<UploadFile>::new);
     let mut is_uploading = use_signal(|| false);
     let mut upload_error = use_signal(|| None::<String>);
-    let mut upload_success = use_signal(|| false);
+    let mut upload_message = use_signal(|| None::<String>);
     let mut is_dragging = use_signal(|| false);
-    let mut upload_progress = use_signal(|| 0u8); // 0-100の進捗率
+    // 送信済みバイト数と合計バイト数
+    let mut upload_progress = use_signal(|| (0u64, 0u64));
 
     let on_upload_success = props.on_upload_success;
 
@@ -45,68 +97,56 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
     let handle_file_select = move |evt: Event<FormData>| {
         spawn(async move {
             upload_error.set(None);
-            upload_success.set(false);
+            upload_message.set(None);
 
             let files = evt.files();
-            if files.is_empty() {
-                selected_file.set(SelectedFile::default());
+            if files.len() > MAX_FILES_PER_UPLOAD {
+                upload_error.set(Some(format!(
+                    "一度にアップロードできるファイルは{}件までです",
+                    MAX_FILES_PER_UPLOAD
+                )));
+                selected_files.set(Vec::new());
                 return;
             }
 
-            let file = &files[0];
-            let name = file.name();
-            let size = file.size();
-
-            // ファイルサイズチェック（10MB制限）
-            if size > 10 * 1024 * 1024 {
-                upload_error.set(Some("ファイルサイズは10MB以下にしてください".to_string()));
-                selected_file.set(SelectedFile::default());
-                return;
-            }
-
-            // ファイル拡張子チェック
-            let lower_name = name.to_lowercase();
-            let allowed_extensions = [".jpg", ".jpeg", ".png", ".gif", ".pdf"];
-            let is_valid_extension = allowed_extensions
-                .iter()
-                .any(|ext| lower_name.ends_with(ext));
-            if !is_valid_extension {
-                upload_error.set(Some(
-                    "対応ファイル形式: JPG, JPEG, PNG, GIF, PDF".to_string(),
-                ));
-                selected_file.set(SelectedFile::default());
-                return;
-            }
+            let mut selected = Vec::with_capacity(files.len());
+            for file in files.iter() {
+                let name = file.name();
+
+                // ファイル拡張子チェック
+                if !is_allowed_upload(&name) {
+                    upload_error.set(Some(format!(
+                        "対応ファイル形式: JPG, JPEG, PNG, GIF, PDF（{}）",
+                        name
+                    )));
+                    selected_files.set(Vec::new());
+                    return;
+                }
 
-            // ファイル内容を読み込み
-            #[cfg(feature = "web")]
-            {
-                let read_result = file.read_bytes().await;
-                match read_result {
-                    Ok(bytes) => {
-                        let bytes_vec: Vec<u8> = bytes.to_vec();
-                        let base64_data = encode_base64(&bytes_vec);
-                        selected_file.set(SelectedFile {
-                            name,
-                            size,
-                            data: Some(base64_data),
-                        });
-                    }
+                // ファイル内容を読み込み
+                #[cfg(feature = "web")]
+                match file.read_bytes().await {
+                    Ok(bytes) => selected.push(UploadFile {
+                        mime_type: mime_type_for(&name).to_string(),
+                        name,
+                        bytes: bytes.to_vec(),
+                    }),
                     Err(e) => {
                         upload_error.set(Some(format!("ファイル読み込みエラー: {}", e)));
-                        selected_file.set(SelectedFile::default());
+                        selected_files.set(Vec::new());
+                        return;
                     }
                 }
-            }
-
-            #[cfg(not(feature = "web"))]
-            {
-                selected_file.set(SelectedFile {
+                #[cfg(not(feature = "web"))]
+                selected.push(UploadFile {
                     name,
-                    size,
-                    data: None,
+                    ..Default::default()
                 });
             }
+
+            // ファイルサイズチェック（種類ごとの上限）
+            upload_error.set(oversized_files(&selected, selected_category()));
+            selected_files.set(selected);
         });
     };
 
@@ -129,50 +169,72 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
 
     // アップロードハンドラ
     let handle_upload = move |_| {
-        let file = selected_file.read().clone();
+        let files = selected_files.read().clone();
         let category = *selected_category.read();
         let desc = description.read().clone();
         let employee_id = props.employee_id;
 
         spawn(async move {
-            if file.data.is_none() {
+            if files.is_empty() {
                 upload_error.set(Some("ファイルが選択されていません".to_string()));
                 return;
             }
+            if let Some(message) = oversized_files(&files, category) {
+                upload_error.set(Some(message));
+                return;
+            }
 
             is_uploading.set(true);
             upload_error.set(None);
-            upload_progress.set(0);
-
-            // 進捗シミュレーション: ファイル準備中
-            upload_progress.set(10);
-
-            let upload_data = FileUpload {
-                employee_id,
-                category,
-                filename: file.name.clone(),
-                data: file.data.clone().unwrap(),
-                description: if desc.is_empty() { None } else { Some(desc) },
-                related_id: None,
+            upload_message.set(None);
+            upload_progress.set((0, 0));
+
+            // 種類・説明・添付先はファイルより先に送る（サーバーは受信しながら保存する）
+            #[cfg(feature = "web")]
+            let result = {
+                let mut fields = vec![
+                    ("category", category.as_str().to_string()),
+                    ("description", desc),
+                ];
+                if let Some(relation) = relation {
+                    fields.push(("related_type", relation.record_type.as_str().to_string()));
+                    fields.push(("related_id", relation.record_id.to_string()));
+                }
+                super::upload_request::send_multipart(
+                    &crate::modules::employee_documents::models::upload_url(employee_id),
+                    &fields,
+                    &files,
+                    move |sent, total| upload_progress.set((sent, total)),
+                )
+                .await
+                .and_then(|body| {
+                    serde_json::from_str::<DocumentUploadResult>(&body).map_err(|e| e.to_string())
+                })
+            };
+            #[cfg(not(feature = "web"))]
+            let result: Result<DocumentUploadResult, String> = {
+                let _ = (employee_id, desc);
+                Err("この環境ではアップロードできません".to_string())
             };
 
-            // 進捗シミュレーション: アップロード開始
-            upload_progress.set(30);
-
-            // サーバー関数を直接呼び出し
-            match crate::modules::employee_documents::upload_document(upload_data).await {
-                Ok(_) => {
-                    // 進捗: 完了
-                    upload_progress.set(100);
-                    // 成功
-                    upload_success.set(true);
-                    selected_file.set(SelectedFile::default());
-                    description.set(String::new());
-                    // コールバックを呼び出し
-                    on_upload_success.call(());
+            match result {
+                Ok(result) => {
+                    if !result.documents.is_empty() {
+                        upload_message.set(Some(format!(
+                            "✅ {}件のファイルをアップロードしました",
+                            result.documents.len()
+                        )));
+                        // コールバックを呼び出し
+                        on_upload_success.call(());
+                    }
+                    if result.errors.is_empty() {
+                        selected_files.set(Vec::new());
+                        description.set(String::new());
+                    } else {
+                        upload_error.set(Some(result.errors.join(" / ")));
+                    }
                 }
                 Err(e) => {
-                    upload_progress.set(0);
                     upload_error.set(Some(format!("アップロードエラー: {}", e)));
                 }
             }
@@ -181,31 +243,28 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
         });
     };
 
-    // ファイルサイズをフォーマット
-    let format_size = |size: u64| -> String {
-        if size < 1024 {
-            format!("{} B", size)
-        } else if size < 1024 * 1024 {
-            format!("{:.1} KB", size as f64 / 1024.0)
-        } else {
-            format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
-        }
-    };
-
-    let file = selected_file.read();
-    let has_file = file.data.is_some();
+    let files = selected_files.read();
+    let has_file = !files.is_empty();
+    let total_size: u64 = files.iter().map(|f| f.size()).sum();
+    let current_category = selected_category();
+    let max_size = format_size(current_category.max_bytes());
     let is_loading = *is_uploading.read();
+    let (sent, total) = upload_progress();
+    // 同じ画面に複数のフォームを置けるよう、添付先ごとに入力欄のIDを分ける
+    let input_id = match relation {
+        Some(r) => format!("file-input-{}-{}", r.record_type.as_str(), r.record_id),
+        None => "file-input".to_string(),
+    };
+    let percent = if total > 0 { sent * 100 / total } else { 0 };
 
     rsx! {
         div { class: "bg-white p-6 rounded-lg shadow-md",
             h3 { class: "text-lg font-semibold mb-4", "ドキュメントアップロード" }
 
             // 成功メッセージ
-            if *upload_success.read() {
+            if let Some(message) = upload_message.read().as_ref() {
                 div { class: "mb-4 p-4 bg-green-50 border border-green-200 rounded-lg",
-                    p { class: "text-sm text-green-800",
-                        "✅ ファイルが正常にアップロードされました"
-                    }
+                    p { class: "text-sm text-green-800", "{message}" }
                 }
             }
 
@@ -228,27 +287,18 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                         class: "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500",
                         disabled: is_loading,
                         onchange: move |evt| {
-                            let value = evt.value();
-                            let category = match value.as_str() {
-                                "FACE_PHOTO" => DocumentCategory::FacePhoto,
-                                "LICENSE_FRONT" => DocumentCategory::LicenseFront,
-                                "LICENSE_BACK" => DocumentCategory::LicenseBack,
-                                "QUALIFICATION_CERT" => DocumentCategory::QualificationCert,
-                                "VEHICLE_INSPECTION" => DocumentCategory::VehicleInspection,
-                                "HEALTH_CHECKUP" => DocumentCategory::HealthCheckup,
-                                "APTITUDE_CHECKUP" => DocumentCategory::AptitudeCheckup,
-                                _ => DocumentCategory::Other,
-                            };
-                            selected_category.set(category);
+                            if let Some(category) = DocumentCategory::parse(&evt.value()) {
+                                selected_category.set(category);
+                                upload_error.set(oversized_files(&selected_files.read(), category));
+                            }
                         },
-                        option { value: "FACE_PHOTO", "顔写真" }
-                        option { value: "LICENSE_FRONT", "免許証（表）" }
-                        option { value: "LICENSE_BACK", "免許証（裏）" }
-                        option { value: "QUALIFICATION_CERT", "資格証明書" }
-                        option { value: "VEHICLE_INSPECTION", "車検証" }
-                        option { value: "HEALTH_CHECKUP", "健康診断書" }
-                        option { value: "APTITUDE_CHECKUP", "適性診断書" }
-                        option { value: "OTHER", "その他" }
+                        for category in DocumentCategory::ALL {
+                            option {
+                                value: "{category.as_str()}",
+                                selected: selected_category() == category,
+                                "{category.display_name()}"
+                            }
+                        }
                     }
                 }
 
@@ -261,8 +311,9 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                     // 隠しファイル入力
                     input {
                         r#type: "file",
-                        id: "file-input",
+                        id: "{input_id}",
                         accept: ".jpg,.jpeg,.png,.gif,.pdf",
+                        multiple: true,
                         disabled: is_loading,
                         class: "hidden",
                         onchange: handle_file_select,
@@ -270,7 +321,7 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
 
                     // ドラッグ＆ドロップエリア（クリックでもファイル選択可能）
                     label {
-                        r#for: "file-input",
+                        r#for: "{input_id}",
                         class: if *is_dragging.read() {
                             "border-2 border-dashed border-blue-500 bg-blue-50 rounded-lg p-8 text-center cursor-pointer transition-colors block"
                         } else if has_file {
@@ -285,12 +336,14 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                         if has_file {
                             // 選択されたファイル情報
                             div { class: "text-green-700",
-                                p { class: "text-xl mb-2", "✅ ファイルが選択されました" }
-                                p { class: "text-base font-medium",
-                                    "📄 {file.name}"
-                                }
-                                p { class: "text-sm text-green-600 mt-1",
-                                    "({format_size(file.size)})"
+                                p { class: "text-xl mb-2", "✅ {files.len()}件のファイルが選択されました" }
+                                for file in files.iter() {
+                                    p { class: "text-base font-medium",
+                                        "📄 {file.name}"
+                                        span { class: "text-sm text-green-600 ml-2",
+                                            "({format_size(file.size())})"
+                                        }
+                                    }
                                 }
                                 p { class: "text-sm text-gray-500 mt-3",
                                     "別のファイルを選択するにはここをクリックするか、ドラッグ＆ドロップしてください"
@@ -315,7 +368,12 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                     }
 
                     p { class: "mt-2 text-xs text-gray-500 text-center",
-                        "対応形式: 画像（JPG, PNG, GIF）、PDF（最大10MB）"
+                        "対応形式: 画像（JPG, PNG, GIF）、PDF（{current_category.display_name()}は1ファイル{max_size}まで、一度に{MAX_FILES_PER_UPLOAD}件まで）"
+                    }
+                    if current_category.is_versioned() {
+                        p { class: "mt-1 text-xs text-gray-500 text-center",
+                            "登録済みの{current_category.display_name()}は新しい版に置き換えられ、以前の版は履歴から閲覧・復元できます"
+                        }
                     }
                 }
 
@@ -339,25 +397,25 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                 // アップロード進捗表示
                 if is_loading {
                     div { class: "space-y-2",
-                        // 進捗バー
+                        // 進捗バー（実際に送信したバイト数）
                         div { class: "w-full bg-gray-200 rounded-full h-3 overflow-hidden",
                             div {
                                 class: "bg-blue-600 h-3 rounded-full transition-all duration-300 ease-out",
-                                style: "width: {upload_progress()}%",
+                                style: "width: {percent}%",
                             }
                         }
                         // 進捗テキスト
                         div { class: "flex justify-between text-sm text-gray-600",
                             span {
-                                if *upload_progress.read() < 30 {
-                                    "ファイル準備中..."
-                                } else if *upload_progress.read() < 100 {
-                                    "S3にアップロード中..."
+                                if total == 0 {
+                                    "送信準備中..."
+                                } else if sent < total {
+                                    "アップロード中... {format_size(sent)} / {format_size(total)}"
                                 } else {
-                                    "完了!"
+                                    "保存中..."
                                 }
                             }
-                            span { "{upload_progress()}%" }
+                            span { "{percent}%" }
                         }
                     }
                 }
@@ -375,6 +433,8 @@ pub fn DocumentUploadForm(props: DocumentUploadFormProps) -> Element {
                         onclick: handle_upload,
                         if is_loading {
                             "アップロード中..."
+                        } else if has_file {
+                            "アップロード（{format_size(total_size)}）"
                         } else {
                             "アップロード"
                         }
@@ -0,0 +1,124 @@
+//! ドキュメントの版の一覧（置き換え履歴）コンポーネント
+
+use crate::modules::employee_documents::{get_document_versions, restore_document_version};
+use dioxus::prelude::*;
+
+/// 版の一覧のプロパティ
+#[derive(Props, Clone, PartialEq)]
+pub struct DocumentVersionsProps {
+    /// いずれかの版のドキュメントID
+    pub document_id: i32,
+    /// 以前の版を復元したときのコールバック
+    pub on_restored: EventHandler<()>,
+}
+
+/// ドキュメントの版の一覧
+#[component]
+pub fn DocumentVersions(props: DocumentVersionsProps) -> Element {
+    let document_id = props.document_id;
+    let mut versions =
+        use_resource(move || async move { get_document_versions(document_id).await });
+    let mut restoring_id = use_signal(|| None::<i32>);
+    let mut error = use_signal(|| None::<String>);
+    let on_restored = props.on_restored;
+
+    let handle_restore = move |id: i32| {
+        spawn(async move {
+            restoring_id.set(Some(id));
+            error.set(None);
+            match restore_document_version(id).await {
+                Ok(_) => {
+                    versions.restart();
+                    on_restored.call(());
+                }
+                Err(e) => error.set(Some(format!("復元に失敗しました: {}", e))),
+            }
+            restoring_id.set(None);
+        });
+    };
+
+    let format_date =
+        |dt: chrono::DateTime<chrono::Utc>| -> String { dt.format("%Y/%m/%d %H:%M").to_string() };
+
+    rsx! {
+        div { class: "mt-3 border-t border-gray-200 pt-3",
+            h4 { class: "text-sm font-semibold text-gray-700 mb-2", "版の履歴" }
+
+            if let Some(err) = error.read().as_ref() {
+                p { class: "mb-2 text-sm text-red-700", "❌ {err}" }
+            }
+
+            match &*versions.read() {
+                Some(Ok(list)) => rsx! {
+                    table { class: "w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-gray-500 border-b",
+                                th { class: "py-1 pr-2", "版" }
+                                th { class: "py-1 pr-2", "ファイル名" }
+                                th { class: "py-1 pr-2", "アップロード" }
+                                th { class: "py-1 pr-2", "置き換え" }
+                                th { class: "py-1" }
+                            }
+                        }
+                        tbody {
+                            for doc in list.iter() {
+                                tr {
+                                    key: "{doc.id}",
+                                    class: if doc.is_current { "border-b bg-amber-50" } else { "border-b" },
+                                    td { class: "py-1 pr-2 whitespace-nowrap",
+                                        "第{doc.version}版"
+                                        if doc.is_current {
+                                            span { class: "ml-1 bg-green-100 text-green-800 px-1.5 py-0.5 rounded text-xs",
+                                                "現在"
+                                            }
+                                        }
+                                    }
+                                    td { class: "py-1 pr-2 break-all", "{doc.filename}" }
+                                    td { class: "py-1 pr-2 whitespace-nowrap", "{format_date(doc.uploaded_at)}" }
+                                    td { class: "py-1 pr-2 whitespace-nowrap",
+                                        if let Some(superseded_at) = doc.superseded_at {
+                                            "{format_date(superseded_at)}"
+                                        } else {
+                                            "-"
+                                        }
+                                    }
+                                    td { class: "py-1 text-right whitespace-nowrap",
+                                        if doc.s3_key.is_some() {
+                                            a {
+                                                href: doc.file_url(false),
+                                                target: "_blank",
+                                                class: "px-2 py-0.5 text-xs bg-blue-100 text-blue-700 rounded hover:bg-blue-200",
+                                                "表示"
+                                            }
+                                        }
+                                        if !doc.is_current {
+                                            button {
+                                                class: "ml-2 px-2 py-0.5 text-xs bg-amber-100 text-amber-800 rounded hover:bg-amber-200 disabled:opacity-50",
+                                                disabled: restoring_id().is_some(),
+                                                onclick: {
+                                                    let id = doc.id;
+                                                    move |_| handle_restore(id)
+                                                },
+                                                if restoring_id() == Some(doc.id) {
+                                                    "復元中..."
+                                                } else {
+                                                    "この版に戻す"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-sm text-red-700", "履歴の取得に失敗しました: {e}" }
+                },
+                None => rsx! {
+                    p { class: "text-sm text-gray-500", "読み込み中..." }
+                },
+            }
+        }
+    }
+}
@@ -1,7 +1,14 @@
 //! 従業員ドキュメント管理のUIコンポーネント
 
+pub mod attached_documents;
+pub mod document_archive_button;
 pub mod document_list;
 pub mod document_upload_form;
+pub mod document_versions;
+pub mod upload_request;
 
+pub use attached_documents::AttachedDocuments;
+pub use document_archive_button::DocumentArchiveButton;
 pub use document_list::DocumentList;
 pub use document_upload_form::DocumentUploadForm;
+pub use document_versions::DocumentVersions;
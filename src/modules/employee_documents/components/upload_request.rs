@@ -0,0 +1,79 @@
+//! multipart/form-data でのファイル送信（Web専用）
+//! 送信済みバイト数を受け取るため、fetchではなくXMLHttpRequestを使う
+
+/// 送信するファイル
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UploadFile {
+    pub name: String,
+    pub mime_type: String,
+    pub bytes: Vec<u8>,
+}
+
+impl UploadFile {
+    pub fn size(&self) -> u64 {
+        self.bytes.len() as u64
+    }
+}
+
+/// フォームを送信し、レスポンス本文を返す
+///
+/// `on_progress`には送信済みバイト数と合計バイト数を渡す
+#[cfg(feature = "web")]
+pub async fn send_multipart(
+    url: &str,
+    fields: &[(&str, String)],
+    files: &[UploadFile],
+    mut on_progress: impl FnMut(u64, u64) + 'static,
+) -> Result<String, String> {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::{JsCast, JsValue};
+
+    let js_error = |e: JsValue| format!("{:?}", e);
+
+    let form = web_sys::FormData::new().map_err(js_error)?;
+    for (name, value) in fields {
+        form.append_with_str(name, value).map_err(js_error)?;
+    }
+    for file in files {
+        let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(file.bytes.as_slice()));
+        let options = web_sys::BlobPropertyBag::new();
+        options.set_type(&file.mime_type);
+        let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .map_err(js_error)?;
+        form.append_with_blob_and_filename("file", &blob, &file.name)
+            .map_err(js_error)?;
+    }
+
+    let xhr = web_sys::XmlHttpRequest::new().map_err(js_error)?;
+    xhr.open("POST", url).map_err(js_error)?;
+
+    let progress =
+        Closure::<dyn FnMut(web_sys::ProgressEvent)>::new(move |e: web_sys::ProgressEvent| {
+            if e.length_computable() {
+                on_progress(e.loaded() as u64, e.total() as u64);
+            }
+        });
+    xhr.upload()
+        .map_err(js_error)?
+        .set_onprogress(Some(progress.as_ref().unchecked_ref()));
+
+    // 完了（onload）または通信エラー（onerror）まで待つ
+    let done = js_sys::Promise::new(&mut |resolve, reject| {
+        xhr.set_onload(Some(&resolve));
+        xhr.set_onerror(Some(&reject));
+    });
+    xhr.send_with_opt_form_data(Some(&form)).map_err(js_error)?;
+    let completed = wasm_bindgen_futures::JsFuture::from(done).await;
+    drop(progress);
+    completed.map_err(|_| "通信エラーが発生しました".to_string())?;
+
+    let status = xhr.status().map_err(js_error)?;
+    let body = xhr.response_text().map_err(js_error)?.unwrap_or_default();
+    if (200..300).contains(&status) {
+        Ok(body)
+    } else if body.is_empty() {
+        Err(format!("HTTP {}", status))
+    } else {
+        Err(body)
+    }
+}
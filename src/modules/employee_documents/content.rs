@@ -0,0 +1,184 @@
+//! アップロードされたファイル内容の検査と画像の加工（サーバー専用）
+//!
+//! 拡張子ではなく先頭バイト（マジックナンバー）で形式を判定し、
+//! 写真はEXIF（撮影位置など）を除去して再エンコード・縮小し、一覧用のサムネイルを作成する。
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageReader};
+use std::io::Cursor;
+
+/// 形式の判定に必要な先頭バイト数
+pub const SNIFF_LEN: usize = 16;
+
+/// 縮小後の長辺の既定値（`DOCUMENT_IMAGE_MAX_PX`で変更可能）
+const DEFAULT_MAX_PX: u32 = 2560;
+
+/// サムネイルの長辺
+const THUMBNAIL_PX: u32 = 320;
+
+/// 再エンコード時のJPEG品質
+const JPEG_QUALITY: u8 = 85;
+
+/// 画像の長辺の上限（ピクセル）
+pub fn max_image_px() -> u32 {
+    std::env::var("DOCUMENT_IMAGE_MAX_PX")
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|px: &u32| *px >= THUMBNAIL_PX)
+        .unwrap_or(DEFAULT_MAX_PX)
+}
+
+/// 先頭バイトからMIMEタイプを判定
+pub fn sniff_mime_type(head: &[u8]) -> Option<&'static str> {
+    if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if head.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+        Some("image/png")
+    } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if head.starts_with(b"%PDF-") {
+        Some("application/pdf")
+    } else {
+        None
+    }
+}
+
+/// ファイル内容が拡張子と一致するか検査し、実際の形式を返す
+///
+/// エラーメッセージにはファイル名を含めない（呼び出し側で付ける）
+pub fn verify_content(expected_mime: &str, head: &[u8]) -> Result<&'static str, String> {
+    match sniff_mime_type(head) {
+        Some(actual) if actual == expected_mime => Ok(actual),
+        Some(actual) => Err(format!(
+            "ファイルの内容（{}）が拡張子と一致しません",
+            actual
+        )),
+        None => Err("ファイルの内容が画像・PDFとして認識できません".to_string()),
+    }
+}
+
+/// 再エンコードの対象とする画像か（GIFはアニメーションを壊さないよう対象外）
+pub fn is_processable_image(mime_type: &str) -> bool {
+    matches!(mime_type, "image/jpeg" | "image/png")
+}
+
+/// 加工後の画像
+pub struct ProcessedImage {
+    /// 本体（EXIF除去・縮小済み）
+    pub data: Vec<u8>,
+    /// サムネイル（JPEG）
+    pub thumbnail: Vec<u8>,
+}
+
+/// 画像を加工する
+///
+/// * EXIFの向きを画素に反映してからメタデータを捨てる（再エンコードでEXIFは書き出されない）
+/// * 長辺が`max_px`を超える場合は縮小する
+/// * 元の形式（JPEG / PNG）で再エンコードし、JPEGのサムネイルを作成する
+pub fn process_image(data: &[u8], mime_type: &str, max_px: u32) -> Result<ProcessedImage, String> {
+    let mut decoder = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?
+        .into_decoder()
+        .map_err(|e| format!("画像を読み込めません: {}", e))?;
+    let orientation = decoder.orientation().map_err(|e| e.to_string())?;
+    let mut image =
+        DynamicImage::from_decoder(decoder).map_err(|e| format!("画像を読み込めません: {}", e))?;
+    image.apply_orientation(orientation);
+
+    if image.width() > max_px || image.height() > max_px {
+        image = image.resize(max_px, max_px, FilterType::Lanczos3);
+    }
+
+    let data = match mime_type {
+        "image/png" => {
+            let mut out = Vec::new();
+            image
+                .write_with_encoder(PngEncoder::new(&mut out))
+                .map_err(|e| e.to_string())?;
+            out
+        }
+        _ => encode_jpeg(&image)?,
+    };
+    let thumbnail = encode_jpeg(&image.thumbnail(THUMBNAIL_PX, THUMBNAIL_PX))?;
+
+    Ok(ProcessedImage { data, thumbnail })
+}
+
+/// JPEGで書き出す（透過はJPEGで扱えないためRGBに変換）
+fn encode_jpeg(image: &DynamicImage) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    DynamicImage::ImageRgb8(image.to_rgb8())
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut out, JPEG_QUALITY))
+        .map_err(|e| e.to_string())?;
+    Ok(out)
+}
+
+/// サムネイルの保存先キー
+pub fn thumbnail_key(s3_key: &str) -> String {
+    format!("{}.thumb.jpg", s3_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_jpeg(width: u32, height: u32) -> Vec<u8> {
+        let image = DynamicImage::new_rgb8(width, height);
+        encode_jpeg(&image).unwrap()
+    }
+
+    /// SOIの直後にEXIF（APP1）セグメントを差し込む
+    fn with_exif(jpeg: &[u8]) -> Vec<u8> {
+        let payload = b"Exif\0\0GPS-DATA-FOR-TEST";
+        let length = (payload.len() + 2) as u16;
+        let mut out = jpeg[..2].to_vec();
+        out.extend_from_slice(&[0xFF, 0xE1]);
+        out.extend_from_slice(&length.to_be_bytes());
+        out.extend_from_slice(payload);
+        out.extend_from_slice(&jpeg[2..]);
+        out
+    }
+
+    #[test]
+    fn test_sniff_mime_type() {
+        assert_eq!(sniff_mime_type(&sample_jpeg(1, 1)), Some("image/jpeg"));
+        assert_eq!(sniff_mime_type(b"%PDF-1.7\n"), Some("application/pdf"));
+        assert_eq!(sniff_mime_type(b"GIF89a...."), Some("image/gif"));
+        assert_eq!(sniff_mime_type(b"MZ\x90\x00"), None);
+    }
+
+    #[test]
+    fn test_verify_content_rejects_renamed_file() {
+        assert!(verify_content("image/jpeg", b"MZ\x90\x00").is_err());
+        assert!(verify_content("image/jpeg", b"%PDF-1.4").is_err());
+        assert_eq!(
+            verify_content("application/pdf", b"%PDF-1.4"),
+            Ok("application/pdf")
+        );
+    }
+
+    #[test]
+    fn test_process_image_strips_exif_and_downsizes() {
+        let original = with_exif(&sample_jpeg(800, 400));
+        assert!(original.windows(4).any(|w| w == b"Exif"));
+
+        let processed = process_image(&original, "image/jpeg", 400).unwrap();
+        assert!(!processed.data.windows(4).any(|w| w == b"Exif"));
+
+        let image = image::load_from_memory(&processed.data).unwrap();
+        assert_eq!((image.width(), image.height()), (400, 200));
+        let thumbnail = image::load_from_memory(&processed.thumbnail).unwrap();
+        assert!(thumbnail.width() <= THUMBNAIL_PX && thumbnail.height() <= THUMBNAIL_PX);
+    }
+
+    #[test]
+    fn test_thumbnail_key() {
+        assert_eq!(
+            thumbnail_key("employees/1/face/a.jpg"),
+            "employees/1/face/a.jpg.thumb.jpg"
+        );
+    }
+}
@@ -1,67 +1,24 @@
 //! 従業員ドキュメントのハンドラー層（サーバー関数）
+//! ファイルのアップロードはサーバー関数ではなく `upload` モジュールのエンドポイントで受け付ける
 
 use super::models::{
-    CreateEmployeeDocument, DocumentCategory, DocumentFilter, EmployeeDocument, FileUpload,
-    UpdateEmployeeDocument,
+    DocumentArchiveRequest, DocumentArchiveSummary, DocumentCategory, DocumentFilter,
+    DocumentRelation, EmployeeDocument, UpdateEmployeeDocument,
 };
 use dioxus::prelude::*;
 
 #[cfg(feature = "server")]
 use super::repository;
 #[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
 use crate::modules::s3_service;
 
-/// ファイルをアップロードしてドキュメントを作成
-#[server(UploadDocument)]
-pub async fn upload_document(upload: FileUpload) -> Result<EmployeeDocument, ServerFnError> {
-    // Base64デコード
-    let data = base64_decode(&upload.data)?;
-
-    // MIMEタイプの推測
-    let mime_type = s3_service::guess_mime_type(&upload.filename);
-
-    // S3キーの生成
-    let category_str = match upload.category {
-        DocumentCategory::FacePhoto => "face",
-        DocumentCategory::LicenseFront => "licenses",
-        DocumentCategory::LicenseBack => "licenses",
-        DocumentCategory::QualificationCert => "qualifications",
-        DocumentCategory::VehicleInspection => "vehicles",
-        DocumentCategory::HealthCheckup => "health",
-        DocumentCategory::AptitudeCheckup => "aptitude",
-        DocumentCategory::Other => "other",
-    };
-
-    let s3_key = s3_service::generate_s3_key(category_str, upload.employee_id, &upload.filename);
-
-    // S3にアップロード
-    let s3_url = s3_service::upload_file(&s3_key, data.clone().into(), mime_type)
-        .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    // DBに保存
-    let create_doc = CreateEmployeeDocument {
-        employee_id: upload.employee_id,
-        category: Some(upload.category),
-        filename: upload.filename,
-        s3_key: Some(s3_key.clone()),
-        s3_url: Some(s3_url.clone()),
-        mime_type: Some(mime_type.to_string()),
-        file_size: Some(data.len() as i64),
-        description: upload.description,
-        related_id: upload.related_id,
-    };
-
-    let document = repository::EmployeeDocumentRepository::create(create_doc)
-        .await
-        .map_err(|e| ServerFnError::new(e.to_string()))?;
-
-    Ok(document)
-}
-
 /// ドキュメントを取得
 #[server(GetDocument)]
 pub async fn get_document(id: i32) -> Result<Option<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let document = repository::EmployeeDocumentRepository::get_by_id(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
@@ -73,6 +30,8 @@ pub async fn get_document(id: i32) -> Result<Option<EmployeeDocument>, ServerFnE
 pub async fn get_employee_documents(
     employee_id: i32,
 ) -> Result<Vec<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let documents = repository::EmployeeDocumentRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
@@ -85,6 +44,8 @@ pub async fn get_documents_by_category(
     employee_id: i32,
     category: DocumentCategory,
 ) -> Result<Vec<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let documents =
         repository::EmployeeDocumentRepository::get_by_category(employee_id, category.as_str())
             .await
@@ -97,6 +58,8 @@ pub async fn get_documents_by_category(
 pub async fn get_documents_by_filter(
     filter: DocumentFilter,
 ) -> Result<Vec<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     // Filter is not implemented in new repository structure, using basic query
     if let Some(employee_id) = filter.employee_id {
         if let Some(category) = filter.category {
@@ -117,32 +80,152 @@ pub async fn get_documents_by_filter(
     Ok(Vec::new())
 }
 
+/// 記録（運転免許・資格・診断など）に添付されたドキュメントを取得（現在の版のみ）
+#[server(GetDocumentsByRelation)]
+pub async fn get_documents_by_relation(
+    relation: DocumentRelation,
+) -> Result<Vec<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    repository::EmployeeDocumentRepository::get_by_relation(relation)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 書類の一括ダウンロード（ZIP）の件数・サイズとURLを取得
+///
+/// ZIPは `archive` モジュールのエンドポイントが順次作成して返す。
+/// 監査などで書類をまとめて提出するため、書類を扱う権限（EditDriver）を要求する
+#[server(PrepareDocumentArchive)]
+pub async fn prepare_document_archive(
+    request: DocumentArchiveRequest,
+) -> Result<DocumentArchiveSummary, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    request.validate().map_err(ServerFnError::new)?;
+
+    let employee_ids = request.target_employee_ids();
+    let documents = repository::EmployeeDocumentRepository::list_for_archive(
+        &employee_ids,
+        request.filter.category,
+        request.filter.relation,
+    )
+    .await
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if documents.is_empty() {
+        return Err(ServerFnError::new("対象の書類がありません".to_string()));
+    }
+
+    Ok(DocumentArchiveSummary {
+        employee_count: employee_ids.len(),
+        document_count: documents.len(),
+        total_bytes: documents.iter().filter_map(|d| d.file_size).sum(),
+        url: request.url(),
+    })
+}
+
 /// ドキュメントを更新
 #[server(UpdateDocument)]
 pub async fn update_document(
     id: i32,
     update: UpdateEmployeeDocument,
 ) -> Result<EmployeeDocument, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    let current = repository::EmployeeDocumentRepository::get_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("Document not found".to_string()))?;
+
+    // バリデーション（添付先の記録が同じ従業員のものか）
+    if let Some(relation) = update.relation {
+        let exists =
+            repository::EmployeeDocumentRepository::relation_exists(current.employee_id, relation)
+                .await
+                .map_err(|e| ServerFnError::new(e.to_string()))?;
+        if !exists {
+            return Err(ServerFnError::new(format!(
+                "添付先の{}（ID: {}）が見つかりません",
+                relation.record_type.display_name(),
+                relation.record_id
+            )));
+        }
+    }
+
+    // バリデーション（付け替え先に同じ種類の現在の版がないか）
+    if current.relation() != update.relation {
+        let conflict = repository::EmployeeDocumentRepository::has_other_current_version(
+            &current,
+            update.relation,
+        )
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+        if conflict {
+            return Err(ServerFnError::new(
+                "添付先には同じ種類の書類が既にあります。新しい版として追加する場合はアップロードしてください"
+                    .to_string(),
+            ));
+        }
+    }
+
     let document = repository::EmployeeDocumentRepository::update(id, update)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
     Ok(document)
 }
 
+/// ドキュメントの版の一覧を取得（新しい版から順に）
+#[server(GetDocumentVersions)]
+pub async fn get_document_versions(id: i32) -> Result<Vec<EmployeeDocument>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let documents = repository::EmployeeDocumentRepository::get_versions(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(documents)
+}
+
+/// 以前の版を現在の版に戻す
+#[server(RestoreDocumentVersion)]
+pub async fn restore_document_version(id: i32) -> Result<EmployeeDocument, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    let document = repository::EmployeeDocumentRepository::get_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("Document not found".to_string()))?;
+    if document.is_current {
+        return Err(ServerFnError::new("この版はすでに現在の版です".to_string()));
+    }
+
+    let document = repository::EmployeeDocumentRepository::restore_version(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(document)
+}
+
 /// ドキュメントを削除（S3からも削除）
+///
+/// 先にDBから削除し、その後でファイルを削除する。ファイルの削除に失敗しても
+/// 行が存在しないファイルが残るだけなので、`reconcile-storage` で後から削除できる
 #[server(DeleteDocument)]
 pub async fn delete_document(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     // ドキュメント情報を取得
     let document = repository::EmployeeDocumentRepository::get_by_id(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?
         .ok_or_else(|| ServerFnError::new("Document not found".to_string()))?;
-
-    // S3から削除（s3_keyがある場合のみ）
-    if let Some(s3_key) = &document.s3_key {
-        s3_service::delete_file(s3_key)
-            .await
-            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if repository::EmployeeDocumentRepository::is_on_legal_hold(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    {
+        return Err(ServerFnError::new(
+            "廃棄の保留中の書類は削除できません".to_string(),
+        ));
     }
 
     // DBから削除
@@ -150,25 +233,45 @@ pub async fn delete_document(id: i32) -> Result<(), ServerFnError> {
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
 
+    // S3から削除（s3_keyがある場合のみ、サムネイルも含む）
+    for key in [&document.s3_key, &document.thumbnail_key]
+        .into_iter()
+        .flatten()
+    {
+        if let Err(e) = s3_service::delete_file(key).await {
+            eprintln!("⚠️ Failed to delete stored file {}: {}", key, e);
+        }
+    }
+
     Ok(())
 }
 
-/// ドキュメントのダウンロードURL取得（署名付きURL）
+/// ドキュメントのダウンロードURL取得
+/// S3では有効期限付きの署名付きURL、ローカル保存ではログインが必要な配信エンドポイントを返す
 #[server(GetDocumentDownloadUrl)]
 pub async fn get_document_download_url(id: i32) -> Result<String, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let document = repository::EmployeeDocumentRepository::get_by_id(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?
         .ok_or_else(|| ServerFnError::new("Document not found".to_string()))?;
+    let s3_key = document
+        .s3_key
+        .as_deref()
+        .ok_or_else(|| ServerFnError::new("File not found".to_string()))?;
 
-    // 現状は直接S3 URLを返す
-    // 本番環境では署名付きURLを生成することを推奨
-    Ok(document.s3_url.unwrap_or_default())
+    let presigned = s3_service::presigned_download_url(s3_key, &document.filename, true)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(presigned.unwrap_or_else(|| document.file_url(true)))
 }
 
 /// 従業員のドキュメント数を取得
 #[server(CountEmployeeDocuments)]
 pub async fn count_employee_documents(employee_id: i32) -> Result<i64, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let count = repository::EmployeeDocumentRepository::count_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
@@ -181,45 +284,11 @@ pub async fn count_documents_by_category(
     employee_id: i32,
     category: DocumentCategory,
 ) -> Result<i64, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     let count =
         repository::EmployeeDocumentRepository::count_by_category(employee_id, category.as_str())
             .await
             .map_err(|e| ServerFnError::new(e.to_string()))?;
     Ok(count)
 }
-
-/// Base64文字列をデコード
-#[cfg(feature = "server")]
-fn base64_decode(encoded: &str) -> Result<Vec<u8>, ServerFnError> {
-    // "data:image/jpeg;base64," のようなプレフィックスを削除
-    let data = if let Some(comma_pos) = encoded.find(',') {
-        &encoded[comma_pos + 1..]
-    } else {
-        encoded
-    };
-
-    // Base64デコード
-    use base64::{engine::general_purpose, Engine as _};
-    general_purpose::STANDARD
-        .decode(data)
-        .map_err(|e| ServerFnError::new(format!("Base64 decode error: {}", e)))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_base64_decode() {
-        let encoded = "data:image/jpeg;base64,SGVsbG8gV29ybGQ=";
-        let decoded = base64_decode(encoded).unwrap();
-        assert_eq!(decoded, b"Hello World");
-    }
-
-    #[test]
-    fn test_base64_decode_without_prefix() {
-        let encoded = "SGVsbG8gV29ybGQ=";
-        let decoded = base64_decode(encoded).unwrap();
-        assert_eq!(decoded, b"Hello World");
-    }
-}
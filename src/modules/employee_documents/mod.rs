@@ -1,10 +1,21 @@
 //! 従業員ドキュメント管理モジュール
-//! AWS S3を使用したファイルアップロード・管理機能
+//! S3（またはローカル）を使用したファイルアップロード・管理機能
+//! ファイルは公開URLを持たず、署名付きURLか認証付きの配信エンドポイント経由で閲覧する
 
 pub mod models;
 
+#[cfg(feature = "server")]
+pub mod archive;
+#[cfg(feature = "server")]
+pub mod content;
+#[cfg(feature = "server")]
+pub mod proxy;
+#[cfg(feature = "server")]
+pub mod reconcile;
 #[cfg(feature = "server")]
 pub mod repository;
+#[cfg(feature = "server")]
+pub mod upload;
 
 pub mod handlers;
 
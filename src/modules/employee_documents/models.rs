@@ -37,7 +37,79 @@ pub enum DocumentCategory {
     Other,
 }
 
+/// 1回のアップロードで送信できるファイル数
+pub const MAX_FILES_PER_UPLOAD: usize = 10;
+
+/// アップロードできるファイルの拡張子
+pub const ALLOWED_UPLOAD_EXTENSIONS: [&str; 5] = [".jpg", ".jpeg", ".png", ".gif", ".pdf"];
+
+const MB: u64 = 1024 * 1024;
+
 impl DocumentCategory {
+    pub const ALL: [DocumentCategory; 8] = [
+        DocumentCategory::FacePhoto,
+        DocumentCategory::LicenseFront,
+        DocumentCategory::LicenseBack,
+        DocumentCategory::QualificationCert,
+        DocumentCategory::VehicleInspection,
+        DocumentCategory::HealthCheckup,
+        DocumentCategory::AptitudeCheckup,
+        DocumentCategory::Other,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == value)
+    }
+
+    /// 1ファイルあたりの上限サイズ（バイト）
+    /// 写真・免許証は画像1枚、診断書などは複数ページのPDFを想定する
+    pub fn max_bytes(&self) -> u64 {
+        match self {
+            DocumentCategory::FacePhoto => 5 * MB,
+            DocumentCategory::LicenseFront | DocumentCategory::LicenseBack => 5 * MB,
+            DocumentCategory::QualificationCert
+            | DocumentCategory::VehicleInspection
+            | DocumentCategory::HealthCheckup
+            | DocumentCategory::AptitudeCheckup => 20 * MB,
+            DocumentCategory::Other => 20 * MB,
+        }
+    }
+
+    /// 版管理の対象か
+    ///
+    /// 同じ従業員・種類・関連IDで新しいファイルをアップロードすると以前の版を置き換える（履歴として残す）。
+    /// 「その他」は複数の書類を並べて保管するため対象外
+    pub fn is_versioned(&self) -> bool {
+        !matches!(self, DocumentCategory::Other)
+    }
+
+    /// 対応する書類種別マスタ（document_types.name）。保管年数の判定に使う
+    pub fn document_type_name(&self) -> &'static str {
+        match self {
+            DocumentCategory::FacePhoto => "顔写真",
+            DocumentCategory::LicenseFront | DocumentCategory::LicenseBack => "免許証コピー",
+            DocumentCategory::QualificationCert => "資格証コピー",
+            DocumentCategory::VehicleInspection => "車検証",
+            DocumentCategory::HealthCheckup => "健康診断書",
+            DocumentCategory::AptitudeCheckup => "適性診断書",
+            DocumentCategory::Other => "その他",
+        }
+    }
+
+    /// 保存先キーのフォルダ名
+    pub fn storage_folder(&self) -> &'static str {
+        match self {
+            DocumentCategory::FacePhoto => "face",
+            DocumentCategory::LicenseFront => "licenses",
+            DocumentCategory::LicenseBack => "licenses",
+            DocumentCategory::QualificationCert => "qualifications",
+            DocumentCategory::VehicleInspection => "vehicles",
+            DocumentCategory::HealthCheckup => "health",
+            DocumentCategory::AptitudeCheckup => "aptitude",
+            DocumentCategory::Other => "other",
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             DocumentCategory::FacePhoto => "FACE_PHOTO",
@@ -71,6 +143,118 @@ impl std::fmt::Display for DocumentCategory {
     }
 }
 
+/// ドキュメントを添付する記録の種類
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum RelatedRecordType {
+    /// 運転免許（licenses）
+    #[serde(rename = "LICENSE")]
+    License,
+    /// 資格（qualifications）
+    #[serde(rename = "QUALIFICATION")]
+    Qualification,
+    /// 健康診断（health_checkup_history）
+    #[serde(rename = "HEALTH_CHECKUP")]
+    HealthCheckup,
+    /// 適性診断（aptitude_checkup_history）
+    #[serde(rename = "APTITUDE_CHECKUP")]
+    AptitudeCheckup,
+    /// 在留カード（residence_cards）
+    #[serde(rename = "RESIDENCE_CARD")]
+    ResidenceCard,
+    /// 車両（vehicles、従業員の記録ではない）
+    #[serde(rename = "VEHICLE")]
+    Vehicle,
+}
+
+impl RelatedRecordType {
+    pub const ALL: [RelatedRecordType; 6] = [
+        RelatedRecordType::License,
+        RelatedRecordType::Qualification,
+        RelatedRecordType::HealthCheckup,
+        RelatedRecordType::AptitudeCheckup,
+        RelatedRecordType::ResidenceCard,
+        RelatedRecordType::Vehicle,
+    ];
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == value)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelatedRecordType::License => "LICENSE",
+            RelatedRecordType::Qualification => "QUALIFICATION",
+            RelatedRecordType::HealthCheckup => "HEALTH_CHECKUP",
+            RelatedRecordType::AptitudeCheckup => "APTITUDE_CHECKUP",
+            RelatedRecordType::ResidenceCard => "RESIDENCE_CARD",
+            RelatedRecordType::Vehicle => "VEHICLE",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RelatedRecordType::License => "運転免許",
+            RelatedRecordType::Qualification => "資格",
+            RelatedRecordType::HealthCheckup => "健康診断",
+            RelatedRecordType::AptitudeCheckup => "適性診断",
+            RelatedRecordType::ResidenceCard => "在留カード",
+            RelatedRecordType::Vehicle => "車両",
+        }
+    }
+
+    /// 従業員ごとの記録か（従業員の記録なら、添付する書類と同じ従業員のものに限る）
+    pub fn is_employee_record(&self) -> bool {
+        !matches!(self, RelatedRecordType::Vehicle)
+    }
+
+    /// この記録に添付する書類の既定の種類
+    pub fn default_category(&self) -> DocumentCategory {
+        match self {
+            RelatedRecordType::License => DocumentCategory::LicenseFront,
+            RelatedRecordType::Qualification => DocumentCategory::QualificationCert,
+            RelatedRecordType::HealthCheckup => DocumentCategory::HealthCheckup,
+            RelatedRecordType::AptitudeCheckup => DocumentCategory::AptitudeCheckup,
+            RelatedRecordType::ResidenceCard => DocumentCategory::Other,
+            RelatedRecordType::Vehicle => DocumentCategory::VehicleInspection,
+        }
+    }
+}
+
+impl std::fmt::Display for RelatedRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// ドキュメントを添付する記録（運転免許・資格・診断・在留カード・車両のいずれか1件）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DocumentRelation {
+    pub record_type: RelatedRecordType,
+    pub record_id: i32,
+}
+
+impl DocumentRelation {
+    pub fn new(record_type: RelatedRecordType, record_id: i32) -> Self {
+        Self {
+            record_type,
+            record_id,
+        }
+    }
+
+    /// 種類とIDの組から作成（どちらか一方だけの場合はNone）
+    pub fn from_parts(
+        record_type: Option<RelatedRecordType>,
+        record_id: Option<i32>,
+    ) -> Option<Self> {
+        Some(Self::new(record_type?, record_id?))
+    }
+}
+
 /// 従業員ドキュメント
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "server", derive(sqlx::FromRow))]
@@ -85,16 +269,24 @@ pub struct EmployeeDocument {
     pub filename: String,
     /// S3オブジェクトキー（パス）
     pub s3_key: Option<String>,
-    /// S3 URL
-    pub s3_url: Option<String>,
+    /// サムネイルのオブジェクトキー（画像のみ）
+    pub thumbnail_key: Option<String>,
     /// MIMEタイプ
     pub mime_type: Option<String>,
     /// ファイルサイズ（バイト）
     pub file_size: Option<i64>,
     /// 説明・メモ
     pub description: Option<String>,
-    /// 関連ID（資格IDや車両IDなど）
+    /// 添付先の記録の種類
+    pub related_type: Option<RelatedRecordType>,
+    /// 添付先の記録のID（`related_type` のテーブルのID）
     pub related_id: Option<i32>,
+    /// 版番号（同じ従業員・種類・添付先の中で1から連番）
+    pub version: i32,
+    /// 現在の版か（falseなら新しい版に置き換えられた履歴）
+    pub is_current: bool,
+    /// 新しい版に置き換えられた日時
+    pub superseded_at: Option<DateTime<Utc>>,
     /// アップロード日時
     pub uploaded_at: DateTime<Utc>,
     /// 作成日時
@@ -103,6 +295,35 @@ pub struct EmployeeDocument {
     pub updated_at: DateTime<Utc>,
 }
 
+impl EmployeeDocument {
+    /// 添付先の記録
+    pub fn relation(&self) -> Option<DocumentRelation> {
+        DocumentRelation::from_parts(self.related_type, self.related_id)
+    }
+
+    /// 以前の版があるか
+    pub fn has_history(&self) -> bool {
+        self.version > 1 || !self.is_current
+    }
+
+    /// 認証付きの配信エンドポイントのURL
+    /// `download`がtrueなら保存、falseならブラウザで表示する
+    pub fn file_url(&self, download: bool) -> String {
+        if download {
+            format!("/api/documents/{}/file?download=1", self.id)
+        } else {
+            format!("/api/documents/{}/file", self.id)
+        }
+    }
+
+    /// 一覧表示用のサムネイルのURL（サムネイルがない場合はNone）
+    pub fn thumbnail_url(&self) -> Option<String> {
+        self.thumbnail_key
+            .as_ref()
+            .map(|_| format!("/api/documents/{}/file?thumbnail=1", self.id))
+    }
+}
+
 /// 新規ドキュメント作成用の入力データ
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateEmployeeDocument {
@@ -114,16 +335,16 @@ pub struct CreateEmployeeDocument {
     pub filename: String,
     /// S3オブジェクトキー
     pub s3_key: Option<String>,
-    /// S3 URL
-    pub s3_url: Option<String>,
+    /// サムネイルのオブジェクトキー
+    pub thumbnail_key: Option<String>,
     /// MIMEタイプ
     pub mime_type: Option<String>,
     /// ファイルサイズ
     pub file_size: Option<i64>,
     /// 説明
     pub description: Option<String>,
-    /// 関連ID
-    pub related_id: Option<i32>,
+    /// 添付先の記録
+    pub relation: Option<DocumentRelation>,
 }
 
 /// ドキュメント更新用の入力データ
@@ -131,36 +352,166 @@ pub struct CreateEmployeeDocument {
 pub struct UpdateEmployeeDocument {
     /// 説明
     pub description: Option<String>,
-    /// 関連ID
-    pub related_id: Option<i32>,
+    /// 添付先の記録
+    pub relation: Option<DocumentRelation>,
 }
 
 /// ドキュメント一覧取得用のフィルタ
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct DocumentFilter {
     /// 従業員ID
     pub employee_id: Option<i32>,
     /// カテゴリ
     pub category: Option<DocumentCategory>,
-    /// 関連ID
-    pub related_id: Option<i32>,
+    /// 添付先の記録
+    pub relation: Option<DocumentRelation>,
 }
 
-/// アップロード用のファイルデータ
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileUpload {
-    /// 従業員ID
+/// 一括ダウンロード（ZIP）で1回に指定できる従業員数
+pub const MAX_ARCHIVE_EMPLOYEES: usize = 100;
+
+/// 書類の一括ダウンロード（ZIP）の対象
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DocumentArchiveRequest {
+    /// 対象の従業員
+    pub employee_ids: Vec<i32>,
+    /// 種類・関連IDでの絞り込み（`employee_id` を指定した場合はその従業員も対象に含める）
+    pub filter: DocumentFilter,
+}
+
+impl DocumentArchiveRequest {
+    /// 対象の従業員ID（重複を除いて昇順）
+    pub fn target_employee_ids(&self) -> Vec<i32> {
+        let mut ids: Vec<i32> = self
+            .employee_ids
+            .iter()
+            .copied()
+            .chain(self.filter.employee_id)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    /// ZIPを返すエンドポイントのURL
+    pub fn url(&self) -> String {
+        let employees: Vec<String> = self
+            .target_employee_ids()
+            .iter()
+            .map(|id| id.to_string())
+            .collect();
+        let mut url = format!("/api/documents/archive?employees={}", employees.join(","));
+        if let Some(category) = self.filter.category {
+            url.push_str(&format!("&category={}", category.as_str()));
+        }
+        if let Some(relation) = self.filter.relation {
+            url.push_str(&format!(
+                "&related_type={}&related_id={}",
+                relation.record_type.as_str(),
+                relation.record_id
+            ));
+        }
+        url
+    }
+
+    /// エンドポイントのクエリから組み立てる
+    pub fn from_query(
+        employees: &str,
+        category: Option<&str>,
+        related_type: Option<&str>,
+        related_id: Option<i32>,
+    ) -> Result<Self, String> {
+        let employee_ids = employees
+            .split(',')
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.trim().parse::<i32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "従業員IDが不正です".to_string())?;
+        let category = match category.filter(|c| !c.is_empty()) {
+            Some(value) => Some(
+                DocumentCategory::parse(value)
+                    .ok_or_else(|| format!("不明な書類の種類です: {}", value))?,
+            ),
+            None => None,
+        };
+        let relation = match (related_type.filter(|t| !t.is_empty()), related_id) {
+            (Some(value), Some(id)) => Some(DocumentRelation::new(
+                RelatedRecordType::parse(value)
+                    .ok_or_else(|| format!("不明な記録の種類です: {}", value))?,
+                id,
+            )),
+            (None, None) => None,
+            _ => return Err("添付先の記録の種類とIDを指定してください".to_string()),
+        };
+        Ok(Self {
+            employee_ids,
+            filter: DocumentFilter {
+                employee_id: None,
+                category,
+                relation,
+            },
+        })
+    }
+
+    /// 対象の従業員数を検証
+    pub fn validate(&self) -> Result<(), String> {
+        let count = self.target_employee_ids().len();
+        if count == 0 {
+            return Err("従業員を1人以上指定してください".to_string());
+        }
+        if count > MAX_ARCHIVE_EMPLOYEES {
+            return Err(format!(
+                "一度にダウンロードできるのは{}人までです",
+                MAX_ARCHIVE_EMPLOYEES
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 一括ダウンロードの内容（ダウンロード前の確認用）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DocumentArchiveSummary {
+    pub employee_count: usize,
+    pub document_count: usize,
+    /// 書類の合計サイズ（バイト、サイズ不明の書類は含まない）
+    pub total_bytes: i64,
+    /// ZIPを返すエンドポイントのURL
+    pub url: String,
+}
+
+/// アップロードエンドポイントのURL（multipart/form-data で POST する）
+///
+/// フィールドは `category`・`description`・`related_type`・`related_id` を先に、続けて `file` を1つ以上送る
+pub fn upload_url(employee_id: i32) -> String {
+    format!("/api/employees/{}/documents", employee_id)
+}
+
+/// アップロードの結果（ファイルごとに成否を返す）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DocumentUploadResult {
+    /// 保存できたドキュメント
+    pub documents: Vec<EmployeeDocument>,
+    /// 保存できなかったファイルのエラー
+    pub errors: Vec<String>,
+}
+
+/// ファイル名の拡張子がアップロード可能か
+pub fn is_allowed_upload(filename: &str) -> bool {
+    let lower = filename.to_lowercase();
+    ALLOWED_UPLOAD_EXTENSIONS
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+}
+
+/// ドキュメントが参照しているファイル（保存先との突き合わせ用）
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentKeys {
+    pub id: i32,
     pub employee_id: i32,
-    /// カテゴリ
-    pub category: DocumentCategory,
-    /// ファイル名
     pub filename: String,
-    /// Base64エンコードされたファイルデータ
-    pub data: String,
-    /// 説明
-    pub description: Option<String>,
-    /// 関連ID
-    pub related_id: Option<i32>,
+    pub s3_key: Option<String>,
+    pub thumbnail_key: Option<String>,
 }
 
 #[cfg(test)]
@@ -180,6 +531,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_document_category_parse() {
+        for category in DocumentCategory::ALL {
+            assert_eq!(DocumentCategory::parse(category.as_str()), Some(category));
+        }
+        assert_eq!(DocumentCategory::parse("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn test_document_category_is_versioned() {
+        assert!(DocumentCategory::LicenseFront.is_versioned());
+        assert!(DocumentCategory::FacePhoto.is_versioned());
+        assert!(!DocumentCategory::Other.is_versioned());
+    }
+
+    #[test]
+    fn test_is_allowed_upload() {
+        assert!(is_allowed_upload("photo.JPG"));
+        assert!(is_allowed_upload("診断書.pdf"));
+        assert!(!is_allowed_upload("script.exe"));
+        assert!(!is_allowed_upload("pdf"));
+    }
+
+    #[test]
+    fn test_document_archive_request_url() {
+        let request = DocumentArchiveRequest {
+            employee_ids: vec![3, 1, 3],
+            filter: DocumentFilter {
+                employee_id: Some(2),
+                category: Some(DocumentCategory::LicenseFront),
+                relation: None,
+            },
+        };
+        assert_eq!(request.target_employee_ids(), vec![1, 2, 3]);
+        assert_eq!(
+            request.url(),
+            "/api/documents/archive?employees=1,2,3&category=LICENSE_FRONT"
+        );
+
+        let parsed =
+            DocumentArchiveRequest::from_query("1,2,3", Some("LICENSE_FRONT"), None, None).unwrap();
+        assert_eq!(parsed.target_employee_ids(), vec![1, 2, 3]);
+        assert_eq!(parsed.filter.category, Some(DocumentCategory::LicenseFront));
+        assert!(DocumentArchiveRequest::from_query("1,x", None, None, None).is_err());
+        assert!(DocumentArchiveRequest::from_query("1", None, Some("LICENSE"), None).is_err());
+        assert!(DocumentArchiveRequest::from_query("", None, None, None)
+            .unwrap()
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_related_record_type_parse() {
+        for record_type in RelatedRecordType::ALL {
+            assert_eq!(
+                RelatedRecordType::parse(record_type.as_str()),
+                Some(record_type)
+            );
+        }
+        assert!(!RelatedRecordType::Vehicle.is_employee_record());
+        assert_eq!(
+            DocumentRelation::from_parts(Some(RelatedRecordType::License), Some(3)),
+            Some(DocumentRelation::new(RelatedRecordType::License, 3))
+        );
+        assert_eq!(DocumentRelation::from_parts(None, Some(3)), None);
+    }
+
     #[test]
     fn test_document_category_as_str() {
         assert_eq!(DocumentCategory::FacePhoto.as_str(), "FACE_PHOTO");
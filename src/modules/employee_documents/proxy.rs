@@ -0,0 +1,90 @@
+//! ドキュメント配信エンドポイント（サーバー専用）
+//!
+//! `GET /api/documents/{id}/file` でログイン中のユーザーにのみファイルを返す。
+//! 署名付きURLに対応する保存先（S3）はそのURLへリダイレクトし、
+//! 対応しない保存先（ローカル）はファイルを順次読み出して配信する。
+
+use super::repository::EmployeeDocumentRepository;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::session::user_from_cookies;
+use crate::modules::s3_service;
+use axum::body::Body;
+use axum::extract::{Path, Query};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Redirect, Response};
+use serde::Deserialize;
+use tower_cookies::Cookies;
+
+/// 配信エンドポイントのパス（Axumのルート定義用）
+pub const ROUTE: &str = "/api/documents/{id}/file";
+
+#[derive(Debug, Deserialize)]
+pub struct FileQuery {
+    /// 1ならダウンロード（保存）、省略時はブラウザで表示
+    #[serde(default)]
+    download: Option<u8>,
+    /// 1ならサムネイルを返す
+    #[serde(default)]
+    thumbnail: Option<u8>,
+}
+
+/// ドキュメントのファイルを返す
+pub async fn serve_document(
+    cookies: Cookies,
+    Path(id): Path<i32>,
+    Query(query): Query<FileQuery>,
+) -> Response {
+    match user_from_cookies(&cookies).await {
+        Ok(Some(user)) if user.can(Permission::View) => {}
+        Ok(_) => return StatusCode::UNAUTHORIZED.into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+
+    let document = match EmployeeDocumentRepository::get_by_id(id).await {
+        Ok(Some(doc)) => doc,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+    let thumbnail = query.thumbnail == Some(1);
+    let key = if thumbnail {
+        document.thumbnail_key.as_deref()
+    } else {
+        document.s3_key.as_deref()
+    };
+    let Some(s3_key) = key else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let attachment = query.download == Some(1);
+
+    // 署名付きURLを発行できる場合はリダイレクト
+    match s3_service::presigned_download_url(s3_key, &document.filename, attachment).await {
+        Ok(Some(url)) => return Redirect::temporary(&url).into_response(),
+        Ok(None) => {}
+        Err(e) => return (StatusCode::BAD_GATEWAY, e.to_string()).into_response(),
+    }
+
+    let reader = match s3_service::open_file(s3_key).await {
+        Ok(reader) => reader,
+        Err(e) => return (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    };
+    let content_type = if thumbnail {
+        "image/jpeg".to_string()
+    } else {
+        document
+            .mime_type
+            .unwrap_or_else(|| s3_service::guess_mime_type(&document.filename).to_string())
+    };
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                s3_service::content_disposition(&document.filename, attachment),
+            ),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        Body::from_stream(tokio_util::io::ReaderStream::new(reader)),
+    )
+        .into_response()
+}
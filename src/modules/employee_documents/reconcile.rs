@@ -0,0 +1,369 @@
+//! 保存先のファイルとドキュメントの突き合わせ（サーバー専用）
+//!
+//! `employees/` 以下のファイルと `employee_documents` の `s3_key`・`thumbnail_key` を比較し、
+//! どの行からも参照されていないファイル（孤立ファイル）と、ファイルが無い行（欠損）を報告する。
+//! `--repair` を付けると孤立ファイルを削除し、欠損した行を削除（サムネイルのみの欠損は参照を解除）する。
+//! 廃棄の保留（リーガルホールド）中の行は削除せず、保留中として報告する。
+//!
+//! 実行例: `cargo run --features server -- reconcile-storage [--repair]`
+
+use super::models::DocumentKeys;
+use super::repository::EmployeeDocumentRepository;
+use crate::modules::s3_service::{self, StoredObject};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashSet;
+
+/// コマンド名（`main` の引数で判定する）
+pub const COMMAND: &str = "reconcile-storage";
+
+/// 突き合わせの対象とするキーの接頭辞
+pub const OBJECT_PREFIX: &str = "employees/";
+
+/// アップロード中（行の登録前）のファイルを孤立ファイルと判定しないための猶予（分）
+const ORPHAN_GRACE_MINUTES: i64 = 60;
+
+/// 欠損しているファイルの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingFile {
+    /// 本体（行ごと削除する）
+    Original,
+    /// サムネイル（参照を解除する）
+    Thumbnail,
+}
+
+/// ファイルが見つからない行
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingDocument {
+    pub id: i32,
+    pub employee_id: i32,
+    pub filename: String,
+    pub key: String,
+    pub missing: MissingFile,
+}
+
+/// 突き合わせの結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReconcileReport {
+    pub scanned_objects: usize,
+    pub scanned_documents: usize,
+    /// どの行からも参照されていないファイル
+    pub orphan_objects: Vec<StoredObject>,
+    /// ファイルが見つからない行
+    pub dangling_documents: Vec<DanglingDocument>,
+    /// 猶予期間内のため判定を保留したファイル数
+    pub recent_objects: usize,
+}
+
+impl ReconcileReport {
+    pub fn is_consistent(&self) -> bool {
+        self.orphan_objects.is_empty() && self.dangling_documents.is_empty()
+    }
+}
+
+/// 修復の結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RepairOutcome {
+    pub deleted_objects: usize,
+    pub deleted_documents: usize,
+    pub cleared_thumbnails: usize,
+    /// 廃棄の保留中のため削除しなかった行
+    pub held_documents: Vec<DanglingDocument>,
+    pub errors: Vec<String>,
+}
+
+/// 保存先のファイル一覧とドキュメントを比較する
+///
+/// `OBJECT_PREFIX` 以外のキー（旧形式のパスなど）を持つ行は対象外とする
+pub fn compare(
+    objects: Vec<StoredObject>,
+    documents: &[DocumentKeys],
+    now: DateTime<Utc>,
+) -> ReconcileReport {
+    let stored: HashSet<&str> = objects.iter().map(|o| o.key.as_str()).collect();
+    let mut referenced = HashSet::new();
+    let mut dangling_documents = Vec::new();
+
+    for doc in documents {
+        for (key, missing) in [
+            (&doc.s3_key, MissingFile::Original),
+            (&doc.thumbnail_key, MissingFile::Thumbnail),
+        ] {
+            let Some(key) = key.as_deref().filter(|k| k.starts_with(OBJECT_PREFIX)) else {
+                continue;
+            };
+            referenced.insert(key.to_string());
+            if !stored.contains(key) {
+                dangling_documents.push(DanglingDocument {
+                    id: doc.id,
+                    employee_id: doc.employee_id,
+                    filename: doc.filename.clone(),
+                    key: key.to_string(),
+                    missing,
+                });
+            }
+        }
+    }
+
+    let threshold = now - Duration::minutes(ORPHAN_GRACE_MINUTES);
+    let scanned_objects = objects.len();
+    let mut recent_objects = 0;
+    let orphan_objects = objects
+        .into_iter()
+        .filter(|o| !referenced.contains(&o.key))
+        .filter(|o| {
+            let is_recent = o.last_modified.is_some_and(|t| t > threshold);
+            if is_recent {
+                recent_objects += 1;
+            }
+            !is_recent
+        })
+        .collect();
+
+    ReconcileReport {
+        scanned_objects,
+        scanned_documents: documents.len(),
+        orphan_objects,
+        dangling_documents,
+        recent_objects,
+    }
+}
+
+/// 保存先とデータベースを読み込んで比較する
+pub async fn reconcile() -> Result<ReconcileReport, String> {
+    let objects = s3_service::list_files(OBJECT_PREFIX)
+        .await
+        .map_err(|e| e.to_string())?;
+    let documents = EmployeeDocumentRepository::list_storage_keys()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(compare(objects, &documents, Utc::now()))
+}
+
+/// 報告された不整合を修復する
+///
+/// 欠損した行の削除は通常の削除と同じく監査ログに記録され、以前の版があれば現在の版に戻る。
+/// 書類または従業員が廃棄の保留中の行は削除しない
+pub async fn repair(report: &ReconcileReport) -> RepairOutcome {
+    let mut outcome = RepairOutcome::default();
+
+    for object in &report.orphan_objects {
+        match s3_service::delete_file(&object.key).await {
+            Ok(()) => outcome.deleted_objects += 1,
+            Err(e) => outcome.errors.push(format!("{}: {}", object.key, e)),
+        }
+    }
+
+    // 本体が無い行は削除（同じ行のサムネイルも削除）
+    let mut deleted_ids = HashSet::new();
+    for doc in report
+        .dangling_documents
+        .iter()
+        .filter(|d| d.missing == MissingFile::Original)
+    {
+        match EmployeeDocumentRepository::is_on_legal_hold(doc.id).await {
+            Ok(true) => {
+                outcome.held_documents.push(doc.clone());
+                continue;
+            }
+            Ok(false) => {}
+            Err(e) => {
+                outcome.errors.push(format!("document #{}: {}", doc.id, e));
+                continue;
+            }
+        }
+        let thumbnail_key = match EmployeeDocumentRepository::get_by_id(doc.id).await {
+            Ok(Some(current)) => current.thumbnail_key,
+            Ok(None) => continue,
+            Err(e) => {
+                outcome.errors.push(format!("document #{}: {}", doc.id, e));
+                continue;
+            }
+        };
+        match EmployeeDocumentRepository::delete(doc.id).await {
+            Ok(()) => {
+                outcome.deleted_documents += 1;
+                deleted_ids.insert(doc.id);
+                if let Some(key) = thumbnail_key {
+                    s3_service::delete_file(&key).await.ok();
+                }
+            }
+            Err(e) => outcome.errors.push(format!("document #{}: {}", doc.id, e)),
+        }
+    }
+
+    // サムネイルだけが無い行は参照を解除（一覧ではアイコン表示になる）
+    for doc in report
+        .dangling_documents
+        .iter()
+        .filter(|d| d.missing == MissingFile::Thumbnail && !deleted_ids.contains(&d.id))
+    {
+        match EmployeeDocumentRepository::clear_thumbnail_key(doc.id).await {
+            Ok(()) => outcome.cleared_thumbnails += 1,
+            Err(e) => outcome.errors.push(format!("document #{}: {}", doc.id, e)),
+        }
+    }
+
+    outcome
+}
+
+/// コマンドとして実行し、終了コードを返す（0: 正常、1: エラー、2: 不整合あり・未修復（保留中の行を含む））
+pub async fn run_command(repair_requested: bool) -> i32 {
+    let report = match reconcile().await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("❌ Reconciliation failed: {}", e);
+            return 1;
+        }
+    };
+
+    println!(
+        "Scanned {} objects under {} and {} documents",
+        report.scanned_objects, OBJECT_PREFIX, report.scanned_documents
+    );
+    if report.recent_objects > 0 {
+        println!(
+            "Skipped {} objects modified within the last {} minutes",
+            report.recent_objects, ORPHAN_GRACE_MINUTES
+        );
+    }
+    for object in &report.orphan_objects {
+        println!("orphan object: {} ({} bytes)", object.key, object.size);
+    }
+    for doc in &report.dangling_documents {
+        let kind = match doc.missing {
+            MissingFile::Original => "file",
+            MissingFile::Thumbnail => "thumbnail",
+        };
+        println!(
+            "dangling document #{} (employee #{}, {}): missing {} {}",
+            doc.id, doc.employee_id, doc.filename, kind, doc.key
+        );
+    }
+
+    if report.is_consistent() {
+        println!("✅ Storage and documents are consistent");
+        return 0;
+    }
+    if !repair_requested {
+        println!(
+            "⚠️ Found {} orphan objects and {} dangling documents (run with --repair to fix)",
+            report.orphan_objects.len(),
+            report.dangling_documents.len()
+        );
+        return 2;
+    }
+
+    let outcome = repair(&report).await;
+    println!(
+        "🔧 Deleted {} orphan objects and {} dangling documents, cleared {} thumbnails",
+        outcome.deleted_objects, outcome.deleted_documents, outcome.cleared_thumbnails
+    );
+    for doc in &outcome.held_documents {
+        println!(
+            "held document #{} (employee #{}, {}): on legal hold, not deleted",
+            doc.id, doc.employee_id, doc.filename
+        );
+    }
+    for error in &outcome.errors {
+        eprintln!("❌ {}", error);
+    }
+    if !outcome.errors.is_empty() {
+        1
+    } else if !outcome.held_documents.is_empty() {
+        2
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(key: &str, age_minutes: i64, now: DateTime<Utc>) -> StoredObject {
+        StoredObject {
+            key: key.to_string(),
+            size: 10,
+            last_modified: Some(now - Duration::minutes(age_minutes)),
+        }
+    }
+
+    fn document(id: i32, s3_key: Option<&str>, thumbnail_key: Option<&str>) -> DocumentKeys {
+        DocumentKeys {
+            id,
+            employee_id: 1,
+            filename: format!("{}.jpg", id),
+            s3_key: s3_key.map(str::to_string),
+            thumbnail_key: thumbnail_key.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_compare_finds_orphans_and_dangling_rows() {
+        let now = Utc::now();
+        let report = compare(
+            vec![
+                object("employees/1/face/a.jpg", 120, now),
+                object("employees/1/face/a.jpg.thumb.jpg", 120, now),
+                object("employees/1/face/orphan.jpg", 120, now),
+                object("employees/1/face/uploading.jpg", 5, now),
+                object("employees/1/health/b.pdf", 120, now),
+            ],
+            &[
+                document(
+                    1,
+                    Some("employees/1/face/a.jpg"),
+                    Some("employees/1/face/a.jpg.thumb.jpg"),
+                ),
+                document(2, Some("employees/1/health/b.pdf"), None),
+                document(
+                    3,
+                    Some("employees/1/face/missing.jpg"),
+                    Some("employees/1/face/missing.jpg.thumb.jpg"),
+                ),
+                document(
+                    4,
+                    Some("employees/1/health/b.pdf"),
+                    Some("employees/1/x.thumb.jpg"),
+                ),
+                // 旧形式のパスは対象外
+                document(5, Some("uploads/legacy.pdf"), None),
+            ],
+            now,
+        );
+
+        let orphans: Vec<&str> = report
+            .orphan_objects
+            .iter()
+            .map(|o| o.key.as_str())
+            .collect();
+        assert_eq!(orphans, ["employees/1/face/orphan.jpg"]);
+        assert_eq!(report.recent_objects, 1);
+
+        let dangling: Vec<(i32, MissingFile)> = report
+            .dangling_documents
+            .iter()
+            .map(|d| (d.id, d.missing))
+            .collect();
+        assert_eq!(
+            dangling,
+            [
+                (3, MissingFile::Original),
+                (3, MissingFile::Thumbnail),
+                (4, MissingFile::Thumbnail),
+            ]
+        );
+        assert!(!report.is_consistent());
+    }
+
+    #[test]
+    fn test_compare_consistent() {
+        let now = Utc::now();
+        let report = compare(
+            vec![object("employees/2/other/a.pdf", 120, now)],
+            &[document(1, Some("employees/2/other/a.pdf"), None)],
+            now,
+        );
+        assert!(report.is_consistent());
+    }
+}
@@ -1,9 +1,44 @@
 //! 従業員ドキュメントのリポジトリ層
 
+use super::archive::ArchiveDocument;
 use super::models::{
-    CreateEmployeeDocument, DocumentFilter, EmployeeDocument, UpdateEmployeeDocument,
+    CreateEmployeeDocument, DocumentCategory, DocumentFilter, DocumentKeys, DocumentRelation,
+    EmployeeDocument, RelatedRecordType, UpdateEmployeeDocument,
 };
 use crate::db;
+use crate::modules::audit_log;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// 版の置き換え（監査ログ用）
+#[derive(Serialize)]
+struct VersionState {
+    is_current: bool,
+    superseded_by: Option<i32>,
+    superseded_at: Option<DateTime<Utc>>,
+}
+
+/// 置き換えた以前の版を監査ログに記録
+async fn record_superseded(employee_id: i32, superseded: &[(i32, Option<DateTime<Utc>>)], by: i32) {
+    for (id, superseded_at) in superseded {
+        audit_log::record_update(
+            "employee_documents",
+            *id,
+            Some(employee_id),
+            Some(&VersionState {
+                is_current: true,
+                superseded_by: None,
+                superseded_at: None,
+            }),
+            &VersionState {
+                is_current: false,
+                superseded_by: Some(by),
+                superseded_at: *superseded_at,
+            },
+        )
+        .await;
+    }
+}
 
 /// 従業員ドキュメントリポジトリ
 pub struct EmployeeDocumentRepository;
@@ -15,45 +50,146 @@ impl EmployeeDocumentRepository {
     }
 
     /// ドキュメントを作成
+    ///
+    /// 版管理の対象となる種類は、同じ従業員・種類・関連IDの現在の版を置き換える（以前の版は履歴として残す）
     pub async fn create(doc: CreateEmployeeDocument) -> Result<EmployeeDocument, sqlx::Error> {
         let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+        let category = doc.category.as_ref().map(|c| c.as_str());
+        let related_type = doc.relation.map(|r| r.record_type.as_str());
+        let related_id = doc.relation.map(|r| r.record_id);
+
+        // 置き換える現在の版をロックし、次の版番号を決める
+        // （現在の版がない場合の同時アップロードは、現在の版の一意制約で後の方が失敗する）
+        let (previous_ids, version) = if doc.category.is_some_and(|c| c.is_versioned()) {
+            let previous_ids = sqlx::query_scalar!(
+                r#"
+                SELECT id FROM employee_documents
+                WHERE employee_id = $1 AND category = $2
+                  AND related_type IS NOT DISTINCT FROM $3
+                  AND related_id IS NOT DISTINCT FROM $4 AND is_active = TRUE
+                FOR UPDATE
+                "#,
+                doc.employee_id,
+                category,
+                related_type,
+                related_id
+            )
+            .fetch_all(&mut *tx)
+            .await?;
+            let latest = sqlx::query_scalar!(
+                r#"
+                SELECT MAX(version) FROM employee_documents
+                WHERE employee_id = $1 AND category = $2
+                  AND related_type IS NOT DISTINCT FROM $3
+                  AND related_id IS NOT DISTINCT FROM $4
+                "#,
+                doc.employee_id,
+                category,
+                related_type,
+                related_id
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            (previous_ids, latest.unwrap_or(0) + 1)
+        } else {
+            (Vec::new(), 1)
+        };
+
+        // 一意制約に反しないよう、新しい版を追加する前に現在の版を外す
+        if !previous_ids.is_empty() {
+            sqlx::query!(
+                r#"
+                UPDATE employee_documents
+                SET is_active = FALSE, updated_at = CURRENT_TIMESTAMP
+                WHERE id = ANY($1)
+                "#,
+                &previous_ids
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
 
         let document = sqlx::query_as!(
             EmployeeDocument,
             r#"
             INSERT INTO employee_documents (
-                employee_id, category, file_name, s3_key, s3_url,
-                mime_type, file_size, notes, related_id
+                employee_id, category, file_name, s3_key, thumbnail_key,
+                mime_type, file_size, notes, related_type, related_id, version,
+                document_type_id
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11,
+                (SELECT id FROM document_types WHERE name = $12)
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             RETURNING
                 id,
                 employee_id,
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
             "#,
             doc.employee_id,
-            doc.category.as_ref().map(|c| c.as_str()),
+            category,
             doc.filename,
             doc.s3_key,
-            doc.s3_url,
+            doc.thumbnail_key,
             doc.mime_type,
             doc.file_size,
             doc.description,
-            doc.related_id
+            related_type,
+            related_id,
+            version,
+            doc.category.map(|c| c.document_type_name())
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await?;
 
+        let superseded: Vec<(i32, Option<DateTime<Utc>>)> = if previous_ids.is_empty() {
+            Vec::new()
+        } else {
+            sqlx::query!(
+                r#"
+                UPDATE employee_documents
+                SET
+                    superseded_by = $1,
+                    superseded_at = CURRENT_TIMESTAMP
+                WHERE id = ANY($2)
+                RETURNING id, superseded_at
+                "#,
+                document.id,
+                &previous_ids
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.superseded_at))
+            .collect()
+        };
+
+        tx.commit().await?;
+
+        audit_log::record_create(
+            "employee_documents",
+            document.id,
+            Some(document.employee_id),
+            &document,
+        )
+        .await;
+        record_superseded(document.employee_id, &superseded, document.id).await;
+
         Ok(document)
     }
 
@@ -70,11 +206,15 @@ impl EmployeeDocumentRepository {
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
@@ -89,7 +229,7 @@ impl EmployeeDocumentRepository {
         Ok(document)
     }
 
-    /// 従業員IDでドキュメント一覧を取得
+    /// 従業員IDでドキュメント一覧を取得（現在の版のみ）
     pub async fn get_by_employee_id(
         employee_id: i32,
     ) -> Result<Vec<EmployeeDocument>, sqlx::Error> {
@@ -104,16 +244,20 @@ impl EmployeeDocumentRepository {
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
             FROM employee_documents
-            WHERE employee_id = $1
+            WHERE employee_id = $1 AND is_active = TRUE
             ORDER BY uploaded_at DESC
             "#,
             employee_id
@@ -124,7 +268,7 @@ impl EmployeeDocumentRepository {
         Ok(documents)
     }
 
-    /// 従業員の特定カテゴリのドキュメントを取得
+    /// 従業員の特定カテゴリのドキュメントを取得（現在の版のみ）
     pub async fn get_by_category(
         employee_id: i32,
         category: &str,
@@ -140,16 +284,20 @@ impl EmployeeDocumentRepository {
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
             FROM employee_documents
-            WHERE employee_id = $1 AND category = $2
+            WHERE employee_id = $1 AND category = $2 AND is_active = TRUE
             ORDER BY uploaded_at DESC
             "#,
             employee_id,
@@ -161,10 +309,9 @@ impl EmployeeDocumentRepository {
         Ok(documents)
     }
 
-    /// 関連IDでドキュメントを取得
-    pub async fn get_by_related_id(
-        employee_id: i32,
-        related_id: i32,
+    /// 添付先の記録でドキュメントを取得（現在の版のみ）
+    pub async fn get_by_relation(
+        relation: DocumentRelation,
     ) -> Result<Vec<EmployeeDocument>, sqlx::Error> {
         let pool = Self::pool().await?;
 
@@ -177,20 +324,24 @@ impl EmployeeDocumentRepository {
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
             FROM employee_documents
-            WHERE employee_id = $1 AND related_id = $2
-            ORDER BY uploaded_at DESC
+            WHERE related_type = $1 AND related_id = $2 AND is_active = TRUE
+            ORDER BY category, uploaded_at DESC
             "#,
-            employee_id,
-            related_id
+            relation.record_type.as_str(),
+            relation.record_id
         )
         .fetch_all(pool)
         .await?;
@@ -198,12 +349,98 @@ impl EmployeeDocumentRepository {
         Ok(documents)
     }
 
+    /// 添付先の記録が存在するか（従業員の記録は同じ従業員のものに限る）
+    pub async fn relation_exists(
+        employee_id: i32,
+        relation: DocumentRelation,
+    ) -> Result<bool, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let id = relation.record_id;
+
+        let owner = match relation.record_type {
+            RelatedRecordType::License => {
+                sqlx::query_scalar!("SELECT employee_id FROM licenses WHERE id = $1", id)
+                    .fetch_optional(pool)
+                    .await?
+            }
+            RelatedRecordType::Qualification => {
+                sqlx::query_scalar!("SELECT employee_id FROM qualifications WHERE id = $1", id)
+                    .fetch_optional(pool)
+                    .await?
+            }
+            RelatedRecordType::HealthCheckup => {
+                sqlx::query_scalar!(
+                    "SELECT employee_id FROM health_checkup_history WHERE id = $1",
+                    id
+                )
+                .fetch_optional(pool)
+                .await?
+            }
+            RelatedRecordType::AptitudeCheckup => {
+                sqlx::query_scalar!(
+                    "SELECT employee_id FROM aptitude_checkup_history WHERE id = $1",
+                    id
+                )
+                .fetch_optional(pool)
+                .await?
+            }
+            RelatedRecordType::ResidenceCard => {
+                sqlx::query_scalar!("SELECT employee_id FROM residence_cards WHERE id = $1", id)
+                    .fetch_optional(pool)
+                    .await?
+            }
+            RelatedRecordType::Vehicle => {
+                let exists = sqlx::query_scalar!(
+                    r#"SELECT EXISTS(SELECT 1 FROM vehicles WHERE id = $1) AS "exists!""#,
+                    id
+                )
+                .fetch_one(pool)
+                .await?;
+                return Ok(exists);
+            }
+        };
+
+        Ok(owner == Some(employee_id))
+    }
+
+    /// 添付先を付け替えた場合に、付け替え先に同じ種類の現在の版が既にあるか
+    ///
+    /// 現在の版は従業員・種類・添付先ごとに1件に限る（一意制約）ため、ある場合は付け替えられない
+    pub async fn has_other_current_version(
+        doc: &EmployeeDocument,
+        relation: Option<DocumentRelation>,
+    ) -> Result<bool, sqlx::Error> {
+        if !doc.is_current || !doc.category.is_some_and(|c| c.is_versioned()) {
+            return Ok(false);
+        }
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM employee_documents
+                WHERE employee_id = $1 AND category = $2
+                  AND related_type IS NOT DISTINCT FROM $3
+                  AND related_id IS NOT DISTINCT FROM $4
+                  AND is_active = TRUE AND id <> $5
+            ) AS "exists!"
+            "#,
+            doc.employee_id,
+            doc.category.as_ref().map(|c| c.as_str()),
+            relation.map(|r| r.record_type.as_str()),
+            relation.map(|r| r.record_id),
+            doc.id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
     /// ドキュメントを更新
     pub async fn update(
         id: i32,
         update: UpdateEmployeeDocument,
     ) -> Result<EmployeeDocument, sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
 
         let document = sqlx::query_as!(
             EmployeeDocument,
@@ -211,7 +448,8 @@ impl EmployeeDocumentRepository {
             UPDATE employee_documents
             SET
                 notes = $2,
-                related_id = $3,
+                related_type = $3,
+                related_id = $4,
                 updated_at = CURRENT_TIMESTAMP
             WHERE id = $1
             RETURNING
@@ -220,33 +458,320 @@ impl EmployeeDocumentRepository {
                 category as "category?: _",
                 file_name as filename,
                 s3_key as "s3_key?",
-                s3_url as "s3_url?",
+                thumbnail_key,
                 mime_type as "mime_type?",
                 file_size as "file_size?",
                 notes as description,
+                related_type as "related_type?: _",
                 related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
                 uploaded_at,
                 created_at,
                 updated_at
             "#,
             id,
             update.description,
-            update.related_id
+            update.relation.map(|r| r.record_type.as_str()),
+            update.relation.map(|r| r.record_id)
         )
         .fetch_one(pool)
         .await?;
 
+        audit_log::record_update(
+            "employee_documents",
+            document.id,
+            Some(document.employee_id),
+            old.as_ref(),
+            &document,
+        )
+        .await;
+
+        Ok(document)
+    }
+
+    /// ドキュメントの全ての版を取得（新しい版から順に）
+    pub async fn get_versions(id: i32) -> Result<Vec<EmployeeDocument>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let Some(document) = Self::get_by_id(id).await? else {
+            return Ok(Vec::new());
+        };
+        if !document.category.is_some_and(|c| c.is_versioned()) {
+            return Ok(vec![document]);
+        }
+
+        let documents = sqlx::query_as!(
+            EmployeeDocument,
+            r#"
+            SELECT
+                id,
+                employee_id,
+                category as "category?: _",
+                file_name as filename,
+                s3_key as "s3_key?",
+                thumbnail_key,
+                mime_type as "mime_type?",
+                file_size as "file_size?",
+                notes as description,
+                related_type as "related_type?: _",
+                related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
+                uploaded_at,
+                created_at,
+                updated_at
+            FROM employee_documents
+            WHERE employee_id = $1 AND category = $2
+              AND related_type IS NOT DISTINCT FROM $3
+              AND related_id IS NOT DISTINCT FROM $4
+            ORDER BY version DESC, id DESC
+            "#,
+            document.employee_id,
+            document.category.as_ref().map(|c| c.as_str()),
+            document.related_type.as_ref().map(|t| t.as_str()),
+            document.related_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(documents)
+    }
+
+    /// 以前の版を現在の版に戻す（現在の版は履歴として残す）
+    pub async fn restore_version(id: i32) -> Result<EmployeeDocument, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?.ok_or(sqlx::Error::RowNotFound)?;
+        let mut tx = pool.begin().await?;
+
+        let superseded: Vec<(i32, Option<DateTime<Utc>>)> = sqlx::query!(
+            r#"
+            UPDATE employee_documents
+            SET
+                is_active = FALSE,
+                superseded_by = $1,
+                superseded_at = CURRENT_TIMESTAMP,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE employee_id = $2 AND category = $3
+              AND related_type IS NOT DISTINCT FROM $4
+              AND related_id IS NOT DISTINCT FROM $5
+              AND is_active = TRUE AND id <> $1
+            RETURNING id, superseded_at
+            "#,
+            id,
+            old.employee_id,
+            old.category.as_ref().map(|c| c.as_str()),
+            old.related_type.as_ref().map(|t| t.as_str()),
+            old.related_id
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|r| (r.id, r.superseded_at))
+        .collect();
+
+        let document = sqlx::query_as!(
+            EmployeeDocument,
+            r#"
+            UPDATE employee_documents
+            SET
+                is_active = TRUE,
+                superseded_by = NULL,
+                superseded_at = NULL,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING
+                id,
+                employee_id,
+                category as "category?: _",
+                file_name as filename,
+                s3_key as "s3_key?",
+                thumbnail_key,
+                mime_type as "mime_type?",
+                file_size as "file_size?",
+                notes as description,
+                related_type as "related_type?: _",
+                related_id,
+                version,
+                is_active as is_current,
+                superseded_at,
+                uploaded_at,
+                created_at,
+                updated_at
+            "#,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        audit_log::record_update(
+            "employee_documents",
+            document.id,
+            Some(document.employee_id),
+            Some(&old),
+            &document,
+        )
+        .await;
+        record_superseded(document.employee_id, &superseded, document.id).await;
+
         Ok(document)
     }
 
+    /// 書類または従業員が廃棄の保留（リーガルホールド）中か
+    pub async fn is_on_legal_hold(id: i32) -> Result<bool, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let held = sqlx::query_scalar!(
+            r#"
+            SELECT (d.legal_hold OR e.legal_hold) AS "held!"
+            FROM employee_documents d
+            JOIN employees e ON e.id = d.employee_id
+            WHERE d.id = $1
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(held.unwrap_or(false))
+    }
+
     /// ドキュメントを削除
+    ///
+    /// 現在の版を削除した場合は、残っている最新の版を現在の版に戻す
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
+        let mut tx = pool.begin().await?;
 
         sqlx::query!("DELETE FROM employee_documents WHERE id = $1", id)
-            .execute(pool)
+            .execute(&mut *tx)
+            .await?;
+
+        if let Some(doc) = old
+            .as_ref()
+            .filter(|d| d.is_current && d.category.is_some_and(|c| c.is_versioned()))
+        {
+            sqlx::query!(
+                r#"
+                UPDATE employee_documents
+                SET
+                    is_active = TRUE,
+                    superseded_by = NULL,
+                    superseded_at = NULL,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = (
+                    SELECT id FROM employee_documents
+                    WHERE employee_id = $1 AND category = $2
+                      AND related_type IS NOT DISTINCT FROM $3
+                      AND related_id IS NOT DISTINCT FROM $4
+                    ORDER BY version DESC, id DESC
+                    LIMIT 1
+                )
+                "#,
+                doc.employee_id,
+                doc.category.as_ref().map(|c| c.as_str()),
+                doc.related_type.as_ref().map(|t| t.as_str()),
+                doc.related_id
+            )
+            .execute(&mut *tx)
             .await?;
+        }
+
+        tx.commit().await?;
+
+        audit_log::record_delete(
+            "employee_documents",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// 一括ダウンロードの対象を取得（現在の版のみ、社員番号・種類・アップロード日時の順）
+    pub async fn list_for_archive(
+        employee_ids: &[i32],
+        category: Option<DocumentCategory>,
+        relation: Option<DocumentRelation>,
+    ) -> Result<Vec<ArchiveDocument>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ArchiveDocument,
+            r#"
+            SELECT
+                d.id,
+                d.employee_id,
+                e.employee_code AS "employee_code!",
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                d.category AS "category?: DocumentCategory",
+                d.file_name AS filename,
+                d.s3_key AS "s3_key?",
+                d.file_size AS "file_size?",
+                d.version,
+                d.notes AS description,
+                d.uploaded_at
+            FROM employee_documents d
+            JOIN employees e ON e.id = d.employee_id
+            WHERE d.employee_id = ANY($1)
+              AND d.is_active = TRUE
+              AND ($2::VARCHAR IS NULL OR d.category = $2)
+              AND ($3::VARCHAR IS NULL OR (d.related_type = $3 AND d.related_id = $4))
+            ORDER BY e.employee_code, d.category, d.uploaded_at
+            "#,
+            employee_ids,
+            category.as_ref().map(|c| c.as_str()),
+            relation.map(|r| r.record_type.as_str()),
+            relation.map(|r| r.record_id)
+        )
+        .fetch_all(pool)
+        .await
+    }
 
+    /// すべての版のドキュメントが参照している保存先のファイルを取得（突き合わせ用）
+    pub async fn list_storage_keys() -> Result<Vec<DocumentKeys>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            DocumentKeys,
+            r#"
+            SELECT id, employee_id, file_name AS filename, s3_key, thumbnail_key
+            FROM employee_documents
+            ORDER BY id
+            "#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 従業員のすべての版のドキュメントの保存先のファイルを取得（従業員の削除後に削除する）
+    pub async fn storage_keys_by_employee_id(employee_id: i32) -> Result<Vec<String>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let rows = sqlx::query!(
+            "SELECT s3_key, thumbnail_key FROM employee_documents WHERE employee_id = $1",
+            employee_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .flat_map(|r| [r.s3_key, r.thumbnail_key])
+            .flatten()
+            .collect())
+    }
+
+    /// サムネイルの参照を解除（サムネイルのファイルが見つからない場合）
+    pub async fn clear_thumbnail_key(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query!(
+            "UPDATE employee_documents SET thumbnail_key = NULL, updated_at = CURRENT_TIMESTAMP WHERE id = $1",
+            id
+        )
+        .execute(pool)
+        .await?;
         Ok(())
     }
 
@@ -255,7 +780,7 @@ impl EmployeeDocumentRepository {
         let pool = Self::pool().await?;
 
         let result = sqlx::query!(
-            "SELECT COUNT(*) as count FROM employee_documents WHERE employee_id = $1",
+            "SELECT COUNT(*) as count FROM employee_documents WHERE employee_id = $1 AND is_active = TRUE",
             employee_id
         )
         .fetch_one(pool)
@@ -269,7 +794,7 @@ impl EmployeeDocumentRepository {
         let pool = Self::pool().await?;
 
         let result = sqlx::query!(
-            "SELECT COUNT(*) as count FROM employee_documents WHERE employee_id = $1 AND category = $2",
+            "SELECT COUNT(*) as count FROM employee_documents WHERE employee_id = $1 AND category = $2 AND is_active = TRUE",
             employee_id,
             category
         )
@@ -0,0 +1,331 @@
+//! ドキュメントのアップロードエンドポイント（サーバー専用）
+//!
+//! `POST /api/employees/{employee_id}/documents` で multipart/form-data を受け取り、
+//! ファイル本体をBase64に変換せず、受信しながらそのまま保存先へ書き込む。
+//! 内容は先頭バイトで検査し、画像はEXIF除去・縮小してサムネイルを作成する（`content`モジュール）。
+//! 先に `category`・`description`・`related_type`・`related_id` を、続けて `file` を1つ以上送る。
+//! 版管理の対象となる種類は1件のみ受け付け、同じ従業員・種類・添付先の現在の版を置き換える。
+
+use super::content;
+use super::models::{
+    is_allowed_upload, CreateEmployeeDocument, DocumentCategory, DocumentRelation,
+    DocumentUploadResult, RelatedRecordType, MAX_FILES_PER_UPLOAD,
+};
+use super::repository::EmployeeDocumentRepository;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::session::user_from_cookies;
+use crate::modules::s3_service::{self, ByteChunks, S3Error};
+use axum::extract::multipart::Field;
+use axum::extract::{Multipart, Path};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use tower_cookies::Cookies;
+
+/// アップロードエンドポイントのパス（Axumのルート定義用）
+pub const ROUTE: &str = "/api/employees/{employee_id}/documents";
+
+/// ファイル以外の項目（種類・説明・添付先）の上限（バイト）
+const MAX_TEXT_FIELD_BYTES: usize = 4 * 1024;
+
+fn error_response(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, message.into()).into_response()
+}
+
+/// ファイル以外の項目を読み込む（ルート全体の上限を外しているため、項目ごとに上限で打ち切る）
+async fn read_text_field(mut field: Field<'_>) -> Result<String, Response> {
+    let mut bytes = Vec::new();
+    loop {
+        match field.chunk().await {
+            Ok(Some(chunk)) => {
+                if bytes.len() + chunk.len() > MAX_TEXT_FIELD_BYTES {
+                    return Err(error_response(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        format!(
+                            "{}は{}KB以下にしてください",
+                            field.name().unwrap_or_default(),
+                            MAX_TEXT_FIELD_BYTES / 1024
+                        ),
+                    ));
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+            Ok(None) => break,
+            Err(e) => return Err(error_response(StatusCode::BAD_REQUEST, e.to_string())),
+        }
+    }
+    String::from_utf8(bytes).map_err(|e| error_response(StatusCode::BAD_REQUEST, e.to_string()))
+}
+
+/// ファイルを受信しながら保存し、ドキュメントを登録する
+pub async fn upload_documents(
+    cookies: Cookies,
+    Path(employee_id): Path<i32>,
+    mut multipart: Multipart,
+) -> Response {
+    match user_from_cookies(&cookies).await {
+        Ok(Some(user)) if user.can(Permission::EditDriver) => {}
+        Ok(Some(_)) => {
+            return error_response(StatusCode::FORBIDDEN, "この操作を行う権限がありません")
+        }
+        Ok(None) => return error_response(StatusCode::UNAUTHORIZED, "ログインが必要です"),
+        Err(e) => return error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
+
+    let mut category = None::<DocumentCategory>;
+    let mut description = None::<String>;
+    let mut related_type = None::<RelatedRecordType>;
+    let mut related_id = None::<i32>;
+    let mut relation = None::<DocumentRelation>;
+    let mut file_count = 0usize;
+    let mut result = DocumentUploadResult::default();
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return error_response(StatusCode::BAD_REQUEST, e.to_string()),
+        };
+
+        let name = field.name().unwrap_or_default().to_string();
+        match name.as_str() {
+            "category" => {
+                let value = match read_text_field(field).await {
+                    Ok(value) => value,
+                    Err(response) => return response,
+                };
+                match DocumentCategory::parse(&value) {
+                    Some(c) => category = Some(c),
+                    None => {
+                        return error_response(
+                            StatusCode::BAD_REQUEST,
+                            format!("不明なドキュメントの種類です: {}", value),
+                        )
+                    }
+                }
+            }
+            "description" => {
+                let value = match read_text_field(field).await {
+                    Ok(value) => value,
+                    Err(response) => return response,
+                };
+                description = Some(value).filter(|v| !v.trim().is_empty());
+            }
+            "related_type" => {
+                let value = match read_text_field(field).await {
+                    Ok(value) => value,
+                    Err(response) => return response,
+                };
+                if value.trim().is_empty() {
+                    continue;
+                }
+                match RelatedRecordType::parse(value.trim()) {
+                    Some(t) => related_type = Some(t),
+                    None => {
+                        return error_response(
+                            StatusCode::BAD_REQUEST,
+                            format!("不明な添付先の種類です: {}", value),
+                        )
+                    }
+                }
+            }
+            "related_id" => {
+                let value = match read_text_field(field).await {
+                    Ok(value) => value,
+                    Err(response) => return response,
+                };
+                related_id = value.trim().parse().ok();
+            }
+            "file" => {
+                // バリデーション
+                let Some(category) = category else {
+                    return error_response(
+                        StatusCode::BAD_REQUEST,
+                        "ドキュメントの種類をファイルより先に送信してください",
+                    );
+                };
+                file_count += 1;
+                // 添付先は最初のファイルの前に1回だけ検査する
+                if file_count == 1 && (related_type.is_some() || related_id.is_some()) {
+                    let Some(r) = DocumentRelation::from_parts(related_type, related_id) else {
+                        return error_response(
+                            StatusCode::BAD_REQUEST,
+                            "添付先の記録の種類とIDを指定してください",
+                        );
+                    };
+                    match EmployeeDocumentRepository::relation_exists(employee_id, r).await {
+                        Ok(true) => relation = Some(r),
+                        Ok(false) => {
+                            return error_response(
+                                StatusCode::BAD_REQUEST,
+                                format!(
+                                    "添付先の{}（ID: {}）が見つかりません",
+                                    r.record_type.display_name(),
+                                    r.record_id
+                                ),
+                            )
+                        }
+                        Err(e) => {
+                            return error_response(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+                        }
+                    }
+                }
+                if file_count > MAX_FILES_PER_UPLOAD {
+                    return error_response(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        format!(
+                            "一度にアップロードできるファイルは{}件までです",
+                            MAX_FILES_PER_UPLOAD
+                        ),
+                    );
+                }
+                let filename = field.file_name().unwrap_or_default().to_string();
+                // 版管理の対象は1件ずつ（同じ送信内のファイル同士で置き換え合わないように）
+                if category.is_versioned() && file_count > 1 {
+                    result.errors.push(format!(
+                        "{}: {}は1件ずつアップロードしてください",
+                        filename,
+                        category.display_name()
+                    ));
+                    continue;
+                }
+                if !is_allowed_upload(&filename) {
+                    result.errors.push(format!(
+                        "{}: 対応していないファイル形式です（JPG, PNG, GIF, PDF）",
+                        filename
+                    ));
+                    continue;
+                }
+
+                // 上限を超えた時点で受信を打ち切る
+                let max_bytes = category.max_bytes();
+                let mut received = 0u64;
+                let chunks = field.map(move |chunk| {
+                    let chunk = chunk.map_err(|e| S3Error::from(e.to_string()))?;
+                    received += chunk.len() as u64;
+                    if received > max_bytes {
+                        return Err(S3Error::from(format!(
+                            "{}は{}MB以下にしてください",
+                            category.display_name(),
+                            max_bytes / (1024 * 1024)
+                        )));
+                    }
+                    Ok(chunk)
+                });
+
+                let s3_key =
+                    s3_service::generate_s3_key(category.storage_folder(), employee_id, &filename);
+                let stored = match store_file(&s3_key, &filename, Box::pin(chunks)).await {
+                    Ok(stored) => stored,
+                    Err(message) => {
+                        result.errors.push(format!("{}: {}", filename, message));
+                        continue;
+                    }
+                };
+
+                let create_doc = CreateEmployeeDocument {
+                    employee_id,
+                    category: Some(category),
+                    filename: filename.clone(),
+                    s3_key: Some(s3_key.clone()),
+                    thumbnail_key: stored.thumbnail_key.clone(),
+                    mime_type: Some(stored.mime_type.to_string()),
+                    file_size: Some(stored.size as i64),
+                    description: description.clone(),
+                    relation,
+                };
+                match EmployeeDocumentRepository::create(create_doc).await {
+                    Ok(document) => result.documents.push(document),
+                    Err(e) => {
+                        // 登録できなかったファイルは保存先からも削除
+                        s3_service::delete_file(&s3_key).await.ok();
+                        if let Some(key) = &stored.thumbnail_key {
+                            s3_service::delete_file(key).await.ok();
+                        }
+                        result.errors.push(format!("{}: {}", filename, e));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if file_count == 0 {
+        return error_response(StatusCode::BAD_REQUEST, "ファイルが選択されていません");
+    }
+    Json(result).into_response()
+}
+
+/// 保存したファイルの情報
+struct StoredFile {
+    mime_type: &'static str,
+    size: u64,
+    thumbnail_key: Option<String>,
+}
+
+/// 内容を検査してから保存する
+///
+/// 画像（JPEG / PNG）はEXIF除去・縮小のため全体を受信してから加工し、サムネイルも保存する。
+/// PDFなどはそのまま受信しながら保存する。
+async fn store_file(
+    s3_key: &str,
+    filename: &str,
+    mut chunks: ByteChunks<'_>,
+) -> Result<StoredFile, String> {
+    // 先頭を読み込んで形式を判定
+    let mut head = Vec::new();
+    while head.len() < content::SNIFF_LEN {
+        match chunks.next().await {
+            Some(chunk) => head.extend_from_slice(&chunk.map_err(|e| e.message)?),
+            None => break,
+        }
+    }
+    let mime_type = content::verify_content(s3_service::guess_mime_type(filename), &head)?;
+
+    if !content::is_processable_image(mime_type) {
+        // 読み込んだ先頭部分を戻して、残りとあわせて保存
+        let head = futures_util::stream::once(async move { Ok::<_, S3Error>(Bytes::from(head)) });
+        let size = s3_service::upload_stream(s3_key, Box::pin(head.chain(chunks)), mime_type)
+            .await
+            .map_err(|e| e.message)?;
+        return Ok(StoredFile {
+            mime_type,
+            size,
+            thumbnail_key: None,
+        });
+    }
+
+    let mut data = head;
+    while let Some(chunk) = chunks.next().await {
+        data.extend_from_slice(&chunk.map_err(|e| e.message)?);
+    }
+    let max_px = content::max_image_px();
+    let processed =
+        tokio::task::spawn_blocking(move || content::process_image(&data, mime_type, max_px))
+            .await
+            .map_err(|e| e.to_string())??;
+
+    let size = processed.data.len() as u64;
+    s3_service::upload_file(s3_key, Bytes::from(processed.data), mime_type)
+        .await
+        .map_err(|e| e.message)?;
+    let thumbnail_key = content::thumbnail_key(s3_key);
+    if let Err(e) = s3_service::upload_file(
+        &thumbnail_key,
+        Bytes::from(processed.thumbnail),
+        "image/jpeg",
+    )
+    .await
+    {
+        s3_service::delete_file(s3_key).await.ok();
+        return Err(e.message);
+    }
+
+    Ok(StoredFile {
+        mime_type,
+        size,
+        thumbnail_key: Some(thumbnail_key),
+    })
+}
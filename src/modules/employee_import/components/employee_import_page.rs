@@ -0,0 +1,273 @@
+use crate::components::{Icon, IconType, INPUT_CLASS};
+use crate::modules::employee_import::handlers::{preview_employee_import, run_employee_import};
+use crate::modules::employee_import::models::{
+    ImportEncoding, ImportReport, IMPORT_COLUMNS, REJECTED_COLUMNS, REQUIRED_COLUMNS,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+const SECTION_TITLE_CLASS: &str = "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400";
+const BUTTON_PRIMARY_CLASS: &str = "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed";
+const TH_CLASS: &str = "px-4 py-2 text-left text-xs font-semibold text-gray-600";
+
+/// Base64エンコード関数（web feature用）
+#[cfg(feature = "web")]
+fn encode_base64(data: &[u8]) -> String {
+    use base64::{engine::general_purpose, Engine as _};
+    general_purpose::STANDARD.encode(data)
+}
+
+/// 従業員CSV取込画面
+#[component]
+pub fn EmployeeImportPage() -> Element {
+    let nav = navigator();
+    // Base64エンコードしたファイル内容
+    let mut file_data = use_signal(|| None::<String>);
+    let mut encoding = use_signal(ImportEncoding::default);
+    let mut report = use_signal(|| None::<ImportReport>);
+    let mut error_message = use_signal(String::new);
+    let mut is_processing = use_signal(|| false);
+
+    let handle_file_select = move |evt: Event<FormData>| async move {
+        report.set(None);
+        error_message.set(String::new());
+        file_data.set(None);
+
+        let files = evt.files();
+        let Some(file) = files.first() else {
+            return;
+        };
+
+        #[cfg(feature = "web")]
+        match file.read_bytes().await {
+            Ok(bytes) => file_data.set(Some(encode_base64(&bytes))),
+            Err(e) => error_message.set(format!("ファイル読み込みエラー: {}", e)),
+        }
+        #[cfg(not(feature = "web"))]
+        let _ = file;
+    };
+
+    // ドライラン（検証のみ）
+    let handle_preview = move |_| async move {
+        let Some(data) = file_data() else {
+            return;
+        };
+        is_processing.set(true);
+        error_message.set(String::new());
+        match preview_employee_import(data, encoding()).await {
+            Ok(r) => report.set(Some(r)),
+            Err(e) => {
+                report.set(None);
+                error_message.set(format!("検証に失敗しました: {}", e));
+            }
+        }
+        is_processing.set(false);
+    };
+
+    // 問題のない行を登録
+    let handle_import = move |_| async move {
+        let Some(data) = file_data() else {
+            return;
+        };
+        is_processing.set(true);
+        error_message.set(String::new());
+        match run_employee_import(data, encoding()).await {
+            Ok(r) => {
+                report.set(Some(r));
+                file_data.set(None);
+            }
+            Err(e) => error_message.set(format!("取り込みに失敗しました: {}", e)),
+        }
+        is_processing.set(false);
+    };
+
+    rsx! {
+        document::Title { "従業員CSV取込" }
+        div { class: "flex flex-col h-full",
+            // ヘッダー
+            div { class: "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white shadow-sm",
+                div { class: "flex items-center gap-3",
+                    button {
+                        class: "p-2 hover:bg-gray-100 rounded-lg transition-colors",
+                        onclick: move |_| {
+                            nav.push(Route::EmployeesPage {});
+                        },
+                        title: "一覧に戻る",
+                        Icon {
+                            icon_type: IconType::ArrowLeft,
+                            class: Some("size-5".to_string())
+                        }
+                    }
+                    h1 { class: "text-lg font-semibold text-gray-800", "従業員CSV取込" }
+                }
+            }
+
+            div { class: "flex-1 overflow-auto p-6 space-y-6",
+                // ファイル選択
+                div { class: SECTION_CLASS,
+                    h3 { class: SECTION_TITLE_CLASS, "CSVファイル" }
+                    div { class: "grid grid-cols-[1fr_200px_auto] gap-4 items-end",
+                        div { class: "space-y-2",
+                            label { class: "block text-sm font-medium text-gray-700", "ファイル" }
+                            input {
+                                class: INPUT_CLASS,
+                                r#type: "file",
+                                accept: ".csv,text/csv",
+                                disabled: is_processing(),
+                                onchange: handle_file_select,
+                            }
+                        }
+                        div { class: "space-y-2",
+                            label { class: "block text-sm font-medium text-gray-700", "文字コード" }
+                            select {
+                                class: INPUT_CLASS,
+                                disabled: is_processing(),
+                                onchange: move |evt| {
+                                    if let Some(e) = ImportEncoding::parse(&evt.value()) {
+                                        encoding.set(e);
+                                        report.set(None);
+                                    }
+                                },
+                                for e in ImportEncoding::ALL {
+                                    option {
+                                        value: "{e.as_str()}",
+                                        selected: encoding() == e,
+                                        "{e.display_name()}"
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            class: BUTTON_PRIMARY_CLASS,
+                            disabled: is_processing() || file_data().is_none(),
+                            onclick: handle_preview,
+                            "検証する"
+                        }
+                    }
+                    if !error_message().is_empty() {
+                        p { class: "mt-4 text-red-500 text-sm", "{error_message}" }
+                    }
+                    div { class: "mt-4 text-sm text-gray-600 space-y-1",
+                        p { "1行目は見出し行です。次の見出しの列を取り込みます（順不同、その他の列は無視します）。" }
+                        p {
+                            for (name, _) in IMPORT_COLUMNS.iter() {
+                                span {
+                                    class: "inline-block mr-2 mb-1 px-2 py-0.5 rounded bg-gray-100",
+                                    class: if REQUIRED_COLUMNS.contains(name) { "font-semibold text-gray-800" },
+                                    "{name}"
+                                    if REQUIRED_COLUMNS.contains(name) {
+                                        span { class: "text-red-500", " *" }
+                                    }
+                                }
+                            }
+                        }
+                        p { "会社・営業所・部署・役職は組織設定に登録済みの名称で入力してください。日付は 2024-04-01 または 2024/4/1 の形式です。" }
+                        for (name, _, reason) in REJECTED_COLUMNS.iter() {
+                            p { class: "text-amber-700", "{name}: {reason}" }
+                        }
+                    }
+                }
+
+                // 検証結果
+                if let Some(r) = report() {
+                    ImportReportView {
+                        report: r,
+                        is_processing: is_processing(),
+                        can_import: file_data().is_some(),
+                        on_import: handle_import,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 検証結果・取込結果の表示
+#[component]
+fn ImportReportView(
+    report: ImportReport,
+    is_processing: bool,
+    can_import: bool,
+    on_import: EventHandler<()>,
+) -> Element {
+    let valid_count = report.valid_count();
+    let error_count = report.error_count();
+    let duplicate_codes = report.duplicate_codes.join("、");
+    let existing_codes = report.existing_codes.join("、");
+
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold",
+                    if report.imported > 0 { "取込結果" } else { "検証結果（ドライラン）" }
+                }
+                if report.imported == 0 && can_import {
+                    button {
+                        class: BUTTON_PRIMARY_CLASS,
+                        disabled: is_processing || valid_count == 0,
+                        onclick: move |_| on_import.call(()),
+                        "問題のない {valid_count} 件を取り込む"
+                    }
+                }
+            }
+
+            if report.imported > 0 {
+                div { class: "mb-4 p-4 bg-green-50 border border-green-200 rounded-lg",
+                    p { class: "text-sm text-green-800", "✅ {report.imported} 件の従業員を登録しました" }
+                }
+            }
+
+            div { class: "flex gap-6 mb-4 text-sm text-gray-700",
+                p { "文字コード: {report.encoding}" }
+                p { "全 {report.rows.len()} 行" }
+                p { class: "text-green-700 font-semibold", "取込可能 {valid_count} 行" }
+                p { class: "text-red-600 font-semibold", "エラー {error_count} 行" }
+            }
+
+            if !report.duplicate_codes.is_empty() {
+                p { class: "mb-2 text-sm text-red-600",
+                    "ファイル内で重複している社員番号: {duplicate_codes}"
+                }
+            }
+            if !report.existing_codes.is_empty() {
+                p { class: "mb-4 text-sm text-red-600",
+                    "既に登録済みの社員番号: {existing_codes}"
+                }
+            }
+
+            table { class: "w-full",
+                thead { class: "bg-gray-50",
+                    tr {
+                        th { class: TH_CLASS, "行" }
+                        th { class: TH_CLASS, "社員番号" }
+                        th { class: TH_CLASS, "氏名" }
+                        th { class: TH_CLASS, "結果" }
+                    }
+                }
+                tbody { class: "divide-y divide-gray-100",
+                    for row in report.rows.iter() {
+                        tr {
+                            key: "{row.line}",
+                            class: if !row.is_valid() { "bg-red-50" },
+                            td { class: "px-4 py-2 text-sm text-gray-500", "{row.line}" }
+                            td { class: "px-4 py-2 text-sm text-gray-800", "{row.employee_code}" }
+                            td { class: "px-4 py-2 text-sm text-gray-800", "{row.name}" }
+                            td { class: "px-4 py-2 text-sm",
+                                if row.is_valid() {
+                                    span { class: "text-green-700", "OK" }
+                                } else {
+                                    ul { class: "text-red-600 space-y-0.5",
+                                        for error in row.errors.iter() {
+                                            li { "{error}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
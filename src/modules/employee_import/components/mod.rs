@@ -0,0 +1,5 @@
+// 従業員CSV取込のコンポーネント
+
+mod employee_import_page;
+
+pub use employee_import_page::EmployeeImportPage;
@@ -0,0 +1,119 @@
+//! 従業員CSV取込のハンドラー層（サーバー関数）
+
+use super::models::{ImportEncoding, ImportReport};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::ImportRecord;
+#[cfg(feature = "server")]
+use super::parser;
+#[cfg(feature = "server")]
+use super::repository::EmployeeImportRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// 取り込めるCSVの上限サイズ
+#[cfg(feature = "server")]
+const MAX_CSV_BYTES: usize = 5 * 1024 * 1024;
+
+/// CSVを検証し、取込可能な行とレポートを作成
+#[cfg(feature = "server")]
+async fn prepare(
+    data: &str,
+    encoding: ImportEncoding,
+) -> Result<(Vec<ImportRecord>, ImportReport), ServerFnError> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    // "data:text/csv;base64," のようなプレフィックスを削除
+    let data = data.split_once(',').map_or(data, |(_, body)| body);
+    let bytes = general_purpose::STANDARD
+        .decode(data)
+        .map_err(|e| ServerFnError::new(format!("ファイルを読み込めません: {}", e)))?;
+    if bytes.len() > MAX_CSV_BYTES {
+        return Err(ServerFnError::new(
+            "CSVファイルは5MB以下にしてください".to_string(),
+        ));
+    }
+
+    let (text, used) = parser::decode(&bytes, encoding).map_err(ServerFnError::new)?;
+    let lookup = EmployeeImportRepository::get_lookup()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let today = chrono::Local::now().date_naive();
+    let mut rows = parser::parse(&text, &lookup, today).map_err(ServerFnError::new)?;
+
+    let codes: Vec<String> = rows
+        .iter()
+        .map(|r| r.result.employee_code.clone())
+        .filter(|c| !c.is_empty())
+        .collect();
+    let existing_codes = EmployeeImportRepository::get_existing_codes(&codes)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    let duplicate_codes = parser::mark_duplicates(&mut rows, &existing_codes);
+
+    let mut records = Vec::new();
+    let mut results = Vec::with_capacity(rows.len());
+    for row in rows {
+        if let Some(record) = row.record {
+            records.push(record);
+        }
+        results.push(row.result);
+    }
+
+    Ok((
+        records,
+        ImportReport {
+            encoding: used.display_name().to_string(),
+            rows: results,
+            duplicate_codes,
+            existing_codes,
+            imported: 0,
+        },
+    ))
+}
+
+/// CSVを検証のみ行う（ドライラン）
+/// `data`はBase64エンコードしたファイル内容
+#[server]
+pub async fn preview_employee_import(
+    data: String,
+    encoding: ImportEncoding,
+) -> Result<ImportReport, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    let (_, report) = prepare(&data, encoding).await?;
+    Ok(report)
+}
+
+/// CSVを検証し、問題のない行を1トランザクションで登録
+#[server]
+pub async fn run_employee_import(
+    data: String,
+    encoding: ImportEncoding,
+) -> Result<ImportReport, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
+    let (records, mut report) = prepare(&data, encoding).await?;
+    if records.is_empty() {
+        return Err(ServerFnError::new("取り込める行がありません".to_string()));
+    }
+
+    report.imported = EmployeeImportRepository::insert_all(records)
+        .await
+        .map_err(|e| {
+            let message = e.to_string();
+            if message.contains("employees_email_key") {
+                ServerFnError::new(
+                    "既に使用されているメールアドレスが含まれているため取り込めませんでした"
+                        .to_string(),
+                )
+            } else {
+                ServerFnError::new(format!(
+                    "取り込みに失敗したため全件取り消しました: {}",
+                    message
+                ))
+            }
+        })?;
+    Ok(report)
+}
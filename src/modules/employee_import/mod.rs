@@ -0,0 +1,14 @@
+//! 従業員CSV取込モジュール
+//! 旧Excel台帳から出力したCSV（Shift_JIS / UTF-8）を検証し、従業員と現住所を一括登録する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod parser;
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::EmployeeImportPage;
+pub use models::{ImportEncoding, ImportReport, ImportRowResult};
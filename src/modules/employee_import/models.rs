@@ -0,0 +1,129 @@
+//! 従業員CSV取込のモデル定義
+
+use crate::modules::employees::{CreateAddress, EmployeeFull};
+use serde::{Deserialize, Serialize};
+
+/// CSVファイルの文字コード
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportEncoding {
+    /// UTF-8として読めればUTF-8、読めなければShift_JIS
+    #[default]
+    Auto,
+    Utf8,
+    ShiftJis,
+}
+
+impl ImportEncoding {
+    pub const ALL: [ImportEncoding; 3] = [
+        ImportEncoding::Auto,
+        ImportEncoding::Utf8,
+        ImportEncoding::ShiftJis,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportEncoding::Auto => "auto",
+            ImportEncoding::Utf8 => "utf8",
+            ImportEncoding::ShiftJis => "shift_jis",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == value)
+    }
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImportEncoding::Auto => "自動判定",
+            ImportEncoding::Utf8 => "UTF-8",
+            ImportEncoding::ShiftJis => "Shift_JIS",
+        }
+    }
+}
+
+/// 取込対象の列と、見出しとして受け付ける名称
+/// 旧Excel台帳の見出しの揺れを吸収するため複数の別名を持つ
+pub const IMPORT_COLUMNS: &[(&str, &[&str])] = &[
+    ("社員番号", &["社員番号", "従業員コード", "社員コード"]),
+    ("姓", &["姓", "氏"]),
+    ("名", &["名"]),
+    ("姓（カナ）", &["姓（カナ）", "姓(カナ)", "セイ"]),
+    ("名（カナ）", &["名（カナ）", "名(カナ)", "メイ"]),
+    ("生年月日", &["生年月日"]),
+    ("性別", &["性別"]),
+    ("メールアドレス", &["メールアドレス", "メール"]),
+    ("電話番号", &["電話番号", "固定電話"]),
+    ("携帯電話", &["携帯電話", "携帯番号", "携帯"]),
+    ("入社日", &["入社日", "雇用開始日"]),
+    ("会社", &["会社", "会社名"]),
+    ("営業所", &["営業所", "営業所名"]),
+    ("部署", &["部署", "部署名"]),
+    ("役職", &["役職"]),
+    ("郵便番号", &["郵便番号"]),
+    ("都道府県", &["都道府県"]),
+    ("市区町村", &["市区町村"]),
+    ("番地", &["番地", "町名番地"]),
+    ("建物名", &["建物名", "建物名・部屋番号"]),
+];
+
+/// 取り込まない列と見出しの別名、理由（値が入っている行はエラーにする）
+pub const REJECTED_COLUMNS: &[(&str, &[&str], &str)] = &[(
+    "選任日",
+    &["選任日", "ドライバー選任日"],
+    "取り込めません。運転者の選任は従業員詳細の「運転者選任」で前提条件を確認してから行ってください",
+)];
+
+/// 必須の列
+pub const REQUIRED_COLUMNS: [&str; 3] = ["社員番号", "姓", "名"];
+
+/// 取込可能な1行分のデータ（従業員と現住所）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportRecord {
+    /// CSV上の行番号（見出し行を1行目とする）
+    pub line: usize,
+    /// idと作成日時は登録時に採番される
+    pub employee: EmployeeFull,
+    /// employee_idは登録時に設定される
+    pub address: Option<CreateAddress>,
+}
+
+/// 1行分の検証結果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImportRowResult {
+    pub line: usize,
+    pub employee_code: String,
+    pub name: String,
+    /// 空なら取込可能
+    pub errors: Vec<String>,
+}
+
+impl ImportRowResult {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// 取込結果のレポート（ドライラン・本番共通）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// 判定した文字コード
+    pub encoding: String,
+    pub rows: Vec<ImportRowResult>,
+    /// ファイル内で重複している社員番号
+    pub duplicate_codes: Vec<String>,
+    /// 既に登録済みの社員番号
+    pub existing_codes: Vec<String>,
+    /// 実際に登録した件数（ドライランでは0）
+    pub imported: usize,
+}
+
+impl ImportReport {
+    pub fn valid_count(&self) -> usize {
+        self.rows.iter().filter(|r| r.is_valid()).count()
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.rows.len() - self.valid_count()
+    }
+}
@@ -0,0 +1,448 @@
+//! CSVの文字コード判定・列の対応付け・1行ごとの検証
+
+use super::models::{
+    ImportEncoding, ImportRecord, ImportRowResult, IMPORT_COLUMNS, REJECTED_COLUMNS,
+    REQUIRED_COLUMNS,
+};
+use crate::modules::employees::validation::{
+    validate_address_field, validate_employee_code, validate_employee_name, validate_postal_code,
+};
+use crate::modules::employees::{CreateAddress, EmployeeFull};
+use chrono::{NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+
+/// 名称からIDを引くためのマスタ
+#[derive(Debug, Default)]
+pub struct NameLookup {
+    pub companies: HashMap<String, i32>,
+    pub offices: HashMap<String, i32>,
+    pub departments: HashMap<String, i32>,
+    pub positions: HashMap<String, i32>,
+}
+
+/// 検証済みの1行（取込可能な場合のみrecordを持つ）
+#[derive(Debug, Clone)]
+pub struct ParsedRow {
+    pub result: ImportRowResult,
+    pub record: Option<ImportRecord>,
+}
+
+/// バイト列を文字列に変換し、実際に使った文字コードを返す
+pub fn decode(bytes: &[u8], encoding: ImportEncoding) -> Result<(String, ImportEncoding), String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    let utf8 = || {
+        std::str::from_utf8(bytes)
+            .map(|s| s.to_string())
+            .map_err(|_| "UTF-8として読み込めません。文字コードを確認してください".to_string())
+    };
+    let shift_jis = || {
+        let (text, _, had_errors) = encoding_rs::SHIFT_JIS.decode(bytes);
+        if had_errors {
+            Err("Shift_JISとして読み込めません。文字コードを確認してください".to_string())
+        } else {
+            Ok(text.into_owned())
+        }
+    };
+
+    match encoding {
+        ImportEncoding::Utf8 => utf8().map(|s| (s, ImportEncoding::Utf8)),
+        ImportEncoding::ShiftJis => shift_jis().map(|s| (s, ImportEncoding::ShiftJis)),
+        ImportEncoding::Auto => utf8()
+            .map(|s| (s, ImportEncoding::Utf8))
+            .or_else(|_| shift_jis().map(|s| (s, ImportEncoding::ShiftJis))),
+    }
+}
+
+/// CSV全体を読み込み、1行ごとに検証する
+/// 見出し行が不正な場合のみErrを返し、各行の不備はImportRowResult.errorsに入る
+pub fn parse(text: &str, lookup: &NameLookup, today: NaiveDate) -> Result<Vec<ParsedRow>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("見出し行を読み込めません: {}", e))?
+        .clone();
+    let columns = map_columns(headers.iter())?;
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        // 見出し行を1行目として数える（引用符内の改行は考慮しない）
+        let line = record
+            .as_ref()
+            .ok()
+            .and_then(|r| r.position())
+            .map(|p| p.line() as usize)
+            .unwrap_or(index + 2);
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                rows.push(ParsedRow {
+                    result: ImportRowResult {
+                        line,
+                        employee_code: String::new(),
+                        name: String::new(),
+                        errors: vec![format!("行を読み込めません: {}", e)],
+                    },
+                    record: None,
+                });
+                continue;
+            }
+        };
+        let get = |column: &str| -> String {
+            columns
+                .get(column)
+                .and_then(|&i| record.get(i))
+                .map(|v| v.trim().to_string())
+                .unwrap_or_default()
+        };
+
+        // 空行は読み飛ばす
+        if record.iter().all(|v| v.trim().is_empty()) {
+            continue;
+        }
+
+        rows.push(parse_row(line, &get, lookup, today));
+    }
+
+    Ok(rows)
+}
+
+/// 見出しを正規の列名に対応付ける
+fn map_columns<'a>(
+    headers: impl Iterator<Item = &'a str>,
+) -> Result<HashMap<&'static str, usize>, String> {
+    let mut columns = HashMap::new();
+    for (index, header) in headers.enumerate() {
+        let header = header.trim().trim_start_matches('\u{feff}');
+        if let Some((name, _)) = IMPORT_COLUMNS
+            .iter()
+            .find(|(_, aliases)| aliases.contains(&header))
+        {
+            columns.entry(*name).or_insert(index);
+        } else if let Some((name, _, _)) = REJECTED_COLUMNS
+            .iter()
+            .find(|(_, aliases, _)| aliases.contains(&header))
+        {
+            columns.entry(*name).or_insert(index);
+        }
+    }
+
+    let missing: Vec<&str> = REQUIRED_COLUMNS
+        .iter()
+        .filter(|c| !columns.contains_key(*c))
+        .copied()
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("必須の列がありません: {}", missing.join("、")));
+    }
+    Ok(columns)
+}
+
+/// 1行分を検証し、問題がなければ登録用のデータを作成する
+fn parse_row(
+    line: usize,
+    get: &dyn Fn(&str) -> String,
+    lookup: &NameLookup,
+    today: NaiveDate,
+) -> ParsedRow {
+    let mut errors = Vec::new();
+    let mut check = |column: &str, result: Result<(), String>| {
+        if let Err(e) = result {
+            errors.push(format!("{}: {}", column, e));
+        }
+    };
+
+    let employee_code = get("社員番号");
+    let last_name = get("姓");
+    let first_name = get("名");
+    check("社員番号", validate_employee_code(&employee_code));
+    check("姓", validate_employee_name(&last_name));
+    check("名", validate_employee_name(&first_name));
+
+    let mut date = |column: &str| match parse_date(&get(column)) {
+        Ok(d) => d,
+        Err(e) => {
+            check(column, Err(e));
+            None
+        }
+    };
+    let birth_date = date("生年月日");
+    let start_date = date("入社日");
+    for (column, _, reason) in REJECTED_COLUMNS {
+        if !get(column).is_empty() {
+            check(column, Err(reason.to_string()));
+        }
+    }
+
+    let gender = match normalize_gender(&get("性別")) {
+        Ok(g) => g,
+        Err(e) => {
+            check("性別", Err(e));
+            None
+        }
+    };
+
+    let mut resolve = |column: &str, names: &HashMap<String, i32>| {
+        let name = get(column);
+        if name.is_empty() {
+            return None;
+        }
+        let id = names.get(&name).copied();
+        if id.is_none() {
+            check(column, Err(format!("「{}」が登録されていません", name)));
+        }
+        id
+    };
+    let office_id = resolve("営業所", &lookup.offices);
+    let department_id = resolve("部署", &lookup.departments);
+    let position_id = resolve("役職", &lookup.positions);
+    let company_id = resolve("会社", &lookup.companies);
+
+    // 住所の列がひとつでも入力されていれば現住所として登録する
+    let postal_code: String = get("郵便番号")
+        .chars()
+        .filter(|c| !matches!(c, '-' | 'ー' | '〒'))
+        .collect();
+    let prefecture = get("都道府県");
+    let city = get("市区町村");
+    let street = get("番地");
+    let building = get("建物名");
+    let has_address = [&postal_code, &prefecture, &city, &street, &building]
+        .iter()
+        .any(|v| !v.is_empty());
+    if has_address {
+        check("郵便番号", validate_postal_code(&postal_code));
+        check("都道府県", validate_address_field(&prefecture, "都道府県"));
+        check("市区町村", validate_address_field(&city, "市区町村"));
+        check("番地", validate_address_field(&street, "番地"));
+    }
+
+    let result = ImportRowResult {
+        line,
+        employee_code: employee_code.clone(),
+        name: format!("{} {}", last_name, first_name).trim().to_string(),
+        errors,
+    };
+    if !result.is_valid() {
+        return ParsedRow {
+            result,
+            record: None,
+        };
+    }
+
+    let now = Utc::now();
+    let optional = |column: &str| Some(get(column)).filter(|v| !v.is_empty());
+    let employee = EmployeeFull {
+        id: 0,
+        company_id,
+        first_name,
+        last_name,
+        first_name_kana: optional("名（カナ）"),
+        last_name_kana: optional("姓（カナ）"),
+        legal_name: None,
+        nationality_id: None,
+        birth_date,
+        gender,
+        email: optional("メールアドレス"),
+        phone: optional("電話番号"),
+        mobile: optional("携帯電話"),
+        employee_code,
+        start_date,
+        end_date: None,
+        office_id,
+        department_id,
+        position_id,
+        driver_start_date: None,
+        driver_end_date: None,
+        driver_end_note: None,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+    };
+    let address = has_address.then(|| CreateAddress {
+        employee_id: 0,
+        postal_code,
+        prefecture,
+        city,
+        street,
+        building: Some(building).filter(|b| !b.is_empty()),
+        start_date: start_date.unwrap_or(today),
+        is_current: true,
+    });
+
+    ParsedRow {
+        result,
+        record: Some(ImportRecord {
+            line,
+            employee,
+            address,
+        }),
+    }
+}
+
+/// 日付の読み取り（空欄はNone）
+/// Excelから出力した `2024/4/1` 形式も受け付ける
+fn parse_date(value: &str) -> Result<Option<NaiveDate>, String> {
+    if value.is_empty() {
+        return Ok(None);
+    }
+    ["%Y-%m-%d", "%Y/%m/%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .map(Some)
+        .ok_or_else(|| format!("日付の形式が正しくありません（例: 1980-04-01）: {}", value))
+}
+
+/// 性別の表記ゆれを吸収（空欄はNone）
+fn normalize_gender(value: &str) -> Result<Option<String>, String> {
+    match value {
+        "" => Ok(None),
+        "男" | "男性" => Ok(Some("男性".to_string())),
+        "女" | "女性" => Ok(Some("女性".to_string())),
+        "その他" => Ok(Some("その他".to_string())),
+        _ => Err(format!(
+            "男性・女性・その他のいずれかを入力してください: {}",
+            value
+        )),
+    }
+}
+
+/// ファイル内の重複と登録済みの社員番号をエラーにする
+/// 戻り値はファイル内で重複していた社員番号
+pub fn mark_duplicates(rows: &mut [ParsedRow], existing_codes: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates: Vec<String> = Vec::new();
+    for row in rows.iter() {
+        let code = &row.result.employee_code;
+        if !code.is_empty() && !seen.insert(code.clone()) && !duplicates.contains(code) {
+            duplicates.push(code.clone());
+        }
+    }
+
+    for row in rows.iter_mut() {
+        let code = &row.result.employee_code;
+        if duplicates.contains(code) {
+            row.result
+                .errors
+                .push("社員番号: ファイル内で重複しています".to_string());
+            row.record = None;
+        }
+        if existing_codes.contains(code) {
+            row.result
+                .errors
+                .push("社員番号: 既に登録されています".to_string());
+            row.record = None;
+        }
+    }
+
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()
+    }
+
+    fn lookup() -> NameLookup {
+        NameLookup {
+            offices: HashMap::from([("本社営業所".to_string(), 1)]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let (sjis, _, _) = encoding_rs::SHIFT_JIS.encode("社員番号,姓,名");
+        let (text, used) = decode(&sjis, ImportEncoding::Auto).unwrap();
+        assert_eq!(text, "社員番号,姓,名");
+        assert_eq!(used, ImportEncoding::ShiftJis);
+
+        let bom = "\u{feff}社員番号".as_bytes();
+        let (text, used) = decode(bom, ImportEncoding::Auto).unwrap();
+        assert_eq!(text, "社員番号");
+        assert_eq!(used, ImportEncoding::Utf8);
+
+        assert!(decode(&sjis, ImportEncoding::Utf8).is_err());
+    }
+
+    #[test]
+    fn test_parse_valid_row() {
+        let csv = "社員番号,姓,名,セイ,メイ,入社日,性別,営業所,郵便番号,都道府県,市区町村,番地\n\
+                   A001,山田,太郎,ヤマダ,タロウ,2020/4/1,男,本社営業所,100-0001,東京都,千代田区,1-1\n";
+        let rows = parse(csv, &lookup(), today()).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].result.is_valid(), "{:?}", rows[0].result.errors);
+
+        let record = rows[0].record.as_ref().unwrap();
+        assert_eq!(record.line, 2);
+        assert_eq!(record.employee.last_name_kana.as_deref(), Some("ヤマダ"));
+        assert_eq!(record.employee.gender.as_deref(), Some("男性"));
+        assert_eq!(record.employee.office_id, Some(1));
+        let address = record.address.as_ref().unwrap();
+        assert_eq!(address.postal_code, "1000001");
+        assert_eq!(
+            address.start_date,
+            NaiveDate::from_ymd_opt(2020, 4, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_row() {
+        let csv = "社員番号,姓,名,生年月日,営業所,郵便番号\n\
+                   a 1,山田,,1980-13-01,大阪営業所,123\n\
+                   ,,,,,\n";
+        let rows = parse(csv, &lookup(), today()).unwrap();
+        assert_eq!(rows.len(), 1, "空行は読み飛ばす");
+        let errors = &rows[0].result.errors;
+        assert!(rows[0].record.is_none());
+        assert!(errors.iter().any(|e| e.starts_with("社員番号:")));
+        assert!(errors.iter().any(|e| e.starts_with("名:")));
+        assert!(errors.iter().any(|e| e.starts_with("生年月日:")));
+        assert!(errors
+            .iter()
+            .any(|e| e.contains("「大阪営業所」が登録されていません")));
+        assert!(errors.iter().any(|e| e.starts_with("郵便番号:")));
+        assert!(errors.iter().any(|e| e.starts_with("都道府県:")));
+    }
+
+    #[test]
+    fn test_selection_date_is_rejected() {
+        let csv = "社員番号,姓,名,ドライバー選任日\nA001,山田,太郎,2024/4/1\nA002,佐藤,花子,\n";
+        let rows = parse(csv, &lookup(), today()).unwrap();
+        assert!(rows[0]
+            .result
+            .errors
+            .iter()
+            .any(|e| e.starts_with("選任日:")));
+        assert!(rows[1].result.is_valid(), "{:?}", rows[1].result.errors);
+        let record = rows[1].record.as_ref().unwrap();
+        assert_eq!(record.employee.driver_start_date, None);
+    }
+
+    #[test]
+    fn test_missing_required_columns() {
+        let err = parse("社員番号,氏名\nA001,山田太郎\n", &lookup(), today()).unwrap_err();
+        assert_eq!(err, "必須の列がありません: 姓、名");
+    }
+
+    #[test]
+    fn test_mark_duplicates() {
+        let csv =
+            "社員番号,姓,名\nA001,山田,太郎\nA002,佐藤,花子\nA001,鈴木,一郎\nB001,田中,次郎\n";
+        let mut rows = parse(csv, &lookup(), today()).unwrap();
+        let duplicates = mark_duplicates(&mut rows, &["B001".to_string()]);
+        assert_eq!(duplicates, vec!["A001"]);
+
+        let valid: Vec<&str> = rows
+            .iter()
+            .filter(|r| r.record.is_some())
+            .map(|r| r.result.employee_code.as_str())
+            .collect();
+        assert_eq!(valid, vec!["A002"]);
+    }
+}
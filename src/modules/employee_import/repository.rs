@@ -0,0 +1,153 @@
+//! 従業員CSV取込のリポジトリ層
+
+use super::models::ImportRecord;
+use super::parser::NameLookup;
+use crate::db;
+use crate::modules::audit_log;
+use crate::modules::employees::{Address, EmployeeFull};
+use std::collections::HashMap;
+
+pub struct EmployeeImportRepository;
+
+impl EmployeeImportRepository {
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 名称→IDの対応表を取得
+    pub async fn get_lookup() -> Result<NameLookup, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let to_map = |rows: Vec<(i32, String)>| -> HashMap<String, i32> {
+            rows.into_iter().map(|(id, name)| (name, id)).collect()
+        };
+
+        let companies = sqlx::query!(r#"SELECT id, name FROM companies ORDER BY id"#)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+        let offices = sqlx::query!(r#"SELECT id, name FROM offices ORDER BY id"#)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+        let departments = sqlx::query!(r#"SELECT id, name FROM departments ORDER BY id"#)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+        let positions = sqlx::query!(r#"SELECT id, name FROM positions ORDER BY id"#)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.name))
+            .collect();
+
+        Ok(NameLookup {
+            companies: to_map(companies),
+            offices: to_map(offices),
+            departments: to_map(departments),
+            positions: to_map(positions),
+        })
+    }
+
+    /// 指定した社員番号のうち既に登録されているものを取得
+    pub async fn get_existing_codes(codes: &[String]) -> Result<Vec<String>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(
+            r#"SELECT employee_code FROM employees WHERE employee_code = ANY($1::text[])"#,
+            codes
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 従業員と現住所を1トランザクションで登録
+    /// 1件でも失敗した場合はすべて取り消す
+    pub async fn insert_all(records: Vec<ImportRecord>) -> Result<usize, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+        let mut saved = Vec::with_capacity(records.len());
+
+        for record in records {
+            let e = record.employee;
+            let employee = sqlx::query_as!(
+                EmployeeFull,
+                r#"INSERT INTO employees (
+                    company_id, first_name, last_name, first_name_kana, last_name_kana,
+                    birth_date, gender, email, phone, mobile, employee_code, start_date,
+                    office_id, department_id, position_id,
+                    is_active, created_at, updated_at
+                 )
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15,
+                         TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                 RETURNING
+                    id, company_id, first_name, last_name, first_name_kana, last_name_kana,
+                    legal_name, nationality_id, birth_date, gender, email, phone, mobile,
+                    employee_code, start_date, end_date, office_id, department_id, position_id,
+                    driver_start_date, driver_end_date, driver_end_note, is_active,
+                    created_at, updated_at"#,
+                e.company_id,
+                e.first_name,
+                e.last_name,
+                e.first_name_kana,
+                e.last_name_kana,
+                e.birth_date,
+                e.gender,
+                e.email,
+                e.phone,
+                e.mobile,
+                e.employee_code,
+                e.start_date,
+                e.office_id,
+                e.department_id,
+                e.position_id,
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            let address = match record.address {
+                Some(a) => Some(
+                    sqlx::query_as!(
+                        Address,
+                        r#"INSERT INTO addresses (
+                            employee_id, postal_code, prefecture, city, street, building,
+                            start_date, is_current, created_at, updated_at
+                         )
+                         VALUES ($1, $2, $3, $4, $5, $6, $7, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                         RETURNING
+                            id, employee_id, postal_code, prefecture, city, street, building,
+                            start_date, end_date, is_current, created_at, updated_at"#,
+                        employee.id,
+                        a.postal_code,
+                        a.prefecture,
+                        a.city,
+                        a.street,
+                        a.building,
+                        a.start_date,
+                    )
+                    .fetch_one(&mut *tx)
+                    .await?,
+                ),
+                None => None,
+            };
+
+            saved.push((employee, address));
+        }
+
+        tx.commit().await?;
+
+        // 確定した分だけ監査ログに記録
+        for (employee, address) in &saved {
+            audit_log::record_create("employees", employee.id, Some(employee.id), employee).await;
+            if let Some(address) = address {
+                audit_log::record_create("addresses", address.id, Some(employee.id), address).await;
+            }
+        }
+
+        Ok(saved.len())
+    }
+}
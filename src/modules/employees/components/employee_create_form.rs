@@ -1,12 +1,12 @@
 use crate::modules::employees::components::form_components::{use_field_validation, InputLabel};
 use crate::modules::employees::handlers::{check_employee_code_available, create_employee};
-use crate::modules::employees::models::EmployeeWithAddress;
+use crate::modules::employees::models::EmployeeListPage;
 use crate::modules::employees::validation::{validate_employee_code, validate_employee_name};
 use dioxus::prelude::*;
 
 #[component]
 pub fn EmployeeCreateForm(
-    mut employees_list: Resource<Result<Vec<EmployeeWithAddress>, ServerFnError>>,
+    mut employees_list: Resource<Result<EmployeeListPage, ServerFnError>>,
     on_close: EventHandler<MouseEvent>,
 ) -> Element {
     let employee_code = use_signal(String::new);
@@ -1,12 +1,24 @@
 use crate::components::{Icon, IconType};
+use crate::modules::aptitude_schedule::DriverAptitudeSection;
 use crate::modules::department_position_history::handlers::get_department_position_history_by_employee;
 use crate::modules::department_position_history::DepartmentPositionHistory;
-use crate::modules::employee_documents::components::{DocumentList, DocumentUploadForm};
+use crate::modules::driver_ledger::DriverLedgerButton;
+use crate::modules::driver_selection::DriverSelectionPanel;
+use crate::modules::employee_documents::components::{
+    AttachedDocuments, DocumentList, DocumentUploadForm,
+};
+use crate::modules::employee_documents::models::{DocumentRelation, RelatedRecordType};
 use crate::modules::employees::components::employee_full_edit_form::EmployeeFullEditForm;
+use crate::modules::employees::components::record_sections::{
+    AptitudeCheckupSection, HealthCheckupSection, QualificationSection, ResidenceCardSection,
+};
 use crate::modules::employees::handlers::{delete_employee, get_all_addresses, get_employee_full};
 use crate::modules::employees::models::{Address, EmployeeFull};
+use crate::modules::guidance_compliance::DriverGuidanceSection;
+use crate::modules::health_follow_up::DriverHealthSection;
 use crate::modules::licenses::handlers::get_licenses_by_employee;
 use crate::modules::licenses::License;
+use crate::modules::offboarding::OffboardingPanel;
 use crate::Route;
 
 use dioxus::prelude::*;
@@ -39,6 +51,7 @@ pub fn EmployeeDetailPage(id: i32) -> Element {
                     }
                     div { class: "flex items-center gap-3",
                         if !edit_mode() {
+                            DriverLedgerButton { employee_id: id }
                             button {
                                 class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm bg-red-500 text-white hover:bg-red-600",
                                 onclick: move |_| show_delete_confirm.set(true),
@@ -74,7 +87,10 @@ pub fn EmployeeDetailPage(id: i32) -> Element {
                                 }
                             } else {
                                 rsx! {
-                                    EmployeeDetailView { employee: employee.clone() }
+                                    EmployeeDetailView {
+                                        employee: employee.clone(),
+                                        on_change: move |_| employee_resource.restart(),
+                                    }
                                 }
                             }
                         },
@@ -179,7 +195,7 @@ pub fn EmployeeDetailPage(id: i32) -> Element {
 }
 
 #[component]
-fn EmployeeDetailView(employee: EmployeeFull) -> Element {
+fn EmployeeDetailView(employee: EmployeeFull, on_change: EventHandler<()>) -> Element {
     let addresses_resource =
         use_resource(move || async move { get_all_addresses(employee.id).await });
     let dept_history_resource: Resource<Result<Vec<DepartmentPositionHistory>, ServerFnError>> =
@@ -306,6 +322,17 @@ fn EmployeeDetailView(employee: EmployeeFull) -> Element {
                         }
                     }
                 }
+                DriverSelectionPanel {
+                    employee_id: employee.id,
+                    is_active: employee.is_active,
+                    driver_start_date: employee.driver_start_date,
+                    driver_end_date: employee.driver_end_date,
+                    on_selected: move |_| on_change.call(()),
+                }
+                OffboardingPanel {
+                    employee_id: employee.id,
+                    on_change: move |_| on_change.call(()),
+                }
             }
 
             // 運転免許証セクション
@@ -323,7 +350,7 @@ fn EmployeeDetailView(employee: EmployeeFull) -> Element {
                             rsx! {
                                 div { class: "space-y-4",
                                     for license in licenses_list.iter() {
-                                        LicenseView { license: license.clone() }
+                                        LicenseView { key: "{license.id}", license: license.clone() }
                                     }
                                 }
                             }
@@ -340,6 +367,21 @@ fn EmployeeDetailView(employee: EmployeeFull) -> Element {
                 }
             }
 
+            // 資格・健康診断・適性診断・在留カード（添付スキャンを含む）
+            QualificationSection { employee_id: employee.id }
+            HealthCheckupSection { employee_id: employee.id }
+            AptitudeCheckupSection { employee_id: employee.id }
+            ResidenceCardSection { employee_id: employee.id }
+
+            // 健康診断の受診計画・事後措置
+            DriverHealthSection { employee_id: employee.id }
+
+            // 適性診断の受診計画
+            DriverAptitudeSection { employee_id: employee.id }
+
+            // 指導教育の実施状況・事故歴
+            DriverGuidanceSection { employee_id: employee.id }
+
             // ドキュメント管理セクション
             DocumentManagementSection { employee_id: employee.id }
 
@@ -580,6 +622,12 @@ fn LicenseView(license: License) -> Element {
                     }
                 }
             }
+
+            // 免許証のスキャン
+            AttachedDocuments {
+                employee_id: license.employee_id,
+                relation: DocumentRelation::new(RelatedRecordType::License, license.id),
+            }
         }
     }
 }
@@ -349,20 +349,14 @@ pub fn EmployeeFullEditForm(employee: EmployeeFull, on_close: EventHandler<()>)
 
                         div { class: "space-y-2",
                             label { class: "block text-sm font-medium text-gray-700", "雇用終了日" }
+                            // 退職は従業員詳細の「選任解除・退職の手続き」で行う
                             input {
-                                class: "w-full px-3 py-2 border rounded-lg focus:outline-none focus:ring-2 focus:ring-amber-400 transition-colors",
+                                class: "w-full px-3 py-2 border rounded-lg bg-gray-100 text-gray-600",
                                 r#type: "date",
+                                disabled: true,
                                 value: "{employee_data().end_date.map(|d| d.to_string()).unwrap_or_default()}",
-                                oninput: move |evt| {
-                                    let mut emp = employee_data();
-                                    emp.end_date = if evt.value().is_empty() {
-                                        None
-                                    } else {
-                                        chrono::NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()
-                                    };
-                                    employee_data.set(emp);
-                                },
                             }
+                            p { class: "text-xs text-gray-500", "退職は従業員詳細の「選任解除・退職の手続き」で行います" }
                         }
                     }
                 }
@@ -374,52 +368,36 @@ pub fn EmployeeFullEditForm(employee: EmployeeFull, on_close: EventHandler<()>)
                         div { class: "grid grid-cols-2 gap-3",
                             div { class: "space-y-2",
                                 label { class: "block text-sm font-medium text-gray-700", "選任開始日" }
+                                // 選任は前提条件を確認してから従業員詳細の「運転者選任」で行う
                                 input {
-                                    class: "w-full px-3 py-2 border rounded-lg focus:outline-none focus:ring-2 focus:ring-amber-400 transition-colors",
+                                    class: "w-full px-3 py-2 border rounded-lg bg-gray-100 text-gray-600",
                                     r#type: "date",
+                                    disabled: true,
                                     value: "{employee_data().driver_start_date.map(|d| d.to_string()).unwrap_or_default()}",
-                                    oninput: move |evt| {
-                                        let mut emp = employee_data();
-                                        emp.driver_start_date = if evt.value().is_empty() {
-                                            None
-                                        } else {
-                                            chrono::NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()
-                                        };
-                                        employee_data.set(emp);
-                                    },
                                 }
+                                p { class: "text-xs text-gray-500", "選任は従業員詳細の「運転者選任」で前提条件を確認してから行います" }
                             }
 
                             div { class: "space-y-2",
                                 label { class: "block text-sm font-medium text-gray-700", "選任終了日" }
+                                // 選任解除は従業員詳細の「選任解除・退職の手続き」で行う
                                 input {
-                                    class: "w-full px-3 py-2 border rounded-lg focus:outline-none focus:ring-2 focus:ring-amber-400 transition-colors",
+                                    class: "w-full px-3 py-2 border rounded-lg bg-gray-100 text-gray-600",
                                     r#type: "date",
+                                    disabled: true,
                                     value: "{employee_data().driver_end_date.map(|d| d.to_string()).unwrap_or_default()}",
-                                    oninput: move |evt| {
-                                        let mut emp = employee_data();
-                                        emp.driver_end_date = if evt.value().is_empty() {
-                                            None
-                                        } else {
-                                            chrono::NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok()
-                                        };
-                                        employee_data.set(emp);
-                                    },
                                 }
+                                p { class: "text-xs text-gray-500", "選任解除は従業員詳細の「選任解除・退職の手続き」で行います" }
                             }
                         }
 
                         div { class: "space-y-2",
                             label { class: "block text-sm font-medium text-gray-700", "選任解除理由" }
                             textarea {
-                                class: "w-full px-3 py-2 border rounded-lg focus:outline-none focus:ring-2 focus:ring-amber-400 transition-colors",
+                                class: "w-full px-3 py-2 border rounded-lg bg-gray-100 text-gray-600",
                                 rows: 3,
+                                disabled: true,
                                 value: "{employee_data().driver_end_note.clone().unwrap_or_default()}",
-                                oninput: move |evt| {
-                                    let mut emp = employee_data();
-                                    emp.driver_end_note = if evt.value().is_empty() { None } else { Some(evt.value()) };
-                                    employee_data.set(emp);
-                                },
                             }
                         }
                     }
@@ -0,0 +1,88 @@
+use crate::modules::employees::models::{EmployeeListPage, EmployeeSearchQuery, EmployeeSortKey};
+use dioxus::prelude::*;
+
+const SELECT_CLASS: &str = "px-3 py-2 border border-gray-300 rounded-lg text-sm bg-white focus:outline-none focus:ring-2 focus:ring-amber-400";
+const PAGE_BUTTON_CLASS: &str = "px-3 py-1.5 rounded-lg text-sm font-semibold transition-colors bg-gray-100 text-gray-700 hover:bg-gray-200 disabled:opacity-40 disabled:cursor-not-allowed";
+
+/// 並び順の選択（キーと昇順・降順）
+#[component]
+pub fn EmployeeSortControl(mut query: Signal<EmployeeSearchQuery>) -> Element {
+    rsx! {
+        div { class: "flex items-center gap-2",
+            select {
+                class: SELECT_CLASS,
+                onchange: move |evt| {
+                    if let Some(sort) = EmployeeSortKey::parse(&evt.value()) {
+                        let mut q = query();
+                        q.sort = sort;
+                        q.page = 0;
+                        query.set(q);
+                    }
+                },
+                for key in EmployeeSortKey::ALL {
+                    option {
+                        value: "{key.as_str()}",
+                        selected: query().sort == key,
+                        "{key.display_name()}順"
+                    }
+                }
+            }
+            button {
+                class: "px-3 py-2 border border-gray-300 rounded-lg text-sm bg-white hover:bg-gray-50 transition-colors",
+                title: "並び順を反転",
+                onclick: move |_| {
+                    let mut q = query();
+                    q.descending = !q.descending;
+                    q.page = 0;
+                    query.set(q);
+                },
+                if query().descending { "降順 ↓" } else { "昇順 ↑" }
+            }
+        }
+    }
+}
+
+/// ページ送り
+#[component]
+pub fn PageControls(page: EmployeeListPage, on_change: EventHandler<u32>) -> Element {
+    let current = page.page;
+    let total_pages = page.total_pages();
+    let (start, end) = page.range();
+
+    rsx! {
+        div { class: "mt-6 flex items-center justify-between",
+            p { class: "text-sm text-gray-600",
+                if page.total > 0 {
+                    "{page.total} 件中 {start}〜{end} 件を表示"
+                }
+            }
+            div { class: "flex items-center gap-2",
+                button {
+                    class: PAGE_BUTTON_CLASS,
+                    disabled: current == 0,
+                    onclick: move |_| on_change.call(0),
+                    "« 最初"
+                }
+                button {
+                    class: PAGE_BUTTON_CLASS,
+                    disabled: current == 0,
+                    onclick: move |_| on_change.call(current.saturating_sub(1)),
+                    "‹ 前へ"
+                }
+                span { class: "px-2 text-sm text-gray-700", "{current + 1} / {total_pages}" }
+                button {
+                    class: PAGE_BUTTON_CLASS,
+                    disabled: current + 1 >= total_pages,
+                    onclick: move |_| on_change.call(current + 1),
+                    "次へ ›"
+                }
+                button {
+                    class: PAGE_BUTTON_CLASS,
+                    disabled: current + 1 >= total_pages,
+                    onclick: move |_| on_change.call(total_pages - 1),
+                    "最後 »"
+                }
+            }
+        }
+    }
+}
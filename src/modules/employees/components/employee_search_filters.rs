@@ -0,0 +1,88 @@
+use crate::modules::employees::handlers::get_employee_search_masters;
+use crate::modules::employees::models::{DriverStatus, EmployeeSearchQuery, FilterOption};
+use dioxus::prelude::*;
+
+const SELECT_CLASS: &str = "px-3 py-2 border border-gray-300 rounded-lg text-sm bg-white focus:outline-none focus:ring-2 focus:ring-amber-400";
+
+/// 従業員検索の絞り込み条件（営業所・部署・役職・選任状況・免許種別）
+#[component]
+pub fn EmployeeSearchFilters(mut query: Signal<EmployeeSearchQuery>) -> Element {
+    let masters = use_resource(|| async move { get_employee_search_masters().await });
+
+    let mut edit = move |f: &dyn Fn(&mut EmployeeSearchQuery)| {
+        let mut current = query();
+        f(&mut current);
+        // 条件が変わったら先頭ページに戻す
+        current.page = 0;
+        query.set(current);
+    };
+
+    let masters = match &*masters.read_unchecked() {
+        Some(Ok(m)) => m.clone(),
+        _ => Default::default(),
+    };
+
+    rsx! {
+        div { class: "flex flex-wrap items-center gap-2",
+            FilterSelect {
+                placeholder: "すべての営業所".to_string(),
+                options: masters.offices,
+                value: query().office_id,
+                on_change: move |v| edit(&|q| q.office_id = v),
+            }
+            FilterSelect {
+                placeholder: "すべての部署".to_string(),
+                options: masters.departments,
+                value: query().department_id,
+                on_change: move |v| edit(&|q| q.department_id = v),
+            }
+            FilterSelect {
+                placeholder: "すべての役職".to_string(),
+                options: masters.positions,
+                value: query().position_id,
+                on_change: move |v| edit(&|q| q.position_id = v),
+            }
+            select {
+                class: SELECT_CLASS,
+                onchange: move |evt| {
+                    let status = DriverStatus::parse(&evt.value());
+                    edit(&|q| q.driver_status = status);
+                },
+                option { value: "", "選任状況（すべて）" }
+                for status in DriverStatus::ALL {
+                    option {
+                        value: "{status.as_str()}",
+                        selected: query().driver_status == Some(status),
+                        "{status.display_name()}"
+                    }
+                }
+            }
+            FilterSelect {
+                placeholder: "すべての免許種別".to_string(),
+                options: masters.license_types,
+                value: query().license_type_id,
+                on_change: move |v| edit(&|q| q.license_type_id = v),
+            }
+        }
+    }
+}
+
+/// IDで選ぶ絞り込み用セレクト（未選択は条件なし）
+#[component]
+fn FilterSelect(
+    placeholder: String,
+    options: Vec<FilterOption>,
+    value: Option<i32>,
+    on_change: EventHandler<Option<i32>>,
+) -> Element {
+    rsx! {
+        select {
+            class: SELECT_CLASS,
+            onchange: move |evt| on_change.call(evt.value().parse().ok()),
+            option { value: "", "{placeholder}" }
+            for o in options.iter() {
+                option { value: "{o.id}", selected: value == Some(o.id), "{o.name}" }
+            }
+        }
+    }
+}
@@ -41,39 +41,6 @@ pub fn InputLabel(
     }
 }
 
-/// フィールド表示用ラベル（編集不可）
-#[component]
-pub fn InputLabelField(label: String, required: bool, children: Element) -> Element {
-    rsx! {
-        div { class: "space-y-2",
-            label { class: "block text-sm font-medium text-gray-700",
-                "{label}"
-                if required {
-                    span { class: "text-red-500 ml-1", "*" }
-                }
-            }
-            {children}
-        }
-    }
-}
-
-/// 詳細表示用フィールド
-#[component]
-pub fn DetailField(label: String, value: String) -> Element {
-    rsx! {
-        div { class: "space-y-1.5",
-            p { class: "text-sm text-gray-500 font-semibold uppercase tracking-wide", "{label}" }
-            p { class: "text-base text-gray-800",
-                if value.is_empty() {
-                    span { class: "text-gray-400", "未設定" }
-                } else {
-                    "{value}"
-                }
-            }
-        }
-    }
-}
-
 /// セクションヘッダー
 #[component]
 pub fn SectionHeader(title: String) -> Element {
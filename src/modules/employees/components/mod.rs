@@ -6,7 +6,10 @@ mod employee_create_form;
 mod employee_detail_page;
 mod employee_edit_form;
 mod employee_full_edit_form;
+mod employee_list_controls;
+mod employee_search_filters;
 mod form_components;
+mod record_sections;
 
 pub use department_position_history_section::DepartmentPositionHistorySection;
 pub use employee_card::EmployeeCard;
@@ -14,3 +17,5 @@ pub use employee_create_form::EmployeeCreateForm;
 pub use employee_detail_page::EmployeeDetailPage;
 pub use employee_edit_form::EmployeeEditForm;
 pub use employee_full_edit_form::EmployeeFullEditForm;
+pub use employee_list_controls::{EmployeeSortControl, PageControls};
+pub use employee_search_filters::EmployeeSearchFilters;
@@ -0,0 +1,339 @@
+//! 従業員詳細画面の資格・健康診断・適性診断・在留カードの履歴セクション
+//!
+//! 各記録には添付されたスキャン（免許証・修了証・診断結果など）をその場で表示する
+
+use crate::modules::aptitude_checkup_history::{
+    get_all_aptitude_checkup_types, get_aptitude_checkup_history_by_employee,
+    AptitudeCheckupHistory,
+};
+use crate::modules::employee_documents::components::AttachedDocuments;
+use crate::modules::employee_documents::models::{DocumentRelation, RelatedRecordType};
+use crate::modules::health_checkup_history::{
+    get_all_health_checkup_types, get_health_checkup_history_by_employee, HealthCheckupHistory,
+};
+use crate::modules::qualifications::{
+    get_all_qualification_types, get_qualifications_by_employee, Qualification,
+};
+use crate::modules::residence_cards::handlers::get_residence_cards_by_employee;
+use crate::modules::residence_cards::ResidenceCard;
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// 種類IDから名称を引く（見つからない場合はIDを表示）
+fn type_name(names: &HashMap<i32, String>, id: i32) -> String {
+    names
+        .get(&id)
+        .cloned()
+        .unwrap_or_else(|| format!("種別ID: {}", id))
+}
+
+/// 期限切れか
+fn is_expired(expiration_date: Option<NaiveDate>) -> bool {
+    expiration_date.is_some_and(|d| d < chrono::Local::now().date_naive())
+}
+
+/// 資格セクション
+#[component]
+pub fn QualificationSection(employee_id: i32) -> Element {
+    let records =
+        use_resource(move || async move { get_qualifications_by_employee(employee_id).await });
+    let types = use_resource(move || async move { get_all_qualification_types().await });
+    let names: HashMap<i32, String> = match &*types.read_unchecked() {
+        Some(Ok(types)) => types.iter().map(|t| (t.id, t.name.clone())).collect(),
+        _ => HashMap::new(),
+    };
+
+    rsx! {
+        RecordSectionFrame { title: "資格".to_string(),
+            match &*records.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "資格が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "space-y-4",
+                        for record in list.iter() {
+                            QualificationView {
+                                key: "{record.id}",
+                                qualification: record.clone(),
+                                type_name: type_name(&names, record.qualification_type_id),
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "資格の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! { LoadingSpinner {} },
+            }
+        }
+    }
+}
+
+#[component]
+fn QualificationView(qualification: Qualification, type_name: String) -> Element {
+    rsx! {
+        RecordCard {
+            is_active: qualification.is_active,
+            expired: is_expired(qualification.expiration_date),
+            title: type_name,
+            div { class: "grid grid-cols-2 gap-3 text-sm",
+                if let Some(number) = &qualification.qualification_number {
+                    RecordField { label: "資格番号".to_string(), value: number.clone() }
+                }
+                if let Some(issue_date) = qualification.issue_date {
+                    RecordField { label: "取得日".to_string(), value: issue_date.to_string() }
+                }
+                if let Some(expiration_date) = qualification.expiration_date {
+                    RecordField { label: "有効期限".to_string(), value: expiration_date.to_string() }
+                }
+                if let Some(authority) = &qualification.issuing_authority {
+                    RecordField { label: "交付機関".to_string(), value: authority.clone() }
+                }
+            }
+            AttachedDocuments {
+                employee_id: qualification.employee_id,
+                relation: DocumentRelation::new(RelatedRecordType::Qualification, qualification.id),
+            }
+        }
+    }
+}
+
+/// 健康診断セクション
+#[component]
+pub fn HealthCheckupSection(employee_id: i32) -> Element {
+    let records =
+        use_resource(
+            move || async move { get_health_checkup_history_by_employee(employee_id).await },
+        );
+    let types = use_resource(move || async move { get_all_health_checkup_types().await });
+    let names: HashMap<i32, String> = match &*types.read_unchecked() {
+        Some(Ok(types)) => types.iter().map(|t| (t.id, t.name.clone())).collect(),
+        _ => HashMap::new(),
+    };
+
+    rsx! {
+        RecordSectionFrame { title: "健康診断".to_string(),
+            match &*records.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "健康診断が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "space-y-4",
+                        for record in list.iter() {
+                            HealthCheckupView {
+                                key: "{record.id}",
+                                checkup: record.clone(),
+                                type_name: type_name(&names, record.health_checkup_type_id),
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "健康診断の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! { LoadingSpinner {} },
+            }
+        }
+    }
+}
+
+#[component]
+fn HealthCheckupView(checkup: HealthCheckupHistory, type_name: String) -> Element {
+    rsx! {
+        RecordCard {
+            is_active: checkup.is_active,
+            expired: is_expired(checkup.expiration_date),
+            title: type_name,
+            div { class: "grid grid-cols-2 gap-3 text-sm",
+                RecordField { label: "受診日".to_string(), value: checkup.checkup_date.to_string() }
+                if let Some(expiration_date) = checkup.expiration_date {
+                    RecordField { label: "有効期限".to_string(), value: expiration_date.to_string() }
+                }
+                if let Some(institution) = &checkup.medical_institution {
+                    RecordField { label: "医療機関".to_string(), value: institution.clone() }
+                }
+                if let Some(result) = &checkup.result {
+                    RecordField { label: "結果".to_string(), value: result.clone() }
+                }
+            }
+            AttachedDocuments {
+                employee_id: checkup.employee_id,
+                relation: DocumentRelation::new(RelatedRecordType::HealthCheckup, checkup.id),
+            }
+        }
+    }
+}
+
+/// 適性診断セクション
+#[component]
+pub fn AptitudeCheckupSection(employee_id: i32) -> Element {
+    let records =
+        use_resource(
+            move || async move { get_aptitude_checkup_history_by_employee(employee_id).await },
+        );
+    let types = use_resource(move || async move { get_all_aptitude_checkup_types().await });
+    let names: HashMap<i32, String> = match &*types.read_unchecked() {
+        Some(Ok(types)) => types.iter().map(|t| (t.id, t.name.clone())).collect(),
+        _ => HashMap::new(),
+    };
+
+    rsx! {
+        RecordSectionFrame { title: "適性診断".to_string(),
+            match &*records.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "適性診断が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "space-y-4",
+                        for record in list.iter() {
+                            AptitudeCheckupView {
+                                key: "{record.id}",
+                                checkup: record.clone(),
+                                type_name: type_name(&names, record.aptitude_checkup_type_id),
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "適性診断の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! { LoadingSpinner {} },
+            }
+        }
+    }
+}
+
+#[component]
+fn AptitudeCheckupView(checkup: AptitudeCheckupHistory, type_name: String) -> Element {
+    rsx! {
+        RecordCard {
+            is_active: checkup.is_active,
+            expired: is_expired(checkup.expiration_date),
+            title: type_name,
+            div { class: "grid grid-cols-2 gap-3 text-sm",
+                RecordField { label: "受診日".to_string(), value: checkup.checkup_date.to_string() }
+                if let Some(expiration_date) = checkup.expiration_date {
+                    RecordField { label: "有効期限".to_string(), value: expiration_date.to_string() }
+                }
+                if let Some(organization) = &checkup.testing_organization {
+                    RecordField { label: "実施機関".to_string(), value: organization.clone() }
+                }
+                if let Some(result) = &checkup.result {
+                    RecordField { label: "結果".to_string(), value: result.clone() }
+                }
+            }
+            AttachedDocuments {
+                employee_id: checkup.employee_id,
+                relation: DocumentRelation::new(RelatedRecordType::AptitudeCheckup, checkup.id),
+            }
+        }
+    }
+}
+
+/// 在留カードセクション（登録がない場合は表示しない）
+#[component]
+pub fn ResidenceCardSection(employee_id: i32) -> Element {
+    let records =
+        use_resource(move || async move { get_residence_cards_by_employee(employee_id).await });
+
+    match &*records.read_unchecked() {
+        Some(Ok(list)) if list.is_empty() => rsx! {},
+        Some(Ok(list)) => rsx! {
+            RecordSectionFrame { title: "在留カード".to_string(),
+                div { class: "space-y-4",
+                    for card in list.iter() {
+                        ResidenceCardView { key: "{card.id}", card: card.clone() }
+                    }
+                }
+            }
+        },
+        Some(Err(e)) => rsx! {
+            RecordSectionFrame { title: "在留カード".to_string(),
+                p { class: "text-red-500 text-center py-4", "在留カードの読み込みに失敗しました: {e}" }
+            }
+        },
+        None => rsx! {},
+    }
+}
+
+#[component]
+fn ResidenceCardView(card: ResidenceCard) -> Element {
+    rsx! {
+        RecordCard {
+            is_active: card.is_active,
+            expired: is_expired(Some(card.expiration_date)),
+            title: format!("在留資格ID: {}", card.residence_card_type_id),
+            div { class: "grid grid-cols-2 gap-3 text-sm",
+                if let Some(number) = &card.card_number {
+                    RecordField { label: "在留カード番号".to_string(), value: number.clone() }
+                }
+                if let Some(issue_date) = card.issue_date {
+                    RecordField { label: "交付日".to_string(), value: issue_date.to_string() }
+                }
+                RecordField { label: "有効期限".to_string(), value: card.expiration_date.to_string() }
+                if let Some(restrictions) = &card.work_restrictions {
+                    RecordField { label: "就労制限".to_string(), value: restrictions.clone() }
+                }
+            }
+            AttachedDocuments {
+                employee_id: card.employee_id,
+                relation: DocumentRelation::new(RelatedRecordType::ResidenceCard, card.id),
+            }
+        }
+    }
+}
+
+#[component]
+fn RecordSectionFrame(title: String, children: Element) -> Element {
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400", "{title}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+fn RecordCard(is_active: bool, expired: bool, title: String, children: Element) -> Element {
+    rsx! {
+        div {
+            class: "border border-gray-200 rounded-lg p-4",
+            class: if is_active { "bg-green-50 border-green-300" } else { "bg-gray-50" },
+
+            div { class: "flex items-center gap-2 mb-3",
+                if is_active {
+                    span { class: "inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-green-400 text-gray-800",
+                        "有効"
+                    }
+                }
+                if expired {
+                    span { class: "inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-red-100 text-red-800",
+                        "期限切れ"
+                    }
+                }
+                span { class: "text-sm text-gray-600 font-semibold", "{title}" }
+            }
+            {children}
+        }
+    }
+}
+
+#[component]
+fn RecordField(label: String, value: String) -> Element {
+    rsx! {
+        div { class: "space-y-1",
+            p { class: "text-xs text-gray-500 font-semibold", "{label}" }
+            p { class: "text-gray-800", "{value}" }
+        }
+    }
+}
+
+#[component]
+fn LoadingSpinner() -> Element {
+    rsx! {
+        div { class: "flex justify-center py-4",
+            div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+        }
+    }
+}
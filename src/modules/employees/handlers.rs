@@ -1,5 +1,6 @@
 use super::models::{
-    Address, CreateAddress, Employee, EmployeeFull, EmployeeWithAddress, UpdateAddress,
+    Address, CreateAddress, Employee, EmployeeFull, EmployeeListPage, EmployeeSearchMasters,
+    EmployeeSearchQuery, EmployeeWithAddress, UpdateAddress,
 };
 #[cfg(feature = "server")]
 use super::repository::EmployeeRepository;
@@ -8,6 +9,13 @@ use super::validation::{
 };
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::employee_documents::repository::EmployeeDocumentRepository;
+#[cfg(feature = "server")]
+use crate::modules::s3_service;
+
 /// データベースエラーを`ServerFnError`に変換するヘルパー関数
 #[cfg(feature = "server")]
 fn db_error_to_server_error(error: sqlx::Error) -> ServerFnError {
@@ -38,6 +46,8 @@ fn validate_employee_basic(
 /// 全従業員の取得
 #[server]
 pub async fn get_employees() -> Result<Vec<Employee>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::get_all()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -49,6 +59,8 @@ pub async fn check_employee_code_available(
     employee_code: String,
     exclude_id: Option<i32>, // 編集時に自分自身を除外
 ) -> Result<bool, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::is_employee_code_available(&employee_code, exclude_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -61,6 +73,8 @@ pub async fn create_employee(
     first_name: String,
     last_name: String,
 ) -> Result<Employee, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     validate_employee_basic(&employee_code, &first_name, &last_name)?;
 
@@ -78,6 +92,8 @@ pub async fn update_employee(
     last_name: String,
     is_active: bool,
 ) -> Result<Employee, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     validate_employee_basic(&employee_code, &first_name, &last_name)?;
 
@@ -87,16 +103,45 @@ pub async fn update_employee(
 }
 
 /// 従業員の削除
+///
+/// 書類の行は従業員と一緒に削除されるため、保存先のファイルは削除後に消す
+/// （失敗したファイルは `reconcile-storage` で後から削除できる）。
+/// 従業員または書類が廃棄の保留中の場合は削除しない
 #[server]
 pub async fn delete_employee(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    if EmployeeRepository::has_legal_hold(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    {
+        return Err(ServerFnError::new(
+            "廃棄の保留中の従業員・書類があるため削除できません".to_string(),
+        ));
+    }
+
+    let storage_keys = EmployeeDocumentRepository::storage_keys_by_employee_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
     EmployeeRepository::delete(id)
         .await
-        .map_err(|e| ServerFnError::new(e.to_string()))
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    for key in &storage_keys {
+        if let Err(e) = s3_service::delete_file(key).await {
+            eprintln!("⚠️ Failed to delete stored file {}: {}", key, e);
+        }
+    }
+
+    Ok(())
 }
 
 /// IDで従業員の詳細情報を取得
 #[server]
 pub async fn get_employee_full(id: i32) -> Result<Option<EmployeeFull>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::get_by_id(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -105,12 +150,35 @@ pub async fn get_employee_full(id: i32) -> Result<Option<EmployeeFull>, ServerFn
 /// 従業員の詳細情報を更新
 #[server]
 pub async fn update_employee_full(employee: EmployeeFull) -> Result<EmployeeFull, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     validate_employee_basic(
         &employee.employee_code,
         &employee.first_name,
         &employee.last_name,
     )?;
+    // 選任開始日は前提条件を確認する運転者選任（driver_selection）でのみ設定する
+    let current = EmployeeRepository::get_by_id(employee.id)
+        .await
+        .map_err(db_error_to_server_error)?
+        .ok_or_else(|| ServerFnError::new("従業員が見つかりません".to_string()))?;
+    if employee.driver_start_date != current.driver_start_date {
+        return Err(ServerFnError::new(
+            "選任開始日は従業員詳細の「運転者選任」で前提条件を確認してから設定してください"
+                .to_string(),
+        ));
+    }
+    // 雇用終了日・選任終了日・解除理由は選任解除・退職の手続き（offboarding）でのみ設定する
+    if employee.end_date != current.end_date
+        || employee.driver_end_date != current.driver_end_date
+        || employee.driver_end_note != current.driver_end_note
+    {
+        return Err(ServerFnError::new(
+            "雇用終了日・選任終了日・選任解除理由は従業員詳細の「選任解除・退職の手続き」で設定してください"
+                .to_string(),
+        ));
+    }
 
     EmployeeRepository::update_full(employee)
         .await
@@ -120,14 +188,47 @@ pub async fn update_employee_full(employee: EmployeeFull) -> Result<EmployeeFull
 /// 住所情報を含む全従業員の取得
 #[server]
 pub async fn get_employees_with_address() -> Result<Vec<EmployeeWithAddress>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::get_all_with_address()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
 }
 
+/// 条件に一致する従業員を住所情報付きで1ページ分検索
+#[server]
+pub async fn search_employees(
+    query: EmployeeSearchQuery,
+) -> Result<EmployeeListPage, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    // バリデーション
+    if query.keyword.chars().count() > 100 {
+        return Err(ServerFnError::new(
+            "検索キーワードは100文字以内で入力してください".to_string(),
+        ));
+    }
+
+    EmployeeRepository::search(&query)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 従業員検索のフィルタ用マスタを取得
+#[server]
+pub async fn get_employee_search_masters() -> Result<EmployeeSearchMasters, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    EmployeeRepository::get_search_masters()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
 /// 従業員の現住所を取得
 #[server]
 pub async fn get_current_address(employee_id: i32) -> Result<Option<Address>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::get_current_address(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -136,6 +237,8 @@ pub async fn get_current_address(employee_id: i32) -> Result<Option<Address>, Se
 /// 従業員の全住所履歴を取得
 #[server]
 pub async fn get_all_addresses(employee_id: i32) -> Result<Vec<Address>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmployeeRepository::get_all_addresses(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -144,6 +247,8 @@ pub async fn get_all_addresses(employee_id: i32) -> Result<Vec<Address>, ServerF
 /// 住所の作成
 #[server]
 pub async fn create_address(address: CreateAddress) -> Result<Address, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     validate_postal_code(&address.postal_code).map_err(ServerFnError::new)?;
     validate_address_field(&address.prefecture, "都道府県").map_err(ServerFnError::new)?;
@@ -158,6 +263,8 @@ pub async fn create_address(address: CreateAddress) -> Result<Address, ServerFnE
 /// 住所の更新
 #[server]
 pub async fn update_address(address: UpdateAddress) -> Result<Address, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     validate_postal_code(&address.postal_code).map_err(ServerFnError::new)?;
     validate_address_field(&address.prefecture, "都道府県").map_err(ServerFnError::new)?;
@@ -172,6 +279,8 @@ pub async fn update_address(address: UpdateAddress) -> Result<Address, ServerFnE
 /// 住所の削除
 #[server]
 pub async fn delete_address(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     EmployeeRepository::delete_address(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -1,14 +1,16 @@
 mod components;
-mod handlers;
+pub mod handlers;
 pub mod models;
 mod page;
 
 #[cfg(feature = "server")]
-mod repository;
-mod validation;
+pub mod repository;
+pub mod validation;
 
 pub use components::EmployeeDetailPage;
 pub use models::{
-    Address, CreateAddress, Employee, EmployeeFull, EmployeeWithAddress, UpdateAddress,
+    Address, CreateAddress, DriverStatus, Employee, EmployeeFull, EmployeeListPage,
+    EmployeeSearchMasters, EmployeeSearchQuery, EmployeeSortKey, EmployeeWithAddress, FilterOption,
+    UpdateAddress,
 };
 pub use page::EmployeesPage;
@@ -139,3 +139,275 @@ pub struct UpdateAddress {
     pub end_date: Option<NaiveDate>,
     pub is_current: bool,
 }
+
+// ドライバー選任状況（検索条件）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DriverStatus {
+    /// 選任中（選任日あり・解除日なし、または解除日が未来）
+    Selected,
+    /// 選任解除済み
+    Released,
+    /// 未選任
+    NotSelected,
+}
+
+impl DriverStatus {
+    pub const ALL: [DriverStatus; 3] = [
+        DriverStatus::Selected,
+        DriverStatus::Released,
+        DriverStatus::NotSelected,
+    ];
+
+    /// SQLに渡す値
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DriverStatus::Selected => "selected",
+            DriverStatus::Released => "released",
+            DriverStatus::NotSelected => "not_selected",
+        }
+    }
+
+    /// 文字列から変換
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.as_str() == value)
+    }
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DriverStatus::Selected => "選任中",
+            DriverStatus::Released => "選任解除",
+            DriverStatus::NotSelected => "未選任",
+        }
+    }
+}
+
+// 一覧の並び順
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmployeeSortKey {
+    /// 社員番号
+    #[default]
+    EmployeeCode,
+    /// 氏名（カナ）
+    Kana,
+    /// 入社日
+    HireDate,
+    /// 更新日時
+    UpdatedAt,
+}
+
+impl EmployeeSortKey {
+    pub const ALL: [EmployeeSortKey; 4] = [
+        EmployeeSortKey::EmployeeCode,
+        EmployeeSortKey::Kana,
+        EmployeeSortKey::HireDate,
+        EmployeeSortKey::UpdatedAt,
+    ];
+
+    /// SQLに渡す値
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmployeeSortKey::EmployeeCode => "employee_code",
+            EmployeeSortKey::Kana => "kana",
+            EmployeeSortKey::HireDate => "hire_date",
+            EmployeeSortKey::UpdatedAt => "updated_at",
+        }
+    }
+
+    /// 文字列から変換
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EmployeeSortKey::EmployeeCode => "社員番号",
+            EmployeeSortKey::Kana => "氏名（カナ）",
+            EmployeeSortKey::HireDate => "入社日",
+            EmployeeSortKey::UpdatedAt => "更新日時",
+        }
+    }
+}
+
+/// 1ページあたりの既定件数
+pub const DEFAULT_PER_PAGE: u32 = 48;
+/// 1ページあたりの最大件数
+pub const MAX_PER_PAGE: u32 = 200;
+
+// 従業員検索の条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeSearchQuery {
+    /// 社員番号・氏名（漢字・カナ）・電話番号・現住所に対するキーワード（空白区切りでAND検索）
+    pub keyword: String,
+    /// 在職状況（Noneの場合は全員）
+    pub is_active: Option<bool>,
+    pub office_id: Option<i32>,
+    pub department_id: Option<i32>,
+    pub position_id: Option<i32>,
+    pub driver_status: Option<DriverStatus>,
+    /// 有効な運転免許の種類
+    pub license_type_id: Option<i32>,
+    pub sort: EmployeeSortKey,
+    /// 降順で並べるか
+    pub descending: bool,
+    /// ページ番号（0始まり）
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl Default for EmployeeSearchQuery {
+    fn default() -> Self {
+        Self {
+            keyword: String::new(),
+            is_active: None,
+            office_id: None,
+            department_id: None,
+            position_id: None,
+            driver_status: None,
+            license_type_id: None,
+            sort: EmployeeSortKey::default(),
+            descending: false,
+            page: 0,
+            per_page: DEFAULT_PER_PAGE,
+        }
+    }
+}
+
+impl EmployeeSearchQuery {
+    /// キーワードをLIKE用のパターンに分解
+    /// 全角スペースも区切りとして扱い（`split_whitespace`はU+3000を含む）、`%` `_` `\` はエスケープする
+    pub fn keyword_patterns(&self) -> Vec<String> {
+        self.keyword
+            .split_whitespace()
+            .map(|term| {
+                let escaped = term
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                format!("%{}%", escaped)
+            })
+            .collect()
+    }
+
+    /// 取得件数（1〜MAX_PER_PAGEに丸める）
+    pub fn limit(&self) -> i64 {
+        i64::from(self.per_page.clamp(1, MAX_PER_PAGE))
+    }
+
+    /// 読み飛ばす件数
+    pub fn offset(&self) -> i64 {
+        i64::from(self.page) * self.limit()
+    }
+}
+
+/// 従業員一覧の1ページ分
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeListPage {
+    pub items: Vec<EmployeeWithAddress>,
+    /// 条件に一致する全件数
+    pub total: i64,
+    /// ページ番号（0始まり）
+    pub page: u32,
+    pub per_page: u32,
+}
+
+impl EmployeeListPage {
+    /// 総ページ数（0件でも1ページとする）
+    pub fn total_pages(&self) -> u32 {
+        let per_page = i64::from(self.per_page.max(1));
+        u32::try_from((self.total + per_page - 1) / per_page)
+            .unwrap_or(u32::MAX)
+            .max(1)
+    }
+
+    /// 表示中の範囲（1始まり、該当なしの場合は0〜0）
+    pub fn range(&self) -> (i64, i64) {
+        if self.items.is_empty() {
+            return (0, 0);
+        }
+        let start = i64::from(self.page) * i64::from(self.per_page) + 1;
+        (start, start + self.items.len() as i64 - 1)
+    }
+}
+
+// 検索フィルタの選択肢
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct FilterOption {
+    pub id: i32,
+    pub name: String,
+}
+
+// 従業員検索で使うマスタ一式
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeSearchMasters {
+    pub offices: Vec<FilterOption>,
+    pub departments: Vec<FilterOption>,
+    pub positions: Vec<FilterOption>,
+    pub license_types: Vec<FilterOption>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyword_patterns() {
+        let query = EmployeeSearchQuery {
+            keyword: " 山田　太郎  090 ".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(query.keyword_patterns(), vec!["%山田%", "%太郎%", "%090%"]);
+
+        let query = EmployeeSearchQuery {
+            keyword: "100%_a\\b".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(query.keyword_patterns(), vec!["%100\\%\\_a\\\\b%"]);
+
+        assert!(EmployeeSearchQuery::default().keyword_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_paging() {
+        let query = EmployeeSearchQuery {
+            page: 2,
+            per_page: 50,
+            ..Default::default()
+        };
+        assert_eq!(query.limit(), 50);
+        assert_eq!(query.offset(), 100);
+
+        let query = EmployeeSearchQuery {
+            per_page: 10_000,
+            ..Default::default()
+        };
+        assert_eq!(query.limit(), i64::from(MAX_PER_PAGE));
+
+        let page = EmployeeListPage {
+            total: 101,
+            per_page: 50,
+            ..Default::default()
+        };
+        assert_eq!(page.total_pages(), 3);
+        assert_eq!(page.range(), (0, 0));
+        assert_eq!(EmployeeListPage::default().total_pages(), 1);
+    }
+
+    #[test]
+    fn test_sort_key_parse() {
+        for key in EmployeeSortKey::ALL {
+            assert_eq!(EmployeeSortKey::parse(key.as_str()), Some(key));
+        }
+        assert_eq!(EmployeeSortKey::parse(""), None);
+    }
+
+    #[test]
+    fn test_driver_status_parse() {
+        for status in DriverStatus::ALL {
+            assert_eq!(DriverStatus::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(DriverStatus::parse("unknown"), None);
+    }
+}
@@ -1,9 +1,14 @@
 use dioxus::prelude::*;
 
 use crate::components::SearchBar;
-use crate::modules::employees::components::{EmployeeCard, EmployeeCreateForm, EmployeeEditForm};
-use crate::modules::employees::handlers::get_employees_with_address;
-use crate::modules::employees::models::EmployeeWithAddress;
+use crate::modules::employee_documents::models::DocumentArchiveRequest;
+use crate::modules::employee_documents::DocumentArchiveButton;
+use crate::modules::employees::components::{
+    EmployeeCard, EmployeeCreateForm, EmployeeEditForm, EmployeeSearchFilters, EmployeeSortControl,
+    PageControls,
+};
+use crate::modules::employees::handlers::search_employees;
+use crate::modules::employees::models::{EmployeeSearchQuery, EmployeeWithAddress};
 use crate::Route;
 
 // 共通のCSSクラス定数
@@ -22,11 +27,15 @@ const GRID_CLASS: &str = "grid grid-cols-[repeat(auto-fill,minmax(360px,1fr))] g
 
 #[component]
 pub fn EmployeesPage() -> Element {
-    let employees_list = use_resource(|| async move { get_employees_with_address().await });
+    // 検索条件（在職中のみで開始）
+    let mut query = use_signal(|| EmployeeSearchQuery {
+        is_active: Some(true),
+        ..Default::default()
+    });
+    let employees_list = use_resource(move || async move { search_employees(query()).await });
     let mut selected_employee = use_signal(|| None::<EmployeeWithAddress>);
     let mut create_panel = use_signal(|| false);
     let mut edit_panel = use_signal(|| false);
-    let mut show_active_only = use_signal(|| true); // true: 在職中, false: 退職済み
 
     rsx! {
         document::Title { "従業員一覧" }
@@ -38,7 +47,20 @@ pub fn EmployeesPage() -> Element {
                         h1 { class: "text-lg font-semibold text-gray-800", "従業員一覧" }
                     }
                     div { class: "flex items-center gap-3",
-                        SearchBar {}
+                        SearchBar {
+                            placeholder: "社員番号・氏名・電話番号・住所で検索".to_string(),
+                            on_search: move |keyword| {
+                                let mut q = query();
+                                q.keyword = keyword;
+                                q.page = 0;
+                                query.set(q);
+                            },
+                        }
+                        Link {
+                            class: "py-2 px-4 rounded-lg border border-gray-300 text-sm font-semibold text-gray-700 hover:bg-gray-50 transition-colors",
+                            to: Route::EmployeeImportPage {},
+                            "CSV取込"
+                        }
                         button {
                             class: format!("{} {}", BUTTON_PRIMARY_CLASS, if create_panel() { "opacity-0 pointer-events-none" } else { "opacity-100" }),
                             disabled: edit_panel(),
@@ -50,48 +72,83 @@ pub fn EmployeesPage() -> Element {
 
                 // コンテンツ
                 match &*employees_list.read_unchecked() {
-                    Some(Ok(list)) => rsx! {
-                        div { class: CONTENT_CLASS,
-                            div { class: "mb-6 flex items-center justify-between",
-                                div { class: "flex items-center gap-3",
-                                    p { class: "text-gray-700 font-semibold text-lg",
-                                        "全 {list.iter().filter(|e| e.is_active == show_active_only()).count()} 件"
+                    Some(Ok(result)) => {
+                        let list = &result.items;
+                        let show_active_only = query().is_active.unwrap_or(true); // true: 在職中, false: 退職済み
+                        rsx! {
+                            div { class: CONTENT_CLASS,
+                                div { class: "mb-6 flex items-center justify-between",
+                                    div { class: "flex items-center gap-3",
+                                        p { class: "text-gray-700 font-semibold text-lg", "全 {result.total} 件" }
+                                        div { class: "h-6 w-px bg-gray-300" }
+                                        button {
+                                            class: format!("{} {}", BUTTON_TOGGLE_BASE_CLASS, if show_active_only {
+                                                BUTTON_TOGGLE_ACTIVE_CLASS
+                                            } else {
+                                                BUTTON_TOGGLE_INACTIVE_CLASS
+                                            }),
+                                            onclick: move |_| {
+                                                let mut q = query();
+                                                q.is_active = Some(!show_active_only);
+                                                q.page = 0;
+                                                query.set(q);
+                                            },
+                                            if show_active_only { "在職中のみ" } else { "退職済みのみ" }
+                                        }
+                                        div { class: "h-6 w-px bg-gray-300" }
+                                        EmployeeSearchFilters { query }
                                     }
-                                    div { class: "h-6 w-px bg-gray-300" }
-                                    button {
-                                        class: format!("{} {}", BUTTON_TOGGLE_BASE_CLASS, if show_active_only() {
-                                            BUTTON_TOGGLE_ACTIVE_CLASS
-                                        } else {
-                                            BUTTON_TOGGLE_INACTIVE_CLASS
-                                        }),
-                                        onclick: move |_| show_active_only.set(!show_active_only()),
-                                        if show_active_only() { "在職中のみ" } else { "退職済みのみ" }
+                                    div { class: "flex items-center gap-3",
+                                        // 表示中のページの従業員の書類をまとめてダウンロード（監査での提出用）
+                                        if !list.is_empty() {
+                                            DocumentArchiveButton {
+                                                request: DocumentArchiveRequest {
+                                                    employee_ids: list.iter().map(|e| e.id).collect(),
+                                                    ..Default::default()
+                                                },
+                                                label: "表示中の従業員の書類をZIP".to_string(),
+                                            }
+                                        }
+                                        EmployeeSortControl { query }
                                     }
                                 }
-                            }
-                            div { class: GRID_CLASS,
-                                for emp in list.iter().filter(|e| e.is_active == show_active_only()) {
-                                    {
-                                        let emp = emp.clone();
-                                        let is_selected = selected_employee()
-                                            .is_some_and(|selected| selected.id == emp.id);
-                                        let nav = navigator();
-                                        rsx! {
-                                            EmployeeCard {
-                                                employee: emp.clone(),
-                                                is_selected,
-                                                on_click: move |_| {
-                                                    if !create_panel() {
-                                                        nav.push(Route::EmployeeDetailPage { id: emp.id });
-                                                    }
-                                                },
+                                if list.is_empty() {
+                                    p { class: "text-gray-500 text-center py-12", "条件に一致する従業員はいません" }
+                                }
+                                div { class: GRID_CLASS,
+                                    for emp in list.iter() {
+                                        {
+                                            let emp = emp.clone();
+                                            let is_selected = selected_employee()
+                                                .is_some_and(|selected| selected.id == emp.id);
+                                            let nav = navigator();
+                                            rsx! {
+                                                EmployeeCard {
+                                                    employee: emp.clone(),
+                                                    is_selected,
+                                                    on_click: move |_| {
+                                                        if !create_panel() {
+                                                            nav.push(Route::EmployeeDetailPage { id: emp.id });
+                                                        }
+                                                    },
+                                                }
                                             }
                                         }
                                     }
                                 }
+                                if result.page > 0 || result.total_pages() > 1 {
+                                    PageControls {
+                                        page: result.clone(),
+                                        on_change: move |page| {
+                                            let mut q = query();
+                                            q.page = page;
+                                            query.set(q);
+                                        },
+                                    }
+                                }
                             }
                         }
-                    },
+                    }
                     Some(Err(e)) => rsx! {
                         div { class: "flex-1 flex items-center justify-center p-6",
                             div { class: "text-center",
@@ -1,13 +1,62 @@
 #[cfg(feature = "server")]
 use super::models::{
-    Address, CreateAddress, Employee, EmployeeFull, EmployeeWithAddress, UpdateAddress,
+    Address, CreateAddress, Employee, EmployeeFull, EmployeeListPage, EmployeeSearchMasters,
+    EmployeeSearchQuery, EmployeeWithAddress, FilterOption, UpdateAddress,
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct EmployeeRepository;
 
+/// 検索結果の行（全件数付き）
+#[cfg(feature = "server")]
+struct EmployeeSearchRow {
+    id: i32,
+    employee_code: String,
+    first_name: String,
+    last_name: String,
+    mobile: Option<String>,
+    nationality_id: Option<i32>,
+    birth_date: Option<chrono::NaiveDate>,
+    gender: Option<String>,
+    is_active: bool,
+    created_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+    current_postal_code: Option<String>,
+    current_prefecture: Option<String>,
+    current_city: Option<String>,
+    current_street: Option<String>,
+    current_building: Option<String>,
+    total_count: i64,
+}
+
+#[cfg(feature = "server")]
+impl From<EmployeeSearchRow> for EmployeeWithAddress {
+    fn from(row: EmployeeSearchRow) -> Self {
+        Self {
+            id: row.id,
+            employee_code: row.employee_code,
+            first_name: row.first_name,
+            last_name: row.last_name,
+            mobile: row.mobile,
+            nationality_id: row.nationality_id,
+            birth_date: row.birth_date,
+            gender: row.gender,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            current_postal_code: row.current_postal_code,
+            current_prefecture: row.current_prefecture,
+            current_city: row.current_city,
+            current_street: row.current_street,
+            current_building: row.current_building,
+        }
+    }
+}
+
 #[cfg(feature = "server")]
 impl EmployeeRepository {
     /// データベースプールを取得するヘルパーメソッド
@@ -102,7 +151,7 @@ impl EmployeeRepository {
         last_name: String,
     ) -> Result<Employee, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let employee = sqlx::query_as!(
             Employee,
             r#"INSERT INTO employees (employee_code, first_name, last_name, is_active, created_at, updated_at)
              VALUES ($1, $2, $3, true, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -112,7 +161,11 @@ impl EmployeeRepository {
             last_name
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create("employees", employee.id, Some(employee.id), &employee).await;
+
+        Ok(employee)
     }
 
     /// 従業員の更新
@@ -124,7 +177,8 @@ impl EmployeeRepository {
         is_active: bool,
     ) -> Result<Employee, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(id).await?;
+        let employee = sqlx::query_as!(
             Employee,
             r#"UPDATE employees SET employee_code = $1, first_name = $2, last_name = $3, is_active = $4, updated_at = CURRENT_TIMESTAMP
              WHERE id = $5
@@ -136,14 +190,29 @@ impl EmployeeRepository {
             id
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        // 変更前と同じ型で比較するため、更新後の行も詳細情報として読み直す
+        if let Some(new) = Self::get_by_id(employee.id).await? {
+            audit_log::record_update(
+                "employees",
+                employee.id,
+                Some(employee.id),
+                old.as_ref(),
+                &new,
+            )
+            .await;
+        }
+
+        Ok(employee)
     }
 
     /// 従業員の詳細情報を更新（全フィールド対応）
     #[allow(dead_code)]
     pub async fn update_full(employee: EmployeeFull) -> Result<EmployeeFull, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(employee.id).await?;
+        let updated = sqlx::query_as!(
             EmployeeFull,
             r#"UPDATE employees SET
                 company_id = $2,
@@ -221,15 +290,48 @@ impl EmployeeRepository {
             employee.is_active
         )
         .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update(
+            "employees",
+            updated.id,
+            Some(updated.id),
+            old.as_ref(),
+            &updated,
+        )
+        .await;
+
+        Ok(updated)
+    }
+
+    /// 従業員または従業員の書類のいずれかが廃棄の保留（リーガルホールド）中か
+    pub async fn has_legal_hold(id: i32) -> Result<bool, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(
+            r#"
+            SELECT (
+                EXISTS (SELECT 1 FROM employees WHERE id = $1 AND legal_hold)
+                OR EXISTS (
+                    SELECT 1 FROM employee_documents WHERE employee_id = $1 AND legal_hold
+                )
+            ) AS "held!"
+            "#,
+            id
+        )
+        .fetch_one(pool)
         .await
     }
 
     /// 従業員の削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM employees WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete("employees", id, old.as_ref().map(|o| o.id), old.as_ref()).await;
+
         Ok(())
     }
 
@@ -265,6 +367,142 @@ impl EmployeeRepository {
         .await
     }
 
+    /// 条件に一致する従業員を住所情報付きで1ページ分検索
+    /// キーワードは空白区切りの各語が社員番号・氏名・カナ・電話番号・現住所のいずれかに含まれるものを返す
+    pub async fn search(query: &EmployeeSearchQuery) -> Result<EmployeeListPage, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let patterns = query.keyword_patterns();
+        let rows = sqlx::query_as!(
+            EmployeeSearchRow,
+            r#"
+            SELECT
+                e.id,
+                e.employee_code,
+                e.first_name,
+                e.last_name,
+                e.mobile,
+                e.nationality_id,
+                e.birth_date,
+                e.gender,
+                e.is_active,
+                e.created_at,
+                e.updated_at,
+                a.postal_code as "current_postal_code?",
+                a.prefecture as "current_prefecture?",
+                a.city as "current_city?",
+                a.street as "current_street?",
+                a.building as "current_building?",
+                COUNT(*) OVER () as "total_count!"
+            FROM employees e
+            LEFT JOIN addresses a ON e.id = a.employee_id AND a.is_current = true
+            WHERE NOT EXISTS (
+                    SELECT 1 FROM unnest($1::text[]) AS p(pattern)
+                    WHERE NOT (
+                        e.employee_code ILIKE p.pattern
+                        OR (e.last_name || e.first_name) ILIKE p.pattern
+                        OR (COALESCE(e.last_name_kana, '') || COALESCE(e.first_name_kana, '')) ILIKE p.pattern
+                        OR REPLACE(COALESCE(e.phone, ''), '-', '') ILIKE REPLACE(p.pattern, '-', '')
+                        OR REPLACE(COALESCE(e.mobile, ''), '-', '') ILIKE REPLACE(p.pattern, '-', '')
+                        OR COALESCE(a.postal_code, '') ILIKE p.pattern
+                        OR (COALESCE(a.prefecture, '') || COALESCE(a.city, '') || COALESCE(a.street, '')
+                            || COALESCE(a.building, '')) ILIKE p.pattern
+                    )
+                )
+              AND ($2::bool IS NULL OR e.is_active = $2)
+              AND ($3::int IS NULL OR e.office_id = $3)
+              AND ($4::int IS NULL OR e.department_id = $4)
+              AND ($5::int IS NULL OR e.position_id = $5)
+              AND (
+                    $6::text IS NULL
+                    OR ($6 = 'selected' AND e.driver_start_date IS NOT NULL
+                        AND (e.driver_end_date IS NULL OR e.driver_end_date > CURRENT_DATE))
+                    OR ($6 = 'released' AND e.driver_end_date <= CURRENT_DATE)
+                    OR ($6 = 'not_selected' AND e.driver_start_date IS NULL)
+                )
+              AND (
+                    $7::int IS NULL
+                    OR EXISTS (
+                        SELECT 1 FROM licenses l
+                        WHERE l.employee_id = e.id
+                          AND l.license_type_id = $7
+                          AND l.is_active = TRUE
+                    )
+                )
+            ORDER BY
+                CASE WHEN $8 = 'employee_code' AND NOT $9 THEN e.employee_code END ASC,
+                CASE WHEN $8 = 'employee_code' AND $9 THEN e.employee_code END DESC,
+                CASE WHEN $8 = 'kana' AND NOT $9
+                    THEN COALESCE(e.last_name_kana, '') || COALESCE(e.first_name_kana, '') END ASC,
+                CASE WHEN $8 = 'kana' AND $9
+                    THEN COALESCE(e.last_name_kana, '') || COALESCE(e.first_name_kana, '') END DESC,
+                CASE WHEN $8 = 'hire_date' AND NOT $9 THEN e.start_date END ASC NULLS LAST,
+                CASE WHEN $8 = 'hire_date' AND $9 THEN e.start_date END DESC NULLS LAST,
+                CASE WHEN $8 = 'updated_at' AND NOT $9 THEN e.updated_at END ASC,
+                CASE WHEN $8 = 'updated_at' AND $9 THEN e.updated_at END DESC,
+                e.id
+            LIMIT $10 OFFSET $11
+            "#,
+            &patterns[..],
+            query.is_active,
+            query.office_id,
+            query.department_id,
+            query.position_id,
+            query.driver_status.map(|s| s.as_str()),
+            query.license_type_id,
+            query.sort.as_str(),
+            query.descending,
+            query.limit(),
+            query.offset(),
+        )
+        .fetch_all(pool)
+        .await?;
+
+        // 範囲外のページでは件数が取れないため0件として返す
+        let total = rows.first().map(|r| r.total_count).unwrap_or(0);
+        Ok(EmployeeListPage {
+            items: rows.into_iter().map(EmployeeWithAddress::from).collect(),
+            total,
+            page: query.page,
+            per_page: query.limit() as u32,
+        })
+    }
+
+    /// 検索フィルタ用のマスタを取得
+    pub async fn get_search_masters() -> Result<EmployeeSearchMasters, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let offices = sqlx::query_as!(
+            FilterOption,
+            r#"SELECT id, name FROM offices WHERE is_active = TRUE ORDER BY company_id, id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let departments = sqlx::query_as!(
+            FilterOption,
+            r#"SELECT id, name FROM departments ORDER BY company_id, id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let positions = sqlx::query_as!(
+            FilterOption,
+            r#"SELECT id, name FROM positions ORDER BY company_id, id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let license_types = sqlx::query_as!(
+            FilterOption,
+            r#"SELECT id, name FROM license_types ORDER BY display_order, id"#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(EmployeeSearchMasters {
+            offices,
+            departments,
+            positions,
+            license_types,
+        })
+    }
+
     /// 従業員の現住所を取得
     pub async fn get_current_address(employee_id: i32) -> Result<Option<Address>, sqlx::Error> {
         let pool = Self::pool().await?;
@@ -324,6 +562,34 @@ impl EmployeeRepository {
         .await
     }
 
+    /// IDで住所を取得
+    pub async fn get_address_by_id(id: i32) -> Result<Option<Address>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Address,
+            r#"
+            SELECT
+                id,
+                employee_id,
+                postal_code,
+                prefecture,
+                city,
+                street,
+                building,
+                start_date,
+                end_date,
+                is_current,
+                created_at,
+                updated_at
+            FROM addresses
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
     /// 住所の作成
     pub async fn create_address(address: CreateAddress) -> Result<Address, sqlx::Error> {
         let pool = Self::pool().await?;
@@ -338,7 +604,7 @@ impl EmployeeRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let saved = sqlx::query_as!(
             Address,
             r#"
             INSERT INTO addresses (
@@ -378,12 +644,17 @@ impl EmployeeRepository {
             address.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create("addresses", saved.id, Some(saved.employee_id), &saved).await;
+
+        Ok(saved)
     }
 
     /// 住所の更新
     pub async fn update_address(address: UpdateAddress) -> Result<Address, sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_address_by_id(address.id).await?;
 
         // 新しい住所が現住所の場合、既存の現住所フラグを解除（自分以外）
         if address.is_current {
@@ -396,7 +667,7 @@ impl EmployeeRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let saved = sqlx::query_as!(
             Address,
             r#"
             UPDATE addresses SET
@@ -435,15 +706,36 @@ impl EmployeeRepository {
             address.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "addresses",
+            saved.id,
+            Some(saved.employee_id),
+            old.as_ref(),
+            &saved,
+        )
+        .await;
+
+        Ok(saved)
     }
 
     /// 住所の削除
     pub async fn delete_address(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_address_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM addresses WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "addresses",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
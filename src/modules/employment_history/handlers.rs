@@ -3,11 +3,16 @@ use super::models::{CreateEmploymentHistory, EmploymentHistory, UpdateEmployment
 use super::repository::EmploymentHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の職歴一覧を取得
 #[server]
 pub async fn get_employment_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<EmploymentHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmploymentHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -18,6 +23,8 @@ pub async fn get_employment_history_by_employee(
 pub async fn get_current_employment(
     employee_id: i32,
 ) -> Result<Option<EmploymentHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     EmploymentHistoryRepository::get_current_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -28,6 +35,8 @@ pub async fn get_current_employment(
 pub async fn create_employment_history(
     data: CreateEmploymentHistory,
 ) -> Result<EmploymentHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EmploymentHistoryRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -38,6 +47,8 @@ pub async fn create_employment_history(
 pub async fn update_employment_history(
     data: UpdateEmploymentHistory,
 ) -> Result<EmploymentHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     EmploymentHistoryRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -46,6 +57,8 @@ pub async fn update_employment_history(
 /// 職歴を削除
 #[server]
 pub async fn delete_employment_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     EmploymentHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
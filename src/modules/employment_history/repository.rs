@@ -2,6 +2,8 @@
 use super::models::{CreateEmploymentHistory, EmploymentHistory, UpdateEmploymentHistory};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct EmploymentHistoryRepository;
@@ -75,7 +77,7 @@ impl EmploymentHistoryRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             EmploymentHistory,
             r#"INSERT INTO employment_history (employee_id, company_name, department, position, job_description, start_date, end_date, is_current, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -90,12 +92,23 @@ impl EmploymentHistoryRepository {
             data.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "employment_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 職歴を更新
     pub async fn update(data: UpdateEmploymentHistory) -> Result<EmploymentHistory, sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(data.id).await?;
 
         // 現在の職場として更新する場合、他の現在の職場を解除
         if data.is_current {
@@ -111,7 +124,7 @@ impl EmploymentHistoryRepository {
             .await?;
         }
 
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             EmploymentHistory,
             r#"UPDATE employment_history
                SET company_name = $2, department = $3, position = $4, job_description = $5,
@@ -128,15 +141,36 @@ impl EmploymentHistoryRepository {
             data.is_current
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "employment_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 職歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM employment_history WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "employment_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 }
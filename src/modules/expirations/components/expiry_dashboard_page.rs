@@ -0,0 +1,220 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::expirations::handlers::get_expiry_dashboard;
+use crate::modules::expirations::models::{
+    ExpiryItem, ExpiryKind, ExpirySeverity, OfficeExpiryGroup, DEFAULT_WARNING_DAYS,
+};
+use crate::Route;
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str = "flex items-center h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+
+/// 期限切れ・期限間近ダッシュボード（営業所別）
+#[component]
+pub fn ExpiryDashboardPage() -> Element {
+    let mut days = use_signal(|| DEFAULT_WARNING_DAYS);
+    let mut kind_filter = use_signal(|| None::<ExpiryKind>);
+
+    let groups = use_resource(move || async move { get_expiry_dashboard(days()).await });
+
+    rsx! {
+        document::Title { "ダッシュボード" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "ダッシュボード" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-6xl mx-auto space-y-6",
+                    // 抽出条件
+                    div { class: SECTION_CLASS,
+                        div { class: "grid grid-cols-1 md:grid-cols-4 gap-4",
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "抽出期間" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    value: "{days}",
+                                    onchange: move |evt| {
+                                        if let Ok(value) = evt.value().parse() {
+                                            days.set(value);
+                                        }
+                                    },
+                                    for option_days in [30, 60, 90, 180] {
+                                        option { value: "{option_days}", "{option_days}日以内" }
+                                    }
+                                }
+                            }
+                            div { class: "space-y-2 md:col-span-3",
+                                label { class: "block text-sm font-medium text-gray-700", "種別" }
+                                div { class: "flex flex-wrap gap-2",
+                                    KindFilterButton {
+                                        label: "すべて".to_string(),
+                                        active: kind_filter().is_none(),
+                                        on_click: move |_| kind_filter.set(None),
+                                    }
+                                    for kind in ExpiryKind::ALL {
+                                        KindFilterButton {
+                                            label: kind.display_name().to_string(),
+                                            active: kind_filter() == Some(kind),
+                                            on_click: move |_| kind_filter.set(Some(kind)),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    match &*groups.read_unchecked() {
+                        Some(Ok(groups)) => {
+                            let groups: Vec<OfficeExpiryGroup> = groups
+                                .iter()
+                                .map(|g| OfficeExpiryGroup {
+                                    items: g
+                                        .items
+                                        .iter()
+                                        .filter(|item| kind_filter().is_none_or(|k| item.kind == k))
+                                        .cloned()
+                                        .collect(),
+                                    ..g.clone()
+                                })
+                                .filter(|g| !g.items.is_empty())
+                                .collect();
+                            rsx! {
+                                ExpirySummary { groups: groups.clone() }
+                                if groups.is_empty() {
+                                    div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                                        "期限切れ・期限間近の項目はありません"
+                                    }
+                                }
+                                for group in groups {
+                                    OfficeExpirySection { key: "{group.office_id:?}", group }
+                                }
+                            }
+                        }
+                        Some(Err(e)) => rsx! {
+                            div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700",
+                                "エラーが発生しました: {e}"
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex justify-center py-12",
+                                div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn KindFilterButton(label: String, active: bool, on_click: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            class: "px-3 py-1.5 rounded-full text-sm font-medium transition-colors",
+            class: if active { "bg-amber-400 text-gray-800" } else { "bg-gray-100 text-gray-600 hover:bg-gray-200" },
+            onclick: move |evt| on_click.call(evt),
+            "{label}"
+        }
+    }
+}
+
+/// 緊急度ごとの件数
+#[component]
+fn ExpirySummary(groups: Vec<OfficeExpiryGroup>) -> Element {
+    let severities = [
+        ExpirySeverity::Expired,
+        ExpirySeverity::Critical,
+        ExpirySeverity::Warning,
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for severity in severities {
+                {
+                    let count: usize = groups.iter().map(|g| g.count(severity)).sum();
+                    let label = severity.display_name();
+                    rsx! {
+                        div { class: "rounded-xl p-5 {severity.badge_class()}",
+                            p { class: "text-sm font-medium", "{label}" }
+                            p { class: "text-3xl font-bold mt-1", "{count}件" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 営業所ごとの期限一覧
+#[component]
+fn OfficeExpirySection(group: OfficeExpiryGroup) -> Element {
+    let expired = group.count(ExpirySeverity::Expired);
+    let critical = group.count(ExpirySeverity::Critical);
+    let warning = group.count(ExpirySeverity::Warning);
+
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "{group.office_name}" }
+                div { class: "flex gap-2 text-xs font-semibold",
+                    span { class: "px-2 py-1 rounded {ExpirySeverity::Expired.badge_class()}", "期限切れ {expired}" }
+                    span { class: "px-2 py-1 rounded {ExpirySeverity::Critical.badge_class()}", "至急 {critical}" }
+                    span { class: "px-2 py-1 rounded {ExpirySeverity::Warning.badge_class()}", "注意 {warning}" }
+                }
+            }
+            div { class: "overflow-x-auto",
+                table { class: "min-w-full text-sm",
+                    thead {
+                        tr { class: "text-left text-gray-600 border-b border-gray-200",
+                            th { class: "py-2 px-3", "状態" }
+                            th { class: "py-2 px-3", "種別" }
+                            th { class: "py-2 px-3", "項目" }
+                            th { class: "py-2 px-3", "従業員" }
+                            th { class: "py-2 px-3", "有効期限" }
+                            th { class: "py-2 px-3 text-right", "残り日数" }
+                        }
+                    }
+                    tbody {
+                        for item in group.items {
+                            ExpiryRow { key: "{item.kind.as_str()}-{item.record_id}", item }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExpiryRow(item: ExpiryItem) -> Element {
+    let severity = item.severity();
+    let remaining = if item.days_remaining < 0 {
+        format!("{}日超過", -item.days_remaining)
+    } else {
+        format!("{}日", item.days_remaining)
+    };
+    let expiration = item.expiration_date.format("%Y-%m-%d").to_string();
+
+    rsx! {
+        tr { class: "border-b border-gray-100 {severity.row_class()}",
+            td { class: "py-2 px-3",
+                span { class: "px-2 py-0.5 rounded text-xs font-semibold {severity.badge_class()}",
+                    "{severity.display_name()}"
+                }
+            }
+            td { class: "py-2 px-3 text-gray-700", "{item.kind.display_name()}" }
+            td { class: "py-2 px-3 text-gray-800", "{item.item_name}" }
+            td { class: "py-2 px-3",
+                Link {
+                    to: Route::EmployeeDetailPage { id: item.employee_id },
+                    class: "text-amber-700 hover:underline",
+                    "{item.employee_code} {item.employee_name}"
+                }
+            }
+            td { class: "py-2 px-3 text-gray-700", "{expiration}" }
+            td { class: "py-2 px-3 text-right font-semibold", "{remaining}" }
+        }
+    }
+}
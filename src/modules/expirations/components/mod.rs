@@ -0,0 +1,3 @@
+mod expiry_dashboard_page;
+
+pub use expiry_dashboard_page::ExpiryDashboardPage;
@@ -0,0 +1,46 @@
+//! 有効期限管理のハンドラー層（サーバー関数）
+
+use super::models::{group_by_office, ExpiryItem, OfficeExpiryGroup};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::repository::ExpiryRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// 期限切れ・期限間近の項目を取得（営業所で絞り込み可）
+#[server]
+pub async fn get_expiring_items(
+    days: i32,
+    office_id: Option<i32>,
+) -> Result<Vec<ExpiryItem>, ServerFnError> {
+    require_permission(Permission::View).await?;
+    validate_days(days)?;
+
+    ExpiryRepository::get_expiring(days, office_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 営業所ごとにまとめた期限一覧を取得（ダッシュボード用）
+#[server]
+pub async fn get_expiry_dashboard(days: i32) -> Result<Vec<OfficeExpiryGroup>, ServerFnError> {
+    require_permission(Permission::View).await?;
+    validate_days(days)?;
+
+    let items = ExpiryRepository::get_expiring(days, None)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(group_by_office(items))
+}
+
+/// 抽出日数のバリデーション
+#[cfg(feature = "server")]
+fn validate_days(days: i32) -> Result<(), ServerFnError> {
+    if !(0..=365).contains(&days) {
+        return Err(ServerFnError::new(
+            "日数は0〜365の範囲で指定してください".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,13 @@
+//! 有効期限管理モジュール
+//! 免許・資格・健康診断・適性診断・指導教育・在留カードの期限切れ／期限間近を横断的に抽出する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::*;
+pub use handlers::*;
+pub use models::*;
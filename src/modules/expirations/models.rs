@@ -0,0 +1,258 @@
+//! 有効期限管理のモデル定義
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 期限が近い場合に警告とみなす既定の日数
+pub const DEFAULT_WARNING_DAYS: i32 = 60;
+
+/// 至急対応とみなす残り日数
+pub const CRITICAL_DAYS: i32 = 30;
+
+/// 期限管理の対象種別
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum ExpiryKind {
+    /// 運転免許
+    #[serde(rename = "LICENSE")]
+    License,
+    /// 資格
+    #[serde(rename = "QUALIFICATION")]
+    Qualification,
+    /// 健康診断
+    #[serde(rename = "HEALTH_CHECKUP")]
+    HealthCheckup,
+    /// 適性診断
+    #[serde(rename = "APTITUDE_CHECKUP")]
+    AptitudeCheckup,
+    /// 指導教育
+    #[serde(rename = "GUIDANCE_EDUCATION")]
+    GuidanceEducation,
+    /// 在留カード
+    #[serde(rename = "RESIDENCE_CARD")]
+    ResidenceCard,
+}
+
+impl ExpiryKind {
+    pub const ALL: [ExpiryKind; 6] = [
+        ExpiryKind::License,
+        ExpiryKind::Qualification,
+        ExpiryKind::HealthCheckup,
+        ExpiryKind::AptitudeCheckup,
+        ExpiryKind::GuidanceEducation,
+        ExpiryKind::ResidenceCard,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExpiryKind::License => "LICENSE",
+            ExpiryKind::Qualification => "QUALIFICATION",
+            ExpiryKind::HealthCheckup => "HEALTH_CHECKUP",
+            ExpiryKind::AptitudeCheckup => "APTITUDE_CHECKUP",
+            ExpiryKind::GuidanceEducation => "GUIDANCE_EDUCATION",
+            ExpiryKind::ResidenceCard => "RESIDENCE_CARD",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ExpiryKind::License => "運転免許",
+            ExpiryKind::Qualification => "資格",
+            ExpiryKind::HealthCheckup => "健康診断",
+            ExpiryKind::AptitudeCheckup => "適性診断",
+            ExpiryKind::GuidanceEducation => "指導教育",
+            ExpiryKind::ResidenceCard => "在留カード",
+        }
+    }
+}
+
+impl std::fmt::Display for ExpiryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 期限の緊急度
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExpirySeverity {
+    /// 期限切れ
+    Expired,
+    /// 至急（残り30日以内）
+    Critical,
+    /// 注意（警告日数以内）
+    Warning,
+}
+
+impl ExpirySeverity {
+    /// 残り日数から緊急度を判定
+    pub fn from_days_remaining(days_remaining: i32) -> Self {
+        if days_remaining < 0 {
+            ExpirySeverity::Expired
+        } else if days_remaining <= CRITICAL_DAYS {
+            ExpirySeverity::Critical
+        } else {
+            ExpirySeverity::Warning
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ExpirySeverity::Expired => "期限切れ",
+            ExpirySeverity::Critical => "至急",
+            ExpirySeverity::Warning => "注意",
+        }
+    }
+
+    /// バッジ表示用のTailwindクラス
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            ExpirySeverity::Expired => "bg-red-100 text-red-700 border border-red-300",
+            ExpirySeverity::Critical => "bg-orange-100 text-orange-700 border border-orange-300",
+            ExpirySeverity::Warning => "bg-yellow-50 text-yellow-700 border border-yellow-300",
+        }
+    }
+
+    /// 行の背景色
+    pub fn row_class(&self) -> &'static str {
+        match self {
+            ExpirySeverity::Expired => "bg-red-50",
+            ExpirySeverity::Critical => "bg-orange-50",
+            ExpirySeverity::Warning => "",
+        }
+    }
+}
+
+/// 期限切れ／期限間近の1件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct ExpiryItem {
+    pub kind: ExpiryKind,
+    /// 対象レコードのID（licenses.id など）
+    pub record_id: i32,
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    /// 種別名（免許種類・資格名・診断種別など）
+    pub item_name: String,
+    pub expiration_date: NaiveDate,
+    /// 残り日数（期限切れは負数）
+    pub days_remaining: i32,
+}
+
+impl ExpiryItem {
+    pub fn severity(&self) -> ExpirySeverity {
+        ExpirySeverity::from_days_remaining(self.days_remaining)
+    }
+}
+
+/// 営業所ごとの期限一覧
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfficeExpiryGroup {
+    pub office_id: Option<i32>,
+    pub office_name: String,
+    pub items: Vec<ExpiryItem>,
+}
+
+impl OfficeExpiryGroup {
+    /// 緊急度ごとの件数
+    pub fn count(&self, severity: ExpirySeverity) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.severity() == severity)
+            .count()
+    }
+}
+
+/// 期限一覧を営業所ごとにまとめる（営業所未設定は末尾）
+pub fn group_by_office(items: Vec<ExpiryItem>) -> Vec<OfficeExpiryGroup> {
+    let mut groups: Vec<OfficeExpiryGroup> = Vec::new();
+    for item in items {
+        match groups.iter_mut().find(|g| g.office_id == item.office_id) {
+            Some(group) => group.items.push(item),
+            None => groups.push(OfficeExpiryGroup {
+                office_id: item.office_id,
+                office_name: item
+                    .office_name
+                    .clone()
+                    .unwrap_or_else(|| "営業所未設定".to_string()),
+                items: vec![item],
+            }),
+        }
+    }
+
+    groups.sort_by(|a, b| {
+        a.office_id
+            .is_none()
+            .cmp(&b.office_id.is_none())
+            .then_with(|| a.office_name.cmp(&b.office_name))
+    });
+    for group in groups.iter_mut() {
+        group
+            .items
+            .sort_by(|a, b| a.days_remaining.cmp(&b.days_remaining));
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(office_id: Option<i32>, office_name: Option<&str>, days: i32) -> ExpiryItem {
+        ExpiryItem {
+            kind: ExpiryKind::License,
+            record_id: 1,
+            employee_id: 1,
+            employee_code: "001".to_string(),
+            employee_name: "山田 太郎".to_string(),
+            office_id,
+            office_name: office_name.map(str::to_string),
+            item_name: "中型".to_string(),
+            expiration_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            days_remaining: days,
+        }
+    }
+
+    #[test]
+    fn test_severity_from_days_remaining() {
+        assert_eq!(
+            ExpirySeverity::from_days_remaining(-1),
+            ExpirySeverity::Expired
+        );
+        assert_eq!(
+            ExpirySeverity::from_days_remaining(0),
+            ExpirySeverity::Critical
+        );
+        assert_eq!(
+            ExpirySeverity::from_days_remaining(30),
+            ExpirySeverity::Critical
+        );
+        assert_eq!(
+            ExpirySeverity::from_days_remaining(31),
+            ExpirySeverity::Warning
+        );
+    }
+
+    #[test]
+    fn test_group_by_office() {
+        let groups = group_by_office(vec![
+            item(None, None, 10),
+            item(Some(2), Some("横浜営業所"), 40),
+            item(Some(1), Some("本社営業所"), 5),
+            item(Some(2), Some("横浜営業所"), -3),
+        ]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].office_name, "本社営業所");
+        assert_eq!(groups[1].office_name, "横浜営業所");
+        assert_eq!(groups[1].items[0].days_remaining, -3);
+        assert_eq!(groups[1].count(ExpirySeverity::Expired), 1);
+        assert_eq!(groups[2].office_name, "営業所未設定");
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(feature = "server")]
+use super::models::{ExpiryItem, ExpiryKind};
+#[cfg(feature = "server")]
+use crate::db;
+
+#[cfg(feature = "server")]
+pub struct ExpiryRepository;
+
+#[cfg(feature = "server")]
+impl ExpiryRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 期限切れ・期限間近（指定日数以内）の項目を全種別まとめて取得
+    ///
+    /// 同じ従業員・同じ種別の記録が複数ある場合は最も新しい期限のみを対象とする
+    /// （更新済みの古い記録を期限切れとして扱わないため）。在籍中の従業員のみ。
+    pub async fn get_expiring(
+        days: i32,
+        office_id: Option<i32>,
+    ) -> Result<Vec<ExpiryItem>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            ExpiryItem,
+            r#"
+            WITH latest AS (
+                SELECT * FROM (
+                    SELECT DISTINCT ON (l.employee_id, l.license_type_id)
+                        'LICENSE'::VARCHAR AS kind, l.id AS record_id, l.employee_id,
+                        t.name AS item_name, l.expiration_date
+                    FROM licenses l
+                    JOIN license_types t ON t.id = l.license_type_id
+                    WHERE l.is_active = TRUE
+                    ORDER BY l.employee_id, l.license_type_id, l.expiration_date DESC
+                ) lic
+                UNION ALL
+                SELECT * FROM (
+                    SELECT DISTINCT ON (q.employee_id, q.qualification_type_id)
+                        'QUALIFICATION'::VARCHAR, q.id, q.employee_id,
+                        t.name, q.expiration_date
+                    FROM qualifications q
+                    JOIN qualification_types t ON t.id = q.qualification_type_id
+                    WHERE q.is_active = TRUE AND q.expiration_date IS NOT NULL
+                    ORDER BY q.employee_id, q.qualification_type_id, q.expiration_date DESC
+                ) qua
+                UNION ALL
+                SELECT * FROM (
+                    SELECT DISTINCT ON (h.employee_id, h.health_checkup_type_id)
+                        'HEALTH_CHECKUP'::VARCHAR, h.id, h.employee_id,
+                        t.name, h.expiration_date
+                    FROM health_checkup_history h
+                    JOIN health_checkup_types t ON t.id = h.health_checkup_type_id
+                    WHERE h.is_active = TRUE AND h.expiration_date IS NOT NULL
+                    ORDER BY h.employee_id, h.health_checkup_type_id, h.expiration_date DESC
+                ) hea
+                UNION ALL
+                SELECT * FROM (
+                    SELECT DISTINCT ON (a.employee_id, a.aptitude_checkup_type_id)
+                        'APTITUDE_CHECKUP'::VARCHAR, a.id, a.employee_id,
+                        t.name, a.expiration_date
+                    FROM aptitude_checkup_history a
+                    JOIN aptitude_checkup_types t ON t.id = a.aptitude_checkup_type_id
+                    WHERE a.is_active = TRUE AND a.expiration_date IS NOT NULL
+                    ORDER BY a.employee_id, a.aptitude_checkup_type_id, a.expiration_date DESC
+                ) apt
+                UNION ALL
+                SELECT * FROM (
+                    SELECT DISTINCT ON (g.employee_id, g.guidance_education_type_id)
+                        'GUIDANCE_EDUCATION'::VARCHAR, g.id, g.employee_id,
+                        t.name, g.expiration_date
+                    FROM guidance_education_history g
+                    JOIN guidance_education_types t ON t.id = g.guidance_education_type_id
+                    WHERE g.expiration_date IS NOT NULL
+                    ORDER BY g.employee_id, g.guidance_education_type_id, g.expiration_date DESC
+                ) gui
+                UNION ALL
+                SELECT * FROM (
+                    SELECT DISTINCT ON (r.employee_id)
+                        'RESIDENCE_CARD'::VARCHAR, r.id, r.employee_id,
+                        t.name, r.expiration_date
+                    FROM residence_cards r
+                    JOIN residence_card_types t ON t.id = r.residence_card_type_id
+                    WHERE r.is_active = TRUE
+                    ORDER BY r.employee_id, r.expiration_date DESC
+                ) res
+            )
+            SELECT
+                latest.kind AS "kind!: ExpiryKind",
+                latest.record_id AS "record_id!",
+                latest.employee_id AS "employee_id!",
+                e.employee_code,
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                e.office_id,
+                o.name AS "office_name?",
+                latest.item_name AS "item_name!",
+                latest.expiration_date AS "expiration_date!",
+                (latest.expiration_date - CURRENT_DATE)::INT AS "days_remaining!"
+            FROM latest
+            JOIN employees e ON e.id = latest.employee_id
+            LEFT JOIN offices o ON o.id = e.office_id
+            WHERE e.is_active = TRUE
+              AND latest.expiration_date <= CURRENT_DATE + $1::INT
+              AND ($2::INT IS NULL OR e.office_id = $2)
+            ORDER BY latest.expiration_date ASC
+            "#,
+            days,
+            office_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
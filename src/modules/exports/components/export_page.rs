@@ -0,0 +1,156 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::exports::models::{ExportDataset, ExportFormat, ExportRequest};
+use dioxus::prelude::*;
+
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+const SECTION_TITLE_CLASS: &str = "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400";
+const BUTTON_PRIMARY_CLASS: &str = "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors";
+const LINK_BUTTON_CLASS: &str = "text-sm text-amber-700 hover:text-amber-900 hover:underline";
+
+/// すべての列キー
+fn all_columns(dataset: ExportDataset) -> Vec<String> {
+    dataset
+        .columns()
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .collect()
+}
+
+/// データ出力画面（CSV / xlsx）
+#[component]
+pub fn ExportPage() -> Element {
+    let mut dataset = use_signal(ExportDataset::default);
+    let mut format = use_signal(ExportFormat::default);
+    let mut columns = use_signal(|| all_columns(ExportDataset::default()));
+    let mut include_retired = use_signal(|| false);
+
+    // ファイルはダウンロードエンドポイントから順次受け取る
+    let request = ExportRequest {
+        dataset: dataset(),
+        format: format(),
+        columns: columns(),
+        include_retired: include_retired(),
+    };
+
+    rsx! {
+        document::Title { "データ出力" }
+        div { class: "flex flex-col h-full",
+            // ヘッダー
+            div { class: "flex items-center h-14 px-6 border-b border-gray-200 bg-white shadow-sm",
+                h1 { class: "text-lg font-semibold text-gray-800", "データ出力" }
+            }
+
+            div { class: "flex-1 overflow-auto p-6 space-y-6",
+                // 出力対象
+                div { class: SECTION_CLASS,
+                    h3 { class: SECTION_TITLE_CLASS, "出力対象" }
+                    div { class: "flex flex-wrap gap-2",
+                        for d in ExportDataset::ALL {
+                            button {
+                                key: "{d.as_str()}",
+                                class: if dataset() == d {
+                                    "px-4 py-2 rounded-lg font-semibold bg-amber-400 text-gray-800"
+                                } else {
+                                    "px-4 py-2 rounded-lg bg-gray-100 text-gray-700 hover:bg-gray-200"
+                                },
+                                onclick: move |_| {
+                                    dataset.set(d);
+                                    columns.set(all_columns(d));
+                                },
+                                "{d.display_name()}"
+                            }
+                        }
+                    }
+                    label { class: "mt-4 flex items-center gap-2 text-sm text-gray-700",
+                        input {
+                            r#type: "checkbox",
+                            checked: include_retired(),
+                            onchange: move |evt| include_retired.set(evt.checked()),
+                        }
+                        "退職者を含める"
+                    }
+                }
+
+                // 列の選択
+                div { class: SECTION_CLASS,
+                    div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                        h3 { class: "text-xl font-bold", "出力する列" }
+                        div { class: "flex gap-4",
+                            button {
+                                class: LINK_BUTTON_CLASS,
+                                onclick: move |_| columns.set(all_columns(dataset())),
+                                "すべて選択"
+                            }
+                            button {
+                                class: LINK_BUTTON_CLASS,
+                                onclick: move |_| columns.set(Vec::new()),
+                                "すべて解除"
+                            }
+                        }
+                    }
+                    div { class: "grid grid-cols-4 gap-2",
+                        for (key, label) in dataset().columns().iter().copied() {
+                            label {
+                                key: "{key}",
+                                class: "flex items-center gap-2 text-sm text-gray-700",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: columns().iter().any(|c| c == key),
+                                    onchange: move |evt| {
+                                        if evt.checked() {
+                                            columns.write().push(key.to_string());
+                                        } else {
+                                            columns.write().retain(|c| c != key);
+                                        }
+                                    },
+                                }
+                                "{label}"
+                            }
+                        }
+                    }
+                }
+
+                // 出力形式
+                div { class: SECTION_CLASS,
+                    h3 { class: SECTION_TITLE_CLASS, "出力形式" }
+                    div { class: "grid grid-cols-[240px_auto_1fr] gap-4 items-end justify-items-start",
+                        div { class: "space-y-2",
+                            label { class: "block text-sm font-medium text-gray-700", "形式" }
+                            select {
+                                class: INPUT_CLASS,
+                                onchange: move |evt| {
+                                    if let Some(f) = ExportFormat::parse(&evt.value()) {
+                                        format.set(f);
+                                    }
+                                },
+                                for f in ExportFormat::ALL {
+                                    option {
+                                        value: "{f.as_str()}",
+                                        selected: format() == f,
+                                        "{f.display_name()}"
+                                    }
+                                }
+                            }
+                        }
+                        if !columns().is_empty() {
+                            a {
+                                class: BUTTON_PRIMARY_CLASS,
+                                href: request.url(),
+                                download: request.filename(chrono::Local::now().date_naive()),
+                                "⬇ ダウンロード"
+                            }
+                        }
+                    }
+                    if format() == ExportFormat::CsvShiftJis {
+                        p { class: "mt-4 text-sm text-gray-600",
+                            "Shift_JISで表せない文字（一部の旧字体など）は「&#数字;」の形式で出力されます。"
+                        }
+                    }
+                    if columns().is_empty() {
+                        p { class: "mt-4 text-sm text-red-500", "出力する列を1つ以上選択してください" }
+                    }
+                }
+            }
+        }
+    }
+}
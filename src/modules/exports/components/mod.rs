@@ -0,0 +1,5 @@
+// データ出力のコンポーネント
+
+mod export_page;
+
+pub use export_page::ExportPage;
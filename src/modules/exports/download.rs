@@ -0,0 +1,214 @@
+//! データ出力のダウンロード（サーバー専用）
+//!
+//! `GET /api/exports?dataset=...&format=...&columns=...&include_retired=1` で、
+//! 従業員台帳・各履歴をCSVまたはxlsxのファイルとして返す。
+//! CSVはデータベースから行を受け取りながら一定の行数ごとに変換して順次送信する
+//! （xlsxは形式上まとめて作成してから送信する）。
+
+use super::models::{ExportFormat, ExportRequest};
+use super::repository::{ExportRepository, ExportRows};
+use super::writer;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::session::user_from_cookies;
+use crate::modules::s3_service;
+use axum::body::Body;
+use axum::extract::Query;
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tower_cookies::Cookies;
+
+/// ダウンロードエンドポイントのパス（Axumのルート定義用）
+pub const ROUTE: &str = "/api/exports";
+
+/// CSVを1回に変換・送信する行数
+const CSV_CHUNK_ROWS: usize = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    dataset: String,
+    #[serde(default)]
+    format: String,
+    /// 出力する列のキー（カンマ区切り、省略時はすべて）
+    #[serde(default)]
+    columns: String,
+    /// 1なら退職者を含める
+    #[serde(default)]
+    include_retired: Option<u8>,
+}
+
+/// 選択された列だけを取り出す
+fn select_columns(row: Vec<String>, indexes: &[usize]) -> Vec<String> {
+    indexes
+        .iter()
+        .map(|&i| row.get(i).cloned().unwrap_or_default())
+        .collect()
+}
+
+/// データベースから受け取った行を一定の行数ごとにCSVに変換して書き込む
+async fn stream_csv<W>(
+    out: &mut W,
+    format: ExportFormat,
+    headers: &[&str],
+    indexes: &[usize],
+    mut rows: ExportRows,
+) -> Result<(), String>
+where
+    W: AsyncWrite + Unpin,
+{
+    let header = writer::write_csv_header(format, headers)?;
+    out.write_all(&header).await.map_err(|e| e.to_string())?;
+
+    let mut chunk = Vec::with_capacity(CSV_CHUNK_ROWS);
+    while let Some(row) = rows.try_next().await.map_err(|e| e.to_string())? {
+        chunk.push(select_columns(row, indexes));
+        if chunk.len() == CSV_CHUNK_ROWS {
+            let bytes = writer::write_csv_rows(format, &chunk)?;
+            out.write_all(&bytes).await.map_err(|e| e.to_string())?;
+            chunk.clear();
+        }
+    }
+    if !chunk.is_empty() {
+        let bytes = writer::write_csv_rows(format, &chunk)?;
+        out.write_all(&bytes).await.map_err(|e| e.to_string())?;
+    }
+    out.shutdown().await.map_err(|e| e.to_string())
+}
+
+/// 従業員台帳・各履歴をCSVまたはxlsxで返す
+///
+/// 個人情報を一括で持ち出せるため、従業員情報の編集権限を要求する
+pub async fn serve_export(cookies: Cookies, Query(query): Query<ExportQuery>) -> Response {
+    match user_from_cookies(&cookies).await {
+        Ok(Some(user)) if user.can(Permission::EditEmployee) => {}
+        Ok(Some(_)) => return StatusCode::FORBIDDEN.into_response(),
+        Ok(None) => return StatusCode::UNAUTHORIZED.into_response(),
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+
+    let request = match ExportRequest::from_query(
+        &query.dataset,
+        &query.format,
+        &query.columns,
+        query.include_retired == Some(1),
+    ) {
+        Ok(request) => request,
+        Err(e) => return (StatusCode::BAD_REQUEST, e).into_response(),
+    };
+
+    // バリデーション
+    let indexes = request.dataset.column_indexes(&request.columns);
+    if indexes.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            "出力する列を1つ以上選択してください",
+        )
+            .into_response();
+    }
+
+    let columns = request.dataset.columns();
+    let headers: Vec<&'static str> = indexes.iter().map(|&i| columns[i].1).collect();
+    let rows = match ExportRepository::fetch_rows(request.dataset, request.include_retired).await {
+        Ok(rows) => rows,
+        Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    };
+
+    let body = match request.format {
+        // xlsxは形式上、全行をそろえてから作成する
+        ExportFormat::Xlsx => {
+            let rows: Vec<Vec<String>> = match rows
+                .map_ok(|row| select_columns(row, &indexes))
+                .try_collect()
+                .await
+            {
+                Ok(rows) => rows,
+                Err(e) => {
+                    return (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response()
+                }
+            };
+            match writer::write(
+                request.format,
+                request.dataset.display_name(),
+                &headers,
+                &rows,
+            ) {
+                Ok(bytes) => Body::from(bytes),
+                Err(e) => return (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+            }
+        }
+        // CSVは行を受け取りながら変換して送信する
+        ExportFormat::CsvUtf8 | ExportFormat::CsvShiftJis => {
+            let (mut out, reader) = tokio::io::duplex(64 * 1024);
+            let format = request.format;
+            tokio::spawn(async move {
+                // 送信を始めた後はエラーを返せないため、失敗した場合はログに残して打ち切る
+                if let Err(e) = stream_csv(&mut out, format, &headers, &indexes, rows).await {
+                    eprintln!("⚠️ Failed to write data export: {}", e);
+                }
+            });
+            Body::from_stream(tokio_util::io::ReaderStream::new(reader))
+        }
+    };
+
+    let filename = request.filename(chrono::Local::now().date_naive());
+    (
+        [
+            (header::CONTENT_TYPE, request.format.mime_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                s3_service::content_disposition(&filename, true),
+            ),
+            (header::CACHE_CONTROL, "private, no-store".to_string()),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_stream_csv_matches_whole_file() {
+        // 区切りをまたぐ行数で、選択した列だけが出力されることを確認する
+        let rows: Vec<Vec<String>> = (0..CSV_CHUNK_ROWS + 1)
+            .map(|i| {
+                vec![
+                    format!("E{:04}", i),
+                    "山田 太郎".to_string(),
+                    "本社".to_string(),
+                ]
+            })
+            .collect();
+        let selected: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| vec![row[2].clone(), row[0].clone()])
+            .collect();
+        let stream: ExportRows = Box::pin(futures_util::stream::iter(rows.into_iter().map(Ok)));
+
+        let mut bytes = Vec::new();
+        stream_csv(
+            &mut bytes,
+            ExportFormat::CsvShiftJis,
+            &["営業所", "社員番号"],
+            &[2, 0],
+            stream,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            bytes,
+            writer::write(
+                ExportFormat::CsvShiftJis,
+                "test",
+                &["営業所", "社員番号"],
+                &selected
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,15 @@
+//! データ出力モジュール
+//! 監査・社労士向けに従業員台帳と各履歴をCSV（UTF-8 / Shift_JIS）またはxlsxで出力する
+
+pub mod components;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod download;
+#[cfg(feature = "server")]
+pub mod repository;
+#[cfg(feature = "server")]
+pub mod writer;
+
+pub use components::ExportPage;
+pub use models::{ExportDataset, ExportFormat, ExportRequest};
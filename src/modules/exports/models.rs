@@ -0,0 +1,383 @@
+//! データ出力のモデル
+
+use serde::{Deserialize, Serialize};
+
+/// 出力対象のデータ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExportDataset {
+    /// 従業員台帳
+    #[default]
+    Employees,
+    /// 運転免許
+    Licenses,
+    /// 資格
+    Qualifications,
+    /// 保険
+    Insurance,
+    /// 健康診断
+    HealthCheckups,
+    /// 適性診断
+    AptitudeCheckups,
+}
+
+/// 従業員を特定するための共通列（履歴データの先頭に付ける）
+const EMPLOYEE_KEY_COLUMNS: [(&str, &str); 3] = [
+    ("employee_code", "社員番号"),
+    ("employee_name", "氏名"),
+    ("office", "営業所"),
+];
+
+const EMPLOYEE_COLUMNS: &[(&str, &str)] = &[
+    ("employee_code", "社員番号"),
+    ("last_name", "姓"),
+    ("first_name", "名"),
+    ("last_name_kana", "姓（カナ）"),
+    ("first_name_kana", "名（カナ）"),
+    ("legal_name", "戸籍上の氏名"),
+    ("birth_date", "生年月日"),
+    ("gender", "性別"),
+    ("email", "メールアドレス"),
+    ("phone", "電話番号"),
+    ("mobile", "携帯電話"),
+    ("postal_code", "郵便番号"),
+    ("address", "現住所"),
+    ("company", "会社"),
+    ("office", "営業所"),
+    ("department", "部署"),
+    ("position", "役職"),
+    ("start_date", "入社日"),
+    ("end_date", "退職日"),
+    ("driver_start_date", "運転者選任日"),
+    ("driver_end_date", "運転者解任日"),
+    ("driver_end_note", "解任理由"),
+    ("is_active", "在籍"),
+    ("updated_at", "更新日時"),
+];
+
+const LICENSE_COLUMNS: &[(&str, &str)] = &[
+    EMPLOYEE_KEY_COLUMNS[0],
+    EMPLOYEE_KEY_COLUMNS[1],
+    EMPLOYEE_KEY_COLUMNS[2],
+    ("license_type", "免許の種類"),
+    ("license_number", "免許証番号"),
+    ("issue_date", "交付日"),
+    ("expiration_date", "有効期限"),
+    ("issuing_authority", "交付公安委員会"),
+    ("conditions", "条件"),
+    ("is_active", "有効"),
+];
+
+const QUALIFICATION_COLUMNS: &[(&str, &str)] = &[
+    EMPLOYEE_KEY_COLUMNS[0],
+    EMPLOYEE_KEY_COLUMNS[1],
+    EMPLOYEE_KEY_COLUMNS[2],
+    ("qualification_type", "資格の種類"),
+    ("qualification_number", "資格番号"),
+    ("issue_date", "取得日"),
+    ("expiration_date", "有効期限"),
+    ("issuing_authority", "発行機関"),
+    ("is_active", "有効"),
+];
+
+const INSURANCE_COLUMNS: &[(&str, &str)] = &[
+    EMPLOYEE_KEY_COLUMNS[0],
+    EMPLOYEE_KEY_COLUMNS[1],
+    EMPLOYEE_KEY_COLUMNS[2],
+    ("insurance_type", "保険の種類"),
+    ("insurance_number", "保険番号"),
+    ("insurer_name", "保険者名"),
+    ("start_date", "加入日"),
+    ("end_date", "喪失日"),
+    ("is_active", "加入中"),
+];
+
+const HEALTH_CHECKUP_COLUMNS: &[(&str, &str)] = &[
+    EMPLOYEE_KEY_COLUMNS[0],
+    EMPLOYEE_KEY_COLUMNS[1],
+    EMPLOYEE_KEY_COLUMNS[2],
+    ("checkup_type", "健診の種類"),
+    ("checkup_date", "受診日"),
+    ("expiration_date", "有効期限"),
+    ("medical_institution", "医療機関"),
+    ("result", "結果"),
+    ("notes", "備考"),
+    ("is_active", "有効"),
+];
+
+const APTITUDE_CHECKUP_COLUMNS: &[(&str, &str)] = &[
+    EMPLOYEE_KEY_COLUMNS[0],
+    EMPLOYEE_KEY_COLUMNS[1],
+    EMPLOYEE_KEY_COLUMNS[2],
+    ("checkup_type", "診断の種類"),
+    ("checkup_date", "受診日"),
+    ("expiration_date", "有効期限"),
+    ("testing_organization", "実施機関"),
+    ("result", "結果"),
+    ("notes", "備考"),
+    ("is_active", "有効"),
+];
+
+impl ExportDataset {
+    pub const ALL: [ExportDataset; 6] = [
+        ExportDataset::Employees,
+        ExportDataset::Licenses,
+        ExportDataset::Qualifications,
+        ExportDataset::Insurance,
+        ExportDataset::HealthCheckups,
+        ExportDataset::AptitudeCheckups,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportDataset::Employees => "employees",
+            ExportDataset::Licenses => "licenses",
+            ExportDataset::Qualifications => "qualifications",
+            ExportDataset::Insurance => "insurance",
+            ExportDataset::HealthCheckups => "health_checkups",
+            ExportDataset::AptitudeCheckups => "aptitude_checkups",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.as_str() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ExportDataset::Employees => "従業員台帳",
+            ExportDataset::Licenses => "運転免許",
+            ExportDataset::Qualifications => "資格",
+            ExportDataset::Insurance => "保険",
+            ExportDataset::HealthCheckups => "健康診断",
+            ExportDataset::AptitudeCheckups => "適性診断",
+        }
+    }
+
+    /// 出力できる列（キー, 見出し）
+    /// リポジトリが返す各行の値はこの順序に並ぶ
+    pub fn columns(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ExportDataset::Employees => EMPLOYEE_COLUMNS,
+            ExportDataset::Licenses => LICENSE_COLUMNS,
+            ExportDataset::Qualifications => QUALIFICATION_COLUMNS,
+            ExportDataset::Insurance => INSURANCE_COLUMNS,
+            ExportDataset::HealthCheckups => HEALTH_CHECKUP_COLUMNS,
+            ExportDataset::AptitudeCheckups => APTITUDE_CHECKUP_COLUMNS,
+        }
+    }
+
+    /// 選択された列キーを列番号に変換（並びは`columns()`の順）
+    /// 空の場合はすべての列、未知のキーは無視する
+    pub fn column_indexes(&self, selected: &[String]) -> Vec<usize> {
+        let columns = self.columns();
+        if selected.is_empty() {
+            return (0..columns.len()).collect();
+        }
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(_, (key, _))| selected.iter().any(|s| s == key))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// 出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExportFormat {
+    /// Excel（xlsx）
+    #[default]
+    Xlsx,
+    /// CSV（UTF-8、BOM付き）
+    CsvUtf8,
+    /// CSV（Shift_JIS）日本語版Excelでそのまま開ける
+    CsvShiftJis,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [
+        ExportFormat::Xlsx,
+        ExportFormat::CsvUtf8,
+        ExportFormat::CsvShiftJis,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::CsvUtf8 => "csv_utf8",
+            ExportFormat::CsvShiftJis => "csv_sjis",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => "Excel（xlsx）",
+            ExportFormat::CsvUtf8 => "CSV（UTF-8）",
+            ExportFormat::CsvShiftJis => "CSV（Shift_JIS）",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::CsvUtf8 | ExportFormat::CsvShiftJis => "csv",
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ExportFormat::CsvUtf8 | ExportFormat::CsvShiftJis => "text/csv",
+        }
+    }
+}
+
+/// 出力条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct ExportRequest {
+    pub dataset: ExportDataset,
+    pub format: ExportFormat,
+    /// 出力する列のキー（空の場合はすべて）
+    pub columns: Vec<String>,
+    /// 退職者を含める
+    pub include_retired: bool,
+}
+
+impl ExportRequest {
+    /// ファイルを返すエンドポイントのURL
+    pub fn url(&self) -> String {
+        let mut url = format!(
+            "/api/exports?dataset={}&format={}",
+            self.dataset.as_str(),
+            self.format.as_str()
+        );
+        if !self.columns.is_empty() {
+            url.push_str(&format!("&columns={}", self.columns.join(",")));
+        }
+        if self.include_retired {
+            url.push_str("&include_retired=1");
+        }
+        url
+    }
+
+    /// エンドポイントのクエリから出力条件を組み立てる
+    pub fn from_query(
+        dataset: &str,
+        format: &str,
+        columns: &str,
+        include_retired: bool,
+    ) -> Result<Self, String> {
+        let dataset = ExportDataset::parse(dataset)
+            .ok_or_else(|| format!("不明な出力対象です: {}", dataset))?;
+        let format =
+            ExportFormat::parse(format).ok_or_else(|| format!("不明な出力形式です: {}", format))?;
+        let columns = columns
+            .split(',')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok(Self {
+            dataset,
+            format,
+            columns,
+            include_retired,
+        })
+    }
+
+    /// ダウンロード時のファイル名（出力対象と日付）
+    pub fn filename(&self, date: chrono::NaiveDate) -> String {
+        format!(
+            "{}_{}.{}",
+            self.dataset.display_name(),
+            date.format("%Y%m%d"),
+            self.format.extension()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_indexes_empty_selects_all() {
+        let dataset = ExportDataset::Licenses;
+        assert_eq!(
+            dataset.column_indexes(&[]),
+            (0..dataset.columns().len()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_column_indexes_keeps_definition_order() {
+        let selected = vec![
+            "expiration_date".to_string(),
+            "employee_code".to_string(),
+            "unknown".to_string(),
+        ];
+        assert_eq!(
+            ExportDataset::Licenses.column_indexes(&selected),
+            vec![0, 6]
+        );
+    }
+
+    #[test]
+    fn test_column_keys_are_unique() {
+        for dataset in ExportDataset::ALL {
+            let columns = dataset.columns();
+            for (i, (key, _)) in columns.iter().enumerate() {
+                assert!(
+                    columns[i + 1..].iter().all(|(k, _)| k != key),
+                    "{} の列キー {} が重複しています",
+                    dataset.as_str(),
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_roundtrip() {
+        for dataset in ExportDataset::ALL {
+            assert_eq!(ExportDataset::parse(dataset.as_str()), Some(dataset));
+        }
+        for format in ExportFormat::ALL {
+            assert_eq!(ExportFormat::parse(format.as_str()), Some(format));
+        }
+    }
+
+    #[test]
+    fn test_request_url_roundtrip() {
+        let request = ExportRequest {
+            dataset: ExportDataset::Licenses,
+            format: ExportFormat::CsvShiftJis,
+            columns: vec!["employee_code".to_string(), "expiration_date".to_string()],
+            include_retired: true,
+        };
+        assert_eq!(
+            request.url(),
+            "/api/exports?dataset=licenses&format=csv_sjis&columns=employee_code,expiration_date&include_retired=1"
+        );
+
+        let parsed = ExportRequest::from_query(
+            "licenses",
+            "csv_sjis",
+            "employee_code,expiration_date",
+            true,
+        )
+        .unwrap();
+        assert_eq!(parsed, request);
+        assert!(ExportRequest::from_query("unknown", "xlsx", "", false).is_err());
+        assert!(ExportRequest::from_query("licenses", "pdf", "", false).is_err());
+
+        let date = chrono::NaiveDate::from_ymd_opt(2026, 4, 1).unwrap();
+        assert_eq!(request.filename(date), "運転免許_20260401.csv");
+    }
+}
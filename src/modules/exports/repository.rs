@@ -0,0 +1,173 @@
+//! データ出力のリポジトリ層
+//! 各クエリは`ExportDataset::columns()`と同じ順序の文字列配列を1行として返す
+
+use super::models::ExportDataset;
+use crate::db;
+use futures_util::stream::BoxStream;
+
+/// 出力する行のストリーム（1行ずつデータベースから受け取る）
+pub type ExportRows = BoxStream<'static, Result<Vec<String>, sqlx::Error>>;
+
+pub struct ExportRepository;
+
+impl ExportRepository {
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 出力対象の行を順に取得する（全行をまとめて読み込まない）
+    pub async fn fetch_rows(
+        dataset: ExportDataset,
+        include_retired: bool,
+    ) -> Result<ExportRows, sqlx::Error> {
+        let pool = Self::pool().await?;
+        Ok(match dataset {
+            ExportDataset::Employees => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name,
+                        e.first_name,
+                        COALESCE(e.last_name_kana, ''),
+                        COALESCE(e.first_name_kana, ''),
+                        COALESCE(e.legal_name, ''),
+                        COALESCE(to_char(e.birth_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(e.gender, ''),
+                        COALESCE(e.email, ''),
+                        COALESCE(e.phone, ''),
+                        COALESCE(e.mobile, ''),
+                        COALESCE(a.postal_code, ''),
+                        CONCAT(a.prefecture, a.city, a.street, ' ' || a.building),
+                        COALESCE(c.name, ''),
+                        COALESCE(o.name, ''),
+                        COALESCE(d.name, ''),
+                        COALESCE(p.name, ''),
+                        COALESCE(to_char(e.start_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(e.end_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(e.driver_start_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(e.driver_end_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(e.driver_end_note, ''),
+                        CASE WHEN e.is_active THEN '在籍' ELSE '退職' END,
+                        to_char(e.updated_at, 'YYYY-MM-DD HH24:MI')
+                     ] AS "row!: Vec<String>"
+                     FROM employees e
+                     LEFT JOIN addresses a ON a.employee_id = e.id AND a.is_current = TRUE
+                     LEFT JOIN companies c ON c.id = e.company_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     LEFT JOIN departments d ON d.id = e.department_id
+                     LEFT JOIN positions p ON p.id = e.position_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code"#,
+                include_retired
+            )
+            .fetch(pool),
+            ExportDataset::Licenses => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name || ' ' || e.first_name,
+                        COALESCE(o.name, ''),
+                        t.name,
+                        COALESCE(l.license_number, ''),
+                        COALESCE(to_char(l.issue_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(l.expiration_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(l.issuing_authority, ''),
+                        COALESCE(l.conditions, ''),
+                        CASE WHEN l.is_active THEN '有効' ELSE '無効' END
+                     ] AS "row!: Vec<String>"
+                     FROM licenses l
+                     JOIN employees e ON e.id = l.employee_id
+                     JOIN license_types t ON t.id = l.license_type_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code, t.display_order, l.issue_date"#,
+                include_retired
+            )
+            .fetch(pool),
+            ExportDataset::Qualifications => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name || ' ' || e.first_name,
+                        COALESCE(o.name, ''),
+                        t.name,
+                        COALESCE(q.qualification_number, ''),
+                        COALESCE(to_char(q.issue_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(q.expiration_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(q.issuing_authority, ''),
+                        CASE WHEN q.is_active THEN '有効' ELSE '無効' END
+                     ] AS "row!: Vec<String>"
+                     FROM qualifications q
+                     JOIN employees e ON e.id = q.employee_id
+                     JOIN qualification_types t ON t.id = q.qualification_type_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code, q.issue_date"#,
+                include_retired
+            )
+            .fetch(pool),
+            ExportDataset::Insurance => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name || ' ' || e.first_name,
+                        COALESCE(o.name, ''),
+                        t.name,
+                        COALESCE(i.insurance_number, ''),
+                        COALESCE(i.insurer_name, ''),
+                        COALESCE(to_char(i.start_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(to_char(i.end_date, 'YYYY-MM-DD'), ''),
+                        CASE WHEN i.is_active THEN '加入中' ELSE '喪失' END
+                     ] AS "row!: Vec<String>"
+                     FROM insurance_history i
+                     JOIN employees e ON e.id = i.employee_id
+                     JOIN insurance_types t ON t.id = i.insurance_type_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code, i.start_date"#,
+                include_retired
+            )
+            .fetch(pool),
+            ExportDataset::HealthCheckups => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name || ' ' || e.first_name,
+                        COALESCE(o.name, ''),
+                        t.name,
+                        to_char(h.checkup_date, 'YYYY-MM-DD'),
+                        COALESCE(to_char(h.expiration_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(h.medical_institution, ''),
+                        COALESCE(h.result, ''),
+                        COALESCE(h.notes, ''),
+                        CASE WHEN h.is_active THEN '有効' ELSE '無効' END
+                     ] AS "row!: Vec<String>"
+                     FROM health_checkup_history h
+                     JOIN employees e ON e.id = h.employee_id
+                     JOIN health_checkup_types t ON t.id = h.health_checkup_type_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code, h.checkup_date"#,
+                include_retired
+            )
+            .fetch(pool),
+            ExportDataset::AptitudeCheckups => sqlx::query_scalar!(
+                r#"SELECT ARRAY[
+                        e.employee_code,
+                        e.last_name || ' ' || e.first_name,
+                        COALESCE(o.name, ''),
+                        t.name,
+                        to_char(a.checkup_date, 'YYYY-MM-DD'),
+                        COALESCE(to_char(a.expiration_date, 'YYYY-MM-DD'), ''),
+                        COALESCE(a.testing_organization, ''),
+                        COALESCE(a.result, ''),
+                        COALESCE(a.notes, ''),
+                        CASE WHEN a.is_active THEN '有効' ELSE '無効' END
+                     ] AS "row!: Vec<String>"
+                     FROM aptitude_checkup_history a
+                     JOIN employees e ON e.id = a.employee_id
+                     JOIN aptitude_checkup_types t ON t.id = a.aptitude_checkup_type_id
+                     LEFT JOIN offices o ON o.id = e.office_id
+                     WHERE ($1 OR e.is_active = TRUE)
+                     ORDER BY e.employee_code, a.checkup_date"#,
+                include_retired
+            )
+            .fetch(pool),
+        })
+    }
+}
@@ -0,0 +1,150 @@
+//! CSV・xlsxファイルの作成
+
+use super::models::ExportFormat;
+
+/// 見出しと行からファイルを作成
+pub fn write(
+    format: ExportFormat,
+    sheet_name: &str,
+    headers: &[&str],
+    rows: &[Vec<String>],
+) -> Result<Vec<u8>, String> {
+    match format {
+        ExportFormat::Xlsx => write_xlsx(sheet_name, headers, rows),
+        ExportFormat::CsvUtf8 | ExportFormat::CsvShiftJis => {
+            let mut bytes = write_csv_header(format, headers)?;
+            bytes.extend(write_csv_rows(format, rows)?);
+            Ok(bytes)
+        }
+    }
+}
+
+/// CSVの見出し行を作成（UTF-8の場合は先頭にBOMを付ける）
+///
+/// 続けて`write_csv_rows`で作成した行を順に送れば、`write`と同じファイルになる
+pub fn write_csv_header(format: ExportFormat, headers: &[&str]) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    if format == ExportFormat::CsvUtf8 {
+        // Excelで文字化けしないようBOMを付ける
+        bytes.extend([0xEF, 0xBB, 0xBF]);
+    }
+    bytes.extend(write_csv(format, std::iter::once(headers))?);
+    Ok(bytes)
+}
+
+/// CSVの行を作成（見出しなし）
+pub fn write_csv_rows(format: ExportFormat, rows: &[Vec<String>]) -> Result<Vec<u8>, String> {
+    write_csv(format, rows)
+}
+
+fn write_csv<I>(format: ExportFormat, records: I) -> Result<Vec<u8>, String>
+where
+    I: IntoIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: AsRef<[u8]>,
+{
+    let mut writer = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(Vec::new());
+    for record in records {
+        writer.write_record(record).map_err(|e| e.to_string())?;
+    }
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    match format {
+        ExportFormat::CsvShiftJis => {
+            // Shift_JISにない文字（一部の異体字など）は数値文字参照に置き換わる
+            let text = String::from_utf8(bytes).map_err(|e| e.to_string())?;
+            let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode(&text);
+            Ok(bytes.into_owned())
+        }
+        _ => Ok(bytes),
+    }
+}
+
+fn write_xlsx(sheet_name: &str, headers: &[&str], rows: &[Vec<String>]) -> Result<Vec<u8>, String> {
+    use rust_xlsxwriter::{Format, Workbook};
+
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_name(sheet_name).map_err(|e| e.to_string())?;
+
+    let header_format = Format::new().set_bold().set_background_color(0xFDE68A);
+    for (col, header) in headers.iter().enumerate() {
+        worksheet
+            .write_string_with_format(0, col as u16, *header, &header_format)
+            .map_err(|e| e.to_string())?;
+    }
+    // 社員番号や電話番号の先頭の0が落ちないよう、すべて文字列で書き込む
+    for (i, row) in rows.iter().enumerate() {
+        for (col, value) in row.iter().enumerate() {
+            if !value.is_empty() {
+                worksheet
+                    .write_string(i as u32 + 1, col as u16, value)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+    worksheet
+        .set_freeze_panes(1, 0)
+        .map_err(|e| e.to_string())?;
+    worksheet.autofit();
+
+    workbook.save_to_buffer().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows() -> Vec<Vec<String>> {
+        vec![vec!["0012".to_string(), "髙橋, 一郎".to_string()]]
+    }
+
+    #[test]
+    fn test_csv_utf8_has_bom_and_quotes() {
+        let bytes = write(
+            ExportFormat::CsvUtf8,
+            "test",
+            &["社員番号", "氏名"],
+            &rows(),
+        )
+        .unwrap();
+        assert_eq!(&bytes[..3], &[0xEF, 0xBB, 0xBF]);
+        let text = String::from_utf8(bytes[3..].to_vec()).unwrap();
+        assert_eq!(text, "社員番号,氏名\r\n0012,\"髙橋, 一郎\"\r\n");
+    }
+
+    #[test]
+    fn test_csv_shift_jis() {
+        let bytes = write(ExportFormat::CsvShiftJis, "test", &["社員番号"], &[]).unwrap();
+        let (expected, _, _) = encoding_rs::SHIFT_JIS.encode("社員番号\r\n");
+        assert_eq!(bytes, expected.into_owned());
+    }
+
+    #[test]
+    fn test_csv_in_chunks_matches_whole_file() {
+        for format in [ExportFormat::CsvUtf8, ExportFormat::CsvShiftJis] {
+            let rows = vec![rows()[0].clone(), rows()[0].clone()];
+            let mut bytes = write_csv_header(format, &["社員番号", "氏名"]).unwrap();
+            for chunk in rows.chunks(1) {
+                bytes.extend(write_csv_rows(format, chunk).unwrap());
+            }
+            assert_eq!(
+                bytes,
+                write(format, "test", &["社員番号", "氏名"], &rows).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_xlsx_is_zip() {
+        let bytes = write(
+            ExportFormat::Xlsx,
+            "従業員台帳",
+            &["社員番号", "氏名"],
+            &rows(),
+        )
+        .unwrap();
+        assert_eq!(&bytes[..2], b"PK");
+    }
+}
@@ -0,0 +1,256 @@
+use super::guidance_compliance_page::ComplianceCell;
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::guidance_compliance::handlers::{
+    create_driver_accident, delete_driver_accident, get_driver_accidents_by_employee,
+    get_employee_guidance_compliance,
+};
+use crate::modules::guidance_compliance::models::{CreateDriverAccident, DriverAccident};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 指導教育の実施状況と事故歴（従業員詳細用）
+#[component]
+pub fn DriverGuidanceSection(employee_id: i32) -> Element {
+    let can_edit = use_permission(Permission::EditDriver);
+    let mut compliance =
+        use_resource(move || async move { get_employee_guidance_compliance(employee_id).await });
+    let mut accidents =
+        use_resource(move || async move { get_driver_accidents_by_employee(employee_id).await });
+    let mut show_add_form = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+
+    let handle_delete = move |id: i32| async move {
+        error_message.set(String::new());
+        match delete_driver_accident(id).await {
+            Ok(()) => {
+                accidents.restart();
+                compliance.restart();
+            }
+            Err(e) => error_message.set(format!("事故歴の削除に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                "指導教育の実施状況"
+            }
+
+            match &*compliance.read_unchecked() {
+                Some(Ok(Some(result))) => rsx! {
+                    div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                        for (requirement, cell) in result.requirements.iter().zip(result.driver.cells.iter()) {
+                            div { key: "{requirement.type_id}", class: "border border-gray-200 rounded-lg p-3",
+                                p { class: "text-xs text-gray-500 font-semibold mb-1", "{requirement.name}" }
+                                ComplianceCell { cell: cell.clone() }
+                            }
+                        }
+                    }
+                },
+                Some(Ok(None)) => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "選任中の運転者ではないため判定していません" }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "実施状況の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+
+            // 事故歴（死傷事故は事故惹起運転者への特別な指導の契機）
+            div { class: "mt-6",
+                div { class: "flex items-center justify-between mb-3",
+                    h4 { class: "font-semibold text-gray-800", "事故歴" }
+                    if can_edit() {
+                        button {
+                            class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors text-sm",
+                            disabled: show_add_form(),
+                            onclick: move |_| show_add_form.set(true),
+                            "＋ 事故を登録"
+                        }
+                    }
+                }
+
+                if !error_message().is_empty() {
+                    p { class: "mb-3 text-sm text-red-700", "❌ {error_message}" }
+                }
+
+                if show_add_form() {
+                    DriverAccidentForm {
+                        employee_id,
+                        on_save: move |_| {
+                            show_add_form.set(false);
+                            accidents.restart();
+                            compliance.restart();
+                        },
+                        on_cancel: move |_| show_add_form.set(false),
+                    }
+                }
+
+                match &*accidents.read_unchecked() {
+                    Some(Ok(list)) if list.is_empty() => rsx! {
+                        p { class: "text-sm text-gray-500", "事故歴は登録されていません" }
+                    },
+                    Some(Ok(list)) => rsx! {
+                        div { class: "space-y-2",
+                            for accident in list.iter() {
+                                DriverAccidentRow {
+                                    key: "{accident.id}",
+                                    accident: accident.clone(),
+                                    can_delete: can_edit(),
+                                    on_delete: move |id| {
+                                        spawn(handle_delete(id));
+                                    },
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-sm text-red-500", "事故歴の読み込みに失敗しました: {e}" }
+                    },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DriverAccidentRow(
+    accident: DriverAccident,
+    can_delete: bool,
+    on_delete: EventHandler<i32>,
+) -> Element {
+    let mut confirm = use_signal(|| false);
+    let id = accident.id;
+
+    rsx! {
+        div { class: "flex items-start justify-between border border-gray-200 rounded-lg p-3 text-sm",
+            div {
+                div { class: "flex items-center gap-2",
+                    span { class: "font-semibold text-gray-800", "{accident.accident_date}" }
+                    if accident.is_injury_accident {
+                        span { class: "px-2 py-0.5 rounded-full text-xs bg-red-100 text-red-700", "死傷事故" }
+                    } else {
+                        span { class: "px-2 py-0.5 rounded-full text-xs bg-gray-100 text-gray-600", "物損事故" }
+                    }
+                }
+                if let Some(summary) = &accident.summary {
+                    p { class: "mt-1 text-gray-600", "{summary}" }
+                }
+            }
+            if can_delete {
+                if confirm() {
+                    div { class: "flex gap-2",
+                        button {
+                            class: "text-red-600 hover:text-red-800 text-sm font-semibold",
+                            onclick: move |_| {
+                                confirm.set(false);
+                                on_delete.call(id);
+                            },
+                            "削除する"
+                        }
+                        button {
+                            class: "text-gray-500 hover:text-gray-700 text-sm",
+                            onclick: move |_| confirm.set(false),
+                            "キャンセル"
+                        }
+                    }
+                } else {
+                    button {
+                        class: "text-red-600 hover:text-red-800 text-sm font-semibold",
+                        onclick: move |_| confirm.set(true),
+                        "削除"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DriverAccidentForm(
+    employee_id: i32,
+    on_save: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut accident_date = use_signal(String::new);
+    let mut is_injury_accident = use_signal(|| true);
+    let mut summary = use_signal(String::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+
+    let handle_submit = move |_| async move {
+        let Ok(date) = NaiveDate::parse_from_str(&accident_date(), "%Y-%m-%d") else {
+            error_message.set("事故の発生日を入力してください".to_string());
+            return;
+        };
+        is_saving.set(true);
+        error_message.set(String::new());
+        let data = CreateDriverAccident {
+            employee_id,
+            accident_date: date,
+            is_injury_accident: is_injury_accident(),
+            summary: Some(summary()),
+        };
+        match create_driver_accident(data).await {
+            Ok(_) => on_save.call(()),
+            Err(e) => error_message.set(format!("事故歴の登録に失敗しました: {}", e)),
+        }
+        is_saving.set(false);
+    };
+
+    rsx! {
+        div { class: "mb-4 p-4 border border-amber-300 bg-amber-50 rounded-lg space-y-3",
+            if !error_message().is_empty() {
+                p { class: "text-sm text-red-700", "❌ {error_message}" }
+            }
+            div { class: "grid grid-cols-1 md:grid-cols-2 gap-3",
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "発生日" }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLASS,
+                        value: "{accident_date}",
+                        oninput: move |evt| accident_date.set(evt.value()),
+                    }
+                }
+                label { class: "flex items-center gap-2 text-sm text-gray-700 md:mt-6",
+                    input {
+                        r#type: "checkbox",
+                        checked: is_injury_accident(),
+                        onchange: move |evt| is_injury_accident.set(evt.checked()),
+                    }
+                    "死者又は負傷者が生じた事故（事故惹起運転者講習の対象）"
+                }
+            }
+            div { class: "space-y-1",
+                label { class: "block text-sm font-medium text-gray-700", "概要" }
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "2",
+                    value: "{summary}",
+                    oninput: move |evt| summary.set(evt.value()),
+                }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50",
+                    disabled: is_saving(),
+                    onclick: handle_submit,
+                    if is_saving() { "登録中..." } else { "登録" }
+                }
+            }
+        }
+    }
+}
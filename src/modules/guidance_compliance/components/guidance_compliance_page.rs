@@ -0,0 +1,229 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::guidance_compliance::handlers::get_guidance_compliance;
+use crate::modules::guidance_compliance::models::{
+    ComplianceStatus, DriverCompliance, GuidanceCell, GuidanceRequirement, OfficeCompliance,
+};
+use crate::modules::organization::handlers::get_offices;
+use crate::Route;
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str = "flex items-center h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+
+/// 指導教育の実施状況（営業所別の運転者×指導教育の一覧）
+#[component]
+pub fn GuidanceCompliancePage() -> Element {
+    let mut office_id = use_signal(|| None::<i32>);
+    let mut action_only = use_signal(|| false);
+
+    let offices = use_resource(|| async move { get_offices().await });
+    let matrix = use_resource(move || async move { get_guidance_compliance(office_id()).await });
+
+    rsx! {
+        document::Title { "指導教育の実施状況" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "指導教育の実施状況" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-7xl mx-auto space-y-6",
+                    // 抽出条件
+                    div { class: SECTION_CLASS,
+                        div { class: "grid grid-cols-1 md:grid-cols-4 gap-4 items-end",
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "営業所" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| office_id.set(evt.value().parse().ok()),
+                                    option { value: "", "すべて" }
+                                    if let Some(Ok(offices)) = &*offices.read_unchecked() {
+                                        for office in offices.iter() {
+                                            option {
+                                                value: "{office.id}",
+                                                selected: office_id() == Some(office.id),
+                                                "{office.name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            label { class: "flex items-center gap-2 text-sm text-gray-700 md:col-span-3",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: action_only(),
+                                    onchange: move |evt| action_only.set(evt.checked()),
+                                }
+                                "対応が必要な運転者のみ表示"
+                            }
+                        }
+                    }
+
+                    match &*matrix.read_unchecked() {
+                        Some(Ok(matrix)) => {
+                            let offices: Vec<OfficeCompliance> = matrix
+                                .offices
+                                .iter()
+                                .map(|o| OfficeCompliance {
+                                    drivers: o
+                                        .drivers
+                                        .iter()
+                                        .filter(|d| !action_only() || d.worst_status().needs_action())
+                                        .cloned()
+                                        .collect(),
+                                    ..o.clone()
+                                })
+                                .filter(|o| !o.drivers.is_empty())
+                                .collect();
+                            rsx! {
+                                ComplianceSummary { offices: offices.clone() }
+                                if matrix.requirements.is_empty() {
+                                    div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                                        "義務の指導教育種別が設定されていません"
+                                    }
+                                } else if offices.is_empty() {
+                                    div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                                        "該当する運転者はいません"
+                                    }
+                                }
+                                for office in offices {
+                                    OfficeComplianceSection {
+                                        key: "{office.office_id:?}",
+                                        requirements: matrix.requirements.clone(),
+                                        office,
+                                    }
+                                }
+                            }
+                        }
+                        Some(Err(e)) => rsx! {
+                            div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700",
+                                "エラーが発生しました: {e}"
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex justify-center py-12",
+                                div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 状況ごとの件数
+#[component]
+fn ComplianceSummary(offices: Vec<OfficeCompliance>) -> Element {
+    let statuses = [
+        ComplianceStatus::Overdue,
+        ComplianceStatus::NeverTaken,
+        ComplianceStatus::DueSoon,
+    ];
+
+    rsx! {
+        div { class: "grid grid-cols-1 md:grid-cols-3 gap-4",
+            for status in statuses {
+                {
+                    let count: usize = offices.iter().map(|o| o.count(status)).sum();
+                    rsx! {
+                        div { class: "rounded-xl p-5 {status.badge_class()}",
+                            p { class: "text-sm font-medium", "{status.display_name()}" }
+                            p { class: "text-3xl font-bold mt-1", "{count}件" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 営業所ごとの一覧
+#[component]
+fn OfficeComplianceSection(
+    requirements: Vec<GuidanceRequirement>,
+    office: OfficeCompliance,
+) -> Element {
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "{office.office_name}" }
+                span { class: "text-sm text-gray-500", "運転者 {office.drivers.len()}名" }
+            }
+            div { class: "overflow-x-auto",
+                table { class: "min-w-full text-sm",
+                    thead {
+                        tr { class: "text-left text-gray-600 border-b border-gray-200",
+                            th { class: "py-2 px-3 whitespace-nowrap", "運転者" }
+                            th { class: "py-2 px-3 whitespace-nowrap", "選任日" }
+                            for requirement in requirements.iter() {
+                                th { key: "{requirement.type_id}", class: "py-2 px-3 whitespace-nowrap",
+                                    "{requirement.name}"
+                                    if let Some(months) = requirement.required_frequency_months {
+                                        span { class: "block text-xs font-normal text-gray-400", "{months}か月ごと" }
+                                    } else if let Some(trigger) = requirement.trigger {
+                                        span { class: "block text-xs font-normal text-gray-400", "{trigger.display_name()}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for driver in office.drivers {
+                            DriverComplianceRow { key: "{driver.employee_id}", driver }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn DriverComplianceRow(driver: DriverCompliance) -> Element {
+    let start = driver
+        .driver_start_date
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+
+    rsx! {
+        tr { class: "border-b border-gray-100 align-top",
+            td { class: "py-2 px-3 whitespace-nowrap",
+                Link {
+                    to: Route::EmployeeDetailPage { id: driver.employee_id },
+                    class: "text-amber-700 hover:underline",
+                    "{driver.employee_code} {driver.employee_name}"
+                }
+            }
+            td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{start}" }
+            for cell in driver.cells {
+                td { key: "{cell.type_id}", class: "py-2 px-3",
+                    ComplianceCell { cell }
+                }
+            }
+        }
+    }
+}
+
+/// 1つの指導教育の状況（状況・最終受講日・期限）
+#[component]
+pub(super) fn ComplianceCell(cell: GuidanceCell) -> Element {
+    let format = |d: chrono::NaiveDate| d.format("%Y-%m-%d").to_string();
+
+    rsx! {
+        div { class: "space-y-0.5 whitespace-nowrap",
+            span { class: "px-2 py-0.5 rounded text-xs font-semibold {cell.status.badge_class()}",
+                "{cell.status.display_name()}"
+            }
+            if let Some(last) = cell.last_date {
+                p { class: "text-xs text-gray-500", "受講 {format(last)}" }
+            }
+            if let Some(due) = cell.due_date {
+                p { class: "text-xs text-gray-500", "期限 {format(due)}" }
+            }
+            if let Some(reason) = &cell.reason {
+                p { class: "text-xs text-gray-400", "{reason}" }
+            }
+        }
+    }
+}
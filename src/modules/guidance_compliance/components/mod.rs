@@ -0,0 +1,7 @@
+// 指導教育の実施状況のコンポーネント
+
+mod driver_guidance_section;
+mod guidance_compliance_page;
+
+pub use driver_guidance_section::DriverGuidanceSection;
+pub use guidance_compliance_page::GuidanceCompliancePage;
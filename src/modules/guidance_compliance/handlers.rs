@@ -0,0 +1,145 @@
+//! 指導教育の実施状況のハンドラー層（サーバー関数）
+
+use super::models::{
+    CreateDriverAccident, DriverAccident, EmployeeGuidanceCompliance, GuidanceComplianceMatrix,
+};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{
+    evaluate_driver, group_by_office, DriverCompliance, DriverHistory, GuidanceRequirement,
+};
+#[cfg(feature = "server")]
+use super::repository::GuidanceComplianceRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::guidance_education_history::repository::GuidanceEducationHistoryRepository;
+
+/// 営業所別の指導教育の実施状況を取得（営業所で絞り込み可）
+#[server]
+pub async fn get_guidance_compliance(
+    office_id: Option<i32>,
+) -> Result<GuidanceComplianceMatrix, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let (requirements, drivers) = load_compliance(office_id, None).await?;
+    Ok(GuidanceComplianceMatrix {
+        as_of: chrono::Local::now().date_naive(),
+        requirements,
+        offices: group_by_office(drivers),
+    })
+}
+
+/// 従業員1人の指導教育の実施状況を取得（運転者でない場合はNone）
+#[server]
+pub async fn get_employee_guidance_compliance(
+    employee_id: i32,
+) -> Result<Option<EmployeeGuidanceCompliance>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let (requirements, drivers) = load_compliance(None, Some(employee_id)).await?;
+    Ok(drivers
+        .into_iter()
+        .next()
+        .map(|driver| EmployeeGuidanceCompliance {
+            requirements,
+            driver,
+        }))
+}
+
+/// 従業員の事故歴を取得
+#[server]
+pub async fn get_driver_accidents_by_employee(
+    employee_id: i32,
+) -> Result<Vec<DriverAccident>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    GuidanceComplianceRepository::get_accidents_by_employee_id(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 事故歴を作成
+#[server]
+pub async fn create_driver_accident(
+    data: CreateDriverAccident,
+) -> Result<DriverAccident, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    if data.accident_date > chrono::Local::now().date_naive() {
+        return Err(ServerFnError::new(
+            "事故の発生日に未来の日付は指定できません".to_string(),
+        ));
+    }
+    let data = CreateDriverAccident {
+        summary: data
+            .summary
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty()),
+        ..data
+    };
+
+    GuidanceComplianceRepository::create_accident(data)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 事故歴を削除
+#[server]
+pub async fn delete_driver_accident(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    GuidanceComplianceRepository::delete_accident(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 判定対象の指導教育と運転者ごとの実施状況を読み込む
+#[cfg(feature = "server")]
+async fn load_compliance(
+    office_id: Option<i32>,
+    employee_id: Option<i32>,
+) -> Result<(Vec<GuidanceRequirement>, Vec<DriverCompliance>), ServerFnError> {
+    let to_error = |e: sqlx::Error| ServerFnError::new(e.to_string());
+
+    let requirements: Vec<GuidanceRequirement> =
+        GuidanceEducationHistoryRepository::get_all_guidance_education_types()
+            .await
+            .map_err(to_error)?
+            .iter()
+            .filter_map(GuidanceRequirement::from_type)
+            .collect();
+    let drivers = GuidanceComplianceRepository::get_drivers(office_id, employee_id)
+        .await
+        .map_err(to_error)?;
+    let employee_ids: Vec<i32> = drivers.iter().map(|d| d.employee_id).collect();
+    let records = GuidanceComplianceRepository::get_records(&employee_ids)
+        .await
+        .map_err(to_error)?;
+    let accidents = GuidanceComplianceRepository::get_injury_accident_dates(&employee_ids)
+        .await
+        .map_err(to_error)?;
+
+    let today = chrono::Local::now().date_naive();
+    let drivers = drivers
+        .into_iter()
+        .map(|driver| {
+            let history = DriverHistory {
+                records: records
+                    .iter()
+                    .filter(|r| r.employee_id == driver.employee_id)
+                    .copied()
+                    .collect(),
+                injury_accident_dates: accidents
+                    .iter()
+                    .filter(|(id, _)| *id == driver.employee_id)
+                    .map(|(_, date)| *date)
+                    .collect(),
+            };
+            evaluate_driver(&requirements, driver, &history, today)
+        })
+        .collect();
+    Ok((requirements, drivers))
+}
@@ -0,0 +1,14 @@
+//! 指導教育の実施状況モジュール
+//! 指導教育種別の実施頻度・義務と、初任運転者・事故惹起運転者の特別な指導の契機から、
+//! 選任中の運転者ごとに未受講・期限超過・期限間近を判定する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::{DriverGuidanceSection, GuidanceCompliancePage};
+pub use handlers::*;
+pub use models::{ComplianceStatus, DriverAccident, DriverCompliance, GuidanceComplianceMatrix};
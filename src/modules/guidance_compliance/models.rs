@@ -0,0 +1,537 @@
+//! 指導教育の実施状況のモデル定義
+
+use crate::modules::guidance_education_history::{GuidanceEducationType, GuidanceTrigger};
+use chrono::{DateTime, Duration, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 実施期限が近い場合に「期限間近」とみなす日数
+pub const DUE_SOON_DAYS: i64 = 30;
+
+/// 特別な指導（初任運転者・事故惹起運転者）を乗務開始後に実施できる猶予（月）
+pub const SPECIAL_GUIDANCE_GRACE_MONTHS: u32 = 1;
+
+/// 実施状況
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComplianceStatus {
+    /// 受講歴はあるが実施期限を過ぎている
+    Overdue,
+    /// 一度も受講していない（期限を過ぎている、または期限がない）
+    NeverTaken,
+    /// 未実施だが実施期限前、または次回の期限が近い
+    DueSoon,
+    /// 実施済み
+    Compliant,
+    /// 対象外（選任前・事故歴なしなど）
+    NotRequired,
+}
+
+impl ComplianceStatus {
+    pub const ALL: [ComplianceStatus; 5] = [
+        ComplianceStatus::Overdue,
+        ComplianceStatus::NeverTaken,
+        ComplianceStatus::DueSoon,
+        ComplianceStatus::Compliant,
+        ComplianceStatus::NotRequired,
+    ];
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ComplianceStatus::Overdue => "期限超過",
+            ComplianceStatus::NeverTaken => "未受講",
+            ComplianceStatus::DueSoon => "期限間近",
+            ComplianceStatus::Compliant => "実施済",
+            ComplianceStatus::NotRequired => "対象外",
+        }
+    }
+
+    /// 対応が必要か
+    pub fn needs_action(&self) -> bool {
+        matches!(
+            self,
+            ComplianceStatus::Overdue | ComplianceStatus::NeverTaken | ComplianceStatus::DueSoon
+        )
+    }
+
+    /// 期限から状況を判定（期限前は期限間近。期限を過ぎていれば、実施歴の有無で期限超過と未受講を分ける）
+    pub fn by_due(due_date: NaiveDate, has_record: bool, today: NaiveDate) -> Self {
+        match (due_date < today, has_record) {
+            (true, true) => ComplianceStatus::Overdue,
+            (true, false) => ComplianceStatus::NeverTaken,
+            (false, _) => ComplianceStatus::DueSoon,
+        }
+    }
+
+    /// 実施済みの項目の次回の期限から状況を判定（期限超過・期限間近・実施済）
+    pub fn by_next_due(due_date: NaiveDate, today: NaiveDate, due_soon_days: i64) -> Self {
+        if due_date < today {
+            ComplianceStatus::Overdue
+        } else if due_date <= today + Duration::days(due_soon_days) {
+            ComplianceStatus::DueSoon
+        } else {
+            ComplianceStatus::Compliant
+        }
+    }
+
+    /// バッジ表示用のTailwindクラス
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            ComplianceStatus::Overdue => "bg-red-100 text-red-700 border border-red-300",
+            ComplianceStatus::NeverTaken => "bg-red-50 text-red-700 border border-red-200",
+            ComplianceStatus::DueSoon => "bg-yellow-50 text-yellow-700 border border-yellow-300",
+            ComplianceStatus::Compliant => "bg-green-50 text-green-700 border border-green-300",
+            ComplianceStatus::NotRequired => "bg-gray-50 text-gray-400 border border-gray-200",
+        }
+    }
+}
+
+impl std::fmt::Display for ComplianceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 判定対象の指導教育（義務の種別と、初任運転者・事故惹起運転者の特別な指導）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuidanceRequirement {
+    pub type_id: i32,
+    pub name: String,
+    /// 実施頻度（月）。Noneなら1回の受講で足りる
+    pub required_frequency_months: Option<i32>,
+    pub trigger: Option<GuidanceTrigger>,
+}
+
+impl GuidanceRequirement {
+    /// 指導教育種別から判定対象を作る（義務でも特別な指導でもない種別はNone）
+    pub fn from_type(t: &GuidanceEducationType) -> Option<Self> {
+        if !t.is_mandatory && t.trigger_kind.is_none() {
+            return None;
+        }
+        Some(Self {
+            type_id: t.id,
+            name: t.name.clone(),
+            required_frequency_months: t.required_frequency_months.filter(|m| *m > 0),
+            trigger: t.trigger_kind,
+        })
+    }
+}
+
+/// 指導教育の受講記録
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct GuidanceRecord {
+    pub employee_id: i32,
+    pub guidance_education_type_id: i32,
+    pub education_date: NaiveDate,
+    pub expiration_date: Option<NaiveDate>,
+}
+
+/// 判定対象の運転者（選任中・選任予定）
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct DriverRow {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub driver_start_date: Option<NaiveDate>,
+}
+
+/// 運転者1人の判定に使う記録
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DriverHistory {
+    pub records: Vec<GuidanceRecord>,
+    /// 死傷事故の発生日
+    pub injury_accident_dates: Vec<NaiveDate>,
+}
+
+/// 1つの指導教育の実施状況
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuidanceCell {
+    pub type_id: i32,
+    pub status: ComplianceStatus,
+    /// 最後に受講した日
+    pub last_date: Option<NaiveDate>,
+    /// 実施期限（次回の期限、または特別な指導の期限）
+    pub due_date: Option<NaiveDate>,
+    /// 判定の根拠（選任日・事故の発生日など）
+    pub reason: Option<String>,
+}
+
+/// 運転者1人の実施状況
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriverCompliance {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub driver_start_date: Option<NaiveDate>,
+    /// 判定対象の指導教育と同じ順序
+    pub cells: Vec<GuidanceCell>,
+}
+
+impl DriverCompliance {
+    /// 最も対応が必要な状況
+    pub fn worst_status(&self) -> ComplianceStatus {
+        self.cells
+            .iter()
+            .map(|c| c.status)
+            .min()
+            .unwrap_or(ComplianceStatus::NotRequired)
+    }
+}
+
+/// 営業所ごとの実施状況
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfficeCompliance {
+    pub office_id: Option<i32>,
+    pub office_name: String,
+    pub drivers: Vec<DriverCompliance>,
+}
+
+impl OfficeCompliance {
+    /// 指定した状況のセル数
+    pub fn count(&self, status: ComplianceStatus) -> usize {
+        self.drivers
+            .iter()
+            .flat_map(|d| d.cells.iter())
+            .filter(|c| c.status == status)
+            .count()
+    }
+}
+
+/// 営業所別の実施状況の一覧（行: 運転者、列: 指導教育）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuidanceComplianceMatrix {
+    pub as_of: NaiveDate,
+    pub requirements: Vec<GuidanceRequirement>,
+    pub offices: Vec<OfficeCompliance>,
+}
+
+/// 従業員1人の実施状況（従業員詳細用）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeGuidanceCompliance {
+    pub requirements: Vec<GuidanceRequirement>,
+    pub driver: DriverCompliance,
+}
+
+/// 事故歴
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct DriverAccident {
+    pub id: i32,
+    pub employee_id: i32,
+    pub accident_date: NaiveDate,
+    /// 死者又は負傷者が生じた事故か（事故惹起運転者への特別な指導の契機）
+    pub is_injury_accident: bool,
+    pub summary: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 事故歴作成用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateDriverAccident {
+    pub employee_id: i32,
+    pub accident_date: NaiveDate,
+    pub is_injury_accident: bool,
+    pub summary: Option<String>,
+}
+
+/// 月数を加算した日付（範囲外は上限の日付）
+pub fn add_months(date: NaiveDate, months: u32) -> NaiveDate {
+    date.checked_add_months(Months::new(months))
+        .unwrap_or(NaiveDate::MAX)
+}
+
+/// 運転者1人・指導教育1つの実施状況を判定する
+///
+/// * 初任運転者: 選任日（乗務開始日）から1か月以内に受講していなければ未受講。受講歴があれば実施済
+/// * 事故惹起運転者: 最後の死傷事故の後に受講していなければ、事故から1か月を期限とする
+/// * 定期: 最後の受講の有効期限（未設定なら受講日＋実施頻度）を次回の期限とし、30日以内なら期限間近
+/// * 頻度・契機のない義務: 1回でも受講していれば実施済
+pub fn evaluate(
+    requirement: &GuidanceRequirement,
+    driver_start_date: Option<NaiveDate>,
+    history: &DriverHistory,
+    today: NaiveDate,
+) -> GuidanceCell {
+    let mut taken: Vec<&GuidanceRecord> = history
+        .records
+        .iter()
+        .filter(|r| r.guidance_education_type_id == requirement.type_id)
+        .collect();
+    taken.sort_by_key(|r| r.education_date);
+    let latest = taken.last().copied();
+    let last_date = latest.map(|r| r.education_date);
+
+    let cell = |status, due_date, reason: Option<String>| GuidanceCell {
+        type_id: requirement.type_id,
+        status,
+        last_date,
+        due_date,
+        reason,
+    };
+
+    match requirement.trigger {
+        Some(GuidanceTrigger::InitialDriver) => {
+            let Some(start) = driver_start_date else {
+                return cell(ComplianceStatus::NotRequired, None, None);
+            };
+            let reason = Some(format!("選任日 {}", start.format("%Y-%m-%d")));
+            if latest.is_some() {
+                return cell(ComplianceStatus::Compliant, None, reason);
+            }
+            let due = add_months(start, SPECIAL_GUIDANCE_GRACE_MONTHS);
+            cell(
+                ComplianceStatus::by_due(due, false, today),
+                Some(due),
+                reason,
+            )
+        }
+        Some(GuidanceTrigger::Accident) => {
+            let Some(accident) = history.injury_accident_dates.iter().max().copied() else {
+                return cell(ComplianceStatus::NotRequired, None, None);
+            };
+            let reason = Some(format!("事故 {}", accident.format("%Y-%m-%d")));
+            if taken.iter().any(|r| r.education_date >= accident) {
+                return cell(ComplianceStatus::Compliant, None, reason);
+            }
+            let due = add_months(accident, SPECIAL_GUIDANCE_GRACE_MONTHS);
+            cell(
+                ComplianceStatus::by_due(due, latest.is_some(), today),
+                Some(due),
+                reason,
+            )
+        }
+        None => {
+            let Some(latest) = latest else {
+                return cell(ComplianceStatus::NeverTaken, None, None);
+            };
+            let due = latest.expiration_date.or_else(|| {
+                requirement
+                    .required_frequency_months
+                    .map(|m| add_months(latest.education_date, m as u32))
+            });
+            match due {
+                Some(due) => cell(
+                    ComplianceStatus::by_next_due(due, today, DUE_SOON_DAYS),
+                    Some(due),
+                    None,
+                ),
+                None => cell(ComplianceStatus::Compliant, None, None),
+            }
+        }
+    }
+}
+
+/// 運転者1人の実施状況
+pub fn evaluate_driver(
+    requirements: &[GuidanceRequirement],
+    driver: DriverRow,
+    history: &DriverHistory,
+    today: NaiveDate,
+) -> DriverCompliance {
+    let cells = requirements
+        .iter()
+        .map(|r| evaluate(r, driver.driver_start_date, history, today))
+        .collect();
+    DriverCompliance {
+        employee_id: driver.employee_id,
+        employee_code: driver.employee_code,
+        employee_name: driver.employee_name,
+        office_id: driver.office_id,
+        office_name: driver.office_name,
+        driver_start_date: driver.driver_start_date,
+        cells,
+    }
+}
+
+/// 運転者の実施状況を営業所ごとにまとめる（営業所未設定は末尾、営業所内は社員番号順）
+pub fn group_by_office(drivers: Vec<DriverCompliance>) -> Vec<OfficeCompliance> {
+    let mut offices: Vec<OfficeCompliance> = Vec::new();
+    for driver in drivers {
+        match offices.iter_mut().find(|o| o.office_id == driver.office_id) {
+            Some(office) => office.drivers.push(driver),
+            None => offices.push(OfficeCompliance {
+                office_id: driver.office_id,
+                office_name: driver
+                    .office_name
+                    .clone()
+                    .unwrap_or_else(|| "営業所未設定".to_string()),
+                drivers: vec![driver],
+            }),
+        }
+    }
+
+    offices.sort_by(|a, b| {
+        a.office_id
+            .is_none()
+            .cmp(&b.office_id.is_none())
+            .then_with(|| a.office_name.cmp(&b.office_name))
+    });
+    for office in offices.iter_mut() {
+        office
+            .drivers
+            .sort_by(|a, b| a.employee_code.cmp(&b.employee_code));
+    }
+    offices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn requirement(
+        type_id: i32,
+        months: Option<i32>,
+        trigger: Option<GuidanceTrigger>,
+    ) -> GuidanceRequirement {
+        GuidanceRequirement {
+            type_id,
+            name: format!("講習{}", type_id),
+            required_frequency_months: months,
+            trigger,
+        }
+    }
+
+    fn record(type_id: i32, education_date: NaiveDate) -> GuidanceRecord {
+        GuidanceRecord {
+            employee_id: 1,
+            guidance_education_type_id: type_id,
+            education_date,
+            expiration_date: None,
+        }
+    }
+
+    #[test]
+    fn test_status_by_due() {
+        let today = date(2026, 3, 15);
+        assert_eq!(
+            ComplianceStatus::by_due(date(2026, 3, 15), false, today),
+            ComplianceStatus::DueSoon
+        );
+        assert_eq!(
+            ComplianceStatus::by_due(date(2026, 3, 14), false, today),
+            ComplianceStatus::NeverTaken
+        );
+        assert_eq!(
+            ComplianceStatus::by_due(date(2026, 3, 14), true, today),
+            ComplianceStatus::Overdue
+        );
+
+        assert_eq!(
+            ComplianceStatus::by_next_due(date(2026, 3, 14), today, 30),
+            ComplianceStatus::Overdue
+        );
+        assert_eq!(
+            ComplianceStatus::by_next_due(date(2026, 4, 14), today, 30),
+            ComplianceStatus::DueSoon
+        );
+        assert_eq!(
+            ComplianceStatus::by_next_due(date(2026, 4, 15), today, 30),
+            ComplianceStatus::Compliant
+        );
+    }
+
+    #[test]
+    fn test_initial_driver_guidance() {
+        let req = requirement(1, None, Some(GuidanceTrigger::InitialDriver));
+        let today = date(2026, 3, 15);
+        let empty = DriverHistory::default();
+
+        // 選任日から1か月以内は期限間近、過ぎたら未受講
+        let cell = evaluate(&req, Some(date(2026, 3, 1)), &empty, today);
+        assert_eq!(cell.status, ComplianceStatus::DueSoon);
+        assert_eq!(cell.due_date, Some(date(2026, 4, 1)));
+        let cell = evaluate(&req, Some(date(2026, 1, 10)), &empty, today);
+        assert_eq!(cell.status, ComplianceStatus::NeverTaken);
+
+        // 選任前に受講していれば実施済、選任日がなければ対象外
+        let history = DriverHistory {
+            records: vec![record(1, date(2025, 12, 20))],
+            ..Default::default()
+        };
+        let cell = evaluate(&req, Some(date(2026, 1, 10)), &history, today);
+        assert_eq!(cell.status, ComplianceStatus::Compliant);
+        let cell = evaluate(&req, None, &empty, today);
+        assert_eq!(cell.status, ComplianceStatus::NotRequired);
+    }
+
+    #[test]
+    fn test_accident_guidance() {
+        let req = requirement(2, None, Some(GuidanceTrigger::Accident));
+        let today = date(2026, 6, 1);
+
+        let cell = evaluate(&req, None, &DriverHistory::default(), today);
+        assert_eq!(cell.status, ComplianceStatus::NotRequired);
+
+        // 以前の事故で受講していても、後の事故の後に受講していなければ期限超過
+        let mut history = DriverHistory {
+            records: vec![record(2, date(2025, 2, 1))],
+            injury_accident_dates: vec![date(2025, 1, 20), date(2026, 4, 10)],
+        };
+        let cell = evaluate(&req, None, &history, today);
+        assert_eq!(cell.status, ComplianceStatus::Overdue);
+        assert_eq!(cell.due_date, Some(date(2026, 5, 10)));
+
+        history.records.push(record(2, date(2026, 4, 20)));
+        let cell = evaluate(&req, None, &history, today);
+        assert_eq!(cell.status, ComplianceStatus::Compliant);
+    }
+
+    #[test]
+    fn test_periodic_guidance() {
+        let req = requirement(3, Some(12), None);
+        let today = date(2026, 6, 1);
+
+        let cell = evaluate(&req, None, &DriverHistory::default(), today);
+        assert_eq!(cell.status, ComplianceStatus::NeverTaken);
+
+        let history = DriverHistory {
+            records: vec![record(3, date(2024, 5, 1)), record(3, date(2025, 6, 20))],
+            ..Default::default()
+        };
+        let cell = evaluate(&req, None, &history, today);
+        assert_eq!(cell.status, ComplianceStatus::DueSoon);
+        assert_eq!(cell.last_date, Some(date(2025, 6, 20)));
+        assert_eq!(cell.due_date, Some(date(2026, 6, 20)));
+
+        let cell = evaluate(&req, None, &history, date(2026, 7, 1));
+        assert_eq!(cell.status, ComplianceStatus::Overdue);
+
+        // 有効期限が記録されていればそれを次回の期限とする
+        let mut history = history;
+        history.records[1].expiration_date = Some(date(2027, 3, 31));
+        let cell = evaluate(&req, None, &history, date(2026, 7, 1));
+        assert_eq!(cell.status, ComplianceStatus::Compliant);
+        assert_eq!(cell.due_date, Some(date(2027, 3, 31)));
+    }
+
+    #[test]
+    fn test_group_by_office() {
+        let driver = |id: i32, office_id: Option<i32>, office: Option<&str>| DriverCompliance {
+            employee_id: id,
+            employee_code: format!("{:03}", id),
+            employee_name: "山田 太郎".to_string(),
+            office_id,
+            office_name: office.map(str::to_string),
+            driver_start_date: None,
+            cells: vec![],
+        };
+        let offices = group_by_office(vec![
+            driver(3, None, None),
+            driver(2, Some(1), Some("本社")),
+            driver(1, Some(1), Some("本社")),
+        ]);
+        assert_eq!(offices.len(), 2);
+        assert_eq!(offices[0].office_name, "本社");
+        assert_eq!(offices[0].drivers[0].employee_id, 1);
+        assert_eq!(offices[1].office_name, "営業所未設定");
+    }
+}
@@ -0,0 +1,168 @@
+//! 指導教育の実施状況のリポジトリ層
+
+use super::models::{CreateDriverAccident, DriverAccident, DriverRow, GuidanceRecord};
+use crate::db;
+use crate::modules::audit_log;
+use chrono::NaiveDate;
+
+/// 指導教育の実施状況リポジトリ
+pub struct GuidanceComplianceRepository;
+
+impl GuidanceComplianceRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 判定対象の運転者（在籍中で、選任中または選任予定）を取得
+    pub async fn get_drivers(
+        office_id: Option<i32>,
+        employee_id: Option<i32>,
+    ) -> Result<Vec<DriverRow>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            DriverRow,
+            r#"
+            SELECT
+                e.id AS employee_id,
+                e.employee_code,
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                e.office_id,
+                o.name AS "office_name?",
+                e.driver_start_date
+            FROM employees e
+            LEFT JOIN offices o ON o.id = e.office_id
+            WHERE e.is_active = TRUE
+              AND e.driver_start_date IS NOT NULL
+              AND (e.driver_end_date IS NULL OR e.driver_end_date > CURRENT_DATE)
+              AND ($1::INT IS NULL OR e.office_id = $1)
+              AND ($2::INT IS NULL OR e.id = $2)
+            ORDER BY e.employee_code
+            "#,
+            office_id,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 指定した従業員の指導教育の受講記録を取得
+    pub async fn get_records(employee_ids: &[i32]) -> Result<Vec<GuidanceRecord>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            GuidanceRecord,
+            r#"
+            SELECT employee_id, guidance_education_type_id, education_date, expiration_date
+            FROM guidance_education_history
+            WHERE employee_id = ANY($1)
+            ORDER BY employee_id, education_date
+            "#,
+            employee_ids
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 指定した従業員の死傷事故の発生日を取得
+    pub async fn get_injury_accident_dates(
+        employee_ids: &[i32],
+    ) -> Result<Vec<(i32, NaiveDate)>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let rows = sqlx::query!(
+            r#"
+            SELECT employee_id, accident_date
+            FROM driver_accidents
+            WHERE employee_id = ANY($1) AND is_injury_accident = TRUE
+            "#,
+            employee_ids
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.employee_id, r.accident_date))
+            .collect())
+    }
+
+    /// 従業員の事故歴を取得（新しい順）
+    pub async fn get_accidents_by_employee_id(
+        employee_id: i32,
+    ) -> Result<Vec<DriverAccident>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            DriverAccident,
+            r#"SELECT id, employee_id, accident_date, is_injury_accident, summary,
+                      created_at, updated_at
+               FROM driver_accidents
+               WHERE employee_id = $1
+               ORDER BY accident_date DESC"#,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで事故歴を取得
+    pub async fn get_accident_by_id(id: i32) -> Result<Option<DriverAccident>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            DriverAccident,
+            r#"SELECT id, employee_id, accident_date, is_injury_accident, summary,
+                      created_at, updated_at
+               FROM driver_accidents
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 事故歴を作成
+    pub async fn create_accident(
+        data: CreateDriverAccident,
+    ) -> Result<DriverAccident, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let accident = sqlx::query_as!(
+            DriverAccident,
+            r#"INSERT INTO driver_accidents
+               (employee_id, accident_date, is_injury_accident, summary, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, employee_id, accident_date, is_injury_accident, summary,
+                         created_at, updated_at"#,
+            data.employee_id,
+            data.accident_date,
+            data.is_injury_accident,
+            data.summary
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create(
+            "driver_accidents",
+            accident.id,
+            Some(accident.employee_id),
+            &accident,
+        )
+        .await;
+
+        Ok(accident)
+    }
+
+    /// 事故歴を削除
+    pub async fn delete_accident(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_accident_by_id(id).await?;
+        sqlx::query!(r#"DELETE FROM driver_accidents WHERE id = $1"#, id)
+            .execute(pool)
+            .await?;
+        audit_log::record_delete(
+            "driver_accidents",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
+        Ok(())
+    }
+}
@@ -6,11 +6,16 @@ use super::models::{
 use super::repository::GuidanceEducationHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の指導教育履歴を取得
 #[server]
 pub async fn get_guidance_education_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<GuidanceEducationHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     GuidanceEducationHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -21,6 +26,8 @@ pub async fn get_guidance_education_history_by_employee(
 pub async fn create_guidance_education_history(
     data: CreateGuidanceEducationHistory,
 ) -> Result<GuidanceEducationHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.education_date >= expiration_date {
@@ -40,6 +47,8 @@ pub async fn create_guidance_education_history(
 pub async fn update_guidance_education_history(
     data: UpdateGuidanceEducationHistory,
 ) -> Result<GuidanceEducationHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.education_date >= expiration_date {
@@ -57,6 +66,8 @@ pub async fn update_guidance_education_history(
 /// 指導教育履歴を削除
 #[server]
 pub async fn delete_guidance_education_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     GuidanceEducationHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -66,6 +77,8 @@ pub async fn delete_guidance_education_history(id: i32) -> Result<(), ServerFnEr
 #[server]
 pub async fn get_all_guidance_education_types() -> Result<Vec<GuidanceEducationType>, ServerFnError>
 {
+    require_permission(Permission::View).await?;
+
     GuidanceEducationHistoryRepository::get_all_guidance_education_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -1,7 +1,7 @@
 pub mod handlers;
 pub mod models;
 #[cfg(feature = "server")]
-mod repository;
+pub mod repository;
 
 pub use handlers::{
     create_guidance_education_history, delete_guidance_education_history,
@@ -10,5 +10,5 @@ pub use handlers::{
 };
 pub use models::{
     CreateGuidanceEducationHistory, GuidanceEducationHistory, GuidanceEducationType,
-    UpdateGuidanceEducationHistory,
+    GuidanceTrigger, UpdateGuidanceEducationHistory,
 };
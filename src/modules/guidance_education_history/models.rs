@@ -68,6 +68,33 @@ pub struct GuidanceEducationType {
     pub description: Option<String>,
     pub required_frequency_months: Option<i32>,
     pub is_mandatory: bool,
+    /// 特別な指導の契機（初任運転者・事故惹起運転者）
+    pub trigger_kind: Option<GuidanceTrigger>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// 特別な指導の契機
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum GuidanceTrigger {
+    /// 初任運転者（選任前、やむを得ない場合は乗務開始後1か月以内）
+    #[serde(rename = "INITIAL_DRIVER")]
+    InitialDriver,
+    /// 事故惹起運転者（事故後の再乗務前、やむを得ない場合は再乗務後1か月以内）
+    #[serde(rename = "ACCIDENT")]
+    Accident,
+}
+
+impl GuidanceTrigger {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            GuidanceTrigger::InitialDriver => "初任運転者",
+            GuidanceTrigger::Accident => "事故惹起運転者",
+        }
+    }
+}
@@ -1,10 +1,12 @@
 #[cfg(feature = "server")]
 use super::models::{
     CreateGuidanceEducationHistory, GuidanceEducationHistory, GuidanceEducationType,
-    UpdateGuidanceEducationHistory,
+    GuidanceTrigger, UpdateGuidanceEducationHistory,
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct GuidanceEducationHistoryRepository;
@@ -56,7 +58,7 @@ impl GuidanceEducationHistoryRepository {
         data: CreateGuidanceEducationHistory,
     ) -> Result<GuidanceEducationHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             GuidanceEducationHistory,
             r#"INSERT INTO guidance_education_history
                (employee_id, guidance_education_type_id, education_date, expiration_date,
@@ -77,7 +79,17 @@ impl GuidanceEducationHistoryRepository {
             data.notes
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "guidance_education_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 指導教育履歴を更新
@@ -85,7 +97,8 @@ impl GuidanceEducationHistoryRepository {
         data: UpdateGuidanceEducationHistory,
     ) -> Result<GuidanceEducationHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let history = sqlx::query_as!(
             GuidanceEducationHistory,
             r#"UPDATE guidance_education_history
                SET guidance_education_type_id = $2, education_date = $3, expiration_date = $4,
@@ -106,18 +119,38 @@ impl GuidanceEducationHistoryRepository {
             data.notes
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "guidance_education_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 指導教育履歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(
             r#"DELETE FROM guidance_education_history WHERE id = $1"#,
             id
         )
         .execute(pool)
         .await?;
+        audit_log::record_delete(
+            "guidance_education_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -128,6 +161,7 @@ impl GuidanceEducationHistoryRepository {
         sqlx::query_as!(
             GuidanceEducationType,
             r#"SELECT id, name, description, required_frequency_months, is_mandatory,
+                      trigger_kind AS "trigger_kind?: GuidanceTrigger",
                       created_at, updated_at
                FROM guidance_education_types
                ORDER BY id"#
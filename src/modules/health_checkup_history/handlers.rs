@@ -5,11 +5,16 @@ use super::models::{
 use super::repository::HealthCheckupHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の健康診断履歴を取得
 #[server]
 pub async fn get_health_checkup_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<HealthCheckupHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     HealthCheckupHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -20,6 +25,8 @@ pub async fn get_health_checkup_history_by_employee(
 pub async fn get_active_health_checkup_history(
     employee_id: i32,
 ) -> Result<Vec<HealthCheckupHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     HealthCheckupHistoryRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -30,6 +37,8 @@ pub async fn get_active_health_checkup_history(
 pub async fn create_health_checkup_history(
     data: CreateHealthCheckupHistory,
 ) -> Result<HealthCheckupHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.checkup_date >= expiration_date {
@@ -49,6 +58,8 @@ pub async fn create_health_checkup_history(
 pub async fn update_health_checkup_history(
     data: UpdateHealthCheckupHistory,
 ) -> Result<HealthCheckupHistory, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let Some(expiration_date) = data.expiration_date {
         if data.checkup_date >= expiration_date {
@@ -66,6 +77,8 @@ pub async fn update_health_checkup_history(
 /// 健康診断履歴を削除
 #[server]
 pub async fn delete_health_checkup_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     HealthCheckupHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -74,6 +87,8 @@ pub async fn delete_health_checkup_history(id: i32) -> Result<(), ServerFnError>
 /// 全健康診断種別を取得
 #[server]
 pub async fn get_all_health_checkup_types() -> Result<Vec<HealthCheckupType>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     HealthCheckupHistoryRepository::get_all_health_checkup_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -54,6 +54,8 @@ pub struct HealthCheckupType {
     pub description: Option<String>,
     pub required_frequency_months: Option<i32>,
     pub is_mandatory: bool,
+    /// 深夜業従事者のみが対象の健康診断か
+    pub is_night_work: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
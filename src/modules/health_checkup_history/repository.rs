@@ -4,6 +4,8 @@ use super::models::{
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct HealthCheckupHistoryRepository;
@@ -74,7 +76,7 @@ impl HealthCheckupHistoryRepository {
         data: CreateHealthCheckupHistory,
     ) -> Result<HealthCheckupHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             HealthCheckupHistory,
             r#"INSERT INTO health_checkup_history
                (employee_id, health_checkup_type_id, checkup_date, expiration_date,
@@ -93,7 +95,17 @@ impl HealthCheckupHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "health_checkup_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 健康診断履歴を更新
@@ -101,7 +113,8 @@ impl HealthCheckupHistoryRepository {
         data: UpdateHealthCheckupHistory,
     ) -> Result<HealthCheckupHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let history = sqlx::query_as!(
             HealthCheckupHistory,
             r#"UPDATE health_checkup_history
                SET health_checkup_type_id = $2, checkup_date = $3, expiration_date = $4,
@@ -121,15 +134,36 @@ impl HealthCheckupHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "health_checkup_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 健康診断履歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM health_checkup_history WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "health_checkup_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -138,7 +172,8 @@ impl HealthCheckupHistoryRepository {
         let pool = Self::pool().await?;
         sqlx::query_as!(
             HealthCheckupType,
-            r#"SELECT id, name, description, required_frequency_months, is_mandatory, created_at, updated_at
+            r#"SELECT id, name, description, required_frequency_months, is_mandatory,
+                      is_night_work, created_at, updated_at
                FROM health_checkup_types
                ORDER BY id"#
         )
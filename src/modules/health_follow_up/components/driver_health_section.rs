@@ -0,0 +1,371 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::health_follow_up::handlers::{
+    delete_health_follow_up, get_employee_health_status, save_health_follow_up, set_night_worker,
+};
+use crate::modules::health_follow_up::models::{
+    CheckupDue, CheckupFollowUp, SaveHealthFollowUp, WorkDecision,
+};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+fn format_date(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// 健康管理（次回の定期健康診断と有所見者の事後措置、従業員詳細用）
+#[component]
+pub fn DriverHealthSection(employee_id: i32) -> Element {
+    let can_edit = use_permission(Permission::EditDriver);
+    let mut status =
+        use_resource(move || async move { get_employee_health_status(employee_id).await });
+    let mut error_message = use_signal(String::new);
+
+    let handle_night_worker = move |checked: bool| async move {
+        error_message.set(String::new());
+        match set_night_worker(employee_id, checked).await {
+            Ok(()) => status.restart(),
+            Err(e) => error_message.set(format!("深夜業従事者の設定に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                "健康管理"
+            }
+
+            if !error_message().is_empty() {
+                p { class: "mb-3 text-sm text-red-700", "❌ {error_message}" }
+            }
+
+            match &*status.read_unchecked() {
+                Some(Ok(result)) => rsx! {
+                    div { class: "flex flex-wrap items-start gap-6 mb-6",
+                        NextCheckupCard { due: result.checkup.clone() }
+                        label { class: "flex items-center gap-2 text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: result.is_night_worker,
+                                disabled: !can_edit(),
+                                onchange: move |evt| {
+                                    spawn(handle_night_worker(evt.checked()));
+                                },
+                            }
+                            "深夜業に従事する（健康診断を6か月以内ごとに実施）"
+                        }
+                    }
+
+                    h4 { class: "font-semibold text-gray-800 mb-3", "事後措置" }
+                    if result.follow_ups.is_empty() {
+                        p { class: "text-sm text-gray-500", "要精密検査・要治療の健康診断はありません" }
+                    } else {
+                        div { class: "space-y-3",
+                            for item in result.follow_ups.iter() {
+                                FollowUpCard {
+                                    key: "{item.checkup.id}",
+                                    item: item.clone(),
+                                    can_edit: can_edit(),
+                                    on_change: move |_| status.restart(),
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "健康管理の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 次回の定期健康診断
+#[component]
+fn NextCheckupCard(due: CheckupDue) -> Element {
+    rsx! {
+        div { class: "border border-gray-200 rounded-lg p-3 min-w-56",
+            p { class: "text-xs text-gray-500 font-semibold mb-1", "次回の定期健康診断" }
+            span { class: "px-2 py-0.5 rounded text-xs font-semibold {due.status.badge_class()}",
+                "{due.status.display_name()}"
+            }
+            if let Some(last) = due.last_date {
+                p { class: "text-xs text-gray-500 mt-1", "受診 {format_date(Some(last))}" }
+            }
+            if let Some(date) = due.due_date {
+                p { class: "text-sm text-gray-800 font-semibold", "期限 {format_date(Some(date))}" }
+            }
+            if let Some(months) = due.frequency_months {
+                p { class: "text-xs text-gray-400", "{months}か月ごと" }
+            }
+        }
+    }
+}
+
+#[component]
+fn FollowUpCard(item: CheckupFollowUp, can_edit: bool, on_change: EventHandler<()>) -> Element {
+    let mut editing = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+    let stage = item.stage();
+    let follow_up_id = item.follow_up.as_ref().map(|f| f.id);
+
+    let handle_delete = move |_| async move {
+        let Some(id) = follow_up_id else {
+            return;
+        };
+        match delete_health_follow_up(id).await {
+            Ok(()) => on_change.call(()),
+            Err(e) => error_message.set(format!("事後措置の削除に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "border border-gray-200 rounded-lg p-4 text-sm",
+            div { class: "flex items-center justify-between gap-2 mb-2",
+                div { class: "flex items-center gap-2",
+                    span { class: "font-semibold text-gray-800", "{item.checkup.checkup_date} {item.type_name}" }
+                    if let Some(result) = &item.checkup.result {
+                        span { class: "px-2 py-0.5 rounded-full text-xs bg-red-100 text-red-700", "{result}" }
+                    }
+                    span { class: "px-2 py-0.5 rounded text-xs font-semibold {stage.badge_class()}",
+                        "{stage.display_name()}"
+                    }
+                }
+                if can_edit && !editing() {
+                    div { class: "flex gap-3",
+                        button {
+                            class: "text-amber-700 hover:text-amber-900 text-sm font-semibold",
+                            onclick: move |_| editing.set(true),
+                            if follow_up_id.is_some() { "編集" } else { "＋ 事後措置を記録" }
+                        }
+                        if follow_up_id.is_some() {
+                            button {
+                                class: "text-red-600 hover:text-red-800 text-sm font-semibold",
+                                onclick: handle_delete,
+                                "削除"
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "mb-2 text-sm text-red-700", "❌ {error_message}" }
+            }
+
+            if editing() {
+                FollowUpForm {
+                    item: item.clone(),
+                    on_save: move |_| {
+                        editing.set(false);
+                        on_change.call(());
+                    },
+                    on_cancel: move |_| editing.set(false),
+                }
+            } else if let Some(follow_up) = &item.follow_up {
+                div { class: "grid grid-cols-2 md:grid-cols-4 gap-3",
+                    FollowUpField { label: "受診勧奨日", value: format_date(follow_up.referral_date) }
+                    FollowUpField { label: "再検査日", value: format_date(follow_up.re_exam_date) }
+                    FollowUpField {
+                        label: "再検査の医療機関",
+                        value: follow_up.re_exam_institution.clone().unwrap_or_default(),
+                    }
+                    FollowUpField {
+                        label: "就業判定",
+                        value: follow_up
+                            .work_decision
+                            .map(|d| format!("{} ({})", d.display_name(), format_date(follow_up.decision_date)))
+                            .unwrap_or_default(),
+                    }
+                }
+                if let Some(result) = &follow_up.re_exam_result {
+                    p { class: "mt-2 text-gray-700", span { class: "text-xs text-gray-500 mr-2", "再検査の結果" } "{result}" }
+                }
+                if let Some(opinion) = &follow_up.doctor_opinion {
+                    p { class: "mt-1 text-gray-700", span { class: "text-xs text-gray-500 mr-2", "医師の意見" } "{opinion}" }
+                }
+                if let Some(notes) = &follow_up.notes {
+                    p { class: "mt-1 text-gray-500", "{notes}" }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FollowUpField(label: &'static str, value: String) -> Element {
+    rsx! {
+        div {
+            p { class: "text-xs text-gray-500", "{label}" }
+            p { class: "text-gray-800", if value.is_empty() { "-" } else { "{value}" } }
+        }
+    }
+}
+
+#[component]
+fn FollowUpForm(
+    item: CheckupFollowUp,
+    on_save: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let initial = item.follow_up.clone();
+    let checkup_id = item.checkup.id;
+    let text = |value: Option<&String>| value.cloned().unwrap_or_default();
+
+    let mut referral_date =
+        use_signal(|| format_date(initial.as_ref().and_then(|f| f.referral_date)));
+    let mut re_exam_date =
+        use_signal(|| format_date(initial.as_ref().and_then(|f| f.re_exam_date)));
+    let mut re_exam_institution = use_signal(|| {
+        text(
+            initial
+                .as_ref()
+                .and_then(|f| f.re_exam_institution.as_ref()),
+        )
+    });
+    let mut re_exam_result =
+        use_signal(|| text(initial.as_ref().and_then(|f| f.re_exam_result.as_ref())));
+    let mut doctor_opinion =
+        use_signal(|| text(initial.as_ref().and_then(|f| f.doctor_opinion.as_ref())));
+    let mut work_decision = use_signal(|| initial.as_ref().and_then(|f| f.work_decision));
+    let mut decision_date =
+        use_signal(|| format_date(initial.as_ref().and_then(|f| f.decision_date)));
+    let mut notes = use_signal(|| text(initial.as_ref().and_then(|f| f.notes.as_ref())));
+    let mut is_saving = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+
+    let handle_submit = move |_| async move {
+        is_saving.set(true);
+        error_message.set(String::new());
+        let data = SaveHealthFollowUp {
+            health_checkup_history_id: checkup_id,
+            referral_date: parse_date(&referral_date()),
+            re_exam_date: parse_date(&re_exam_date()),
+            re_exam_institution: Some(re_exam_institution()),
+            re_exam_result: Some(re_exam_result()),
+            doctor_opinion: Some(doctor_opinion()),
+            work_decision: work_decision(),
+            decision_date: parse_date(&decision_date()),
+            notes: Some(notes()),
+        };
+        match save_health_follow_up(data).await {
+            Ok(_) => on_save.call(()),
+            Err(e) => error_message.set(format!("事後措置の保存に失敗しました: {}", e)),
+        }
+        is_saving.set(false);
+    };
+
+    rsx! {
+        div { class: "p-4 border border-amber-300 bg-amber-50 rounded-lg space-y-3",
+            if !error_message().is_empty() {
+                p { class: "text-sm text-red-700", "❌ {error_message}" }
+            }
+            div { class: "grid grid-cols-1 md:grid-cols-3 gap-3",
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "受診勧奨日" }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLASS,
+                        value: "{referral_date}",
+                        oninput: move |evt| referral_date.set(evt.value()),
+                    }
+                }
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "再検査日" }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLASS,
+                        value: "{re_exam_date}",
+                        oninput: move |evt| re_exam_date.set(evt.value()),
+                    }
+                }
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "再検査の医療機関" }
+                    input {
+                        r#type: "text",
+                        class: INPUT_CLASS,
+                        value: "{re_exam_institution}",
+                        oninput: move |evt| re_exam_institution.set(evt.value()),
+                    }
+                }
+            }
+            div { class: "space-y-1",
+                label { class: "block text-sm font-medium text-gray-700", "再検査の結果" }
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "2",
+                    value: "{re_exam_result}",
+                    oninput: move |evt| re_exam_result.set(evt.value()),
+                }
+            }
+            div { class: "space-y-1",
+                label { class: "block text-sm font-medium text-gray-700", "乗務の可否についての医師の意見" }
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "2",
+                    value: "{doctor_opinion}",
+                    oninput: move |evt| doctor_opinion.set(evt.value()),
+                }
+            }
+            div { class: "grid grid-cols-1 md:grid-cols-3 gap-3",
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "就業判定" }
+                    select {
+                        class: INPUT_CLASS,
+                        onchange: move |evt| work_decision.set(WorkDecision::parse(&evt.value())),
+                        option { value: "", selected: work_decision().is_none(), "未判定" }
+                        for decision in WorkDecision::ALL {
+                            option {
+                                value: decision.as_str(),
+                                selected: work_decision() == Some(decision),
+                                "{decision.display_name()}"
+                            }
+                        }
+                    }
+                }
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "就業判定日" }
+                    input {
+                        r#type: "date",
+                        class: INPUT_CLASS,
+                        value: "{decision_date}",
+                        oninput: move |evt| decision_date.set(evt.value()),
+                    }
+                }
+                div { class: "space-y-1",
+                    label { class: "block text-sm font-medium text-gray-700", "備考" }
+                    input {
+                        r#type: "text",
+                        class: INPUT_CLASS,
+                        value: "{notes}",
+                        oninput: move |evt| notes.set(evt.value()),
+                    }
+                }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50",
+                    disabled: is_saving(),
+                    onclick: handle_submit,
+                    if is_saving() { "保存中..." } else { "保存" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::health_follow_up::handlers::get_health_follow_up_overview;
+use crate::modules::health_follow_up::models::{DriverCheckupDue, PendingFollowUp};
+use crate::modules::organization::handlers::get_offices;
+use crate::Route;
+use dioxus::prelude::*;
+
+const HEADER_CLASS: &str = "flex items-center h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+
+fn format_date(date: Option<chrono::NaiveDate>) -> String {
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// 健康管理（事後措置が済んでいない健康診断と受診が必要な運転者の一覧）
+#[component]
+pub fn HealthFollowUpPage() -> Element {
+    let mut office_id = use_signal(|| None::<i32>);
+
+    let offices = use_resource(|| async move { get_offices().await });
+    let overview =
+        use_resource(move || async move { get_health_follow_up_overview(office_id()).await });
+
+    rsx! {
+        document::Title { "健康管理" }
+        div { class: "flex flex-col h-full overflow-hidden",
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "健康管理" }
+            }
+            div { class: CONTENT_CLASS,
+                div { class: "max-w-7xl mx-auto space-y-6",
+                    // 抽出条件
+                    div { class: SECTION_CLASS,
+                        div { class: "grid grid-cols-1 md:grid-cols-4 gap-4 items-end",
+                            div { class: "space-y-2",
+                                label { class: "block text-sm font-medium text-gray-700", "営業所" }
+                                select {
+                                    class: INPUT_CLASS,
+                                    onchange: move |evt| office_id.set(evt.value().parse().ok()),
+                                    option { value: "", "すべて" }
+                                    if let Some(Ok(offices)) = &*offices.read_unchecked() {
+                                        for office in offices.iter() {
+                                            option {
+                                                value: "{office.id}",
+                                                selected: office_id() == Some(office.id),
+                                                "{office.name}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    match &*overview.read_unchecked() {
+                        Some(Ok(overview)) => rsx! {
+                            PendingFollowUpSection { pending: overview.pending.clone() }
+                            CheckupDueSection { checkups: overview.checkups.clone() }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700",
+                                "エラーが発生しました: {e}"
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex justify-center py-12",
+                                div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                            }
+                        },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 事後措置が済んでいない健康診断
+#[component]
+fn PendingFollowUpSection(pending: Vec<PendingFollowUp>) -> Element {
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "事後措置が済んでいない健康診断" }
+                span { class: "text-sm text-gray-500", "{pending.len()}件" }
+            }
+            if pending.is_empty() {
+                p { class: "text-center py-8 text-gray-500", "該当する健康診断はありません" }
+            } else {
+                div { class: "overflow-x-auto",
+                    table { class: "min-w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-gray-600 border-b border-gray-200",
+                                th { class: "py-2 px-3 whitespace-nowrap", "運転者" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "営業所" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "健康診断" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "受診日" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "結果" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "状況" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "受診勧奨日" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "再検査日" }
+                            }
+                        }
+                        tbody {
+                            for item in pending {
+                                tr {
+                                    key: "{item.health_checkup_history_id}",
+                                    class: "border-b border-gray-100",
+                                    td { class: "py-2 px-3 whitespace-nowrap",
+                                        Link {
+                                            to: Route::EmployeeDetailPage { id: item.employee_id },
+                                            class: "text-amber-700 hover:underline",
+                                            "{item.employee_code} {item.employee_name}"
+                                        }
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap",
+                                        "{item.office_name.clone().unwrap_or_default()}"
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{item.checkup_type_name}" }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{item.checkup_date}" }
+                                    td { class: "py-2 px-3 text-red-700 font-semibold whitespace-nowrap", "{item.result}" }
+                                    td { class: "py-2 px-3 whitespace-nowrap",
+                                        span { class: "px-2 py-0.5 rounded text-xs font-semibold {item.stage().badge_class()}",
+                                            "{item.stage().display_name()}"
+                                        }
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{format_date(item.referral_date)}" }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{format_date(item.re_exam_date)}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 定期健康診断の受診が必要な運転者
+#[component]
+fn CheckupDueSection(checkups: Vec<DriverCheckupDue>) -> Element {
+    rsx! {
+        div { class: SECTION_CLASS,
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "定期健康診断の受診が必要な運転者" }
+                span { class: "text-sm text-gray-500", "{checkups.len()}名" }
+            }
+            if checkups.is_empty() {
+                p { class: "text-center py-8 text-gray-500", "該当する運転者はいません" }
+            } else {
+                div { class: "overflow-x-auto",
+                    table { class: "min-w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-gray-600 border-b border-gray-200",
+                                th { class: "py-2 px-3 whitespace-nowrap", "運転者" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "営業所" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "受診間隔" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "最終受診日" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "次回期限" }
+                                th { class: "py-2 px-3 whitespace-nowrap", "状況" }
+                            }
+                        }
+                        tbody {
+                            for item in checkups {
+                                tr { key: "{item.employee_id}", class: "border-b border-gray-100",
+                                    td { class: "py-2 px-3 whitespace-nowrap",
+                                        Link {
+                                            to: Route::EmployeeDetailPage { id: item.employee_id },
+                                            class: "text-amber-700 hover:underline",
+                                            "{item.employee_code} {item.employee_name}"
+                                        }
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap",
+                                        "{item.office_name.clone().unwrap_or_default()}"
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap",
+                                        if let Some(months) = item.due.frequency_months {
+                                            "{months}か月ごと"
+                                        }
+                                        if item.is_night_worker {
+                                            span { class: "ml-2 px-2 py-0.5 rounded-full text-xs bg-indigo-100 text-indigo-700", "深夜業" }
+                                        }
+                                    }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap", "{format_date(item.due.last_date)}" }
+                                    td { class: "py-2 px-3 text-gray-700 whitespace-nowrap font-semibold", "{format_date(item.due.due_date)}" }
+                                    td { class: "py-2 px-3 whitespace-nowrap",
+                                        span { class: "px-2 py-0.5 rounded text-xs font-semibold {item.due.status.badge_class()}",
+                                            "{item.due.status.display_name()}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
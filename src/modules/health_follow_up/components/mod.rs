@@ -0,0 +1,7 @@
+// 健康診断の受診計画・事後措置のコンポーネント
+
+mod driver_health_section;
+mod health_follow_up_page;
+
+pub use driver_health_section::DriverHealthSection;
+pub use health_follow_up_page::HealthFollowUpPage;
@@ -0,0 +1,209 @@
+//! 健康診断の受診計画・事後措置のハンドラー層（サーバー関数）
+
+use super::models::{
+    EmployeeHealthStatus, HealthFollowUp, HealthFollowUpOverview, SaveHealthFollowUp,
+};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{
+    next_checkup, requires_follow_up, CheckupFollowUp, DriverCheckupDue, PeriodicCheckupPlan,
+};
+#[cfg(feature = "server")]
+use super::repository::HealthFollowUpRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::health_checkup_history::repository::HealthCheckupHistoryRepository;
+
+/// 事後措置が済んでいない健康診断と、定期健康診断の受診が必要な運転者を取得（営業所で絞り込み可）
+#[server]
+pub async fn get_health_follow_up_overview(
+    office_id: Option<i32>,
+) -> Result<HealthFollowUpOverview, ServerFnError> {
+    require_permission(Permission::View).await?;
+    let to_error = |e: sqlx::Error| ServerFnError::new(e.to_string());
+
+    let pending = HealthFollowUpRepository::get_pending(office_id)
+        .await
+        .map_err(to_error)?;
+    let types = HealthCheckupHistoryRepository::get_all_health_checkup_types()
+        .await
+        .map_err(to_error)?;
+    let drivers = HealthFollowUpRepository::get_drivers(office_id, None)
+        .await
+        .map_err(to_error)?;
+    let employee_ids: Vec<i32> = drivers.iter().map(|d| d.employee_id).collect();
+    let records = HealthFollowUpRepository::get_records(&employee_ids)
+        .await
+        .map_err(to_error)?;
+
+    let today = chrono::Local::now().date_naive();
+    let day_plan = PeriodicCheckupPlan::from_types(&types, false);
+    let night_plan = PeriodicCheckupPlan::from_types(&types, true);
+    let mut checkups: Vec<DriverCheckupDue> = drivers
+        .into_iter()
+        .map(|driver| {
+            let own: Vec<_> = records
+                .iter()
+                .filter(|r| r.employee_id == driver.employee_id)
+                .copied()
+                .collect();
+            let plan = if driver.is_night_worker {
+                night_plan.as_ref()
+            } else {
+                day_plan.as_ref()
+            };
+            DriverCheckupDue {
+                employee_id: driver.employee_id,
+                employee_code: driver.employee_code,
+                employee_name: driver.employee_name,
+                office_id: driver.office_id,
+                office_name: driver.office_name,
+                is_night_worker: driver.is_night_worker,
+                due: next_checkup(plan, &own, today),
+            }
+        })
+        .filter(|d| d.due.status.needs_action())
+        .collect();
+    checkups.sort_by(|a, b| {
+        a.due
+            .status
+            .cmp(&b.due.status)
+            .then_with(|| a.due.due_date.cmp(&b.due.due_date))
+            .then_with(|| a.employee_code.cmp(&b.employee_code))
+    });
+
+    Ok(HealthFollowUpOverview {
+        as_of: today,
+        pending,
+        checkups,
+    })
+}
+
+/// 従業員1人の次回の定期健康診断と事後措置を取得
+#[server]
+pub async fn get_employee_health_status(
+    employee_id: i32,
+) -> Result<EmployeeHealthStatus, ServerFnError> {
+    require_permission(Permission::View).await?;
+    let to_error = |e: sqlx::Error| ServerFnError::new(e.to_string());
+
+    let is_night_worker = HealthFollowUpRepository::is_night_worker(employee_id)
+        .await
+        .map_err(to_error)?
+        .ok_or_else(|| ServerFnError::new("従業員が見つかりません".to_string()))?;
+    let types = HealthCheckupHistoryRepository::get_all_health_checkup_types()
+        .await
+        .map_err(to_error)?;
+    let records = HealthFollowUpRepository::get_records(&[employee_id])
+        .await
+        .map_err(to_error)?;
+    let plan = PeriodicCheckupPlan::from_types(&types, is_night_worker);
+    let checkup = next_checkup(plan.as_ref(), &records, chrono::Local::now().date_naive());
+
+    let history = HealthCheckupHistoryRepository::get_by_employee_id(employee_id)
+        .await
+        .map_err(to_error)?;
+    let mut follow_ups = HealthFollowUpRepository::get_by_employee_id(employee_id)
+        .await
+        .map_err(to_error)?;
+    let follow_ups = history
+        .into_iter()
+        .filter_map(|checkup| {
+            let follow_up = follow_ups
+                .iter()
+                .position(|f| f.health_checkup_history_id == checkup.id)
+                .map(|i| follow_ups.swap_remove(i));
+            if follow_up.is_none() && !requires_follow_up(checkup.result.as_deref()) {
+                return None;
+            }
+            let type_name = types
+                .iter()
+                .find(|t| t.id == checkup.health_checkup_type_id)
+                .map(|t| t.name.clone())
+                .unwrap_or_default();
+            Some(CheckupFollowUp {
+                checkup,
+                type_name,
+                follow_up,
+            })
+        })
+        .collect();
+
+    Ok(EmployeeHealthStatus {
+        is_night_worker,
+        checkup,
+        follow_ups,
+    })
+}
+
+/// 事後措置を登録・更新
+#[server]
+pub async fn save_health_follow_up(
+    data: SaveHealthFollowUp,
+) -> Result<HealthFollowUp, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    let checkup = HealthCheckupHistoryRepository::get_by_id(data.health_checkup_history_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("健康診断が見つかりません".to_string()))?;
+
+    // バリデーション
+    let dates = [data.referral_date, data.re_exam_date, data.decision_date];
+    if dates.iter().flatten().any(|d| *d < checkup.checkup_date) {
+        return Err(ServerFnError::new(
+            "受診勧奨日・再検査日・就業判定日は健康診断の受診日以後の日付を指定してください"
+                .to_string(),
+        ));
+    }
+    if let (Some(referral), Some(re_exam)) = (data.referral_date, data.re_exam_date) {
+        if re_exam < referral {
+            return Err(ServerFnError::new(
+                "再検査日は受診勧奨日以後の日付を指定してください".to_string(),
+            ));
+        }
+    }
+    if data.work_decision.is_some() && data.decision_date.is_none() {
+        return Err(ServerFnError::new(
+            "就業判定日を入力してください".to_string(),
+        ));
+    }
+    let trim = |s: Option<String>| s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    let data = SaveHealthFollowUp {
+        re_exam_institution: trim(data.re_exam_institution),
+        re_exam_result: trim(data.re_exam_result),
+        doctor_opinion: trim(data.doctor_opinion),
+        notes: trim(data.notes),
+        ..data
+    };
+
+    HealthFollowUpRepository::save(data)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("健康診断が見つかりません".to_string()))
+}
+
+/// 事後措置を削除
+#[server]
+pub async fn delete_health_follow_up(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    HealthFollowUpRepository::delete(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 深夜業従事者かを設定（健康診断の受診間隔が6か月になる）
+#[server]
+pub async fn set_night_worker(
+    employee_id: i32,
+    is_night_worker: bool,
+) -> Result<(), ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    HealthFollowUpRepository::set_night_worker(employee_id, is_night_worker)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
@@ -0,0 +1,14 @@
+//! 健康診断の受診計画・事後措置モジュール
+//! 健康診断種別の受診間隔（深夜業従事者は6か月）から次回の受診期限を求め、
+//! 要精密検査・要治療となった健康診断の受診勧奨・再検査・医師の意見・就業判定を記録する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::{DriverHealthSection, HealthFollowUpPage};
+pub use handlers::*;
+pub use models::{EmployeeHealthStatus, HealthFollowUp, HealthFollowUpOverview, WorkDecision};
@@ -0,0 +1,465 @@
+//! 健康診断の受診計画・事後措置のモデル定義
+
+use crate::modules::guidance_compliance::models::add_months;
+use crate::modules::guidance_compliance::ComplianceStatus;
+use crate::modules::health_checkup_history::{HealthCheckupHistory, HealthCheckupType};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 次回の受診期限が近い場合に「期限間近」とみなす日数
+pub const DUE_SOON_DAYS: i64 = 30;
+
+/// 事後措置が必要な判定結果
+pub const FOLLOW_UP_RESULTS: [&str; 2] = ["要精密検査", "要治療"];
+
+/// 判定結果が事後措置の対象か
+pub fn requires_follow_up(result: Option<&str>) -> bool {
+    result.is_some_and(|r| FOLLOW_UP_RESULTS.contains(&r))
+}
+
+/// 就業判定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum WorkDecision {
+    /// 通常勤務
+    #[serde(rename = "NORMAL")]
+    Normal,
+    /// 就業制限（乗務時間・夜間乗務の制限など）
+    #[serde(rename = "RESTRICTED")]
+    Restricted,
+    /// 乗務禁止（休業・配置転換）
+    #[serde(rename = "SUSPENDED")]
+    Suspended,
+}
+
+impl WorkDecision {
+    pub const ALL: [WorkDecision; 3] = [
+        WorkDecision::Normal,
+        WorkDecision::Restricted,
+        WorkDecision::Suspended,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkDecision::Normal => "NORMAL",
+            WorkDecision::Restricted => "RESTRICTED",
+            WorkDecision::Suspended => "SUSPENDED",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.as_str() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WorkDecision::Normal => "通常勤務",
+            WorkDecision::Restricted => "就業制限",
+            WorkDecision::Suspended => "乗務禁止",
+        }
+    }
+
+    /// バッジ表示用のTailwindクラス
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            WorkDecision::Normal => "bg-green-50 text-green-700 border border-green-300",
+            WorkDecision::Restricted => "bg-yellow-50 text-yellow-700 border border-yellow-300",
+            WorkDecision::Suspended => "bg-red-100 text-red-700 border border-red-300",
+        }
+    }
+}
+
+impl std::fmt::Display for WorkDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 事後措置の進み具合
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FollowUpStage {
+    /// 受診勧奨をしていない
+    NotReferred,
+    /// 精密検査・再検査の受診待ち
+    AwaitingReExam,
+    /// 医師の意見を踏まえた就業判定待ち
+    AwaitingDecision,
+    /// 就業判定済み
+    Resolved,
+}
+
+impl FollowUpStage {
+    /// 受診勧奨日・再検査日・就業判定の有無から判定する
+    pub fn from_progress(
+        referral_date: Option<NaiveDate>,
+        re_exam_date: Option<NaiveDate>,
+        decided: bool,
+    ) -> Self {
+        if decided {
+            FollowUpStage::Resolved
+        } else if re_exam_date.is_some() {
+            FollowUpStage::AwaitingDecision
+        } else if referral_date.is_some() {
+            FollowUpStage::AwaitingReExam
+        } else {
+            FollowUpStage::NotReferred
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FollowUpStage::NotReferred => "受診勧奨前",
+            FollowUpStage::AwaitingReExam => "再検査待ち",
+            FollowUpStage::AwaitingDecision => "就業判定待ち",
+            FollowUpStage::Resolved => "対応済",
+        }
+    }
+
+    /// バッジ表示用のTailwindクラス
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            FollowUpStage::NotReferred => "bg-red-100 text-red-700 border border-red-300",
+            FollowUpStage::AwaitingReExam => {
+                "bg-yellow-50 text-yellow-700 border border-yellow-300"
+            }
+            FollowUpStage::AwaitingDecision => {
+                "bg-orange-50 text-orange-700 border border-orange-300"
+            }
+            FollowUpStage::Resolved => "bg-green-50 text-green-700 border border-green-300",
+        }
+    }
+}
+
+/// 健康診断の事後措置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct HealthFollowUp {
+    pub id: i32,
+    pub health_checkup_history_id: i32,
+    pub employee_id: i32,
+    /// 受診勧奨日
+    pub referral_date: Option<NaiveDate>,
+    /// 精密検査・再検査の受診日
+    pub re_exam_date: Option<NaiveDate>,
+    pub re_exam_institution: Option<String>,
+    pub re_exam_result: Option<String>,
+    /// 乗務の可否についての医師の意見
+    pub doctor_opinion: Option<String>,
+    pub work_decision: Option<WorkDecision>,
+    pub decision_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HealthFollowUp {
+    pub fn stage(&self) -> FollowUpStage {
+        FollowUpStage::from_progress(
+            self.referral_date,
+            self.re_exam_date,
+            self.work_decision.is_some(),
+        )
+    }
+}
+
+/// 事後措置の登録・更新用（健康診断1件につき1件）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveHealthFollowUp {
+    pub health_checkup_history_id: i32,
+    pub referral_date: Option<NaiveDate>,
+    pub re_exam_date: Option<NaiveDate>,
+    pub re_exam_institution: Option<String>,
+    pub re_exam_result: Option<String>,
+    pub doctor_opinion: Option<String>,
+    pub work_decision: Option<WorkDecision>,
+    pub decision_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// 事後措置が済んでいない健康診断（運転者ごと）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct PendingFollowUp {
+    pub health_checkup_history_id: i32,
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub checkup_type_name: String,
+    pub checkup_date: NaiveDate,
+    pub result: String,
+    pub referral_date: Option<NaiveDate>,
+    pub re_exam_date: Option<NaiveDate>,
+}
+
+impl PendingFollowUp {
+    pub fn stage(&self) -> FollowUpStage {
+        FollowUpStage::from_progress(self.referral_date, self.re_exam_date, false)
+    }
+}
+
+/// 健康診断の受診記録
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct HealthCheckupRecord {
+    pub employee_id: i32,
+    pub health_checkup_type_id: i32,
+    pub checkup_date: NaiveDate,
+}
+
+/// 受診計画の対象の運転者
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct CheckupDriverRow {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub is_night_worker: bool,
+}
+
+/// 定期の健康診断の受診計画
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodicCheckupPlan {
+    /// 受診したとみなす健康診断種別（雇入時健康診断などの義務の診断を含む）
+    pub type_ids: Vec<i32>,
+    /// 受診間隔（月）
+    pub frequency_months: u32,
+}
+
+impl PeriodicCheckupPlan {
+    /// 健康診断種別から受診計画を作る
+    ///
+    /// 義務の種別のうち最も短い受診間隔を使い、深夜業従事者は深夜業健康診断の間隔（6か月）も対象にする
+    pub fn from_types(types: &[HealthCheckupType], is_night_worker: bool) -> Option<Self> {
+        let applicable: Vec<&HealthCheckupType> = types
+            .iter()
+            .filter(|t| {
+                if t.is_night_work {
+                    is_night_worker
+                } else {
+                    t.is_mandatory
+                }
+            })
+            .collect();
+        let frequency_months = applicable
+            .iter()
+            .filter_map(|t| t.required_frequency_months)
+            .filter(|m| *m > 0)
+            .min()?;
+        Some(Self {
+            type_ids: applicable.iter().map(|t| t.id).collect(),
+            frequency_months: frequency_months as u32,
+        })
+    }
+}
+
+/// 次回の定期健康診断
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckupDue {
+    pub status: ComplianceStatus,
+    /// 最後に受診した日
+    pub last_date: Option<NaiveDate>,
+    /// 次回の受診期限
+    pub due_date: Option<NaiveDate>,
+    /// 受診間隔（月）
+    pub frequency_months: Option<u32>,
+}
+
+/// 最後の受診日と受診間隔から次回の受診期限と状況を求める
+pub fn next_checkup(
+    plan: Option<&PeriodicCheckupPlan>,
+    records: &[HealthCheckupRecord],
+    today: NaiveDate,
+) -> CheckupDue {
+    let Some(plan) = plan else {
+        return CheckupDue {
+            status: ComplianceStatus::NotRequired,
+            last_date: None,
+            due_date: None,
+            frequency_months: None,
+        };
+    };
+    let last_date = records
+        .iter()
+        .filter(|r| plan.type_ids.contains(&r.health_checkup_type_id))
+        .map(|r| r.checkup_date)
+        .max();
+    let due_date = last_date.map(|d| add_months(d, plan.frequency_months));
+    let status = match due_date {
+        None => ComplianceStatus::NeverTaken,
+        Some(due) => ComplianceStatus::by_next_due(due, today, DUE_SOON_DAYS),
+    };
+    CheckupDue {
+        status,
+        last_date,
+        due_date,
+        frequency_months: Some(plan.frequency_months),
+    }
+}
+
+/// 運転者ごとの次回の定期健康診断
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DriverCheckupDue {
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub office_id: Option<i32>,
+    pub office_name: Option<String>,
+    pub is_night_worker: bool,
+    pub due: CheckupDue,
+}
+
+/// 健康管理の一覧（事後措置が済んでいない健康診断と受診が必要な運転者）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthFollowUpOverview {
+    /// 判定日
+    pub as_of: NaiveDate,
+    /// 受診日の古い順
+    pub pending: Vec<PendingFollowUp>,
+    /// 受診が必要な運転者（状況→期限の順）
+    pub checkups: Vec<DriverCheckupDue>,
+}
+
+/// 事後措置の対象の健康診断と事後措置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckupFollowUp {
+    pub checkup: HealthCheckupHistory,
+    pub type_name: String,
+    pub follow_up: Option<HealthFollowUp>,
+}
+
+impl CheckupFollowUp {
+    pub fn stage(&self) -> FollowUpStage {
+        self.follow_up
+            .as_ref()
+            .map(|f| f.stage())
+            .unwrap_or(FollowUpStage::NotReferred)
+    }
+}
+
+/// 従業員1人の健康管理の状況（従業員詳細用）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmployeeHealthStatus {
+    pub is_night_worker: bool,
+    pub checkup: CheckupDue,
+    /// 判定結果が要精密検査・要治療、または事後措置を登録した健康診断（新しい順）
+    pub follow_ups: Vec<CheckupFollowUp>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn checkup_type(
+        id: i32,
+        months: Option<i32>,
+        mandatory: bool,
+        night: bool,
+    ) -> HealthCheckupType {
+        HealthCheckupType {
+            id,
+            name: format!("種別{}", id),
+            description: None,
+            required_frequency_months: months,
+            is_mandatory: mandatory,
+            is_night_work: night,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn types() -> Vec<HealthCheckupType> {
+        vec![
+            checkup_type(1, Some(12), true, false),
+            checkup_type(2, None, true, false),
+            checkup_type(3, Some(12), false, false),
+            checkup_type(4, Some(6), false, true),
+        ]
+    }
+
+    fn record(type_id: i32, checkup_date: NaiveDate) -> HealthCheckupRecord {
+        HealthCheckupRecord {
+            employee_id: 1,
+            health_checkup_type_id: type_id,
+            checkup_date,
+        }
+    }
+
+    #[test]
+    fn test_periodic_plan() {
+        let day = PeriodicCheckupPlan::from_types(&types(), false).unwrap();
+        assert_eq!(day.frequency_months, 12);
+        assert_eq!(day.type_ids, vec![1, 2]);
+
+        let night = PeriodicCheckupPlan::from_types(&types(), true).unwrap();
+        assert_eq!(night.frequency_months, 6);
+        assert_eq!(night.type_ids, vec![1, 2, 4]);
+
+        assert!(
+            PeriodicCheckupPlan::from_types(&[checkup_type(2, None, true, false)], false).is_none()
+        );
+    }
+
+    #[test]
+    fn test_next_checkup() {
+        let plan = PeriodicCheckupPlan::from_types(&types(), true).unwrap();
+        let today = date(2026, 6, 1);
+
+        let due = next_checkup(Some(&plan), &[], today);
+        assert_eq!(due.status, ComplianceStatus::NeverTaken);
+
+        // 雇入時健康診断も受診とみなし、深夜業従事者は6か月ごと
+        let records = [record(2, date(2025, 10, 1)), record(3, date(2026, 2, 1))];
+        let due = next_checkup(Some(&plan), &records, today);
+        assert_eq!(due.last_date, Some(date(2025, 10, 1)));
+        assert_eq!(due.due_date, Some(date(2026, 4, 1)));
+        assert_eq!(due.status, ComplianceStatus::Overdue);
+
+        let records = [record(4, date(2025, 12, 20))];
+        let due = next_checkup(Some(&plan), &records, today);
+        assert_eq!(due.status, ComplianceStatus::DueSoon);
+
+        let due = next_checkup(None, &records, today);
+        assert_eq!(due.status, ComplianceStatus::NotRequired);
+    }
+
+    #[test]
+    fn test_follow_up_stage() {
+        assert!(requires_follow_up(Some("要精密検査")));
+        assert!(!requires_follow_up(Some("要経過観察")));
+        assert!(!requires_follow_up(None));
+
+        let d = Some(date(2026, 5, 1));
+        assert_eq!(
+            FollowUpStage::from_progress(None, None, false),
+            FollowUpStage::NotReferred
+        );
+        assert_eq!(
+            FollowUpStage::from_progress(d, None, false),
+            FollowUpStage::AwaitingReExam
+        );
+        assert_eq!(
+            FollowUpStage::from_progress(d, d, false),
+            FollowUpStage::AwaitingDecision
+        );
+        assert_eq!(
+            FollowUpStage::from_progress(None, None, true),
+            FollowUpStage::Resolved
+        );
+        assert_eq!(
+            WorkDecision::parse("RESTRICTED"),
+            Some(WorkDecision::Restricted)
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! 健康診断の受診計画・事後措置のリポジトリ層
+
+use super::models::{
+    CheckupDriverRow, HealthCheckupRecord, HealthFollowUp, PendingFollowUp, SaveHealthFollowUp,
+    FOLLOW_UP_RESULTS,
+};
+use crate::db;
+use crate::modules::audit_log;
+use serde::Serialize;
+
+/// 深夜業従事者の設定（監査ログ用）
+#[derive(Serialize)]
+struct NightWorkerSetting {
+    is_night_worker: bool,
+}
+
+/// 健康診断の事後措置リポジトリ
+pub struct HealthFollowUpRepository;
+
+impl HealthFollowUpRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 従業員の事後措置を取得
+    pub async fn get_by_employee_id(employee_id: i32) -> Result<Vec<HealthFollowUp>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            HealthFollowUp,
+            r#"
+            SELECT id, health_checkup_history_id, employee_id, referral_date, re_exam_date,
+                   re_exam_institution, re_exam_result, doctor_opinion,
+                   work_decision AS "work_decision?: _", decision_date, notes,
+                   created_at, updated_at
+            FROM health_checkup_follow_ups
+            WHERE employee_id = $1
+            "#,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで事後措置を取得
+    pub async fn get_by_id(id: i32) -> Result<Option<HealthFollowUp>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            HealthFollowUp,
+            r#"
+            SELECT id, health_checkup_history_id, employee_id, referral_date, re_exam_date,
+                   re_exam_institution, re_exam_result, doctor_opinion,
+                   work_decision AS "work_decision?: _", decision_date, notes,
+                   created_at, updated_at
+            FROM health_checkup_follow_ups
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 健康診断の事後措置を取得
+    pub async fn get_by_checkup_id(checkup_id: i32) -> Result<Option<HealthFollowUp>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            HealthFollowUp,
+            r#"
+            SELECT id, health_checkup_history_id, employee_id, referral_date, re_exam_date,
+                   re_exam_institution, re_exam_result, doctor_opinion,
+                   work_decision AS "work_decision?: _", decision_date, notes,
+                   created_at, updated_at
+            FROM health_checkup_follow_ups
+            WHERE health_checkup_history_id = $1
+            "#,
+            checkup_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 事後措置を登録・更新（健康診断が存在しない場合はNone）
+    pub async fn save(data: SaveHealthFollowUp) -> Result<Option<HealthFollowUp>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_checkup_id(data.health_checkup_history_id).await?;
+        let work_decision = data.work_decision.map(|d| d.as_str());
+        let saved = sqlx::query_as!(
+            HealthFollowUp,
+            r#"
+            INSERT INTO health_checkup_follow_ups (
+                health_checkup_history_id, employee_id, referral_date, re_exam_date,
+                re_exam_institution, re_exam_result, doctor_opinion, work_decision,
+                decision_date, notes
+            )
+            SELECT h.id, h.employee_id, $2::DATE, $3::DATE, $4::VARCHAR, $5::TEXT, $6::TEXT,
+                   $7::VARCHAR, $8::DATE, $9::TEXT
+            FROM health_checkup_history h
+            WHERE h.id = $1
+            ON CONFLICT (health_checkup_history_id) DO UPDATE SET
+                referral_date = EXCLUDED.referral_date,
+                re_exam_date = EXCLUDED.re_exam_date,
+                re_exam_institution = EXCLUDED.re_exam_institution,
+                re_exam_result = EXCLUDED.re_exam_result,
+                doctor_opinion = EXCLUDED.doctor_opinion,
+                work_decision = EXCLUDED.work_decision,
+                decision_date = EXCLUDED.decision_date,
+                notes = EXCLUDED.notes,
+                updated_at = CURRENT_TIMESTAMP
+            RETURNING id, health_checkup_history_id, employee_id, referral_date, re_exam_date,
+                      re_exam_institution, re_exam_result, doctor_opinion,
+                      work_decision AS "work_decision?: _", decision_date, notes,
+                      created_at, updated_at
+            "#,
+            data.health_checkup_history_id,
+            data.referral_date,
+            data.re_exam_date,
+            data.re_exam_institution,
+            data.re_exam_result,
+            data.doctor_opinion,
+            work_decision,
+            data.decision_date,
+            data.notes
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(saved) = &saved {
+            match &old {
+                Some(old) => {
+                    audit_log::record_update(
+                        "health_checkup_follow_ups",
+                        saved.id,
+                        Some(saved.employee_id),
+                        Some(old),
+                        saved,
+                    )
+                    .await
+                }
+                None => {
+                    audit_log::record_create(
+                        "health_checkup_follow_ups",
+                        saved.id,
+                        Some(saved.employee_id),
+                        saved,
+                    )
+                    .await
+                }
+            }
+        }
+
+        Ok(saved)
+    }
+
+    /// 事後措置を削除
+    pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
+        sqlx::query!("DELETE FROM health_checkup_follow_ups WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete(
+            "health_checkup_follow_ups",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// 事後措置が済んでいない健康診断を取得（選任中の運転者の有効な健康診断、受診日の古い順）
+    pub async fn get_pending(office_id: Option<i32>) -> Result<Vec<PendingFollowUp>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let results: Vec<String> = FOLLOW_UP_RESULTS.iter().map(|r| r.to_string()).collect();
+        sqlx::query_as!(
+            PendingFollowUp,
+            r#"
+            SELECT
+                h.id AS health_checkup_history_id,
+                e.id AS employee_id,
+                e.employee_code,
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                e.office_id,
+                o.name AS "office_name?",
+                t.name AS checkup_type_name,
+                h.checkup_date,
+                h.result AS "result!",
+                f.referral_date AS "referral_date?",
+                f.re_exam_date AS "re_exam_date?"
+            FROM health_checkup_history h
+            JOIN employees e ON e.id = h.employee_id
+            JOIN health_checkup_types t ON t.id = h.health_checkup_type_id
+            LEFT JOIN offices o ON o.id = e.office_id
+            LEFT JOIN health_checkup_follow_ups f ON f.health_checkup_history_id = h.id
+            WHERE h.is_active = TRUE
+              AND h.result = ANY($1)
+              AND f.work_decision IS NULL
+              AND e.is_active = TRUE
+              AND e.driver_start_date IS NOT NULL
+              AND (e.driver_end_date IS NULL OR e.driver_end_date > CURRENT_DATE)
+              AND ($2::INT IS NULL OR e.office_id = $2)
+            ORDER BY h.checkup_date, e.employee_code
+            "#,
+            &results,
+            office_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 受診計画の対象の運転者（在籍中で、選任中または選任予定）を取得
+    pub async fn get_drivers(
+        office_id: Option<i32>,
+        employee_id: Option<i32>,
+    ) -> Result<Vec<CheckupDriverRow>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            CheckupDriverRow,
+            r#"
+            SELECT
+                e.id AS employee_id,
+                e.employee_code,
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                e.office_id,
+                o.name AS "office_name?",
+                e.is_night_worker
+            FROM employees e
+            LEFT JOIN offices o ON o.id = e.office_id
+            WHERE e.is_active = TRUE
+              AND e.driver_start_date IS NOT NULL
+              AND (e.driver_end_date IS NULL OR e.driver_end_date > CURRENT_DATE)
+              AND ($1::INT IS NULL OR e.office_id = $1)
+              AND ($2::INT IS NULL OR e.id = $2)
+            ORDER BY e.employee_code
+            "#,
+            office_id,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 指定した従業員の健康診断の受診記録を取得
+    pub async fn get_records(
+        employee_ids: &[i32],
+    ) -> Result<Vec<HealthCheckupRecord>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            HealthCheckupRecord,
+            r#"
+            SELECT employee_id, health_checkup_type_id, checkup_date
+            FROM health_checkup_history
+            WHERE employee_id = ANY($1)
+            ORDER BY employee_id, checkup_date
+            "#,
+            employee_ids
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 従業員が深夜業従事者か（従業員が存在しない場合はNone）
+    pub async fn is_night_worker(employee_id: i32) -> Result<Option<bool>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_scalar!(
+            "SELECT is_night_worker FROM employees WHERE id = $1",
+            employee_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 深夜業従事者かを設定
+    pub async fn set_night_worker(
+        employee_id: i32,
+        is_night_worker: bool,
+    ) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::is_night_worker(employee_id).await?;
+        sqlx::query!(
+            r#"
+            UPDATE employees
+            SET is_night_worker = $2, updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            "#,
+            employee_id,
+            is_night_worker
+        )
+        .execute(pool)
+        .await?;
+
+        audit_log::record_update(
+            "employees",
+            employee_id,
+            Some(employee_id),
+            old.map(|is_night_worker| NightWorkerSetting { is_night_worker })
+                .as_ref(),
+            &NightWorkerSetting { is_night_worker },
+        )
+        .await;
+
+        Ok(())
+    }
+}
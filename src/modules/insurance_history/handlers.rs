@@ -5,11 +5,16 @@ use super::models::{
 use super::repository::InsuranceHistoryRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の保険証履歴を取得
 #[server]
 pub async fn get_insurance_history_by_employee(
     employee_id: i32,
 ) -> Result<Vec<InsuranceHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     InsuranceHistoryRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -20,6 +25,8 @@ pub async fn get_insurance_history_by_employee(
 pub async fn get_active_insurance_history(
     employee_id: i32,
 ) -> Result<Vec<InsuranceHistory>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     InsuranceHistoryRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -30,6 +37,8 @@ pub async fn get_active_insurance_history(
 pub async fn create_insurance_history(
     data: CreateInsuranceHistory,
 ) -> Result<InsuranceHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     if let Some(end_date) = data.end_date {
         if data.start_date >= end_date {
@@ -49,6 +58,8 @@ pub async fn create_insurance_history(
 pub async fn update_insurance_history(
     data: UpdateInsuranceHistory,
 ) -> Result<InsuranceHistory, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     // バリデーション
     if let Some(end_date) = data.end_date {
         if data.start_date >= end_date {
@@ -66,6 +77,8 @@ pub async fn update_insurance_history(
 /// 保険証履歴を削除
 #[server]
 pub async fn delete_insurance_history(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     InsuranceHistoryRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -74,6 +87,8 @@ pub async fn delete_insurance_history(id: i32) -> Result<(), ServerFnError> {
 /// 全保険種別を取得
 #[server]
 pub async fn get_all_insurance_types() -> Result<Vec<InsuranceType>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     InsuranceHistoryRepository::get_all_insurance_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
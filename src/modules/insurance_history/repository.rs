@@ -4,6 +4,8 @@ use super::models::{
 };
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct InsuranceHistoryRepository;
@@ -72,7 +74,7 @@ impl InsuranceHistoryRepository {
     /// 保険証履歴を作成
     pub async fn create(data: CreateInsuranceHistory) -> Result<InsuranceHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let history = sqlx::query_as!(
             InsuranceHistory,
             r#"INSERT INTO insurance_history
                (employee_id, insurance_type_id, insurance_number, start_date,
@@ -90,13 +92,24 @@ impl InsuranceHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "insurance_history",
+            history.id,
+            Some(history.employee_id),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 保険証履歴を更新
     pub async fn update(data: UpdateInsuranceHistory) -> Result<InsuranceHistory, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let history = sqlx::query_as!(
             InsuranceHistory,
             r#"UPDATE insurance_history
                SET insurance_type_id = $2, insurance_number = $3, start_date = $4,
@@ -115,15 +128,36 @@ impl InsuranceHistoryRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "insurance_history",
+            history.id,
+            Some(history.employee_id),
+            old.as_ref(),
+            &history,
+        )
+        .await;
+
+        Ok(history)
     }
 
     /// 保険証履歴を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM insurance_history WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "insurance_history",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
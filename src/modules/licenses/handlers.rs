@@ -3,9 +3,14 @@ use super::models::{CreateLicense, License, LicenseType, UpdateLicense};
 use super::repository::LicenseRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の運転免許証を取得
 #[server]
 pub async fn get_licenses_by_employee(employee_id: i32) -> Result<Vec<License>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     LicenseRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -14,6 +19,8 @@ pub async fn get_licenses_by_employee(employee_id: i32) -> Result<Vec<License>,
 /// 有効な運転免許証を取得
 #[server]
 pub async fn get_active_licenses(employee_id: i32) -> Result<Vec<License>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     LicenseRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -22,6 +29,8 @@ pub async fn get_active_licenses(employee_id: i32) -> Result<Vec<License>, Serve
 /// 運転免許証を作成
 #[server]
 pub async fn create_license(data: CreateLicense) -> Result<License, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if data.issue_date.is_some() && data.issue_date >= Some(data.expiration_date) {
         return Err(ServerFnError::new(
@@ -37,6 +46,8 @@ pub async fn create_license(data: CreateLicense) -> Result<License, ServerFnErro
 /// 運転免許証を更新
 #[server]
 pub async fn update_license(data: UpdateLicense) -> Result<License, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if data.issue_date.is_some() && data.issue_date >= Some(data.expiration_date) {
         return Err(ServerFnError::new(
@@ -52,6 +63,8 @@ pub async fn update_license(data: UpdateLicense) -> Result<License, ServerFnErro
 /// 運転免許証を削除
 #[server]
 pub async fn delete_license(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     LicenseRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -60,6 +73,8 @@ pub async fn delete_license(id: i32) -> Result<(), ServerFnError> {
 /// 全免許種別を取得
 #[server]
 pub async fn get_all_license_types() -> Result<Vec<LicenseType>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     LicenseRepository::get_all_license_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -1,7 +1,7 @@
 pub mod handlers;
 pub mod models;
 #[cfg(feature = "server")]
-mod repository;
+pub mod repository;
 
 pub use handlers::{
     create_license, delete_license, get_active_licenses, get_all_license_types,
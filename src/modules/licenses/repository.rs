@@ -2,6 +2,8 @@
 use super::models::{CreateLicense, License, LicenseType, UpdateLicense};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct LicenseRepository;
@@ -66,7 +68,7 @@ impl LicenseRepository {
     /// 運転免許証を作成
     pub async fn create(data: CreateLicense) -> Result<License, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let license = sqlx::query_as!(
             License,
             r#"INSERT INTO licenses
                (employee_id, license_type_id, license_number, issue_date,
@@ -86,13 +88,18 @@ impl LicenseRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create("licenses", license.id, Some(license.employee_id), &license).await;
+
+        Ok(license)
     }
 
     /// 運転免許証を更新
     pub async fn update(data: UpdateLicense) -> Result<License, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let license = sqlx::query_as!(
             License,
             r#"UPDATE licenses
                SET license_type_id = $2, license_number = $3, issue_date = $4,
@@ -112,15 +119,36 @@ impl LicenseRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "licenses",
+            license.id,
+            Some(license.employee_id),
+            old.as_ref(),
+            &license,
+        )
+        .await;
+
+        Ok(license)
     }
 
     /// 運転免許証を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM licenses WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "licenses",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
@@ -1,17 +1,33 @@
 pub mod addresses;
 pub mod aptitude_checkup_history;
+pub mod aptitude_schedule;
+pub mod audit_log;
+pub mod auth;
 pub mod bank_accounts;
+pub mod clients;
 pub mod department_position_history;
+pub mod driver_ledger;
+pub mod driver_selection;
 pub mod education_history;
 pub mod emergency_contacts;
+pub mod employee_import;
 pub mod employees;
 pub mod employment_history;
+pub mod expirations;
+pub mod exports;
+pub mod guidance_compliance;
 pub mod guidance_education_history;
 pub mod health_checkup_history;
+pub mod health_follow_up;
 pub mod insurance_history;
 pub mod licenses;
+pub mod money;
+pub mod offboarding;
+pub mod organization;
 pub mod qualifications;
 pub mod residence_cards;
+pub mod retention;
+pub mod vehicles;
 
 // S3とドキュメント管理
 pub mod employee_documents;
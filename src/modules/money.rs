@@ -0,0 +1,53 @@
+//! 金額（DECIMAL）の受け渡し
+//!
+//! 金額はサーバーでは誤差のない BigDecimal、クライアントでは f64 で扱う。
+//! BigDecimal は文字列としてシリアライズされるため、クライアントでは文字列・数値のどちらも読み込めるようにする
+
+use std::fmt::Display;
+
+/// 文字列・数値のどちらの金額も f64 として読み込む（クライアント用）
+#[cfg(not(feature = "server"))]
+pub fn deserialize_opt<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Amount {
+        Number(f64),
+        Text(String),
+    }
+
+    match Option::<Amount>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Amount::Number(value)) => Ok(Some(value)),
+        Some(Amount::Text(value)) => value.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+/// 表示・集計用に金額を f64 に変換（サーバー・クライアントで型が異なるため文字列を経由する）
+pub fn to_f64(amount: &impl Display) -> f64 {
+    amount.to_string().parse().unwrap_or_default()
+}
+
+#[cfg(all(test, feature = "web", not(feature = "server")))]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Deserialize)]
+    struct Row {
+        #[serde(default, deserialize_with = "deserialize_opt")]
+        amount: Option<f64>,
+    }
+
+    #[test]
+    fn test_deserialize_string_or_number() {
+        let parse = |json: &str| serde_json::from_str::<Row>(json).unwrap().amount;
+        assert_eq!(parse(r#"{"amount":"12000.50"}"#), Some(12000.5));
+        assert_eq!(parse(r#"{"amount":12000}"#), Some(12000.0));
+        assert_eq!(parse(r#"{"amount":null}"#), None);
+        assert_eq!(parse(r#"{}"#), None);
+    }
+}
@@ -0,0 +1,5 @@
+// 運転者の選任解除・退職の手続きのコンポーネント
+
+mod offboarding_panel;
+
+pub use offboarding_panel::OffboardingPanel;
@@ -0,0 +1,210 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::driver_ledger::DriverLedgerPdf;
+use crate::modules::offboarding::handlers::{
+    generate_deselected_ledger, get_offboarding_status, offboard_employee,
+};
+use crate::modules::offboarding::models::{OffboardingKind, OffboardingRequest, MAX_REASON_CHARS};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 選任解除・退職の手続きと選任解除の記録（解任印付きの運転者台帳の出力）
+#[component]
+pub fn OffboardingPanel(employee_id: i32, on_change: EventHandler<()>) -> Element {
+    let today = chrono::Local::now().date_naive();
+    let can_edit = use_permission(Permission::EditDriver);
+    let mut status = use_resource(move || async move { get_offboarding_status(employee_id).await });
+    let mut kind = use_signal(|| OffboardingKind::Deselection);
+    let mut end_date = use_signal(move || today);
+    let mut reason = use_signal(String::new);
+    let mut is_saving = use_signal(|| false);
+    let mut error_message = use_signal(String::new);
+    let mut success_message = use_signal(String::new);
+    let mut is_generating = use_signal(|| false);
+    let mut ledger = use_signal(|| None::<DriverLedgerPdf>);
+
+    let current = match &*status.read_unchecked() {
+        Some(Ok(current)) => current.clone(),
+        _ => return rsx! {},
+    };
+    // 選任中でなければ退職の手続きのみ
+    let kinds: Vec<OffboardingKind> = OffboardingKind::ALL
+        .into_iter()
+        .filter(|k| current.is_selected || *k == OffboardingKind::Retirement)
+        .collect();
+    let selected_kind = if kinds.contains(&kind()) {
+        kind()
+    } else {
+        OffboardingKind::Retirement
+    };
+
+    let handle_submit = move |_| async move {
+        is_saving.set(true);
+        error_message.set(String::new());
+        success_message.set(String::new());
+        let request = OffboardingRequest {
+            employee_id,
+            kind: selected_kind,
+            end_date: end_date(),
+            reason: reason(),
+        };
+        match offboard_employee(request).await {
+            Ok(result) => {
+                let mut done = Vec::new();
+                if let Some(deselection) = &result.deselection {
+                    done.push(format!(
+                        "選任を解除しました（台帳の保存期限 {}）",
+                        deselection.ledger_retained_until()
+                    ));
+                }
+                if selected_kind == OffboardingKind::Retirement {
+                    done.push(format!(
+                        "配属{}件・住所{}件・保険{}件を終了し、銀行口座{}件を無効にしました",
+                        result.closed_positions,
+                        result.closed_addresses,
+                        result.closed_insurances,
+                        result.closed_bank_accounts
+                    ));
+                }
+                success_message.set(done.join("。"));
+                reason.set(String::new());
+                status.restart();
+                on_change.call(());
+            }
+            Err(e) => error_message.set(format!("手続きに失敗しました: {}", e)),
+        }
+        is_saving.set(false);
+    };
+
+    let handle_ledger = move |_| async move {
+        is_generating.set(true);
+        error_message.set(String::new());
+        match generate_deselected_ledger(employee_id).await {
+            Ok(pdf) => ledger.set(Some(pdf)),
+            Err(e) => error_message.set(format!("台帳の作成に失敗しました: {}", e)),
+        }
+        is_generating.set(false);
+    };
+
+    rsx! {
+        div { class: "mt-6 space-y-4",
+            if !success_message().is_empty() {
+                p { class: "text-sm text-green-700", "✅ {success_message}" }
+            }
+            if !error_message().is_empty() {
+                p { class: "text-sm text-red-700", "❌ {error_message}" }
+            }
+
+            if current.is_active && can_edit() {
+                div { class: "p-4 border border-gray-300 bg-gray-50 rounded-lg space-y-4",
+                    div {
+                        h4 { class: "font-semibold text-gray-800", "選任解除・退職の手続き" }
+                        p { class: "text-xs text-gray-500",
+                            "退職では現在の配属・住所・保険を退職日で終了し、銀行口座を無効にします。運転者台帳は解任日から3年間保存します"
+                        }
+                    }
+                    div { class: "grid grid-cols-2 gap-3",
+                        div { class: "space-y-1",
+                            label { class: "block text-sm font-medium text-gray-700", "手続き" }
+                            select {
+                                class: INPUT_CLASS,
+                                value: selected_kind.as_str(),
+                                onchange: move |evt| {
+                                    if let Some(k) = OffboardingKind::parse(&evt.value()) {
+                                        kind.set(k);
+                                    }
+                                },
+                                for k in kinds.iter() {
+                                    option { key: "{k.as_str()}", value: k.as_str(), "{k}" }
+                                }
+                            }
+                        }
+                        div { class: "space-y-1",
+                            label { class: "block text-sm font-medium text-gray-700", "{selected_kind.date_label()}" }
+                            input {
+                                r#type: "date",
+                                class: INPUT_CLASS,
+                                max: "{today}",
+                                value: "{end_date}",
+                                oninput: move |evt| {
+                                    if let Ok(date) = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d") {
+                                        end_date.set(date);
+                                    }
+                                },
+                            }
+                        }
+                    }
+                    div { class: "space-y-1",
+                        label { class: "block text-sm font-medium text-gray-700", "理由" }
+                        textarea {
+                            class: INPUT_CLASS,
+                            rows: 2,
+                            maxlength: "{MAX_REASON_CHARS}",
+                            value: "{reason}",
+                            oninput: move |evt| reason.set(evt.value()),
+                        }
+                    }
+                    div { class: "flex justify-end",
+                        button {
+                            class: "px-4 py-2 bg-red-600 text-white rounded-lg hover:bg-red-700 font-semibold transition-colors disabled:opacity-50",
+                            disabled: reason().trim().is_empty() || is_saving(),
+                            onclick: handle_submit,
+                            if is_saving() { "処理中..." } else { "{selected_kind}の手続きを行う" }
+                        }
+                    }
+                }
+            }
+
+            if !current.deselections.is_empty() {
+                div { class: "space-y-2",
+                    div { class: "flex items-center justify-between",
+                        h4 { class: "font-semibold text-gray-800", "選任解除の記録" }
+                        div { class: "flex items-center gap-3",
+                            if let Some(pdf) = ledger() {
+                                a {
+                                    class: "text-sm font-semibold py-1 px-4 rounded-lg bg-green-600 text-white hover:bg-green-700",
+                                    href: pdf.data_url(),
+                                    download: pdf.filename.clone(),
+                                    "⬇ ダウンロード"
+                                }
+                            }
+                            button {
+                                class: "text-sm font-semibold py-1 px-4 rounded-lg bg-white border border-gray-300 text-gray-700 hover:bg-gray-50 disabled:opacity-50",
+                                disabled: is_generating(),
+                                onclick: handle_ledger,
+                                if is_generating() { "作成中..." } else { "📄 解任印付きの運転者台帳" }
+                            }
+                        }
+                    }
+                    table { class: "w-full text-sm",
+                        thead {
+                            tr { class: "text-left text-gray-500 border-b",
+                                th { class: "py-2", "区分" }
+                                th { class: "py-2", "選任期間" }
+                                th { class: "py-2", "理由" }
+                                th { class: "py-2", "台帳の保存期限" }
+                            }
+                        }
+                        tbody {
+                            for deselection in current.deselections.iter() {
+                                tr { key: "{deselection.id}", class: "border-b last:border-0",
+                                    td { class: "py-2",
+                                        span { class: "px-2 py-0.5 rounded text-xs {deselection.kind.badge_class()}",
+                                            "{deselection.kind}"
+                                        }
+                                    }
+                                    td { class: "py-2 text-gray-700",
+                                        "{deselection.driver_start_date} 〜 {deselection.driver_end_date}"
+                                    }
+                                    td { class: "py-2 text-gray-700", "{deselection.reason}" }
+                                    td { class: "py-2 text-gray-700", "{deselection.ledger_retained_until()}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! 運転者の選任解除・退職の手続きのハンドラー層（サーバー関数）
+
+use super::models::{OffboardingRequest, OffboardingResult, OffboardingStatus};
+use crate::modules::driver_ledger::DriverLedgerPdf;
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{validate, OffboardingTarget};
+#[cfg(feature = "server")]
+use super::repository::OffboardingRepository;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::driver_ledger::handlers::build_ledger_pdf;
+
+/// 解任した運転者の台帳に付ける印
+pub const DESELECTION_STAMP: &str = "解任";
+
+/// 従業員の選任解除・退職の手続きの状況を取得
+#[server]
+pub async fn get_offboarding_status(employee_id: i32) -> Result<OffboardingStatus, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let target = get_target(employee_id).await?;
+    let deselections = OffboardingRepository::get_deselections(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(OffboardingStatus {
+        employee_id,
+        is_active: target.is_active,
+        is_selected: target.is_selected(),
+        deselections,
+    })
+}
+
+/// 選任解除・退職の手続きを行う
+#[server]
+pub async fn offboard_employee(
+    request: OffboardingRequest,
+) -> Result<OffboardingResult, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    let target = get_target(request.employee_id).await?;
+
+    // バリデーション
+    let open_periods =
+        OffboardingRepository::get_open_periods(request.employee_id, request.end_date)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    validate(
+        &request,
+        &target,
+        &open_periods,
+        chrono::Local::now().date_naive(),
+    )
+    .map_err(ServerFnError::new)?;
+
+    OffboardingRepository::offboard(&request, &target)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 解任の印を付けた運転者台帳PDFを生成（選任解除の記録がある従業員のみ）
+#[server]
+pub async fn generate_deselected_ledger(
+    employee_id: i32,
+) -> Result<DriverLedgerPdf, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    let deselections = OffboardingRepository::get_deselections(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if deselections.is_empty() {
+        return Err(ServerFnError::new("選任解除の記録がありません".to_string()));
+    }
+
+    let mut pdf = build_ledger_pdf(employee_id, Some(DESELECTION_STAMP)).await?;
+    pdf.filename = format!(
+        "{}_{}.pdf",
+        pdf.filename.trim_end_matches(".pdf"),
+        DESELECTION_STAMP
+    );
+    Ok(pdf)
+}
+
+#[cfg(feature = "server")]
+async fn get_target(employee_id: i32) -> Result<OffboardingTarget, ServerFnError> {
+    OffboardingRepository::get_target(employee_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .ok_or_else(|| ServerFnError::new("従業員が見つかりません".to_string()))
+}
@@ -0,0 +1,14 @@
+//! 運転者の選任解除・退職の手続きモジュール
+//! 選任終了日と解任の理由を記録し（運転者台帳は解任日から3年間保存）、退職では
+//! 現在の配属・住所・保険を退職日で終了させて銀行口座を無効にする。解任印付きの運転者台帳も出力する
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::OffboardingPanel;
+pub use handlers::*;
+pub use models::{DriverDeselection, OffboardingKind, OffboardingRequest, OffboardingResult};
@@ -0,0 +1,323 @@
+//! 運転者の選任解除・退職の手続きのモデル定義
+
+use crate::modules::retention::models::{disposal_date, RETIREE_RETENTION_YEARS};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 解任の理由の最大文字数（employees.driver_end_note に合わせる）
+pub const MAX_REASON_CHARS: usize = 255;
+
+/// 手続きの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum OffboardingKind {
+    /// 選任解除のみ（在籍は継続）
+    #[serde(rename = "DESELECTION")]
+    Deselection,
+    /// 退職（選任中なら選任も解除）
+    #[serde(rename = "RETIREMENT")]
+    Retirement,
+}
+
+impl OffboardingKind {
+    pub const ALL: [OffboardingKind; 2] =
+        [OffboardingKind::Deselection, OffboardingKind::Retirement];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OffboardingKind::Deselection => "DESELECTION",
+            OffboardingKind::Retirement => "RETIREMENT",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.as_str() == value)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            OffboardingKind::Deselection => "選任解除",
+            OffboardingKind::Retirement => "退職",
+        }
+    }
+
+    /// 日付欄の表示名
+    pub fn date_label(&self) -> &'static str {
+        match self {
+            OffboardingKind::Deselection => "解任日",
+            OffboardingKind::Retirement => "退職日",
+        }
+    }
+
+    /// バッジ表示用のTailwindクラス
+    pub fn badge_class(&self) -> &'static str {
+        match self {
+            OffboardingKind::Deselection => "bg-yellow-50 text-yellow-700 border border-yellow-300",
+            OffboardingKind::Retirement => "bg-gray-100 text-gray-700 border border-gray-300",
+        }
+    }
+}
+
+impl std::fmt::Display for OffboardingKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 選任解除の記録
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct DriverDeselection {
+    pub id: i32,
+    pub employee_id: i32,
+    pub kind: OffboardingKind,
+    pub driver_start_date: NaiveDate,
+    /// 選任終了日（解任日）
+    pub driver_end_date: NaiveDate,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DriverDeselection {
+    /// 運転者台帳の保存期限（解任日から3年）
+    pub fn ledger_retained_until(&self) -> NaiveDate {
+        disposal_date(self.driver_end_date, RETIREE_RETENTION_YEARS)
+    }
+}
+
+/// 選任解除・退職の手続きの入力
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OffboardingRequest {
+    pub employee_id: i32,
+    pub kind: OffboardingKind,
+    /// 解任日・退職日
+    pub end_date: NaiveDate,
+    /// 解任・退職の理由
+    pub reason: String,
+}
+
+/// 手続きで終了させる、開始日のある記録（配属・住所・保険）
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenPeriod {
+    /// 記録の表示名
+    pub label: String,
+    pub start_date: NaiveDate,
+}
+
+/// 手続きの対象の従業員
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct OffboardingTarget {
+    pub employee_id: i32,
+    pub is_active: bool,
+    /// 雇用開始日
+    pub start_date: Option<NaiveDate>,
+    pub driver_start_date: Option<NaiveDate>,
+    pub driver_end_date: Option<NaiveDate>,
+}
+
+impl OffboardingTarget {
+    /// 選任中か（選任終了日が設定されていない）
+    pub fn is_selected(&self) -> bool {
+        self.driver_start_date.is_some() && self.driver_end_date.is_none()
+    }
+}
+
+/// 手続きの結果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OffboardingResult {
+    /// 記録した選任解除（選任中でなかった場合はNone）
+    pub deselection: Option<DriverDeselection>,
+    /// 終了した配属履歴の件数
+    pub closed_positions: usize,
+    /// 終了した住所の件数
+    pub closed_addresses: usize,
+    /// 無効にした銀行口座の件数
+    pub closed_bank_accounts: usize,
+    /// 終了した保険の件数
+    pub closed_insurances: usize,
+}
+
+/// 従業員詳細に表示する手続きの状況
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OffboardingStatus {
+    pub employee_id: i32,
+    pub is_active: bool,
+    pub is_selected: bool,
+    /// 選任解除の記録（解任日の新しい順）
+    pub deselections: Vec<DriverDeselection>,
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+/// 手続きの入力を確認する
+///
+/// `open_periods` は退職で終了させる記録（退職日より前に始まっていること）
+pub fn validate(
+    request: &OffboardingRequest,
+    target: &OffboardingTarget,
+    open_periods: &[OpenPeriod],
+    today: NaiveDate,
+) -> Result<(), String> {
+    let label = request.kind.date_label();
+    let reason = request.reason.trim();
+    if reason.is_empty() {
+        return Err(format!("{}の理由を入力してください", request.kind));
+    }
+    if reason.chars().count() > MAX_REASON_CHARS {
+        return Err(format!(
+            "理由は{}文字以内で入力してください",
+            MAX_REASON_CHARS
+        ));
+    }
+    if !target.is_active {
+        return Err("在籍していない従業員です".to_string());
+    }
+    if request.end_date > today {
+        return Err(format!("{}に未来の日付は指定できません", label));
+    }
+
+    if let (true, Some(start)) = (target.is_selected(), target.driver_start_date) {
+        if request.end_date < start {
+            return Err(format!(
+                "{}は選任開始日（{}）以降の日付を指定してください",
+                label,
+                format_date(start)
+            ));
+        }
+    }
+
+    match request.kind {
+        OffboardingKind::Deselection => {
+            if !target.is_selected() {
+                return Err("運転者に選任されていません".to_string());
+            }
+        }
+        OffboardingKind::Retirement => {
+            let starts = target.start_date.map(|d| ("雇用", d)).into_iter().chain(
+                open_periods
+                    .iter()
+                    .map(|p| (p.label.as_str(), p.start_date)),
+            );
+            for (name, start) in starts {
+                if request.end_date <= start {
+                    return Err(format!(
+                        "退職日は{}の開始日（{}）より後の日付を指定してください",
+                        name,
+                        format_date(start)
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn target() -> OffboardingTarget {
+        OffboardingTarget {
+            employee_id: 1,
+            is_active: true,
+            start_date: Some(date(2020, 4, 1)),
+            driver_start_date: Some(date(2021, 4, 1)),
+            driver_end_date: None,
+        }
+    }
+
+    fn request(kind: OffboardingKind, end_date: NaiveDate) -> OffboardingRequest {
+        OffboardingRequest {
+            employee_id: 1,
+            kind,
+            end_date,
+            reason: "配置転換".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_deselection_requires_selected_driver() {
+        let today = date(2026, 4, 1);
+        let req = request(OffboardingKind::Deselection, date(2026, 3, 31));
+        assert!(validate(&req, &target(), &[], today).is_ok());
+
+        let deselected = OffboardingTarget {
+            driver_end_date: Some(date(2025, 3, 31)),
+            ..target()
+        };
+        assert_eq!(
+            validate(&req, &deselected, &[], today),
+            Err("運転者に選任されていません".to_string())
+        );
+
+        let early = request(OffboardingKind::Deselection, date(2021, 3, 31));
+        assert!(validate(&early, &target(), &[], today)
+            .unwrap_err()
+            .contains("選任開始日（2021-04-01）以降"));
+
+        let future = request(OffboardingKind::Deselection, date(2026, 4, 2));
+        assert!(validate(&future, &target(), &[], today).is_err());
+
+        let blank = OffboardingRequest {
+            reason: "  ".to_string(),
+            ..req
+        };
+        assert!(validate(&blank, &target(), &[], today).is_err());
+    }
+
+    #[test]
+    fn test_retirement_must_follow_open_periods() {
+        let today = date(2026, 4, 1);
+        let periods = [OpenPeriod {
+            label: "配属".to_string(),
+            start_date: date(2026, 3, 1),
+        }];
+        let req = request(OffboardingKind::Retirement, date(2026, 3, 31));
+        assert!(validate(&req, &target(), &periods, today).is_ok());
+
+        // 選任されていない従業員も退職の手続きはできる
+        let not_driver = OffboardingTarget {
+            driver_start_date: None,
+            ..target()
+        };
+        assert!(validate(&req, &not_driver, &periods, today).is_ok());
+
+        let same_day = request(OffboardingKind::Retirement, date(2026, 3, 1));
+        assert_eq!(
+            validate(&same_day, &target(), &periods, today),
+            Err("退職日は配属の開始日（2026-03-01）より後の日付を指定してください".to_string())
+        );
+
+        let retired = OffboardingTarget {
+            is_active: false,
+            ..target()
+        };
+        assert!(validate(&req, &retired, &periods, today).is_err());
+    }
+
+    #[test]
+    fn test_ledger_retention_counts_from_deselection() {
+        let deselection = DriverDeselection {
+            id: 1,
+            employee_id: 1,
+            kind: OffboardingKind::Deselection,
+            driver_start_date: date(2021, 4, 1),
+            driver_end_date: date(2026, 3, 31),
+            reason: "配置転換".to_string(),
+            created_at: Utc::now(),
+        };
+        assert_eq!(deselection.ledger_retained_until(), date(2029, 3, 31));
+    }
+}
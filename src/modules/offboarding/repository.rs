@@ -0,0 +1,354 @@
+//! 運転者の選任解除・退職の手続きのリポジトリ層
+
+use super::models::{
+    DriverDeselection, OffboardingKind, OffboardingRequest, OffboardingResult, OffboardingTarget,
+    OpenPeriod,
+};
+use crate::db;
+use crate::modules::audit_log;
+use crate::modules::employees::repository::EmployeeRepository;
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// 配属・住所の終了（監査ログ用）
+#[derive(Serialize)]
+struct PeriodState {
+    end_date: Option<NaiveDate>,
+    is_current: bool,
+}
+
+/// 銀行口座の無効化（監査ログ用）
+#[derive(Serialize)]
+struct BankAccountState {
+    is_primary: bool,
+    is_active: bool,
+}
+
+/// 保険の終了（監査ログ用）
+#[derive(Serialize)]
+struct InsuranceState {
+    end_date: Option<NaiveDate>,
+    is_active: bool,
+}
+
+/// 運転者の選任解除・退職の手続きリポジトリ
+pub struct OffboardingRepository;
+
+impl OffboardingRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 手続きの対象の従業員を取得
+    pub async fn get_target(employee_id: i32) -> Result<Option<OffboardingTarget>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            OffboardingTarget,
+            r#"
+            SELECT
+                id AS employee_id,
+                is_active,
+                start_date,
+                driver_start_date,
+                driver_end_date
+            FROM employees
+            WHERE id = $1
+            "#,
+            employee_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 退職日で終了させる配属・住所・保険の開始日を取得（終了日が退職日以前のものは除く）
+    pub async fn get_open_periods(
+        employee_id: i32,
+        end_date: NaiveDate,
+    ) -> Result<Vec<OpenPeriod>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let rows = sqlx::query!(
+            r#"
+            SELECT '配属' AS "label!", start_date AS "start_date!"
+            FROM department_position_history
+            WHERE employee_id = $1 AND is_current
+              AND (end_date IS NULL OR end_date > $2)
+            UNION ALL
+            SELECT '住所', start_date
+            FROM addresses
+            WHERE employee_id = $1 AND is_current
+              AND (end_date IS NULL OR end_date > $2)
+            UNION ALL
+            SELECT '保険', start_date
+            FROM insurance_history
+            WHERE employee_id = $1 AND is_active
+              AND (end_date IS NULL OR end_date > $2)
+            "#,
+            employee_id,
+            end_date
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| OpenPeriod {
+                label: r.label,
+                start_date: r.start_date,
+            })
+            .collect())
+    }
+
+    /// 選任解除の記録を取得（解任日の新しい順）
+    pub async fn get_deselections(employee_id: i32) -> Result<Vec<DriverDeselection>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            DriverDeselection,
+            r#"
+            SELECT
+                id,
+                employee_id,
+                kind AS "kind: OffboardingKind",
+                driver_start_date,
+                driver_end_date,
+                reason,
+                created_at
+            FROM driver_deselections
+            WHERE employee_id = $1
+            ORDER BY driver_end_date DESC, id DESC
+            "#,
+            employee_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 選任解除・退職の手続きを行う
+    ///
+    /// * 選任中なら選任終了日・解除理由を設定し、選任解除の記録（台帳の保存期間の起算日）を残す
+    /// * 退職なら雇用終了日を設定して在籍を外し、現在の配属・住所・保険を退職日で終了させ、
+    ///   銀行口座を無効にする
+    pub async fn offboard(
+        request: &OffboardingRequest,
+        target: &OffboardingTarget,
+    ) -> Result<OffboardingResult, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let employee_id = request.employee_id;
+        let reason = request.reason.trim();
+        let is_retirement = request.kind == OffboardingKind::Retirement;
+        let old_employee = EmployeeRepository::get_by_id(employee_id).await?;
+
+        let mut tx = pool.begin().await?;
+        let mut result = OffboardingResult::default();
+
+        if let (true, Some(driver_start_date)) = (target.is_selected(), target.driver_start_date) {
+            let deselection = sqlx::query_as!(
+                DriverDeselection,
+                r#"
+                INSERT INTO driver_deselections
+                    (employee_id, kind, driver_start_date, driver_end_date, reason)
+                VALUES ($1, $2, $3, $4, $5)
+                RETURNING
+                    id,
+                    employee_id,
+                    kind AS "kind: OffboardingKind",
+                    driver_start_date,
+                    driver_end_date,
+                    reason,
+                    created_at
+                "#,
+                employee_id,
+                request.kind.as_str(),
+                driver_start_date,
+                request.end_date,
+                reason
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            result.deselection = Some(deselection);
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE employees
+            SET driver_end_date = CASE WHEN $4::BOOLEAN THEN $2::DATE ELSE driver_end_date END,
+                driver_end_note = CASE WHEN $4::BOOLEAN THEN $3::VARCHAR ELSE driver_end_note END,
+                end_date = CASE WHEN $5::BOOLEAN THEN $2::DATE ELSE end_date END,
+                is_active = is_active AND NOT $5::BOOLEAN,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            "#,
+            employee_id,
+            request.end_date,
+            reason,
+            result.deselection.is_some(),
+            is_retirement
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let mut positions = Vec::new();
+        let mut addresses = Vec::new();
+        let mut bank_accounts = Vec::new();
+        let mut insurances = Vec::new();
+        if is_retirement {
+            // 同じテーブルを結合して変更前の値を返す
+            positions = sqlx::query!(
+                r#"
+                UPDATE department_position_history h
+                SET end_date = LEAST(h.end_date, $2::DATE),
+                    is_current = FALSE,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM department_position_history old
+                WHERE old.id = h.id AND h.employee_id = $1 AND h.is_current
+                RETURNING h.id, h.end_date AS "end_date!", old.end_date AS "old_end_date?"
+                "#,
+                employee_id,
+                request.end_date
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.old_end_date, r.end_date))
+            .collect();
+
+            addresses = sqlx::query!(
+                r#"
+                UPDATE addresses a
+                SET end_date = LEAST(a.end_date, $2::DATE),
+                    is_current = FALSE,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM addresses old
+                WHERE old.id = a.id AND a.employee_id = $1 AND a.is_current
+                RETURNING a.id, a.end_date AS "end_date!", old.end_date AS "old_end_date?"
+                "#,
+                employee_id,
+                request.end_date
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.old_end_date, r.end_date))
+            .collect();
+
+            bank_accounts = sqlx::query!(
+                r#"
+                UPDATE employee_bank_accounts b
+                SET is_active = FALSE,
+                    is_primary = FALSE,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM employee_bank_accounts old
+                WHERE old.id = b.id AND b.employee_id = $1 AND b.is_active
+                RETURNING b.id, old.is_primary AS "old_is_primary!"
+                "#,
+                employee_id
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.old_is_primary))
+            .collect();
+
+            insurances = sqlx::query!(
+                r#"
+                UPDATE insurance_history i
+                SET end_date = LEAST(i.end_date, $2::DATE),
+                    is_active = FALSE,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM insurance_history old
+                WHERE old.id = i.id AND i.employee_id = $1 AND i.is_active
+                RETURNING i.id, i.end_date AS "end_date!", old.end_date AS "old_end_date?"
+                "#,
+                employee_id,
+                request.end_date
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|r| (r.id, r.old_end_date, r.end_date))
+            .collect();
+        }
+
+        tx.commit().await?;
+
+        // 監査ログ
+        if let Some(deselection) = &result.deselection {
+            audit_log::record_create(
+                "driver_deselections",
+                deselection.id,
+                Some(employee_id),
+                deselection,
+            )
+            .await;
+        }
+        if let Some(new) = EmployeeRepository::get_by_id(employee_id).await? {
+            audit_log::record_update(
+                "employees",
+                employee_id,
+                Some(employee_id),
+                old_employee.as_ref(),
+                &new,
+            )
+            .await;
+        }
+        for (table, rows) in [
+            ("department_position_history", &positions),
+            ("addresses", &addresses),
+        ] {
+            for (id, old_end_date, end_date) in rows {
+                audit_log::record_update(
+                    table,
+                    *id,
+                    Some(employee_id),
+                    Some(&PeriodState {
+                        end_date: *old_end_date,
+                        is_current: true,
+                    }),
+                    &PeriodState {
+                        end_date: Some(*end_date),
+                        is_current: false,
+                    },
+                )
+                .await;
+            }
+        }
+        for (id, old_is_primary) in &bank_accounts {
+            audit_log::record_update(
+                "employee_bank_accounts",
+                *id,
+                Some(employee_id),
+                Some(&BankAccountState {
+                    is_primary: *old_is_primary,
+                    is_active: true,
+                }),
+                &BankAccountState {
+                    is_primary: false,
+                    is_active: false,
+                },
+            )
+            .await;
+        }
+        for (id, old_end_date, end_date) in &insurances {
+            audit_log::record_update(
+                "insurance_history",
+                *id,
+                Some(employee_id),
+                Some(&InsuranceState {
+                    end_date: *old_end_date,
+                    is_active: true,
+                }),
+                &InsuranceState {
+                    end_date: Some(*end_date),
+                    is_active: false,
+                },
+            )
+            .await;
+        }
+
+        result.closed_positions = positions.len();
+        result.closed_addresses = addresses.len();
+        result.closed_bank_accounts = bank_accounts.len();
+        result.closed_insurances = insurances.len();
+        Ok(result)
+    }
+}
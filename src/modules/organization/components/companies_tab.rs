@@ -0,0 +1,278 @@
+use crate::components::{opt_string, TextField, INPUT_CLASS};
+use crate::modules::organization::components::form_fields::{
+    DeleteButton, FormActions, TD_CLASS, TH_CLASS,
+};
+use crate::modules::organization::handlers::{create_company, delete_company, update_company};
+use crate::modules::organization::models::{Company, CreateCompany};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 会社マスタのタブ
+#[component]
+pub fn CompaniesTab(mut companies: Resource<Result<Vec<Company>, ServerFnError>>) -> Element {
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<Company>>);
+    let mut error_message = use_signal(String::new);
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "会社" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 会社を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                CompanyForm {
+                    key: "{initial.as_ref().map(|c| c.id).unwrap_or(0)}",
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        companies.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*companies.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "会社が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    table { class: "w-full",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                th { class: TH_CLASS, "会社名" }
+                                th { class: TH_CLASS, "法人番号" }
+                                th { class: TH_CLASS, "電話番号" }
+                                th { class: TH_CLASS, "状態" }
+                                th { class: TH_CLASS, "" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for company in list.iter().cloned() {
+                                {
+                                    let id = company.id;
+                                    let edit_item = company.clone();
+                                    rsx! {
+                                        tr { key: "{id}",
+                                            td { class: TD_CLASS,
+                                                p { class: "font-semibold", "{company.name}" }
+                                                if let Some(kana) = &company.name_kana {
+                                                    p { class: "text-xs text-gray-500", "{kana}" }
+                                                }
+                                            }
+                                            td { class: TD_CLASS, "{company.corporate_number.clone().unwrap_or_default()}" }
+                                            td { class: TD_CLASS, "{company.phone.clone().unwrap_or_default()}" }
+                                            td { class: TD_CLASS,
+                                                if company.is_active { "有効" } else { "無効" }
+                                            }
+                                            td { class: "{TD_CLASS} text-right",
+                                                div { class: "flex justify-end gap-3",
+                                                    button {
+                                                        class: "text-amber-700 hover:underline",
+                                                        onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                        "編集"
+                                                    }
+                                                    DeleteButton {
+                                                        on_confirm: move |_| async move {
+                                                            match delete_company(id).await {
+                                                                Ok(_) => {
+                                                                    error_message.set(String::new());
+                                                                    companies.restart();
+                                                                }
+                                                                Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                            }
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "会社の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 会社の入力フォーム（新規は名称のみ、編集は全項目）
+#[component]
+fn CompanyForm(
+    initial: Option<Company>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let mut name = use_signal(|| initial.as_ref().map(|c| c.name.clone()).unwrap_or_default());
+    let mut name_kana = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|c| c.name_kana.clone())
+            .unwrap_or_default()
+    });
+    let mut company = use_signal(|| initial.clone());
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let mut edit = move |f: &dyn Fn(&mut Company)| {
+        let mut current = company();
+        if let Some(c) = current.as_mut() {
+            f(c);
+        }
+        company.set(current);
+    };
+
+    let handle_submit = move |_| async move {
+        is_submitting.set(true);
+        let result = match company() {
+            Some(mut c) => {
+                c.name = name();
+                c.name_kana = opt_string(name_kana());
+                update_company(c).await.map(|_| ())
+            }
+            None => create_company(CreateCompany {
+                name: name(),
+                name_kana: opt_string(name_kana()),
+            })
+            .await
+            .map(|_| ()),
+        };
+        is_submitting.set(false);
+
+        match result {
+            Ok(_) => on_saved.call(()),
+            Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+        }
+    };
+
+    let text =
+        move |f: fn(&Company) -> Option<String>| company().as_ref().and_then(f).unwrap_or_default();
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                TextField {
+                    label: "会社名".to_string(),
+                    value: name(),
+                    required: true,
+                    on_input: move |v| name.set(v),
+                }
+                TextField {
+                    label: "会社名（カナ）".to_string(),
+                    value: name_kana(),
+                    on_input: move |v| name_kana.set(v),
+                }
+                if company().is_some() {
+                    TextField {
+                        label: "法人番号".to_string(),
+                        value: text(|c| c.corporate_number.clone()),
+                        placeholder: "13桁".to_string(),
+                        on_input: move |v: String| edit(&|c| c.corporate_number = opt_string(v.clone())),
+                    }
+                    TextField {
+                        label: "インボイス登録番号".to_string(),
+                        value: text(|c| c.invoice_registration_number.clone()),
+                        placeholder: "T + 13桁".to_string(),
+                        on_input: move |v: String| edit(&|c| c.invoice_registration_number = opt_string(v.clone())),
+                    }
+                    TextField {
+                        label: "代表者名".to_string(),
+                        value: text(|c| c.representative_name.clone()),
+                        on_input: move |v: String| edit(&|c| c.representative_name = opt_string(v.clone())),
+                    }
+                    TextField {
+                        label: "郵便番号".to_string(),
+                        value: text(|c| c.postal_code.clone()),
+                        on_input: move |v: String| edit(&|c| c.postal_code = opt_string(v.clone())),
+                    }
+                    div { class: "col-span-2",
+                        TextField {
+                            label: "住所".to_string(),
+                            value: text(|c| c.address.clone()),
+                            on_input: move |v: String| edit(&|c| c.address = opt_string(v.clone())),
+                        }
+                    }
+                    TextField {
+                        label: "電話番号".to_string(),
+                        value: text(|c| c.phone.clone()),
+                        on_input: move |v: String| edit(&|c| c.phone = opt_string(v.clone())),
+                    }
+                    TextField {
+                        label: "FAX番号".to_string(),
+                        value: text(|c| c.fax.clone()),
+                        on_input: move |v: String| edit(&|c| c.fax = opt_string(v.clone())),
+                    }
+                    TextField {
+                        label: "メールアドレス".to_string(),
+                        value: text(|c| c.email.clone()),
+                        on_input: move |v: String| edit(&|c| c.email = opt_string(v.clone())),
+                    }
+                    div { class: "space-y-2",
+                        label { class: "block text-sm font-medium text-gray-700", "設立日" }
+                        input {
+                            class: INPUT_CLASS,
+                            r#type: "date",
+                            value: text(|c| c.establishment_date.map(|d| d.to_string())),
+                            onchange: move |evt| {
+                                let date = NaiveDate::parse_from_str(&evt.value(), "%Y-%m-%d").ok();
+                                edit(&|c| c.establishment_date = date);
+                            },
+                        }
+                    }
+                    TextField {
+                        label: "資本金（円）".to_string(),
+                        value: text(|c| c.capital_amount.map(|v| v.to_string())),
+                        on_input: move |v: String| edit(&|c| c.capital_amount = v.trim().parse().ok()),
+                    }
+                    TextField {
+                        label: "決算月".to_string(),
+                        value: text(|c| c.fiscal_year_end_month.map(|v| v.to_string())),
+                        placeholder: "1〜12".to_string(),
+                        on_input: move |v: String| edit(&|c| c.fiscal_year_end_month = v.trim().parse().ok()),
+                    }
+                }
+            }
+            if company().is_some() {
+                label { class: "flex items-center gap-2 text-sm text-gray-700",
+                    input {
+                        r#type: "checkbox",
+                        checked: company().is_some_and(|c| c.is_active),
+                        onchange: move |evt| {
+                            let checked = evt.checked();
+                            edit(&|c| c.is_active = checked);
+                        },
+                    }
+                    "有効"
+                }
+            }
+            FormActions {
+                error_message: error_message(),
+                is_submitting: is_submitting(),
+                disabled: name().trim().is_empty(),
+                is_new: company().is_none(),
+                on_submit: handle_submit,
+                on_cancel: move |_| on_cancel.call(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::organization::models::Company;
+use dioxus::prelude::*;
+
+pub const TH_CLASS: &str =
+    "px-4 py-3 text-left text-xs font-semibold text-gray-600 uppercase tracking-wider";
+pub const TD_CLASS: &str = "px-4 py-3 text-sm text-gray-800";
+
+/// 会社IDから会社名を引く
+pub fn company_name(companies: &[Company], id: i32) -> String {
+    companies
+        .iter()
+        .find(|c| c.id == id)
+        .map(|c| c.name.clone())
+        .unwrap_or_else(|| format!("会社ID: {}", id))
+}
+
+/// 会社の選択
+#[component]
+pub fn CompanySelect(
+    companies: Vec<Company>,
+    value: Option<i32>,
+    on_change: EventHandler<Option<i32>>,
+) -> Element {
+    rsx! {
+        div { class: "space-y-2",
+            label { class: "block text-sm font-medium text-gray-700",
+                "会社"
+                span { class: "text-red-500", " *" }
+            }
+            select {
+                class: INPUT_CLASS,
+                onchange: move |evt| on_change.call(evt.value().parse().ok()),
+                option { value: "", "選択してください" }
+                for company in companies.iter() {
+                    option {
+                        value: "{company.id}",
+                        selected: value == Some(company.id),
+                        "{company.name}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 2段階確認の削除ボタン
+#[component]
+pub fn DeleteButton(on_confirm: EventHandler<()>) -> Element {
+    let mut confirming = use_signal(|| false);
+
+    rsx! {
+        if confirming() {
+            span { class: "inline-flex items-center gap-2",
+                button {
+                    class: "px-2 py-1 text-xs font-semibold text-white bg-red-600 rounded hover:bg-red-700",
+                    onclick: move |_| {
+                        confirming.set(false);
+                        on_confirm.call(());
+                    },
+                    "削除する"
+                }
+                button {
+                    class: "px-2 py-1 text-xs text-gray-700 bg-gray-100 rounded hover:bg-gray-200",
+                    onclick: move |_| confirming.set(false),
+                    "戻る"
+                }
+            }
+        } else {
+            button {
+                class: "text-red-600 hover:underline",
+                onclick: move |_| confirming.set(true),
+                "削除"
+            }
+        }
+    }
+}
+
+/// 保存・キャンセルボタンとエラー表示
+#[component]
+pub fn FormActions(
+    error_message: String,
+    is_submitting: bool,
+    disabled: bool,
+    is_new: bool,
+    on_submit: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    rsx! {
+        if !error_message.is_empty() {
+            p { class: "text-red-500 text-sm", "{error_message}" }
+        }
+        div { class: "flex justify-end gap-2",
+            button {
+                class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                onclick: move |_| on_cancel.call(()),
+                "キャンセル"
+            }
+            button {
+                class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                disabled: is_submitting || disabled,
+                onclick: move |_| on_submit.call(()),
+                if is_new { "追加" } else { "更新" }
+            }
+        }
+    }
+}
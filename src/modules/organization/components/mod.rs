@@ -0,0 +1,10 @@
+// 組織マスタ関連のコンポーネント
+
+mod companies_tab;
+mod form_fields;
+mod named_master_tab;
+mod offices_tab;
+
+pub use companies_tab::CompaniesTab;
+pub use named_master_tab::NamedMasterTab;
+pub use offices_tab::OfficesTab;
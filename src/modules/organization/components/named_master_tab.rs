@@ -0,0 +1,198 @@
+use crate::components::TextField;
+use crate::modules::organization::components::form_fields::{
+    company_name, CompanySelect, DeleteButton, FormActions, TD_CLASS, TH_CLASS,
+};
+use crate::modules::organization::handlers::{
+    create_named_master, delete_named_master, get_named_masters, update_named_master,
+};
+use crate::modules::organization::models::{
+    Company, CreateNamedMaster, NamedMaster, NamedMasterKind,
+};
+use dioxus::prelude::*;
+
+/// 部署・役職マスタのタブ
+#[component]
+pub fn NamedMasterTab(kind: NamedMasterKind, companies: Vec<Company>) -> Element {
+    let mut masters = use_resource(move || async move { get_named_masters(kind).await });
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<NamedMaster>>);
+    let mut error_message = use_signal(String::new);
+    let label = kind.display_name();
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "{label}" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ {label}を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                NamedMasterForm {
+                    key: "{initial.as_ref().map(|m| m.id).unwrap_or(0)}",
+                    kind,
+                    companies: companies.clone(),
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        masters.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*masters.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "{label}が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    table { class: "w-full",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                th { class: TH_CLASS, "{label}名" }
+                                th { class: TH_CLASS, "会社" }
+                                th { class: TH_CLASS, "" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for master in list.iter().cloned() {
+                                {
+                                    let id = master.id;
+                                    let edit_item = master.clone();
+                                    rsx! {
+                                        tr { key: "{id}",
+                                            td { class: "{TD_CLASS} font-semibold", "{master.name}" }
+                                            td { class: TD_CLASS, "{company_name(&companies, master.company_id)}" }
+                                            td { class: "{TD_CLASS} text-right",
+                                                div { class: "flex justify-end gap-3",
+                                                    button {
+                                                        class: "text-amber-700 hover:underline",
+                                                        onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                        "編集"
+                                                    }
+                                                    DeleteButton {
+                                                        on_confirm: move |_| async move {
+                                                            match delete_named_master(kind, id).await {
+                                                                Ok(_) => {
+                                                                    error_message.set(String::new());
+                                                                    masters.restart();
+                                                                }
+                                                                Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                            }
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "{label}の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 部署・役職の入力フォーム（新規・編集兼用）
+#[component]
+fn NamedMasterForm(
+    kind: NamedMasterKind,
+    companies: Vec<Company>,
+    initial: Option<NamedMaster>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record = initial.clone();
+    let mut company_id = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|m| m.company_id)
+            .or_else(|| companies.first().map(|c| c.id))
+    });
+    let mut name = use_signal(|| initial.as_ref().map(|m| m.name.clone()).unwrap_or_default());
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| {
+        let record = record.clone();
+        async move {
+            let Some(company_id) = company_id() else {
+                error_message.set("会社を選択してください".to_string());
+                return;
+            };
+            is_submitting.set(true);
+            let result = match record {
+                Some(master) => update_named_master(
+                    kind,
+                    NamedMaster {
+                        company_id,
+                        name: name(),
+                        ..master
+                    },
+                )
+                .await
+                .map(|_| ()),
+                None => create_named_master(
+                    kind,
+                    CreateNamedMaster {
+                        company_id,
+                        name: name(),
+                    },
+                )
+                .await
+                .map(|_| ()),
+            };
+            is_submitting.set(false);
+
+            match result {
+                Ok(_) => on_saved.call(()),
+                Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                CompanySelect {
+                    companies: companies.clone(),
+                    value: company_id(),
+                    on_change: move |v| company_id.set(v),
+                }
+                TextField {
+                    label: format!("{}名", kind.display_name()),
+                    value: name(),
+                    required: true,
+                    on_input: move |v| name.set(v),
+                }
+            }
+            FormActions {
+                error_message: error_message(),
+                is_submitting: is_submitting(),
+                disabled: name().trim().is_empty() || company_id().is_none(),
+                is_new: initial.is_none(),
+                on_submit: handle_submit,
+                on_cancel: move |_| on_cancel.call(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use crate::components::{opt_string, TextField};
+use crate::modules::organization::components::form_fields::{
+    company_name, CompanySelect, DeleteButton, FormActions, TD_CLASS, TH_CLASS,
+};
+use crate::modules::organization::handlers::{create_office, delete_office, update_office};
+use crate::modules::organization::models::{Company, CreateOffice, Office};
+use dioxus::prelude::*;
+
+/// 営業所マスタのタブ
+#[component]
+pub fn OfficesTab(
+    companies: Vec<Company>,
+    mut offices: Resource<Result<Vec<Office>, ServerFnError>>,
+) -> Element {
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<Office>>);
+    let mut error_message = use_signal(String::new);
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "営業所" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 営業所を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                OfficeForm {
+                    key: "{initial.as_ref().map(|o| o.id).unwrap_or(0)}",
+                    companies: companies.clone(),
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        offices.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*offices.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "営業所が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    table { class: "w-full",
+                        thead { class: "bg-gray-50",
+                            tr {
+                                th { class: TH_CLASS, "コード" }
+                                th { class: TH_CLASS, "営業所名" }
+                                th { class: TH_CLASS, "会社" }
+                                th { class: TH_CLASS, "住所" }
+                                th { class: TH_CLASS, "電話番号" }
+                                th { class: TH_CLASS, "" }
+                            }
+                        }
+                        tbody { class: "divide-y divide-gray-100",
+                            for office in list.iter().cloned() {
+                                {
+                                    let id = office.id;
+                                    let edit_item = office.clone();
+                                    rsx! {
+                                        tr {
+                                            key: "{id}",
+                                            class: if !office.is_active { "opacity-60" },
+                                            td { class: TD_CLASS, "{office.office_code.clone().unwrap_or_default()}" }
+                                            td { class: TD_CLASS,
+                                                div { class: "flex items-center gap-2",
+                                                    span { class: "font-semibold", "{office.name}" }
+                                                    if office.is_headquarters {
+                                                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-800", "本社" }
+                                                    }
+                                                    if !office.is_active {
+                                                        span { class: "px-2 py-0.5 rounded-full text-xs font-medium bg-gray-200 text-gray-600", "閉鎖" }
+                                                    }
+                                                }
+                                            }
+                                            td { class: TD_CLASS, "{company_name(&companies, office.company_id)}" }
+                                            td { class: TD_CLASS, "{office.address.clone().unwrap_or_default()}" }
+                                            td { class: TD_CLASS, "{office.phone.clone().unwrap_or_default()}" }
+                                            td { class: "{TD_CLASS} text-right",
+                                                div { class: "flex justify-end gap-3",
+                                                    button {
+                                                        class: "text-amber-700 hover:underline",
+                                                        onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                        "編集"
+                                                    }
+                                                    DeleteButton {
+                                                        on_confirm: move |_| async move {
+                                                            match delete_office(id).await {
+                                                                Ok(_) => {
+                                                                    error_message.set(String::new());
+                                                                    offices.restart();
+                                                                }
+                                                                Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                            }
+                                                        },
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "営業所の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 営業所の入力フォーム（新規・編集兼用）
+#[component]
+fn OfficeForm(
+    companies: Vec<Company>,
+    initial: Option<Office>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record = initial.clone();
+    let text = |f: fn(&Office) -> Option<String>| initial.as_ref().and_then(f).unwrap_or_default();
+    let mut company_id = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|o| o.company_id)
+            .or_else(|| companies.first().map(|c| c.id))
+    });
+    let mut name = use_signal(|| text(|o| Some(o.name.clone())));
+    let mut office_code = use_signal(|| text(|o| o.office_code.clone()));
+    let mut postal_code = use_signal(|| text(|o| o.postal_code.clone()));
+    let mut address = use_signal(|| text(|o| o.address.clone()));
+    let mut phone = use_signal(|| text(|o| o.phone.clone()));
+    let mut is_headquarters = use_signal(|| initial.as_ref().is_some_and(|o| o.is_headquarters));
+    let mut is_active = use_signal(|| initial.as_ref().is_none_or(|o| o.is_active));
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| {
+        let record = record.clone();
+        async move {
+            let Some(company_id) = company_id() else {
+                error_message.set("会社を選択してください".to_string());
+                return;
+            };
+            is_submitting.set(true);
+            let result = match record {
+                Some(office) => update_office(Office {
+                    company_id,
+                    name: name(),
+                    office_code: opt_string(office_code()),
+                    postal_code: opt_string(postal_code()),
+                    address: opt_string(address()),
+                    phone: opt_string(phone()),
+                    is_headquarters: is_headquarters(),
+                    is_active: is_active(),
+                    ..office
+                })
+                .await
+                .map(|_| ()),
+                None => create_office(CreateOffice {
+                    company_id,
+                    name: name(),
+                    office_code: opt_string(office_code()),
+                    postal_code: opt_string(postal_code()),
+                    address: opt_string(address()),
+                    phone: opt_string(phone()),
+                })
+                .await
+                .map(|_| ()),
+            };
+            is_submitting.set(false);
+
+            match result {
+                Ok(_) => on_saved.call(()),
+                Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                CompanySelect {
+                    companies: companies.clone(),
+                    value: company_id(),
+                    on_change: move |v| company_id.set(v),
+                }
+                TextField {
+                    label: "営業所コード".to_string(),
+                    value: office_code(),
+                    on_input: move |v| office_code.set(v),
+                }
+                TextField {
+                    label: "営業所名".to_string(),
+                    value: name(),
+                    required: true,
+                    on_input: move |v| name.set(v),
+                }
+                TextField {
+                    label: "郵便番号".to_string(),
+                    value: postal_code(),
+                    on_input: move |v| postal_code.set(v),
+                }
+                div { class: "col-span-2",
+                    TextField {
+                        label: "住所".to_string(),
+                        value: address(),
+                        on_input: move |v| address.set(v),
+                    }
+                }
+                TextField {
+                    label: "電話番号".to_string(),
+                    value: phone(),
+                    on_input: move |v| phone.set(v),
+                }
+            }
+            if initial.is_some() {
+                div { class: "flex gap-6 text-sm text-gray-700",
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            checked: is_headquarters(),
+                            onchange: move |evt| is_headquarters.set(evt.checked()),
+                        }
+                        "本社"
+                    }
+                    label { class: "flex items-center gap-2",
+                        input {
+                            r#type: "checkbox",
+                            checked: is_active(),
+                            onchange: move |evt| is_active.set(evt.checked()),
+                        }
+                        "稼働中"
+                    }
+                }
+            }
+            FormActions {
+                error_message: error_message(),
+                is_submitting: is_submitting(),
+                disabled: name().trim().is_empty() || company_id().is_none(),
+                is_new: initial.is_none(),
+                on_submit: handle_submit,
+                on_cancel: move |_| on_cancel.call(()),
+            }
+        }
+    }
+}
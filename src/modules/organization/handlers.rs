@@ -0,0 +1,283 @@
+//! 組織マスタのハンドラー層（サーバー関数）
+
+#[cfg(feature = "server")]
+use super::models::{references_message, ReferenceCount};
+use super::models::{
+    Company, CreateCompany, CreateNamedMaster, CreateOffice, NamedMaster, NamedMasterKind, Office,
+};
+#[cfg(feature = "server")]
+use super::repository::OrganizationRepository;
+use super::validation::{
+    validate_fiscal_year_end_month, validate_master_name, validate_office_code,
+};
+use crate::modules::clients::validation::{
+    validate_corporate_number, validate_email, validate_invoice_registration_number, validate_phone,
+};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// データベースエラーを`ServerFnError`に変換するヘルパー関数
+#[cfg(feature = "server")]
+fn db_error_to_server_error(error: sqlx::Error) -> ServerFnError {
+    let error_msg = error.to_string();
+
+    // 重複・制約エラーの場合、わかりやすいメッセージに変換
+    if error_msg.contains("companies_corporate_number_key") {
+        ServerFnError::new("この法人番号は既に登録されています".to_string())
+    } else if error_msg.contains("companies_invoice_registration_number_key") {
+        ServerFnError::new("この登録番号は既に登録されています".to_string())
+    } else if error_msg.contains("uq_offices_company_name") {
+        ServerFnError::new("この営業所名は既に登録されています".to_string())
+    } else if error_msg.contains("uq_offices_company_code") {
+        ServerFnError::new("この営業所コードは既に使用されています".to_string())
+    } else if error_msg.contains("uq_departments_company_name") {
+        ServerFnError::new("この部署名は既に登録されています".to_string())
+    } else if error_msg.contains("uq_positions_company_name") {
+        ServerFnError::new("この役職名は既に登録されています".to_string())
+    } else if error_msg.contains("violates foreign key constraint") {
+        ServerFnError::new("他のデータから参照されているため削除できません".to_string())
+    } else {
+        ServerFnError::new(error_msg)
+    }
+}
+
+/// 参照が残っている場合は削除不可のエラーにする
+#[cfg(feature = "server")]
+fn ensure_unreferenced(name: &str, references: &[ReferenceCount]) -> Result<(), ServerFnError> {
+    match references_message(name, references) {
+        Some(message) => Err(ServerFnError::new(message)),
+        None => Ok(()),
+    }
+}
+
+/// 会社の全項目をバリデーション
+fn validate_company(company: &Company) -> Result<(), ServerFnError> {
+    validate_master_name(&company.name, "会社名", 200).map_err(ServerFnError::new)?;
+    validate_corporate_number(company.corporate_number.as_deref().unwrap_or(""))
+        .map_err(ServerFnError::new)?;
+    validate_invoice_registration_number(
+        company.invoice_registration_number.as_deref().unwrap_or(""),
+    )
+    .map_err(ServerFnError::new)?;
+    validate_phone(company.phone.as_deref().unwrap_or(""), "電話番号")
+        .map_err(ServerFnError::new)?;
+    validate_phone(company.fax.as_deref().unwrap_or(""), "FAX番号").map_err(ServerFnError::new)?;
+    validate_email(company.email.as_deref().unwrap_or("")).map_err(ServerFnError::new)?;
+    if let Some(month) = company.fiscal_year_end_month {
+        validate_fiscal_year_end_month(month).map_err(ServerFnError::new)?;
+    }
+    if company.capital_amount.is_some_and(|amount| amount < 0) {
+        return Err(ServerFnError::new(
+            "資本金は0以上で入力してください".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// 営業所の全項目をバリデーション
+fn validate_office(office: &Office) -> Result<(), ServerFnError> {
+    validate_master_name(&office.name, "営業所名", 100).map_err(ServerFnError::new)?;
+    validate_office_code(office.office_code.as_deref().unwrap_or(""))
+        .map_err(ServerFnError::new)?;
+    validate_phone(office.phone.as_deref().unwrap_or(""), "電話番号")
+        .map_err(ServerFnError::new)?;
+    Ok(())
+}
+
+// ========================================
+// 会社
+// ========================================
+
+/// 全会社を取得
+#[server]
+pub async fn get_companies() -> Result<Vec<Company>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    OrganizationRepository::get_companies()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 会社の作成
+#[server]
+pub async fn create_company(data: CreateCompany) -> Result<Company, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    validate_master_name(&data.name, "会社名", 200).map_err(ServerFnError::new)?;
+
+    OrganizationRepository::create_company(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 会社の全項目を更新
+#[server]
+pub async fn update_company(company: Company) -> Result<Company, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    validate_company(&company)?;
+
+    OrganizationRepository::update_company(company)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 会社の削除（従業員・営業所などから参照されている場合は不可）
+#[server]
+pub async fn delete_company(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    let Some(company) = OrganizationRepository::get_company_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    else {
+        return Err(ServerFnError::new("会社が見つかりません".to_string()));
+    };
+    let references = OrganizationRepository::count_company_references(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    ensure_unreferenced(&company.name, &references)?;
+
+    OrganizationRepository::delete_company(id)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+// ========================================
+// 営業所
+// ========================================
+
+/// 全営業所を取得
+#[server]
+pub async fn get_offices() -> Result<Vec<Office>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    OrganizationRepository::get_offices()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 営業所の作成
+#[server]
+pub async fn create_office(data: CreateOffice) -> Result<Office, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    validate_master_name(&data.name, "営業所名", 100).map_err(ServerFnError::new)?;
+    validate_office_code(data.office_code.as_deref().unwrap_or("")).map_err(ServerFnError::new)?;
+    validate_phone(data.phone.as_deref().unwrap_or(""), "電話番号").map_err(ServerFnError::new)?;
+
+    OrganizationRepository::create_office(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 営業所の全項目を更新
+#[server]
+pub async fn update_office(office: Office) -> Result<Office, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    validate_office(&office)?;
+
+    OrganizationRepository::update_office(office)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 営業所の削除（従業員・配属履歴などから参照されている場合は不可）
+#[server]
+pub async fn delete_office(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    let Some(office) = OrganizationRepository::get_office_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    else {
+        return Err(ServerFnError::new("営業所が見つかりません".to_string()));
+    };
+    let references = OrganizationRepository::count_office_references(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    ensure_unreferenced(&office.name, &references)?;
+
+    OrganizationRepository::delete_office(id)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+// ========================================
+// 部署・役職
+// ========================================
+
+/// 部署または役職の一覧を取得
+#[server]
+pub async fn get_named_masters(kind: NamedMasterKind) -> Result<Vec<NamedMaster>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    OrganizationRepository::get_named_masters(kind)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 部署または役職の作成
+#[server]
+pub async fn create_named_master(
+    kind: NamedMasterKind,
+    data: CreateNamedMaster,
+) -> Result<NamedMaster, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    let label = format!("{}名", kind.display_name());
+    validate_master_name(&data.name, &label, 100).map_err(ServerFnError::new)?;
+
+    OrganizationRepository::create_named_master(kind, data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 部署または役職の更新
+#[server]
+pub async fn update_named_master(
+    kind: NamedMasterKind,
+    data: NamedMaster,
+) -> Result<NamedMaster, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    let label = format!("{}名", kind.display_name());
+    validate_master_name(&data.name, &label, 100).map_err(ServerFnError::new)?;
+
+    OrganizationRepository::update_named_master(kind, data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 部署または役職の削除（従業員・配属履歴から参照されている場合は不可）
+#[server]
+pub async fn delete_named_master(kind: NamedMasterKind, id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    let Some(master) = OrganizationRepository::get_named_master_by_id(kind, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+    else {
+        return Err(ServerFnError::new(format!(
+            "{}が見つかりません",
+            kind.display_name()
+        )));
+    };
+    let references = OrganizationRepository::count_named_master_references(kind, id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    ensure_unreferenced(&master.name, &references)?;
+
+    OrganizationRepository::delete_named_master(kind, id)
+        .await
+        .map_err(db_error_to_server_error)
+}
@@ -0,0 +1,17 @@
+//! 組織設定モジュール
+//! 会社・営業所・部署・役職のマスタ管理
+
+mod components;
+pub mod handlers;
+pub mod models;
+mod page;
+
+#[cfg(feature = "server")]
+pub mod repository;
+mod validation;
+
+pub use models::{
+    Company, CreateCompany, CreateNamedMaster, CreateOffice, NamedMaster, NamedMasterKind, Office,
+    ReferenceCount,
+};
+pub use page::SettingsPage;
@@ -0,0 +1,163 @@
+//! 組織マスタ（会社・営業所・部署・役職）のモデル定義
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 会社
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct Company {
+    pub id: i32,
+    pub name: String,
+    pub name_kana: Option<String>,
+    pub corporate_number: Option<String>,
+    pub invoice_registration_number: Option<String>,
+    pub representative_name: Option<String>,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub email: Option<String>,
+    pub establishment_date: Option<NaiveDate>,
+    pub capital_amount: Option<i64>,
+    pub fiscal_year_end_month: Option<i32>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 会社の新規作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateCompany {
+    pub name: String,
+    pub name_kana: Option<String>,
+}
+
+/// 営業所
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct Office {
+    pub id: i32,
+    pub company_id: i32,
+    pub name: String,
+    pub office_code: Option<String>,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub is_headquarters: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 営業所の新規作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateOffice {
+    pub company_id: i32,
+    pub name: String,
+    pub office_code: Option<String>,
+    pub postal_code: Option<String>,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+}
+
+/// 部署・役職（名称のみのマスタ）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct NamedMaster {
+    pub id: i32,
+    pub company_id: i32,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 部署・役職の新規作成
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateNamedMaster {
+    pub company_id: i32,
+    pub name: String,
+}
+
+/// 名称のみのマスタの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NamedMasterKind {
+    Department,
+    Position,
+}
+
+impl NamedMasterKind {
+    /// テーブル名
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            NamedMasterKind::Department => "departments",
+            NamedMasterKind::Position => "positions",
+        }
+    }
+
+    /// 表示名
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            NamedMasterKind::Department => "部署",
+            NamedMasterKind::Position => "役職",
+        }
+    }
+}
+
+/// 削除を妨げる参照の件数
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceCount {
+    pub label: String,
+    pub count: i64,
+}
+
+/// 参照件数から削除不可のメッセージを作成（参照がなければNone）
+pub fn references_message(name: &str, references: &[ReferenceCount]) -> Option<String> {
+    let used: Vec<String> = references
+        .iter()
+        .filter(|r| r.count > 0)
+        .map(|r| format!("{} {}件", r.label, r.count))
+        .collect();
+    if used.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "「{}」は{}で使用されているため削除できません",
+            name,
+            used.join("、")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_references_message() {
+        let none = vec![ReferenceCount {
+            label: "従業員".to_string(),
+            count: 0,
+        }];
+        assert_eq!(references_message("本社営業所", &none), None);
+
+        let used = vec![
+            ReferenceCount {
+                label: "従業員".to_string(),
+                count: 3,
+            },
+            ReferenceCount {
+                label: "配属履歴".to_string(),
+                count: 0,
+            },
+            ReferenceCount {
+                label: "車両".to_string(),
+                count: 2,
+            },
+        ];
+        assert_eq!(
+            references_message("本社営業所", &used).unwrap(),
+            "「本社営業所」は従業員 3件、車両 2件で使用されているため削除できません"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use dioxus::prelude::*;
+
+use crate::modules::organization::components::{CompaniesTab, NamedMasterTab, OfficesTab};
+use crate::modules::organization::handlers::{get_companies, get_offices};
+use crate::modules::organization::models::NamedMasterKind;
+
+// 共通のCSSクラス定数
+const HEADER_CLASS: &str =
+    "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white";
+const CONTENT_CLASS: &str = "flex-1 overflow-auto p-6";
+const BUTTON_TOGGLE_ACTIVE_CLASS: &str = "bg-amber-400 text-gray-800 hover:bg-amber-500";
+const BUTTON_TOGGLE_INACTIVE_CLASS: &str = "bg-gray-200 text-gray-700 hover:bg-gray-300";
+const BUTTON_TOGGLE_BASE_CLASS: &str =
+    "px-4 py-2 rounded-lg text-sm font-semibold transition-all shadow-sm";
+
+/// 設定画面のタブ
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SettingsTab {
+    Companies,
+    Offices,
+    Departments,
+    Positions,
+}
+
+impl SettingsTab {
+    const ALL: [SettingsTab; 4] = [
+        SettingsTab::Companies,
+        SettingsTab::Offices,
+        SettingsTab::Departments,
+        SettingsTab::Positions,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SettingsTab::Companies => "会社",
+            SettingsTab::Offices => "営業所",
+            SettingsTab::Departments => "部署",
+            SettingsTab::Positions => "役職",
+        }
+    }
+}
+
+#[component]
+pub fn SettingsPage() -> Element {
+    let companies = use_resource(|| async move { get_companies().await });
+    let offices = use_resource(|| async move { get_offices().await });
+    let mut current_tab = use_signal(|| SettingsTab::Companies);
+
+    rsx! {
+        document::Title { "組織設定" }
+        div { class: "flex flex-col h-full",
+            // ヘッダー
+            div { class: HEADER_CLASS,
+                h1 { class: "text-lg font-semibold text-gray-800", "組織設定" }
+            }
+
+            // コンテンツ
+            div { class: CONTENT_CLASS,
+                div { class: "mb-6 flex items-center gap-3",
+                    for tab in SettingsTab::ALL {
+                        button {
+                            key: "{tab.label()}",
+                            class: format!("{} {}", BUTTON_TOGGLE_BASE_CLASS, if current_tab() == tab {
+                                BUTTON_TOGGLE_ACTIVE_CLASS
+                            } else {
+                                BUTTON_TOGGLE_INACTIVE_CLASS
+                            }),
+                            onclick: move |_| current_tab.set(tab),
+                            "{tab.label()}"
+                        }
+                    }
+                }
+
+                match &*companies.read_unchecked() {
+                    Some(Ok(list)) => {
+                        let list = list.clone();
+                        let tab = current_tab();
+                        match tab {
+                            SettingsTab::Companies => rsx! {
+                                CompaniesTab { companies }
+                            },
+                            SettingsTab::Offices => rsx! {
+                                OfficesTab { companies: list, offices }
+                            },
+                            SettingsTab::Departments => rsx! {
+                                NamedMasterTab { key: "{tab.label()}", kind: NamedMasterKind::Department, companies: list }
+                            },
+                            SettingsTab::Positions => rsx! {
+                                NamedMasterTab { key: "{tab.label()}", kind: NamedMasterKind::Position, companies: list }
+                            },
+                        }
+                    }
+                    Some(Err(_)) => rsx! {
+                        div { class: "text-center py-12",
+                            p { class: "text-red-500 text-lg", "エラーが発生しました" }
+                        }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center items-center py-12",
+                            div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
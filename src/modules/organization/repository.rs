@@ -0,0 +1,522 @@
+#[cfg(feature = "server")]
+use super::models::{
+    Company, CreateCompany, CreateNamedMaster, CreateOffice, NamedMaster, NamedMasterKind, Office,
+    ReferenceCount,
+};
+#[cfg(feature = "server")]
+use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
+
+#[cfg(feature = "server")]
+pub struct OrganizationRepository;
+
+#[cfg(feature = "server")]
+fn reference(label: &str, count: i64) -> ReferenceCount {
+    ReferenceCount {
+        label: label.to_string(),
+        count,
+    }
+}
+
+#[cfg(feature = "server")]
+impl OrganizationRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    // ========================================
+    // 会社
+    // ========================================
+
+    /// 全会社を取得
+    pub async fn get_companies() -> Result<Vec<Company>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Company,
+            r#"SELECT id, name, name_kana, corporate_number, invoice_registration_number,
+                      representative_name, postal_code, address, phone, fax, email,
+                      establishment_date, capital_amount, fiscal_year_end_month,
+                      is_active, created_at, updated_at
+               FROM companies
+               ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで会社を取得
+    pub async fn get_company_by_id(id: i32) -> Result<Option<Company>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Company,
+            r#"SELECT id, name, name_kana, corporate_number, invoice_registration_number,
+                      representative_name, postal_code, address, phone, fax, email,
+                      establishment_date, capital_amount, fiscal_year_end_month,
+                      is_active, created_at, updated_at
+               FROM companies WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 会社の作成
+    pub async fn create_company(data: CreateCompany) -> Result<Company, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let company = sqlx::query_as!(
+            Company,
+            r#"INSERT INTO companies (name, name_kana, is_active, created_at, updated_at)
+               VALUES ($1, $2, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, name, name_kana, corporate_number, invoice_registration_number,
+                         representative_name, postal_code, address, phone, fax, email,
+                         establishment_date, capital_amount, fiscal_year_end_month,
+                         is_active, created_at, updated_at"#,
+            data.name,
+            data.name_kana
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("companies", company.id, None, &company).await;
+
+        Ok(company)
+    }
+
+    /// 会社の全項目を更新
+    pub async fn update_company(company: Company) -> Result<Company, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_company_by_id(company.id).await?;
+        let updated = sqlx::query_as!(
+            Company,
+            r#"UPDATE companies SET
+                name = $2,
+                name_kana = $3,
+                corporate_number = $4,
+                invoice_registration_number = $5,
+                representative_name = $6,
+                postal_code = $7,
+                address = $8,
+                phone = $9,
+                fax = $10,
+                email = $11,
+                establishment_date = $12,
+                capital_amount = $13,
+                fiscal_year_end_month = $14,
+                is_active = $15,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING id, name, name_kana, corporate_number, invoice_registration_number,
+                      representative_name, postal_code, address, phone, fax, email,
+                      establishment_date, capital_amount, fiscal_year_end_month,
+                      is_active, created_at, updated_at"#,
+            company.id,
+            company.name,
+            company.name_kana,
+            company.corporate_number,
+            company.invoice_registration_number,
+            company.representative_name,
+            company.postal_code,
+            company.address,
+            company.phone,
+            company.fax,
+            company.email,
+            company.establishment_date,
+            company.capital_amount,
+            company.fiscal_year_end_month,
+            company.is_active
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update("companies", updated.id, None, old.as_ref(), &updated).await;
+
+        Ok(updated)
+    }
+
+    /// 会社の削除
+    pub async fn delete_company(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_company_by_id(id).await?;
+        sqlx::query!("DELETE FROM companies WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("companies", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 会社を参照している件数
+    pub async fn count_company_references(id: i32) -> Result<Vec<ReferenceCount>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let row = sqlx::query!(
+            r#"SELECT
+                (SELECT COUNT(*) FROM employees WHERE company_id = $1) AS "employees!",
+                (SELECT COUNT(*) FROM offices WHERE company_id = $1) AS "offices!",
+                (SELECT COUNT(*) FROM departments WHERE company_id = $1) AS "departments!",
+                (SELECT COUNT(*) FROM positions WHERE company_id = $1) AS "positions!",
+                (SELECT COUNT(*) FROM vehicles WHERE company_id = $1) AS "vehicles!",
+                (SELECT COUNT(*) FROM clients WHERE company_id = $1) AS "clients!""#,
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(vec![
+            reference("従業員", row.employees),
+            reference("営業所", row.offices),
+            reference("部署", row.departments),
+            reference("役職", row.positions),
+            reference("車両", row.vehicles),
+            reference("取引先", row.clients),
+        ])
+    }
+
+    // ========================================
+    // 営業所
+    // ========================================
+
+    /// 全営業所を取得
+    pub async fn get_offices() -> Result<Vec<Office>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Office,
+            r#"SELECT id, company_id, name, office_code, postal_code, address, phone,
+                      is_headquarters, is_active, created_at, updated_at
+               FROM offices
+               ORDER BY company_id, is_headquarters DESC, office_code NULLS LAST, name"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで営業所を取得
+    pub async fn get_office_by_id(id: i32) -> Result<Option<Office>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Office,
+            r#"SELECT id, company_id, name, office_code, postal_code, address, phone,
+                      is_headquarters, is_active, created_at, updated_at
+               FROM offices WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 営業所の作成
+    pub async fn create_office(data: CreateOffice) -> Result<Office, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let office = sqlx::query_as!(
+            Office,
+            r#"INSERT INTO offices
+               (company_id, name, office_code, postal_code, address, phone,
+                is_headquarters, is_active, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, FALSE, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, company_id, name, office_code, postal_code, address, phone,
+                         is_headquarters, is_active, created_at, updated_at"#,
+            data.company_id,
+            data.name,
+            data.office_code,
+            data.postal_code,
+            data.address,
+            data.phone
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("offices", office.id, None, &office).await;
+
+        Ok(office)
+    }
+
+    /// 営業所の全項目を更新（本社に指定した場合は同じ会社の他の営業所の指定を外す）
+    pub async fn update_office(office: Office) -> Result<Office, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_office_by_id(office.id).await?;
+        let mut tx = pool.begin().await?;
+        let updated = sqlx::query_as!(
+            Office,
+            r#"UPDATE offices SET
+                company_id = $2,
+                name = $3,
+                office_code = $4,
+                postal_code = $5,
+                address = $6,
+                phone = $7,
+                is_headquarters = $8,
+                is_active = $9,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING id, company_id, name, office_code, postal_code, address, phone,
+                      is_headquarters, is_active, created_at, updated_at"#,
+            office.id,
+            office.company_id,
+            office.name,
+            office.office_code,
+            office.postal_code,
+            office.address,
+            office.phone,
+            office.is_headquarters,
+            office.is_active
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        if updated.is_headquarters {
+            sqlx::query!(
+                r#"UPDATE offices
+                   SET is_headquarters = FALSE, updated_at = CURRENT_TIMESTAMP
+                   WHERE company_id = $1 AND id <> $2 AND is_headquarters = TRUE"#,
+                updated.company_id,
+                updated.id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        audit_log::record_update("offices", updated.id, None, old.as_ref(), &updated).await;
+
+        Ok(updated)
+    }
+
+    /// 営業所の削除
+    pub async fn delete_office(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_office_by_id(id).await?;
+        sqlx::query!("DELETE FROM offices WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("offices", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 営業所を参照している件数
+    ///
+    /// 外部キーは ON DELETE SET NULL のため、削除すると所属が黙って外れてしまう
+    pub async fn count_office_references(id: i32) -> Result<Vec<ReferenceCount>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let row = sqlx::query!(
+            r#"SELECT
+                (SELECT COUNT(*) FROM employees WHERE office_id = $1) AS "employees!",
+                (SELECT COUNT(*) FROM department_position_history WHERE office_id = $1) AS "history!",
+                (SELECT COUNT(*) FROM vehicles WHERE office_id = $1) AS "vehicles!",
+                (SELECT COUNT(*) FROM users WHERE office_id = $1) AS "users!""#,
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(vec![
+            reference("従業員", row.employees),
+            reference("配属履歴", row.history),
+            reference("車両", row.vehicles),
+            reference("ユーザー", row.users),
+        ])
+    }
+
+    // ========================================
+    // 部署・役職
+    // ========================================
+
+    /// 部署または役職の一覧を取得
+    pub async fn get_named_masters(kind: NamedMasterKind) -> Result<Vec<NamedMaster>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        match kind {
+            NamedMasterKind::Department => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"SELECT id, company_id, name, created_at, updated_at
+                       FROM departments ORDER BY company_id, id"#
+                )
+                .fetch_all(pool)
+                .await
+            }
+            NamedMasterKind::Position => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"SELECT id, company_id, name, created_at, updated_at
+                       FROM positions ORDER BY company_id, id"#
+                )
+                .fetch_all(pool)
+                .await
+            }
+        }
+    }
+
+    /// IDで部署または役職を取得
+    pub async fn get_named_master_by_id(
+        kind: NamedMasterKind,
+        id: i32,
+    ) -> Result<Option<NamedMaster>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        match kind {
+            NamedMasterKind::Department => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"SELECT id, company_id, name, created_at, updated_at
+                       FROM departments WHERE id = $1"#,
+                    id
+                )
+                .fetch_optional(pool)
+                .await
+            }
+            NamedMasterKind::Position => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"SELECT id, company_id, name, created_at, updated_at
+                       FROM positions WHERE id = $1"#,
+                    id
+                )
+                .fetch_optional(pool)
+                .await
+            }
+        }
+    }
+
+    /// 部署または役職の作成
+    pub async fn create_named_master(
+        kind: NamedMasterKind,
+        data: CreateNamedMaster,
+    ) -> Result<NamedMaster, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let created = match kind {
+            NamedMasterKind::Department => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"INSERT INTO departments (company_id, name, created_at, updated_at)
+                       VALUES ($1, $2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                       RETURNING id, company_id, name, created_at, updated_at"#,
+                    data.company_id,
+                    data.name
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            NamedMasterKind::Position => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"INSERT INTO positions (company_id, name, created_at, updated_at)
+                       VALUES ($1, $2, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                       RETURNING id, company_id, name, created_at, updated_at"#,
+                    data.company_id,
+                    data.name
+                )
+                .fetch_one(pool)
+                .await?
+            }
+        };
+
+        audit_log::record_create(kind.table_name(), created.id, None, &created).await;
+
+        Ok(created)
+    }
+
+    /// 部署または役職の更新
+    pub async fn update_named_master(
+        kind: NamedMasterKind,
+        data: NamedMaster,
+    ) -> Result<NamedMaster, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_named_master_by_id(kind, data.id).await?;
+        let updated = match kind {
+            NamedMasterKind::Department => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"UPDATE departments
+                       SET company_id = $2, name = $3, updated_at = CURRENT_TIMESTAMP
+                       WHERE id = $1
+                       RETURNING id, company_id, name, created_at, updated_at"#,
+                    data.id,
+                    data.company_id,
+                    data.name
+                )
+                .fetch_one(pool)
+                .await?
+            }
+            NamedMasterKind::Position => {
+                sqlx::query_as!(
+                    NamedMaster,
+                    r#"UPDATE positions
+                       SET company_id = $2, name = $3, updated_at = CURRENT_TIMESTAMP
+                       WHERE id = $1
+                       RETURNING id, company_id, name, created_at, updated_at"#,
+                    data.id,
+                    data.company_id,
+                    data.name
+                )
+                .fetch_one(pool)
+                .await?
+            }
+        };
+
+        audit_log::record_update(kind.table_name(), updated.id, None, old.as_ref(), &updated).await;
+
+        Ok(updated)
+    }
+
+    /// 部署または役職の削除
+    pub async fn delete_named_master(kind: NamedMasterKind, id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_named_master_by_id(kind, id).await?;
+        match kind {
+            NamedMasterKind::Department => {
+                sqlx::query!("DELETE FROM departments WHERE id = $1", id)
+                    .execute(pool)
+                    .await?;
+            }
+            NamedMasterKind::Position => {
+                sqlx::query!("DELETE FROM positions WHERE id = $1", id)
+                    .execute(pool)
+                    .await?;
+            }
+        }
+
+        audit_log::record_delete(kind.table_name(), id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    /// 部署または役職を参照している件数
+    pub async fn count_named_master_references(
+        kind: NamedMasterKind,
+        id: i32,
+    ) -> Result<Vec<ReferenceCount>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let (employees, history) = match kind {
+            NamedMasterKind::Department => {
+                let row = sqlx::query!(
+                    r#"SELECT
+                        (SELECT COUNT(*) FROM employees WHERE department_id = $1) AS "employees!",
+                        (SELECT COUNT(*) FROM department_position_history
+                          WHERE department_id = $1) AS "history!""#,
+                    id
+                )
+                .fetch_one(pool)
+                .await?;
+                (row.employees, row.history)
+            }
+            NamedMasterKind::Position => {
+                let row = sqlx::query!(
+                    r#"SELECT
+                        (SELECT COUNT(*) FROM employees WHERE position_id = $1) AS "employees!",
+                        (SELECT COUNT(*) FROM department_position_history
+                          WHERE position_id = $1) AS "history!""#,
+                    id
+                )
+                .fetch_one(pool)
+                .await?;
+                (row.employees, row.history)
+            }
+        };
+
+        Ok(vec![
+            reference("従業員", employees),
+            reference("配属履歴", history),
+        ])
+    }
+}
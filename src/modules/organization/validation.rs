@@ -0,0 +1,84 @@
+/// マスタ名称のバリデーション
+///
+/// ルール:
+/// - 空文字列は不可
+/// - 前後の空白は不可
+/// - 上限文字数以内
+pub fn validate_master_name(name: &str, label: &str, max_len: usize) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(format!("{}を入力してください", label));
+    }
+
+    if name != name.trim() {
+        return Err(format!("{}の前後に空白を含めることはできません", label));
+    }
+
+    if name.chars().count() > max_len {
+        return Err(format!("{}は{}文字以内で入力してください", label, max_len));
+    }
+
+    Ok(())
+}
+
+/// 営業所コードのバリデーション
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 半角大文字アルファベット(A-Z)、数字(0-9)、ハイフンのみ許可
+/// - 50文字以内
+pub fn validate_office_code(code: &str) -> Result<(), String> {
+    if code.is_empty() {
+        return Ok(());
+    }
+
+    let is_valid = code
+        .chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-');
+    if !is_valid {
+        return Err(
+            "営業所コードは半角大文字アルファベット(A-Z)・数字(0-9)・ハイフンのみ使用できます"
+                .to_string(),
+        );
+    }
+
+    if code.len() > 50 {
+        return Err("営業所コードは50文字以内で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 決算月のバリデーション（1〜12）
+pub fn validate_fiscal_year_end_month(month: i32) -> Result<(), String> {
+    if !(1..=12).contains(&month) {
+        return Err("決算月は1〜12で入力してください".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_master_name() {
+        assert!(validate_master_name("総務部", "部署名", 100).is_ok());
+        assert!(validate_master_name("", "部署名", 100).is_err());
+        assert!(validate_master_name("総務部 ", "部署名", 100).is_err());
+        assert!(validate_master_name(&"あ".repeat(101), "部署名", 100).is_err());
+    }
+
+    #[test]
+    fn test_office_code() {
+        assert!(validate_office_code("").is_ok());
+        assert!(validate_office_code("TKY-01").is_ok());
+        assert!(validate_office_code("tky-01").is_err());
+    }
+
+    #[test]
+    fn test_fiscal_year_end_month() {
+        assert!(validate_fiscal_year_end_month(3).is_ok());
+        assert!(validate_fiscal_year_end_month(0).is_err());
+        assert!(validate_fiscal_year_end_month(13).is_err());
+    }
+}
@@ -3,11 +3,16 @@ use super::models::{CreateQualification, Qualification, QualificationType, Updat
 use super::repository::QualificationRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の資格証を取得
 #[server]
 pub async fn get_qualifications_by_employee(
     employee_id: i32,
 ) -> Result<Vec<Qualification>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     QualificationRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -18,6 +23,8 @@ pub async fn get_qualifications_by_employee(
 pub async fn get_active_qualifications(
     employee_id: i32,
 ) -> Result<Vec<Qualification>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     QualificationRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -28,6 +35,8 @@ pub async fn get_active_qualifications(
 pub async fn create_qualification(
     data: CreateQualification,
 ) -> Result<Qualification, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let (Some(issue_date), Some(expiration_date)) = (data.issue_date, data.expiration_date) {
         if issue_date >= expiration_date {
@@ -47,6 +56,8 @@ pub async fn create_qualification(
 pub async fn update_qualification(
     data: UpdateQualification,
 ) -> Result<Qualification, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
     // バリデーション
     if let (Some(issue_date), Some(expiration_date)) = (data.issue_date, data.expiration_date) {
         if issue_date >= expiration_date {
@@ -64,6 +75,8 @@ pub async fn update_qualification(
 /// 資格証を削除
 #[server]
 pub async fn delete_qualification(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     QualificationRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -72,6 +85,8 @@ pub async fn delete_qualification(id: i32) -> Result<(), ServerFnError> {
 /// 全資格種別を取得
 #[server]
 pub async fn get_all_qualification_types() -> Result<Vec<QualificationType>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     QualificationRepository::get_all_qualification_types()
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
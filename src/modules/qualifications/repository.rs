@@ -2,6 +2,8 @@
 use super::models::{CreateQualification, Qualification, QualificationType, UpdateQualification};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct QualificationRepository;
@@ -68,7 +70,7 @@ impl QualificationRepository {
     /// 資格証を作成
     pub async fn create(data: CreateQualification) -> Result<Qualification, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let qualification = sqlx::query_as!(
             Qualification,
             r#"INSERT INTO qualifications
                (employee_id, qualification_type_id, qualification_number, issue_date,
@@ -87,13 +89,24 @@ impl QualificationRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create(
+            "qualifications",
+            qualification.id,
+            Some(qualification.employee_id),
+            &qualification,
+        )
+        .await;
+
+        Ok(qualification)
     }
 
     /// 資格証を更新
     pub async fn update(data: UpdateQualification) -> Result<Qualification, sqlx::Error> {
         let pool = Self::pool().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let qualification = sqlx::query_as!(
             Qualification,
             r#"UPDATE qualifications
                SET qualification_type_id = $2, qualification_number = $3, issue_date = $4,
@@ -112,15 +125,36 @@ impl QualificationRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "qualifications",
+            qualification.id,
+            Some(qualification.employee_id),
+            old.as_ref(),
+            &qualification,
+        )
+        .await;
+
+        Ok(qualification)
     }
 
     /// 資格証を削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM qualifications WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "qualifications",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
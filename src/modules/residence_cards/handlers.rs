@@ -3,11 +3,16 @@ use super::models::{CreateResidenceCard, ResidenceCard, UpdateResidenceCard};
 use super::repository::ResidenceCardRepository;
 use dioxus::prelude::*;
 
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
 /// 従業員の在留カード一覧を取得
 #[server]
 pub async fn get_residence_cards_by_employee(
     employee_id: i32,
 ) -> Result<Vec<ResidenceCard>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     ResidenceCardRepository::get_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -18,6 +23,8 @@ pub async fn get_residence_cards_by_employee(
 pub async fn get_active_residence_cards(
     employee_id: i32,
 ) -> Result<Vec<ResidenceCard>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     ResidenceCardRepository::get_active_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -28,6 +35,8 @@ pub async fn get_active_residence_cards(
 pub async fn get_current_residence_card(
     employee_id: i32,
 ) -> Result<Option<ResidenceCard>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     ResidenceCardRepository::get_current_by_employee_id(employee_id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -38,6 +47,8 @@ pub async fn get_current_residence_card(
 pub async fn create_residence_card(
     data: CreateResidenceCard,
 ) -> Result<ResidenceCard, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     ResidenceCardRepository::create(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -48,6 +59,8 @@ pub async fn create_residence_card(
 pub async fn update_residence_card(
     data: UpdateResidenceCard,
 ) -> Result<ResidenceCard, ServerFnError> {
+    require_permission(Permission::EditEmployee).await?;
+
     ResidenceCardRepository::update(data)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -56,6 +69,8 @@ pub async fn update_residence_card(
 /// 在留カードを削除
 #[server]
 pub async fn delete_residence_card(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
     ResidenceCardRepository::delete(id)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
@@ -64,6 +79,8 @@ pub async fn delete_residence_card(id: i32) -> Result<(), ServerFnError> {
 /// 期限切れ間近の在留カードを取得
 #[server]
 pub async fn get_expiring_residence_cards(days: i32) -> Result<Vec<ResidenceCard>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
     ResidenceCardRepository::get_expiring_soon(days)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))
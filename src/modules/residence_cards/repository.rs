@@ -2,6 +2,8 @@
 use super::models::{CreateResidenceCard, ResidenceCard, UpdateResidenceCard};
 #[cfg(feature = "server")]
 use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
 
 #[cfg(feature = "server")]
 pub struct ResidenceCardRepository;
@@ -81,7 +83,7 @@ impl ResidenceCardRepository {
     /// 在留カードを作成
     pub async fn create(data: CreateResidenceCard) -> Result<ResidenceCard, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let card = sqlx::query_as!(
             ResidenceCard,
             r#"INSERT INTO residence_cards (employee_id, residence_card_type_id, card_number, issue_date, expiration_date, work_restrictions, is_active, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
@@ -94,13 +96,18 @@ impl ResidenceCardRepository {
             data.work_restrictions
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_create("residence_cards", card.id, Some(card.employee_id), &card).await;
+
+        Ok(card)
     }
 
     /// 在留カードを更新
     pub async fn update(data: UpdateResidenceCard) -> Result<ResidenceCard, sqlx::Error> {
         let pool = db::get_pool_async().await?;
-        sqlx::query_as!(
+        let old = Self::get_by_id(data.id).await?;
+        let card = sqlx::query_as!(
             ResidenceCard,
             r#"UPDATE residence_cards
                SET residence_card_type_id = $2, card_number = $3, issue_date = $4,
@@ -117,15 +124,36 @@ impl ResidenceCardRepository {
             data.is_active
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        audit_log::record_update(
+            "residence_cards",
+            card.id,
+            Some(card.employee_id),
+            old.as_ref(),
+            &card,
+        )
+        .await;
+
+        Ok(card)
     }
 
     /// 在留カードを削除
     pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
         let pool = db::get_pool_async().await?;
+        let old = Self::get_by_id(id).await?;
         sqlx::query!(r#"DELETE FROM residence_cards WHERE id = $1"#, id)
             .execute(pool)
             .await?;
+
+        audit_log::record_delete(
+            "residence_cards",
+            id,
+            old.as_ref().map(|o| o.employee_id),
+            old.as_ref(),
+        )
+        .await;
+
         Ok(())
     }
 
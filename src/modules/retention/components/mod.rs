@@ -0,0 +1,5 @@
+// 保管期限管理のコンポーネント
+
+mod retention_page;
+
+pub use retention_page::RetentionPage;
@@ -0,0 +1,334 @@
+use crate::components::INPUT_CLASS;
+use crate::modules::auth::models::Permission;
+use crate::modules::auth::use_permission;
+use crate::modules::retention::handlers::{
+    get_retention_records, purge_retention_records, set_legal_hold,
+};
+use crate::modules::retention::models::{RetentionKey, RetentionRecord, RetentionTarget};
+use crate::Route;
+use dioxus::prelude::*;
+
+const SECTION_CLASS: &str = "bg-white rounded-xl shadow-sm border border-gray-200 p-6";
+const SECTION_TITLE_CLASS: &str = "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400";
+const BUTTON_PRIMARY_CLASS: &str = "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed";
+const BUTTON_DANGER_CLASS: &str = "px-4 py-2 bg-red-600 text-white rounded-lg hover:bg-red-700 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed";
+const BUTTON_SECONDARY_CLASS: &str =
+    "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors";
+
+/// 保管期限管理画面（廃棄対象の一覧・保留・廃棄）
+#[component]
+pub fn RetentionPage() -> Element {
+    let is_admin = use_permission(Permission::Admin);
+    let mut due_only = use_signal(|| true);
+    let mut selected = use_signal(Vec::<RetentionKey>::new);
+    let mut hold_target = use_signal(|| None::<RetentionRecord>);
+    let mut hold_reason = use_signal(String::new);
+    let mut confirm_purge = use_signal(|| false);
+    let mut is_working = use_signal(|| false);
+    let mut message = use_signal(|| None::<String>);
+    let mut error_message = use_signal(String::new);
+
+    let mut records = use_resource(move || async move { get_retention_records(due_only()).await });
+    let today = chrono::Local::now().date_naive();
+
+    let handle_hold = move |key: RetentionKey, legal_hold: bool, reason: Option<String>| async move {
+        is_working.set(true);
+        error_message.set(String::new());
+        match set_legal_hold(key, legal_hold, reason).await {
+            Ok(()) => {
+                hold_target.set(None);
+                hold_reason.set(String::new());
+                selected.write().retain(|k| *k != key);
+                records.restart();
+            }
+            Err(e) => error_message.set(format!("保留の設定に失敗しました: {}", e)),
+        }
+        is_working.set(false);
+    };
+
+    let handle_purge = move |_| async move {
+        is_working.set(true);
+        error_message.set(String::new());
+        message.set(None);
+        match purge_retention_records(selected()).await {
+            Ok(result) => {
+                let mut text = format!("{}件を廃棄しました", result.purged);
+                if !result.storage_errors.is_empty() {
+                    text.push_str(&format!(
+                        "（保存先のファイル{}件を削除できませんでした: {}）",
+                        result.storage_errors.len(),
+                        result.storage_errors.join(" / ")
+                    ));
+                }
+                message.set(Some(text));
+                selected.set(Vec::new());
+                records.restart();
+            }
+            Err(e) => error_message.set(format!("廃棄に失敗しました: {}", e)),
+        }
+        confirm_purge.set(false);
+        is_working.set(false);
+    };
+
+    let selected_count = selected.read().len();
+
+    rsx! {
+        document::Title { "保管期限" }
+        div { class: "flex flex-col h-full",
+            // ヘッダー
+            div { class: "flex items-center h-14 px-6 border-b border-gray-200 bg-white shadow-sm",
+                h1 { class: "text-lg font-semibold text-gray-800", "保管期限" }
+            }
+
+            div { class: "flex-1 overflow-auto p-6 space-y-6",
+                div { class: SECTION_CLASS,
+                    h3 { class: SECTION_TITLE_CLASS, "廃棄対象の記録" }
+                    p { class: "text-sm text-gray-600 mb-4",
+                        "書類は書類種別の保管年数（未設定は3年）、退職者の記録（乗務員台帳を含む）は退職日・選任解除日のいずれか遅い日から3年間保管します。"
+                        "置き換えられた以前の版の書類は、置き換えられた日から起算します。保留中の記録は期限を過ぎても廃棄できません。"
+                    }
+                    div { class: "flex flex-wrap items-center justify-between gap-4",
+                        label { class: "flex items-center gap-2 text-sm text-gray-700",
+                            input {
+                                r#type: "checkbox",
+                                checked: due_only(),
+                                onchange: move |evt| {
+                                    due_only.set(evt.checked());
+                                    selected.set(Vec::new());
+                                },
+                            }
+                            "廃棄できる記録のみ表示"
+                        }
+                        if is_admin() {
+                            button {
+                                class: BUTTON_DANGER_CLASS,
+                                disabled: selected_count == 0 || is_working(),
+                                onclick: move |_| confirm_purge.set(true),
+                                "選択した{selected_count}件を廃棄"
+                            }
+                        }
+                    }
+                }
+
+                if let Some(text) = message() {
+                    div { class: "p-4 bg-green-50 border border-green-200 rounded-lg text-green-800", "{text}" }
+                }
+                if !error_message().is_empty() {
+                    div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700", "{error_message}" }
+                }
+
+                // 保留の理由の入力
+                if let Some(record) = hold_target() {
+                    div { class: SECTION_CLASS,
+                        h3 { class: SECTION_TITLE_CLASS, "廃棄の保留" }
+                        p { class: "text-sm text-gray-700 mb-3",
+                            "{record.employee_code} {record.employee_name}（{record.item_name}）"
+                        }
+                        if record.target == RetentionTarget::Employee {
+                            p { class: "text-sm text-gray-600 mb-3",
+                                "従業員を保留すると、その従業員の書類もすべて廃棄できなくなります。"
+                            }
+                        }
+                        input {
+                            class: INPUT_CLASS,
+                            placeholder: "保留の理由（訴訟・調査など）",
+                            value: "{hold_reason}",
+                            oninput: move |evt| hold_reason.set(evt.value()),
+                        }
+                        div { class: "flex justify-end gap-3 mt-4",
+                            button {
+                                class: BUTTON_SECONDARY_CLASS,
+                                onclick: move |_| hold_target.set(None),
+                                "キャンセル"
+                            }
+                            button {
+                                class: BUTTON_PRIMARY_CLASS,
+                                disabled: hold_reason().trim().is_empty() || is_working(),
+                                onclick: move |_| handle_hold(record.key(), true, Some(hold_reason())),
+                                "保留する"
+                            }
+                        }
+                    }
+                }
+
+                match &*records.read_unchecked() {
+                    Some(Ok(list)) if list.is_empty() => rsx! {
+                        div { class: "{SECTION_CLASS} text-center py-12 text-gray-500",
+                            if due_only() {
+                                "廃棄できる記録はありません"
+                            } else {
+                                "保管期限の対象となる記録はありません"
+                            }
+                        }
+                    },
+                    Some(Ok(list)) => rsx! {
+                        div { class: "{SECTION_CLASS} overflow-x-auto",
+                            table { class: "w-full text-sm",
+                                thead {
+                                    tr { class: "text-left text-gray-600 border-b",
+                                        if is_admin() {
+                                            th { class: "py-2 pr-2" }
+                                        }
+                                        th { class: "py-2 pr-3", "種別" }
+                                        th { class: "py-2 pr-3", "従業員" }
+                                        th { class: "py-2 pr-3", "項目" }
+                                        th { class: "py-2 pr-3", "起算日" }
+                                        th { class: "py-2 pr-3", "保管年数" }
+                                        th { class: "py-2 pr-3", "廃棄可能日" }
+                                        th { class: "py-2 pr-3", "状態" }
+                                        if is_admin() {
+                                            th { class: "py-2" }
+                                        }
+                                    }
+                                }
+                                tbody {
+                                    for record in list.iter().cloned() {
+                                        RetentionRow {
+                                            key: "{record.target.as_str()}-{record.record_id}",
+                                            is_due: record.is_due(today),
+                                            checked: selected.read().contains(&record.key()),
+                                            is_admin: is_admin(),
+                                            on_toggle: move |key: RetentionKey| {
+                                                let mut keys = selected.write();
+                                                if let Some(pos) = keys.iter().position(|k| *k == key) {
+                                                    keys.remove(pos);
+                                                } else {
+                                                    keys.push(key);
+                                                }
+                                            },
+                                            on_hold: move |record: RetentionRecord| {
+                                                hold_reason.set(String::new());
+                                                hold_target.set(Some(record));
+                                            },
+                                            on_release: move |key: RetentionKey| {
+                                                spawn(handle_hold(key, false, None));
+                                            },
+                                            record,
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        div { class: "p-4 bg-red-50 border border-red-200 rounded-lg text-red-700",
+                            "エラーが発生しました: {e}"
+                        }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center py-12",
+                            div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+            }
+        }
+
+        // 廃棄の確認ダイアログ
+        if confirm_purge() {
+            div { class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+                div { class: "bg-white rounded-lg p-6 max-w-md w-full mx-4 shadow-xl",
+                    h4 { class: "text-lg font-semibold mb-4", "廃棄の確認" }
+                    p { class: "text-gray-600 mb-6",
+                        "選択した{selected_count}件をデータベースと保存先から完全に削除します。この操作は取り消せません。"
+                    }
+                    div { class: "flex justify-end gap-3",
+                        button {
+                            class: BUTTON_SECONDARY_CLASS,
+                            disabled: is_working(),
+                            onclick: move |_| confirm_purge.set(false),
+                            "キャンセル"
+                        }
+                        button {
+                            class: BUTTON_DANGER_CLASS,
+                            disabled: is_working(),
+                            onclick: handle_purge,
+                            if is_working() { "廃棄中..." } else { "廃棄する" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn RetentionRow(
+    record: RetentionRecord,
+    is_due: bool,
+    checked: bool,
+    is_admin: bool,
+    on_toggle: EventHandler<RetentionKey>,
+    on_hold: EventHandler<RetentionRecord>,
+    on_release: EventHandler<RetentionKey>,
+) -> Element {
+    let key = record.key();
+    let kind = match record.category {
+        Some(category) => category.display_name(),
+        None => record.target.display_name(),
+    };
+    let base_date = record.base_date.format("%Y/%m/%d").to_string();
+    let disposal_date = record.disposal_date.format("%Y/%m/%d").to_string();
+    let hold_reason = record.legal_hold_reason.clone().unwrap_or_default();
+
+    rsx! {
+        tr { class: "border-b last:border-0",
+            if is_admin {
+                td { class: "py-2 pr-2",
+                    input {
+                        r#type: "checkbox",
+                        disabled: !is_due,
+                        checked,
+                        onchange: move |_| on_toggle.call(key),
+                    }
+                }
+            }
+            td { class: "py-2 pr-3 whitespace-nowrap", "{kind}" }
+            td { class: "py-2 pr-3 whitespace-nowrap",
+                Link {
+                    to: Route::EmployeeDetailPage { id: record.employee_id },
+                    class: "text-amber-700 hover:underline",
+                    "{record.employee_code} {record.employee_name}"
+                }
+            }
+            td { class: "py-2 pr-3 break-all", "{record.item_name}" }
+            td { class: "py-2 pr-3 whitespace-nowrap", "{base_date}" }
+            td { class: "py-2 pr-3 whitespace-nowrap", "{record.retention_years}年" }
+            td { class: "py-2 pr-3 whitespace-nowrap", "{disposal_date}" }
+            td { class: "py-2 pr-3",
+                if record.legal_hold {
+                    span {
+                        class: "px-2 py-0.5 rounded-full text-xs bg-purple-100 text-purple-700",
+                        title: "{hold_reason}",
+                        "保留中"
+                    }
+                    p { class: "text-xs text-gray-500 mt-1", "{hold_reason}" }
+                } else if is_due {
+                    span { class: "px-2 py-0.5 rounded-full text-xs bg-red-100 text-red-700", "廃棄可" }
+                } else {
+                    span { class: "px-2 py-0.5 rounded-full text-xs bg-gray-100 text-gray-600", "保管中" }
+                }
+            }
+            if is_admin {
+                td { class: "py-2 text-right whitespace-nowrap",
+                    if record.legal_hold {
+                        button {
+                            class: "text-sm text-amber-700 hover:underline",
+                            onclick: move |_| on_release.call(key),
+                            "保留を解除"
+                        }
+                    } else {
+                        button {
+                            class: "text-sm text-amber-700 hover:underline",
+                            onclick: {
+                                let record = record.clone();
+                                move |_| on_hold.call(record.clone())
+                            },
+                            "保留"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
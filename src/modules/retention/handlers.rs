@@ -0,0 +1,153 @@
+//! 保管期限管理のハンドラー層（サーバー関数）
+
+use super::models::{PurgeResult, RetentionKey, RetentionRecord};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::models::{build_records, LegalHold, PurgedRecord, RetentionTarget};
+#[cfg(feature = "server")]
+use super::repository::RetentionRepository;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+#[cfg(feature = "server")]
+use crate::modules::s3_service;
+
+/// 保管期限の一覧を取得（`due_only`なら廃棄できるもののみ）
+#[server]
+pub async fn get_retention_records(due_only: bool) -> Result<Vec<RetentionRecord>, ServerFnError> {
+    require_permission(Permission::Audit).await?;
+
+    let today = chrono::Local::now().date_naive();
+    let records = load_records().await?;
+    Ok(records
+        .into_iter()
+        .filter(|r| !due_only || r.is_due(today))
+        .collect())
+}
+
+/// 廃棄の保留（リーガルホールド）を設定・解除
+#[server]
+pub async fn set_legal_hold(
+    key: RetentionKey,
+    legal_hold: bool,
+    reason: Option<String>,
+) -> Result<(), ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    let reason = reason
+        .map(|r| r.trim().to_string())
+        .filter(|r| !r.is_empty());
+    if legal_hold && reason.is_none() {
+        return Err(ServerFnError::new(
+            "保留の理由を入力してください".to_string(),
+        ));
+    }
+
+    RetentionRepository::set_legal_hold(
+        key.target,
+        key.record_id,
+        LegalHold {
+            legal_hold,
+            legal_hold_reason: if legal_hold { reason } else { None },
+        },
+    )
+    .await
+    .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 保管期限を過ぎた記録を廃棄する（データベースの行と保存先のファイルを削除）
+///
+/// 指定された記録は、この時点で廃棄可能（期限到来・保留なし）であることを再確認してから削除する
+#[server]
+pub async fn purge_retention_records(
+    keys: Vec<RetentionKey>,
+) -> Result<PurgeResult, ServerFnError> {
+    require_permission(Permission::Admin).await?;
+
+    // バリデーション
+    if keys.is_empty() {
+        return Err(ServerFnError::new(
+            "廃棄する記録を選択してください".to_string(),
+        ));
+    }
+    let today = chrono::Local::now().date_naive();
+    let records = load_records().await?;
+    let mut targets = Vec::with_capacity(keys.len());
+    for key in &keys {
+        match records.iter().find(|r| r.key() == *key) {
+            Some(record) if record.is_due(today) => targets.push(record.clone()),
+            Some(record) => {
+                return Err(ServerFnError::new(format!(
+                    "{} {}（{}）はまだ廃棄できません",
+                    record.employee_code, record.employee_name, record.item_name
+                )))
+            }
+            None => {
+                return Err(ServerFnError::new(
+                    "廃棄対象の記録が見つかりません".to_string(),
+                ))
+            }
+        }
+    }
+
+    // 書類を先に削除（従業員を削除すると書類も削除されるため）
+    targets.sort_by_key(|r| r.target == RetentionTarget::Employee);
+    let mut result = PurgeResult::default();
+    for record in targets {
+        let purged = match record.target {
+            RetentionTarget::Document => {
+                RetentionRepository::purge_document(record.record_id).await
+            }
+            RetentionTarget::Employee => {
+                RetentionRepository::purge_employee(record.record_id).await
+            }
+        };
+        let storage_keys = match purged {
+            Ok(Some(keys)) => keys,
+            // 一覧の取得後に保留された場合
+            Ok(None) => {
+                return Err(ServerFnError::new(format!(
+                    "{} {}（{}）は廃棄の保留中の記録があるため廃棄できません",
+                    record.employee_code, record.employee_name, record.item_name
+                )))
+            }
+            // 同じ従業員の書類と従業員記録を同時に選んだ場合など、既に削除済みの行は飛ばす
+            Err(sqlx::Error::RowNotFound) => continue,
+            Err(e) => return Err(ServerFnError::new(e.to_string())),
+        };
+
+        for key in &storage_keys {
+            if let Err(e) = s3_service::delete_file(key).await {
+                result.storage_errors.push(format!("{}: {}", key, e));
+            }
+        }
+        audit_log::record_delete(
+            record.target.table_name(),
+            record.record_id,
+            Some(record.employee_id),
+            Some(&PurgedRecord {
+                target: record.target,
+                employee_code: record.employee_code,
+                item_name: record.item_name,
+                base_date: record.base_date,
+                disposal_date: record.disposal_date,
+                storage_keys,
+            }),
+        )
+        .await;
+        result.purged += 1;
+    }
+
+    Ok(result)
+}
+
+#[cfg(feature = "server")]
+async fn load_records() -> Result<Vec<RetentionRecord>, ServerFnError> {
+    let rows = RetentionRepository::fetch_rows()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(build_records(rows))
+}
@@ -0,0 +1,13 @@
+//! 保管期限管理モジュール
+//! 書類種別の保管年数と退職者の保管期間（3年）から廃棄日を計算し、
+//! 廃棄の保留（リーガルホールド）と監査ログ付きの廃棄（データベース・保存先の両方）を行う
+
+pub mod components;
+pub mod handlers;
+pub mod models;
+
+#[cfg(feature = "server")]
+pub mod repository;
+
+pub use components::RetentionPage;
+pub use models::{RetentionKey, RetentionRecord, RetentionTarget};
@@ -0,0 +1,310 @@
+//! 保管期限管理のモデル定義
+
+use crate::modules::employee_documents::DocumentCategory;
+use chrono::{Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// 退職者の記録（乗務員台帳を含む）の保管年数（退職日・選任解除日のいずれか遅い日から）
+pub const RETIREE_RETENTION_YEARS: i32 = 3;
+
+/// 書類種別に保管期間が設定されていない書類の保管年数
+pub const DEFAULT_DOCUMENT_RETENTION_YEARS: i32 = 3;
+
+/// 保管期限管理の対象種別
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "server", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "server",
+    sqlx(type_name = "VARCHAR", rename_all = "SCREAMING_SNAKE_CASE")
+)]
+pub enum RetentionTarget {
+    /// 従業員書類（employee_documents の1行）
+    #[serde(rename = "DOCUMENT")]
+    Document,
+    /// 退職者の従業員記録（削除すると関連する記録・書類もすべて削除される）
+    #[serde(rename = "EMPLOYEE")]
+    Employee,
+}
+
+impl RetentionTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetentionTarget::Document => "DOCUMENT",
+            RetentionTarget::Employee => "EMPLOYEE",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RetentionTarget::Document => "書類",
+            RetentionTarget::Employee => "退職者記録",
+        }
+    }
+
+    /// 監査ログに記録するテーブル名
+    pub fn table_name(&self) -> &'static str {
+        match self {
+            RetentionTarget::Document => "employee_documents",
+            RetentionTarget::Employee => "employees",
+        }
+    }
+}
+
+impl std::fmt::Display for RetentionTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// 保管期限の計算元となる行（リポジトリから取得）
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct RetentionRow {
+    pub target: RetentionTarget,
+    pub record_id: i32,
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    /// 書類の種類（従業員記録はNone）
+    pub category: Option<DocumentCategory>,
+    /// ファイル名、または記録の名称
+    pub item_name: String,
+    /// 保管期間の起算日（退職日・選任解除日、または新しい版に置き換えられた日）
+    pub base_date: NaiveDate,
+    pub retention_years: i32,
+    pub legal_hold: bool,
+    pub legal_hold_reason: Option<String>,
+}
+
+/// 保管期限の一覧の1件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionRecord {
+    pub target: RetentionTarget,
+    pub record_id: i32,
+    pub employee_id: i32,
+    pub employee_code: String,
+    pub employee_name: String,
+    pub category: Option<DocumentCategory>,
+    pub item_name: String,
+    pub base_date: NaiveDate,
+    pub retention_years: i32,
+    /// 廃棄可能になる日
+    pub disposal_date: NaiveDate,
+    /// 訴訟・調査などのため廃棄を保留しているか
+    pub legal_hold: bool,
+    pub legal_hold_reason: Option<String>,
+}
+
+impl RetentionRecord {
+    /// 廃棄できるか（保管期限を過ぎ、保留されていない）
+    pub fn is_due(&self, today: NaiveDate) -> bool {
+        !self.legal_hold && self.disposal_date <= today
+    }
+
+    pub fn key(&self) -> RetentionKey {
+        RetentionKey {
+            target: self.target,
+            record_id: self.record_id,
+        }
+    }
+}
+
+/// 廃棄・保留の対象を指定するキー
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct RetentionKey {
+    pub target: RetentionTarget,
+    pub record_id: i32,
+}
+
+/// 廃棄の結果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PurgeResult {
+    /// 削除した件数
+    pub purged: usize,
+    /// 削除できなかった保存先のファイル（データベースからは削除済み）
+    pub storage_errors: Vec<String>,
+}
+
+/// 廃棄時に監査ログへ残す内容
+///
+/// 廃棄の目的は個人情報を残さないことのため、行全体ではなく何を廃棄したかだけを記録する
+#[derive(Debug, Clone, Serialize)]
+pub struct PurgedRecord {
+    pub target: RetentionTarget,
+    pub employee_code: String,
+    pub item_name: String,
+    pub base_date: NaiveDate,
+    pub disposal_date: NaiveDate,
+    /// 削除した保存先のファイル
+    pub storage_keys: Vec<String>,
+}
+
+/// 保留の状態（監査ログ用）
+#[derive(Debug, Clone, Serialize)]
+pub struct LegalHold {
+    pub legal_hold: bool,
+    pub legal_hold_reason: Option<String>,
+}
+
+/// 起算日から保管年数後の日付（2月29日起算で平年になる場合は2月28日）
+pub fn disposal_date(base_date: NaiveDate, retention_years: i32) -> NaiveDate {
+    base_date
+        .checked_add_months(Months::new(retention_years.max(0) as u32 * 12))
+        .unwrap_or(NaiveDate::MAX)
+}
+
+/// 書類に保留がある従業員記録の保留理由（従業員記録自体に理由が無い場合）
+pub const HELD_DOCUMENT_REASON: &str = "廃棄の保留中の書類があります";
+
+/// 廃棄日を計算して一覧を作成する（廃棄日の早い順）
+///
+/// 従業員記録を削除するとその従業員の書類もすべて削除されるため、
+/// 従業員記録の廃棄日は、その従業員の書類の廃棄日のうち最も遅い日まで延ばし、
+/// 書類のいずれかが保留中なら従業員記録も保留として扱う
+pub fn build_records(rows: Vec<RetentionRow>) -> Vec<RetentionRecord> {
+    let mut records: Vec<RetentionRecord> = rows
+        .into_iter()
+        .map(|row| RetentionRecord {
+            disposal_date: disposal_date(row.base_date, row.retention_years),
+            target: row.target,
+            record_id: row.record_id,
+            employee_id: row.employee_id,
+            employee_code: row.employee_code,
+            employee_name: row.employee_name,
+            category: row.category,
+            item_name: row.item_name,
+            base_date: row.base_date,
+            retention_years: row.retention_years,
+            legal_hold: row.legal_hold,
+            legal_hold_reason: row.legal_hold_reason,
+        })
+        .collect();
+
+    let mut latest_documents: HashMap<i32, NaiveDate> = HashMap::new();
+    let mut held_documents: HashSet<i32> = HashSet::new();
+    for record in records
+        .iter()
+        .filter(|r| r.target == RetentionTarget::Document)
+    {
+        let latest = latest_documents
+            .entry(record.employee_id)
+            .or_insert(record.disposal_date);
+        *latest = (*latest).max(record.disposal_date);
+        if record.legal_hold {
+            held_documents.insert(record.employee_id);
+        }
+    }
+    for record in records
+        .iter_mut()
+        .filter(|r| r.target == RetentionTarget::Employee)
+    {
+        if let Some(latest) = latest_documents.get(&record.employee_id) {
+            record.disposal_date = record.disposal_date.max(*latest);
+        }
+        if !record.legal_hold && held_documents.contains(&record.employee_id) {
+            record.legal_hold = true;
+            record.legal_hold_reason = Some(HELD_DOCUMENT_REASON.to_string());
+        }
+    }
+
+    records.sort_by(|a, b| {
+        a.disposal_date
+            .cmp(&b.disposal_date)
+            .then_with(|| a.employee_code.cmp(&b.employee_code))
+            .then_with(|| a.record_id.cmp(&b.record_id))
+    });
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn row(
+        target: RetentionTarget,
+        record_id: i32,
+        base_date: NaiveDate,
+        years: i32,
+    ) -> RetentionRow {
+        RetentionRow {
+            target,
+            record_id,
+            employee_id: 1,
+            employee_code: "001".to_string(),
+            employee_name: "山田 太郎".to_string(),
+            category: None,
+            item_name: "記録".to_string(),
+            base_date,
+            retention_years: years,
+            legal_hold: false,
+            legal_hold_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_disposal_date() {
+        assert_eq!(disposal_date(date(2023, 3, 31), 3), date(2026, 3, 31));
+        assert_eq!(disposal_date(date(2024, 2, 29), 3), date(2027, 2, 28));
+        assert_eq!(disposal_date(date(2024, 2, 29), 4), date(2028, 2, 29));
+    }
+
+    #[test]
+    fn test_employee_waits_for_documents() {
+        let records = build_records(vec![
+            row(RetentionTarget::Employee, 1, date(2022, 3, 31), 3),
+            row(RetentionTarget::Document, 10, date(2022, 3, 31), 7),
+            row(RetentionTarget::Document, 11, date(2021, 1, 10), 3),
+        ]);
+
+        assert_eq!(records[0].record_id, 11);
+        let employee = records
+            .iter()
+            .find(|r| r.target == RetentionTarget::Employee)
+            .unwrap();
+        assert_eq!(employee.disposal_date, date(2029, 3, 31));
+    }
+
+    #[test]
+    fn test_employee_held_by_document() {
+        let mut held = row(RetentionTarget::Document, 10, date(2020, 3, 31), 3);
+        held.legal_hold = true;
+        held.legal_hold_reason = Some("訴訟".to_string());
+        let records = build_records(vec![
+            row(RetentionTarget::Employee, 1, date(2020, 3, 31), 3),
+            held,
+        ]);
+
+        let employee = records
+            .iter()
+            .find(|r| r.target == RetentionTarget::Employee)
+            .unwrap();
+        assert!(employee.legal_hold);
+        assert_eq!(
+            employee.legal_hold_reason.as_deref(),
+            Some(HELD_DOCUMENT_REASON)
+        );
+        assert!(!employee.is_due(date(2026, 4, 1)));
+    }
+
+    #[test]
+    fn test_is_due_respects_legal_hold() {
+        let today = date(2026, 4, 1);
+        let mut record = build_records(vec![row(
+            RetentionTarget::Document,
+            1,
+            date(2022, 3, 31),
+            3,
+        )])
+        .remove(0);
+        assert!(record.is_due(today));
+        assert!(!record.is_due(date(2025, 3, 30)));
+
+        record.legal_hold = true;
+        assert!(!record.is_due(today));
+    }
+}
@@ -0,0 +1,359 @@
+//! 保管期限管理のリポジトリ層
+
+use super::models::{
+    LegalHold, RetentionRow, RetentionTarget, DEFAULT_DOCUMENT_RETENTION_YEARS,
+    RETIREE_RETENTION_YEARS,
+};
+use crate::db;
+use crate::modules::audit_log;
+use crate::modules::employee_documents::DocumentCategory;
+
+/// 保管期限管理リポジトリ
+pub struct RetentionRepository;
+
+impl RetentionRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    /// 保管期間の起算日が決まっている書類・退職者を取得
+    ///
+    /// * 書類: 新しい版に置き換えられた日、または従業員の退職日・選任解除日のいずれか遅い日から
+    ///   書類種別の保管年数（未設定は既定値）
+    /// * 退職者: 退職日・選任解除日のいずれか遅い日から3年
+    ///
+    /// 選任解除日には、再選任で消えた過去の選任解除（driver_deselections）も含める
+    ///
+    /// 在籍中の従業員の現在の版の書類は起算日が無いため対象外
+    pub async fn fetch_rows() -> Result<Vec<RetentionRow>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            RetentionRow,
+            r#"
+            WITH deselections AS (
+                SELECT employee_id, MAX(driver_end_date) AS last_driver_end_date
+                FROM driver_deselections
+                GROUP BY employee_id
+            )
+            SELECT
+                'DOCUMENT'::VARCHAR AS "target!: RetentionTarget",
+                d.id AS "record_id!",
+                d.employee_id AS "employee_id!",
+                e.employee_code AS "employee_code!",
+                e.last_name || ' ' || e.first_name AS "employee_name!",
+                d.category AS "category?: DocumentCategory",
+                d.file_name AS "item_name!",
+                COALESCE(
+                    d.superseded_at::DATE,
+                    GREATEST(e.end_date, e.driver_end_date, s.last_driver_end_date)
+                ) AS "base_date!",
+                COALESCE(t.retention_years, $1::INT) AS "retention_years!",
+                (d.legal_hold OR e.legal_hold) AS "legal_hold!",
+                COALESCE(d.legal_hold_reason, e.legal_hold_reason) AS legal_hold_reason
+            FROM employee_documents d
+            JOIN employees e ON e.id = d.employee_id
+            LEFT JOIN document_types t ON t.id = d.document_type_id
+            LEFT JOIN deselections s ON s.employee_id = e.id
+            WHERE d.superseded_at IS NOT NULL OR e.end_date IS NOT NULL
+            UNION ALL
+            SELECT
+                'EMPLOYEE'::VARCHAR,
+                e.id,
+                e.id,
+                e.employee_code,
+                e.last_name || ' ' || e.first_name,
+                NULL::VARCHAR,
+                '従業員記録・乗務員台帳',
+                GREATEST(e.end_date, e.driver_end_date, s.last_driver_end_date),
+                $2::INT,
+                e.legal_hold,
+                e.legal_hold_reason
+            FROM employees e
+            LEFT JOIN deselections s ON s.employee_id = e.id
+            WHERE e.end_date IS NOT NULL
+            "#,
+            DEFAULT_DOCUMENT_RETENTION_YEARS,
+            RETIREE_RETENTION_YEARS
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 廃棄の保留を設定・解除
+    pub async fn set_legal_hold(
+        target: RetentionTarget,
+        id: i32,
+        hold: LegalHold,
+    ) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+
+        let old = match target {
+            RetentionTarget::Document => sqlx::query!(
+                r#"
+                SELECT employee_id, legal_hold, legal_hold_reason
+                FROM employee_documents WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_one(pool)
+            .await
+            .map(|r| {
+                (
+                    r.employee_id,
+                    LegalHold {
+                        legal_hold: r.legal_hold,
+                        legal_hold_reason: r.legal_hold_reason,
+                    },
+                )
+            })?,
+            RetentionTarget::Employee => sqlx::query!(
+                r#"
+                SELECT id, legal_hold, legal_hold_reason
+                FROM employees WHERE id = $1
+                "#,
+                id
+            )
+            .fetch_one(pool)
+            .await
+            .map(|r| {
+                (
+                    r.id,
+                    LegalHold {
+                        legal_hold: r.legal_hold,
+                        legal_hold_reason: r.legal_hold_reason,
+                    },
+                )
+            })?,
+        };
+
+        match target {
+            RetentionTarget::Document => {
+                sqlx::query!(
+                    r#"
+                    UPDATE employee_documents
+                    SET legal_hold = $2, legal_hold_reason = $3, updated_at = CURRENT_TIMESTAMP
+                    WHERE id = $1
+                    "#,
+                    id,
+                    hold.legal_hold,
+                    hold.legal_hold_reason
+                )
+                .execute(pool)
+                .await?;
+            }
+            RetentionTarget::Employee => {
+                sqlx::query!(
+                    r#"
+                    UPDATE employees
+                    SET legal_hold = $2, legal_hold_reason = $3, updated_at = CURRENT_TIMESTAMP
+                    WHERE id = $1
+                    "#,
+                    id,
+                    hold.legal_hold,
+                    hold.legal_hold_reason
+                )
+                .execute(pool)
+                .await?;
+            }
+        }
+
+        let (employee_id, old) = old;
+        audit_log::record_update(
+            target.table_name(),
+            id,
+            Some(employee_id),
+            Some(&old),
+            &hold,
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// 書類の行を削除し、削除すべき保存先のファイルを返す
+    ///
+    /// 書類または従業員記録が廃棄の保留中の場合は削除せずNoneを返す
+    pub async fn purge_document(id: i32) -> Result<Option<Vec<String>>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+
+        // 保留の設定と競合しないよう、書類と従業員の行をロックしてから保留を確認する
+        sqlx::query_scalar!(
+            r#"
+            SELECT d.id FROM employee_documents d
+            JOIN employees e ON e.id = d.employee_id
+            WHERE d.id = $1
+            FOR UPDATE
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        let row = sqlx::query!(
+            r#"
+            DELETE FROM employee_documents
+            WHERE id = $1
+              AND NOT legal_hold
+              AND NOT EXISTS (
+                  SELECT 1 FROM employees e WHERE e.id = employee_id AND e.legal_hold
+              )
+            RETURNING s3_key, thumbnail_key
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        tx.commit().await?;
+        Ok(Some(
+            [row.s3_key, row.thumbnail_key]
+                .into_iter()
+                .flatten()
+                .collect(),
+        ))
+    }
+
+    /// 従業員を削除し（関連する記録・書類も削除される）、削除すべき保存先のファイルを返す
+    ///
+    /// 従業員記録または書類のいずれかが廃棄の保留中の場合は削除せずNoneを返す
+    pub async fn purge_employee(id: i32) -> Result<Option<Vec<String>>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let mut tx = pool.begin().await?;
+
+        let employee_hold = sqlx::query_scalar!(
+            "SELECT legal_hold FROM employees WHERE id = $1 FOR UPDATE",
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+        let documents = sqlx::query!(
+            r#"
+            SELECT s3_key, thumbnail_key, legal_hold FROM employee_documents
+            WHERE employee_id = $1
+            FOR UPDATE
+            "#,
+            id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        if employee_hold || documents.iter().any(|d| d.legal_hold) {
+            return Ok(None);
+        }
+        let keys = documents
+            .into_iter()
+            .flat_map(|r| [r.s3_key, r.thumbnail_key])
+            .flatten()
+            .collect();
+
+        sqlx::query!("DELETE FROM employees WHERE id = $1", id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 書類を登録する（テスト用）
+    async fn insert_document(employee_id: i32) -> i32 {
+        let pool = RetentionRepository::pool().await.unwrap();
+        sqlx::query_scalar!(
+            "INSERT INTO employee_documents (employee_id, file_name) VALUES ($1, 'test.pdf') RETURNING id",
+            employee_id
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    /// 廃棄対象を確定した後に保留された書類は削除しない
+    ///
+    /// マイグレーション済みのデータベース（`DATABASE_URL`）が必要なため、未設定の場合は何もしない
+    #[tokio::test]
+    async fn test_purge_document_rechecks_legal_hold() {
+        if std::env::var("DATABASE_URL").is_err() {
+            return;
+        }
+        let pool = RetentionRepository::pool().await.unwrap();
+        let employee_id = sqlx::query_scalar!(
+            "INSERT INTO employees (last_name, first_name, employee_code) VALUES ('保留', 'テスト', $1) RETURNING id",
+            format!("TEST-{}", uuid::Uuid::new_v4().simple())
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        let held_document = insert_document(employee_id).await;
+        let document = insert_document(employee_id).await;
+
+        // 一覧の取得後に書類が保留された
+        sqlx::query!(
+            "UPDATE employee_documents SET legal_hold = TRUE WHERE id = $1",
+            held_document
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            RetentionRepository::purge_document(held_document)
+                .await
+                .unwrap(),
+            None
+        );
+
+        // 一覧の取得後に従業員記録が保留された
+        sqlx::query!(
+            "UPDATE employees SET legal_hold = TRUE WHERE id = $1",
+            employee_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            RetentionRepository::purge_document(document).await.unwrap(),
+            None
+        );
+
+        let remaining = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM employee_documents WHERE employee_id = $1"#,
+            employee_id
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+        assert_eq!(remaining, 2);
+
+        // 保留を解除すれば削除でき、削除済みの行はRowNotFoundになる
+        sqlx::query!(
+            "UPDATE employees SET legal_hold = FALSE WHERE id = $1",
+            employee_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            RetentionRepository::purge_document(document).await.unwrap(),
+            Some(Vec::new())
+        );
+        assert!(matches!(
+            RetentionRepository::purge_document(document).await,
+            Err(sqlx::Error::RowNotFound)
+        ));
+
+        sqlx::query!("DELETE FROM employees WHERE id = $1", employee_id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+}
@@ -1,8 +1,14 @@
 //! S3サービスモジュール
-//! AWS S3へのファイル操作を提供
+//! ファイル保存先（AWS S3 / S3互換 / ローカル）への操作を提供
 
 #[cfg(feature = "server")]
 pub mod service;
+#[cfg(feature = "server")]
+pub mod storage;
 
 #[cfg(feature = "server")]
 pub use service::*;
+#[cfg(feature = "server")]
+pub use storage::{
+    ByteChunks, LocalStorage, S3Storage, Storage, StorageConfig, StorageReader, StoredObject,
+};
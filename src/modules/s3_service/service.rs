@@ -1,18 +1,18 @@
 //! S3サービスモジュール
-//! AWS S3へのファイルアップロード、ダウンロード、削除を行う
+//! 設定された保存先（S3 / S3互換 / ローカル）へのファイルアップロード、ダウンロード、削除を行う
 
-use aws_sdk_s3::primitives::ByteStream;
-use aws_sdk_s3::Client;
+use super::storage::{ByteChunks, Storage, StorageConfig, StorageReader, StoredObject};
 use bytes::Bytes;
 use std::sync::OnceLock;
+use std::time::Duration;
 
-/// S3クライアントのグローバルインスタンス
-static S3_CLIENT: OnceLock<Client> = OnceLock::new();
+/// 署名付きダウンロードURLの有効期間
+pub const DOWNLOAD_URL_EXPIRES: Duration = Duration::from_secs(5 * 60);
 
-/// S3バケット名
-static BUCKET_NAME: OnceLock<String> = OnceLock::new();
+/// 保存先のグローバルインスタンス
+static STORAGE: OnceLock<Box<dyn Storage>> = OnceLock::new();
 
-/// S3サービスのエラー型
+/// 保存先操作のエラー型
 #[derive(Debug, Clone)]
 pub struct S3Error {
     pub message: String,
@@ -20,7 +20,7 @@ pub struct S3Error {
 
 impl std::fmt::Display for S3Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "S3 Error: {}", self.message)
+        write!(f, "Storage Error: {}", self.message)
     }
 }
 
@@ -40,64 +40,35 @@ impl From<&str> for S3Error {
     }
 }
 
-/// S3クライアントを初期化
-pub async fn init_s3_client(bucket_name: String) {
-    let config = aws_config::load_from_env().await;
-    let client = Client::new(&config);
-
-    S3_CLIENT.set(client).ok();
-    BUCKET_NAME.set(bucket_name).ok();
-
-    println!("S3 client initialized");
-}
-
-/// S3クライアントを取得
-fn get_client() -> Result<&'static Client, S3Error> {
-    S3_CLIENT
-        .get()
-        .ok_or_else(|| S3Error::from("S3 client not initialized"))
+/// 保存先を初期化
+pub async fn init_storage(config: StorageConfig) -> Result<(), S3Error> {
+    let storage = config.build().await?;
+    println!("Storage initialized: {}", storage.name());
+    STORAGE.set(storage).ok();
+    Ok(())
 }
 
-/// バケット名を取得
-fn get_bucket_name() -> Result<&'static str, S3Error> {
-    BUCKET_NAME
+/// 保存先を取得
+fn get_storage() -> Result<&'static dyn Storage, S3Error> {
+    STORAGE
         .get()
-        .map(|s| s.as_str())
-        .ok_or_else(|| S3Error::from("Bucket name not set"))
+        .map(|s| s.as_ref())
+        .ok_or_else(|| S3Error::from("Storage not initialized"))
 }
 
-/// S3にファイルをアップロード
+/// 保存先にファイルをアップロード
 ///
 /// # Arguments
 /// * `key` - S3オブジェクトキー（パス）
 /// * `data` - アップロードするファイルのバイトデータ
 /// * `content_type` - ファイルのMIMEタイプ
 ///
-/// # Returns
-/// アップロード成功時はS3のオブジェクトURL
-pub async fn upload_file(key: &str, data: Bytes, content_type: &str) -> Result<String, S3Error> {
-    let client = get_client()?;
-    let bucket_name = get_bucket_name()?;
-
-    let body = ByteStream::from(data);
-
-    client
-        .put_object()
-        .bucket(bucket_name)
-        .key(key)
-        .body(body)
-        .content_type(content_type)
-        .send()
-        .await
-        .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))?;
-
-    // S3のURLを生成（リージョンによって異なる場合があるので注意）
-    let url = format!("https://{}.s3.amazonaws.com/{}", bucket_name, key);
-
-    Ok(url)
+/// 公開URLは返さない（閲覧時は`presigned_download_url`またはプロキシを使う）
+pub async fn upload_file(key: &str, data: Bytes, content_type: &str) -> Result<(), S3Error> {
+    get_storage()?.put(key, data, content_type).await
 }
 
-/// S3からファイルをダウンロード
+/// 保存先からファイルをダウンロード
 ///
 /// # Arguments
 /// * `key` - S3オブジェクトキー（パス）
@@ -105,47 +76,92 @@ pub async fn upload_file(key: &str, data: Bytes, content_type: &str) -> Result<S
 /// # Returns
 /// ファイルのバイトデータ
 pub async fn download_file(key: &str) -> Result<Bytes, S3Error> {
-    let client = get_client()?;
-    let bucket_name = get_bucket_name()?;
-
-    let response = client
-        .get_object()
-        .bucket(bucket_name)
-        .key(key)
-        .send()
-        .await
-        .map_err(|e| S3Error::from(format!("Failed to download file: {}", e)))?;
+    get_storage()?.get(key).await
+}
+
+/// 保存先にファイルを順次書き込む（大きなファイルもメモリに溜めない）
+///
+/// # Returns
+/// 保存したバイト数
+pub async fn upload_stream(
+    key: &str,
+    chunks: ByteChunks<'_>,
+    content_type: &str,
+) -> Result<u64, S3Error> {
+    get_storage()?.put_stream(key, chunks, content_type).await
+}
+
+/// 保存先のファイルを順次読み出せるように開く（プロキシ配信用）
+pub async fn open_file(key: &str) -> Result<StorageReader, S3Error> {
+    get_storage()?.open(key).await
+}
 
-    let data = response
-        .body
-        .collect()
+/// 有効期限付きのダウンロードURLを発行
+///
+/// # Arguments
+/// * `key` - S3オブジェクトキー（パス）
+/// * `filename` - ダウンロード時のファイル名
+/// * `attachment` - trueなら保存、falseならブラウザで表示
+///
+/// # Returns
+/// 署名付きURLに対応しない保存先（ローカル）の場合はNone
+pub async fn presigned_download_url(
+    key: &str,
+    filename: &str,
+    attachment: bool,
+) -> Result<Option<String>, S3Error> {
+    let disposition = content_disposition(filename, attachment);
+    get_storage()?
+        .presigned_url(key, DOWNLOAD_URL_EXPIRES, &disposition)
         .await
-        .map_err(|e| S3Error::from(format!("Failed to read file data: {}", e)))?
-        .into_bytes();
+}
 
-    Ok(data)
+/// Content-Dispositionヘッダーの値を作成（日本語ファイル名はRFC 5987形式）
+pub fn content_disposition(filename: &str, attachment: bool) -> String {
+    let kind = if attachment { "attachment" } else { "inline" };
+    let fallback: String = filename
+        .chars()
+        .map(|c| {
+            if (c.is_ascii_graphic() && c != '"' && c != '\\') || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = filename
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect();
+    format!(
+        "{}; filename=\"{}\"; filename*=UTF-8''{}",
+        kind, fallback, encoded
+    )
 }
 
-/// S3からファイルを削除
+/// 保存先からファイルを削除
 ///
 /// # Arguments
 /// * `key` - S3オブジェクトキー（パス）
 pub async fn delete_file(key: &str) -> Result<(), S3Error> {
-    let client = get_client()?;
-    let bucket_name = get_bucket_name()?;
-
-    client
-        .delete_object()
-        .bucket(bucket_name)
-        .key(key)
-        .send()
-        .await
-        .map_err(|e| S3Error::from(format!("Failed to delete file: {}", e)))?;
+    get_storage()?.delete(key).await
+}
 
-    Ok(())
+/// 指定した接頭辞で始まるファイルを一覧
+///
+/// # Arguments
+/// * `prefix` - キーの接頭辞（例: "employees/"）
+pub async fn list_files(prefix: &str) -> Result<Vec<StoredObject>, S3Error> {
+    get_storage()?.list(prefix).await
 }
 
-/// S3に複数のファイルを一括削除
+/// 保存先から複数のファイルを一括削除
 ///
 /// # Arguments
 /// * `keys` - 削除するS3オブジェクトキーのリスト
@@ -193,6 +209,18 @@ pub fn guess_mime_type(filename: &str) -> &'static str {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_content_disposition() {
+        assert_eq!(
+            content_disposition("免許証.jpg", false),
+            "inline; filename=\"___.jpg\"; filename*=UTF-8''%E5%85%8D%E8%A8%B1%E8%A8%BC.jpg"
+        );
+        assert_eq!(
+            content_disposition("a \"b\".pdf", true),
+            "attachment; filename=\"a _b_.pdf\"; filename*=UTF-8''a%20%22b%22.pdf"
+        );
+    }
+
     #[test]
     fn test_generate_s3_key() {
         let key = generate_s3_key("face", 123, "photo.jpg");
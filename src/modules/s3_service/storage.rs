@@ -0,0 +1,716 @@
+//! ファイル保存先の抽象化
+//! AWS S3・S3互換ストレージ（MinIOなど）・ローカルファイルシステムを設定で切り替える
+
+use super::service::S3Error;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+use aws_sdk_s3::Client;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, StreamExt};
+use std::future::Future;
+use std::path::{Component, Path, PathBuf};
+use std::pin::Pin;
+use std::time::Duration;
+
+/// 保存先の非同期処理の戻り値
+pub type StorageFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, S3Error>> + Send + 'a>>;
+
+/// ファイル本体を順次読み出すためのリーダー（プロキシ配信用）
+pub type StorageReader = Pin<Box<dyn tokio::io::AsyncRead + Send>>;
+
+/// アップロードされるファイル本体のチャンク列（途中でエラーになった場合は保存を中止する）
+pub type ByteChunks<'a> = Pin<Box<dyn Stream<Item = Result<Bytes, S3Error>> + Send + 'a>>;
+
+/// S3マルチパートアップロードの1パートの大きさ（最後以外は5MB以上が必要）
+const MULTIPART_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// 保存先にあるファイルの情報
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredObject {
+    pub key: String,
+    pub size: u64,
+    /// 最終更新日時（取得できない場合はNone）
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+/// ファイル保存先
+pub trait Storage: Send + Sync {
+    /// ファイルを保存
+    fn put<'a>(&'a self, key: &'a str, data: Bytes, content_type: &'a str)
+        -> StorageFuture<'a, ()>;
+
+    /// ファイルをチャンクごとに受け取りながら保存し、保存したバイト数を返す
+    /// 途中で失敗した場合は書きかけのファイルを残さない
+    fn put_stream<'a>(
+        &'a self,
+        key: &'a str,
+        chunks: ByteChunks<'a>,
+        content_type: &'a str,
+    ) -> StorageFuture<'a, u64>;
+
+    /// ファイルを取得
+    fn get<'a>(&'a self, key: &'a str) -> StorageFuture<'a, Bytes>;
+
+    /// ファイルを削除（存在しない場合も成功とする）
+    fn delete<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()>;
+
+    /// ファイルを全体を読み込まずに開く
+    fn open<'a>(&'a self, key: &'a str) -> StorageFuture<'a, StorageReader>;
+
+    /// 指定した接頭辞で始まるファイルを一覧（キーの昇順）
+    fn list<'a>(&'a self, prefix: &'a str) -> StorageFuture<'a, Vec<StoredObject>>;
+
+    /// 有効期限付きのダウンロードURLを発行
+    /// 署名付きURLに対応しない保存先はNoneを返す（プロキシ経由で配信する）
+    fn presigned_url<'a>(
+        &'a self,
+        key: &'a str,
+        expires_in: Duration,
+        content_disposition: &'a str,
+    ) -> StorageFuture<'a, Option<String>>;
+
+    /// ログ出力用の名前
+    fn name(&self) -> &'static str;
+}
+
+/// 保存先の設定
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageConfig {
+    /// AWS S3（認証情報・リージョンは環境変数から読み込む）
+    S3 { bucket: String },
+    /// S3互換ストレージ（パス形式のアドレス指定）
+    S3Compatible { bucket: String, endpoint: String },
+    /// ローカルファイルシステム
+    Local { root: PathBuf },
+}
+
+impl StorageConfig {
+    /// 環境変数から設定を読み込む
+    ///
+    /// * `STORAGE_BACKEND` - `s3`（既定）/ `s3_compatible` / `local`
+    /// * `S3_BUCKET_NAME` - バケット名
+    /// * `S3_ENDPOINT_URL` - S3互換ストレージのエンドポイント（例: `http://localhost:9000`）
+    /// * `LOCAL_STORAGE_DIR` - ローカル保存先のディレクトリ（既定: `./storage`）
+    pub fn from_env() -> Result<Self, S3Error> {
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        let bucket = var("S3_BUCKET_NAME").unwrap_or_else(|| "rust-ws-app-documents".to_string());
+
+        match var("STORAGE_BACKEND").as_deref().unwrap_or("s3") {
+            "s3" => Ok(StorageConfig::S3 { bucket }),
+            "s3_compatible" => {
+                let endpoint = var("S3_ENDPOINT_URL").ok_or_else(|| {
+                    S3Error::from("S3_ENDPOINT_URL is required for s3_compatible storage")
+                })?;
+                Ok(StorageConfig::S3Compatible { bucket, endpoint })
+            }
+            "local" => Ok(StorageConfig::Local {
+                root: PathBuf::from(
+                    var("LOCAL_STORAGE_DIR").unwrap_or_else(|| "./storage".to_string()),
+                ),
+            }),
+            other => Err(S3Error::from(format!(
+                "Unknown STORAGE_BACKEND: {} (expected s3, s3_compatible or local)",
+                other
+            ))),
+        }
+    }
+
+    /// 設定に応じた保存先を作成
+    pub async fn build(self) -> Result<Box<dyn Storage>, S3Error> {
+        match self {
+            StorageConfig::S3 { bucket } => {
+                let config = aws_config::load_from_env().await;
+                Ok(Box::new(S3Storage {
+                    client: Client::new(&config),
+                    bucket,
+                    endpoint: None,
+                }))
+            }
+            StorageConfig::S3Compatible { bucket, endpoint } => {
+                let config = aws_config::load_from_env().await;
+                let s3_config = aws_sdk_s3::config::Builder::from(&config)
+                    .endpoint_url(&endpoint)
+                    .force_path_style(true)
+                    .build();
+                Ok(Box::new(S3Storage {
+                    client: Client::from_conf(s3_config),
+                    bucket,
+                    endpoint: Some(endpoint.trim_end_matches('/').to_string()),
+                }))
+            }
+            StorageConfig::Local { root } => {
+                tokio::fs::create_dir_all(&root).await.map_err(|e| {
+                    S3Error::from(format!(
+                        "Failed to create storage directory {}: {}",
+                        root.display(),
+                        e
+                    ))
+                })?;
+                Ok(Box::new(LocalStorage { root }))
+            }
+        }
+    }
+}
+
+/// AWS S3 / S3互換ストレージ
+pub struct S3Storage {
+    client: Client,
+    bucket: String,
+    /// S3互換ストレージのエンドポイント（AWS S3の場合はNone）
+    endpoint: Option<String>,
+}
+
+impl S3Storage {
+    /// マルチパートアップロードの各パートを送信して完了させる
+    async fn upload_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        mut buffer: Vec<u8>,
+        chunks: &mut ByteChunks<'_>,
+    ) -> Result<u64, S3Error> {
+        let mut parts = Vec::new();
+        let mut total = 0u64;
+        let mut finished = false;
+
+        while !finished {
+            while buffer.len() < MULTIPART_PART_SIZE {
+                match chunks.next().await {
+                    Some(chunk) => buffer.extend_from_slice(&chunk?),
+                    None => {
+                        finished = true;
+                        break;
+                    }
+                }
+            }
+            if buffer.is_empty() {
+                break;
+            }
+
+            let part_number = parts.len() as i32 + 1;
+            total += buffer.len() as u64;
+            let response = self
+                .client
+                .upload_part()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .body(ByteStream::from(std::mem::take(&mut buffer)))
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to upload part: {}", e)))?;
+            parts.push(
+                CompletedPart::builder()
+                    .set_e_tag(response.e_tag().map(str::to_string))
+                    .part_number(part_number)
+                    .build(),
+            );
+        }
+
+        self.client
+            .complete_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(|e| S3Error::from(format!("Failed to complete upload: {}", e)))?;
+        Ok(total)
+    }
+}
+
+impl Storage for S3Storage {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: Bytes,
+        content_type: &'a str,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            self.client
+                .put_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .body(ByteStream::from(data))
+                .content_type(content_type)
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))?;
+            Ok(())
+        })
+    }
+
+    fn put_stream<'a>(
+        &'a self,
+        key: &'a str,
+        mut chunks: ByteChunks<'a>,
+        content_type: &'a str,
+    ) -> StorageFuture<'a, u64> {
+        Box::pin(async move {
+            // 1パート分に満たない小さいファイルは通常のPUTで保存する
+            let mut buffer = Vec::new();
+            while buffer.len() < MULTIPART_PART_SIZE {
+                match chunks.next().await {
+                    Some(chunk) => buffer.extend_from_slice(&chunk?),
+                    None => {
+                        let size = buffer.len() as u64;
+                        self.put(key, Bytes::from(buffer), content_type).await?;
+                        return Ok(size);
+                    }
+                }
+            }
+
+            let upload_id = self
+                .client
+                .create_multipart_upload()
+                .bucket(&self.bucket)
+                .key(key)
+                .content_type(content_type)
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to start upload: {}", e)))?
+                .upload_id()
+                .map(str::to_string)
+                .ok_or_else(|| S3Error::from("Missing multipart upload id"))?;
+
+            match self
+                .upload_parts(key, &upload_id, buffer, &mut chunks)
+                .await
+            {
+                Ok(size) => Ok(size),
+                Err(e) => {
+                    // 途中までのパートを破棄
+                    let _ = self
+                        .client
+                        .abort_multipart_upload()
+                        .bucket(&self.bucket)
+                        .key(key)
+                        .upload_id(&upload_id)
+                        .send()
+                        .await;
+                    Err(e)
+                }
+            }
+        })
+    }
+
+    fn get<'a>(&'a self, key: &'a str) -> StorageFuture<'a, Bytes> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .get_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to download file: {}", e)))?;
+
+            let data = response
+                .body
+                .collect()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to read file data: {}", e)))?
+                .into_bytes();
+            Ok(data)
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            self.client
+                .delete_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to delete file: {}", e)))?;
+            Ok(())
+        })
+    }
+
+    fn open<'a>(&'a self, key: &'a str) -> StorageFuture<'a, StorageReader> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .get_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to download file: {}", e)))?;
+            Ok(Box::pin(response.body.into_async_read()) as StorageReader)
+        })
+    }
+
+    fn list<'a>(&'a self, prefix: &'a str) -> StorageFuture<'a, Vec<StoredObject>> {
+        Box::pin(async move {
+            let mut objects = Vec::new();
+            let mut pages = self
+                .client
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .prefix(prefix)
+                .into_paginator()
+                .send();
+            while let Some(page) = pages.next().await {
+                let page =
+                    page.map_err(|e| S3Error::from(format!("Failed to list files: {}", e)))?;
+                for object in page.contents() {
+                    let Some(key) = object.key() else { continue };
+                    objects.push(StoredObject {
+                        key: key.to_string(),
+                        size: object.size().unwrap_or(0).max(0) as u64,
+                        last_modified: object
+                            .last_modified()
+                            .and_then(|t| DateTime::from_timestamp(t.secs(), t.subsec_nanos())),
+                    });
+                }
+            }
+            objects.sort_by(|a, b| a.key.cmp(&b.key));
+            Ok(objects)
+        })
+    }
+
+    fn presigned_url<'a>(
+        &'a self,
+        key: &'a str,
+        expires_in: Duration,
+        content_disposition: &'a str,
+    ) -> StorageFuture<'a, Option<String>> {
+        Box::pin(async move {
+            let config = PresigningConfig::expires_in(expires_in)
+                .map_err(|e| S3Error::from(format!("Invalid presign duration: {}", e)))?;
+            let request = self
+                .client
+                .get_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .response_content_disposition(content_disposition)
+                .presigned(config)
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to presign URL: {}", e)))?;
+            Ok(Some(request.uri().to_string()))
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        match self.endpoint {
+            Some(_) => "S3-compatible",
+            None => "S3",
+        }
+    }
+}
+
+/// ローカルファイルシステム（開発・オフライン用）
+/// キーをルートディレクトリからの相対パスとして保存する
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        LocalStorage { root: root.into() }
+    }
+
+    /// キーを保存先のパスに変換（ルート外を指すキーは拒否）
+    fn path_for(&self, key: &str) -> Result<PathBuf, S3Error> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)));
+        if !is_safe {
+            return Err(S3Error::from(format!("Invalid storage key: {}", key)));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+impl Storage for LocalStorage {
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        data: Bytes,
+        _content_type: &'a str,
+    ) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| S3Error::from(format!("Failed to create directory: {}", e)))?;
+            }
+            tokio::fs::write(&path, &data)
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))
+        })
+    }
+
+    fn put_stream<'a>(
+        &'a self,
+        key: &'a str,
+        mut chunks: ByteChunks<'a>,
+        _content_type: &'a str,
+    ) -> StorageFuture<'a, u64> {
+        Box::pin(async move {
+            use tokio::io::AsyncWriteExt;
+
+            let path = self.path_for(key)?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| S3Error::from(format!("Failed to create directory: {}", e)))?;
+            }
+            let mut file = tokio::fs::File::create(&path)
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))?;
+
+            let mut total = 0u64;
+            let result: Result<(), S3Error> = async {
+                while let Some(chunk) = chunks.next().await {
+                    let chunk = chunk?;
+                    total += chunk.len() as u64;
+                    file.write_all(&chunk)
+                        .await
+                        .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))?;
+                }
+                file.flush()
+                    .await
+                    .map_err(|e| S3Error::from(format!("Failed to upload file: {}", e)))
+            }
+            .await;
+
+            if let Err(e) = result {
+                drop(file);
+                tokio::fs::remove_file(&path).await.ok();
+                return Err(e);
+            }
+            Ok(total)
+        })
+    }
+
+    fn get<'a>(&'a self, key: &'a str) -> StorageFuture<'a, Bytes> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            tokio::fs::read(&path)
+                .await
+                .map(Bytes::from)
+                .map_err(|e| S3Error::from(format!("Failed to download file: {}", e)))
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StorageFuture<'a, ()> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            match tokio::fs::remove_file(&path).await {
+                Ok(()) => Ok(()),
+                // S3と同様に、存在しないキーの削除は成功扱い
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+                Err(e) => Err(S3Error::from(format!("Failed to delete file: {}", e))),
+            }
+        })
+    }
+
+    fn open<'a>(&'a self, key: &'a str) -> StorageFuture<'a, StorageReader> {
+        Box::pin(async move {
+            let path = self.path_for(key)?;
+            let file = tokio::fs::File::open(&path)
+                .await
+                .map_err(|e| S3Error::from(format!("Failed to download file: {}", e)))?;
+            Ok(Box::pin(file) as StorageReader)
+        })
+    }
+
+    fn list<'a>(&'a self, prefix: &'a str) -> StorageFuture<'a, Vec<StoredObject>> {
+        Box::pin(async move {
+            // 接頭辞のうちディレクトリ部分から下をたどる
+            let start = match prefix.rfind('/') {
+                Some(pos) => self.path_for(&prefix[..pos])?,
+                None => self.root.clone(),
+            };
+            let mut objects = Vec::new();
+            let mut dirs = vec![start];
+            while let Some(dir) = dirs.pop() {
+                let mut entries = match tokio::fs::read_dir(&dir).await {
+                    Ok(entries) => entries,
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(S3Error::from(format!("Failed to list files: {}", e))),
+                };
+                while let Some(entry) = entries
+                    .next_entry()
+                    .await
+                    .map_err(|e| S3Error::from(format!("Failed to list files: {}", e)))?
+                {
+                    let path = entry.path();
+                    let metadata = entry
+                        .metadata()
+                        .await
+                        .map_err(|e| S3Error::from(format!("Failed to list files: {}", e)))?;
+                    if metadata.is_dir() {
+                        dirs.push(path);
+                        continue;
+                    }
+                    let Ok(relative) = path.strip_prefix(&self.root) else {
+                        continue;
+                    };
+                    let key = relative
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+                    if key.starts_with(prefix) {
+                        objects.push(StoredObject {
+                            key,
+                            size: metadata.len(),
+                            last_modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+                        });
+                    }
+                }
+            }
+            objects.sort_by(|a, b| a.key.cmp(&b.key));
+            Ok(objects)
+        })
+    }
+
+    fn presigned_url<'a>(
+        &'a self,
+        _key: &'a str,
+        _expires_in: Duration,
+        _content_disposition: &'a str,
+    ) -> StorageFuture<'a, Option<String>> {
+        Box::pin(async move { Ok(None) })
+    }
+
+    fn name(&self) -> &'static str {
+        "local"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_storage() -> LocalStorage {
+        let root = std::env::temp_dir().join(format!("rust-ws-app-test-{}", uuid::Uuid::new_v4()));
+        LocalStorage::new(root)
+    }
+
+    #[tokio::test]
+    async fn test_local_storage_roundtrip() {
+        let storage = temp_storage();
+        let key = "employees/1/face/photo.jpg";
+
+        storage
+            .put(key, Bytes::from_static(b"image"), "image/jpeg")
+            .await
+            .unwrap();
+        assert_eq!(
+            storage.get(key).await.unwrap(),
+            Bytes::from_static(b"image")
+        );
+
+        let mut streamed = Vec::new();
+        let mut reader = storage.open(key).await.unwrap();
+        tokio::io::AsyncReadExt::read_to_end(&mut reader, &mut streamed)
+            .await
+            .unwrap();
+        assert_eq!(streamed, b"image");
+        assert_eq!(
+            storage
+                .presigned_url(key, Duration::from_secs(60), "inline")
+                .await
+                .unwrap(),
+            None
+        );
+
+        storage.delete(key).await.unwrap();
+        assert!(storage.get(key).await.is_err());
+        // 2回目の削除も成功する
+        storage.delete(key).await.unwrap();
+
+        tokio::fs::remove_dir_all(&storage.root).await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_local_storage_put_stream_removes_partial_file() {
+        let storage = temp_storage();
+        let chunks: Vec<Result<Bytes, S3Error>> = vec![
+            Ok(Bytes::from_static(b"abc")),
+            Ok(Bytes::from_static(b"def")),
+        ];
+        let size = storage
+            .put_stream(
+                "a.pdf",
+                Box::pin(futures_util::stream::iter(chunks)),
+                "application/pdf",
+            )
+            .await
+            .unwrap();
+        assert_eq!(size, 6);
+        assert_eq!(
+            storage.get("a.pdf").await.unwrap(),
+            Bytes::from_static(b"abcdef")
+        );
+
+        let failing: Vec<Result<Bytes, S3Error>> = vec![
+            Ok(Bytes::from_static(b"abc")),
+            Err(S3Error::from("too large")),
+        ];
+        assert!(storage
+            .put_stream(
+                "b.pdf",
+                Box::pin(futures_util::stream::iter(failing)),
+                "application/pdf"
+            )
+            .await
+            .is_err());
+        assert!(storage.get("b.pdf").await.is_err());
+
+        tokio::fs::remove_dir_all(&storage.root).await.ok();
+    }
+
+    #[tokio::test]
+    async fn test_local_storage_list() {
+        let storage = temp_storage();
+        for key in [
+            "employees/1/face/a.jpg",
+            "employees/2/health/b.pdf",
+            "other/c.pdf",
+        ] {
+            storage
+                .put(key, Bytes::from_static(b"data"), "application/pdf")
+                .await
+                .unwrap();
+        }
+
+        let keys: Vec<String> = storage
+            .list("employees/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|o| o.key)
+            .collect();
+        assert_eq!(keys, ["employees/1/face/a.jpg", "employees/2/health/b.pdf"]);
+        assert!(storage.list("missing/").await.unwrap().is_empty());
+
+        tokio::fs::remove_dir_all(&storage.root).await.ok();
+    }
+
+    #[test]
+    fn test_local_storage_rejects_escaping_keys() {
+        let storage = temp_storage();
+        assert!(storage.path_for("../secret").is_err());
+        assert!(storage.path_for("/etc/passwd").is_err());
+        assert!(storage.path_for("employees/../../x").is_err());
+        assert!(storage.path_for("").is_err());
+        assert!(storage.path_for("employees/1/a.pdf").is_ok());
+    }
+}
@@ -0,0 +1,27 @@
+use crate::components::{opt_number, InputLabelField, INPUT_CLASS};
+use crate::modules::vehicles::models::MasterOption;
+use dioxus::prelude::*;
+
+/// マスタ選択
+#[component]
+pub fn SelectField(
+    label: String,
+    options: Vec<MasterOption>,
+    value: Option<i32>,
+    #[props(default)] required: bool,
+    on_change: EventHandler<Option<i32>>,
+) -> Element {
+    rsx! {
+        InputLabelField { label, required,
+            select {
+                class: INPUT_CLASS,
+                value: "{value.map(|v| v.to_string()).unwrap_or_default()}",
+                onchange: move |evt| on_change.call(opt_number(&evt.value())),
+                option { value: "", if required { "選択してください" } else { "未設定" } }
+                for opt in options {
+                    option { value: "{opt.id}", selected: value == Some(opt.id), "{opt.name}" }
+                }
+            }
+        }
+    }
+}
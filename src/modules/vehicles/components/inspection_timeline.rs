@@ -0,0 +1,289 @@
+use crate::components::{
+    amount_text, format_yen, opt_number, opt_string, DateField, InputLabelField, NumberField,
+    TextField, INPUT_CLASS,
+};
+use crate::modules::vehicles::handlers::{
+    create_vehicle_inspection, delete_vehicle_inspection, get_vehicle_inspections,
+    update_vehicle_inspection,
+};
+use crate::modules::vehicles::models::{
+    CreateVehicleInspection, UpdateVehicleInspection, VehicleInspection, INSPECTION_TYPES,
+};
+use chrono::{Local, NaiveDate};
+use dioxus::prelude::*;
+
+/// 点検履歴タイムライン
+#[component]
+pub fn InspectionTimeline(vehicle_id: i32) -> Element {
+    let mut inspections =
+        use_resource(move || async move { get_vehicle_inspections(vehicle_id).await });
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<VehicleInspection>>);
+    let mut error_message = use_signal(String::new);
+    let today = Local::now().date_naive();
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "点検履歴" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 点検を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                InspectionForm {
+                    key: "{initial.as_ref().map(|i| i.id).unwrap_or(0)}",
+                    vehicle_id,
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        inspections.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*inspections.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "点検履歴が登録されていません" }
+                },
+                Some(Ok(list)) => rsx! {
+                    div { class: "relative border-l-2 border-amber-300 ml-3 space-y-6",
+                        for item in list.iter().cloned() {
+                            {
+                                let id = item.id;
+                                let overdue = item.next_inspection_date.is_some_and(|d| d < today);
+                                let edit_item = item.clone();
+                                rsx! {
+                                    div { key: "{id}", class: "relative pl-6",
+                                        span { class: "absolute -left-[9px] top-1.5 w-4 h-4 rounded-full bg-amber-400 border-2 border-white" }
+                                        div { class: "flex items-start justify-between",
+                                            div {
+                                                p { class: "text-sm text-gray-500", "{item.inspection_date}" }
+                                                p { class: "font-semibold text-gray-800", "{item.inspection_type}" }
+                                            }
+                                            div { class: "flex gap-2 text-sm",
+                                                button {
+                                                    class: "text-amber-700 hover:underline",
+                                                    onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                    "編集"
+                                                }
+                                                button {
+                                                    class: "text-red-600 hover:underline",
+                                                    onclick: move |_| async move {
+                                                        match delete_vehicle_inspection(id).await {
+                                                            Ok(_) => inspections.restart(),
+                                                            Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                        }
+                                                    },
+                                                    "削除"
+                                                }
+                                            }
+                                        }
+                                        div { class: "grid grid-cols-2 gap-2 mt-2 text-sm text-gray-700",
+                                            if let Some(next) = item.next_inspection_date {
+                                                p { class: if overdue { "text-red-600 font-semibold" } else { "" },
+                                                    "次回予定: {next}"
+                                                    if overdue { " (期限超過)" }
+                                                }
+                                            }
+                                            if let Some(location) = &item.inspection_location {
+                                                p { "点検場所: {location}" }
+                                            }
+                                            if let Some(inspector) = &item.inspector_name {
+                                                p { "点検者: {inspector}" }
+                                            }
+                                            if let Some(cost) = &item.cost {
+                                                p { "費用: {format_yen(cost)}" }
+                                            }
+                                        }
+                                        if let Some(notes) = &item.notes {
+                                            p { class: "mt-2 text-sm text-gray-600 whitespace-pre-wrap", "{notes}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "点検履歴の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 点検履歴の入力フォーム（新規・編集兼用）
+#[component]
+fn InspectionForm(
+    vehicle_id: i32,
+    initial: Option<VehicleInspection>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record_id = initial.as_ref().map(|i| i.id);
+    let mut inspection_type = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|i| i.inspection_type.clone())
+            .unwrap_or_else(|| INSPECTION_TYPES[0].to_string())
+    });
+    let mut inspection_date = use_signal(|| initial.as_ref().map(|i| i.inspection_date));
+    let mut next_inspection_date =
+        use_signal(|| initial.as_ref().and_then(|i| i.next_inspection_date));
+    let mut inspector_name = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|i| i.inspector_name.clone())
+            .unwrap_or_default()
+    });
+    let mut inspection_location = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|i| i.inspection_location.clone())
+            .unwrap_or_default()
+    });
+    let mut cost = use_signal(|| initial.as_ref().and_then(|i| i.cost.clone()));
+    let mut notes = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|i| i.notes.clone())
+            .unwrap_or_default()
+    });
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| async move {
+        let Some(date) = inspection_date() else {
+            error_message.set("点検日を入力してください".to_string());
+            return;
+        };
+        if let Some(next) = next_inspection_date() {
+            if next <= date {
+                error_message.set("次回点検予定日は点検日より後にしてください".to_string());
+                return;
+            }
+        }
+
+        is_submitting.set(true);
+        let result = match record_id {
+            Some(id) => update_vehicle_inspection(UpdateVehicleInspection {
+                id,
+                vehicle_id,
+                inspection_type: inspection_type(),
+                inspection_date: date,
+                next_inspection_date: next_inspection_date(),
+                inspector_name: opt_string(inspector_name()),
+                inspection_location: opt_string(inspection_location()),
+                cost: cost(),
+                notes: opt_string(notes()),
+            })
+            .await
+            .map(|_| ()),
+            None => create_vehicle_inspection(CreateVehicleInspection {
+                vehicle_id,
+                inspection_type: inspection_type(),
+                inspection_date: date,
+                next_inspection_date: next_inspection_date(),
+                inspector_name: opt_string(inspector_name()),
+                inspection_location: opt_string(inspection_location()),
+                cost: cost(),
+                notes: opt_string(notes()),
+            })
+            .await
+            .map(|_| ()),
+        };
+        is_submitting.set(false);
+
+        match result {
+            Ok(_) => on_saved.call(()),
+            Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                InputLabelField { label: "点検種別".to_string(), required: true,
+                    input {
+                        class: INPUT_CLASS,
+                        r#type: "text",
+                        list: "inspection-types",
+                        value: "{inspection_type}",
+                        oninput: move |evt| inspection_type.set(evt.value()),
+                    }
+                    datalist { id: "inspection-types",
+                        for t in INSPECTION_TYPES {
+                            option { value: "{t}" }
+                        }
+                    }
+                }
+                DateField {
+                    label: "点検日".to_string(),
+                    value: inspection_date(),
+                    required: true,
+                    on_input: move |v: Option<NaiveDate>| inspection_date.set(v),
+                }
+                DateField {
+                    label: "次回点検予定日".to_string(),
+                    value: next_inspection_date(),
+                    on_input: move |v| next_inspection_date.set(v),
+                }
+                NumberField {
+                    label: "費用（円）".to_string(),
+                    value: amount_text(cost().as_ref()),
+                    on_input: move |v: String| cost.set(opt_number(&v)),
+                }
+                TextField {
+                    label: "点検者".to_string(),
+                    value: inspector_name(),
+                    on_input: move |v| inspector_name.set(v),
+                }
+                TextField {
+                    label: "点検場所".to_string(),
+                    value: inspection_location(),
+                    on_input: move |v| inspection_location.set(v),
+                }
+            }
+            InputLabelField { label: "点検内容・結果".to_string(), required: false,
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "3",
+                    value: "{notes}",
+                    oninput: move |evt| notes.set(evt.value()),
+                }
+            }
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm", "{error_message}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting() || inspection_type().trim().is_empty(),
+                    onclick: handle_submit,
+                    if record_id.is_some() { "更新" } else { "追加" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// 車両関連のコンポーネント
+
+mod form_fields;
+mod inspection_timeline;
+mod repair_timeline;
+mod vehicle_card;
+mod vehicle_create_form;
+mod vehicle_detail_page;
+mod vehicle_edit_form;
+
+pub use inspection_timeline::InspectionTimeline;
+pub use repair_timeline::RepairTimeline;
+pub use vehicle_card::VehicleCard;
+pub use vehicle_create_form::VehicleCreateForm;
+pub use vehicle_detail_page::VehicleDetailPage;
+pub use vehicle_edit_form::VehicleEditForm;
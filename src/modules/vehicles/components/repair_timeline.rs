@@ -0,0 +1,266 @@
+use crate::components::{
+    amount_text, format_yen, opt_number, opt_string, DateField, InputLabelField, NumberField,
+    TextField, INPUT_CLASS,
+};
+use crate::modules::money;
+use crate::modules::vehicles::handlers::{
+    create_vehicle_repair, delete_vehicle_repair, get_vehicle_repairs, update_vehicle_repair,
+};
+use crate::modules::vehicles::models::{
+    CreateVehicleRepair, UpdateVehicleRepair, VehicleRepair, REPAIR_TYPES,
+};
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 修理履歴タイムライン
+#[component]
+pub fn RepairTimeline(vehicle_id: i32) -> Element {
+    let mut repairs = use_resource(move || async move { get_vehicle_repairs(vehicle_id).await });
+    // None: 非表示 / Some(None): 新規 / Some(Some(_)): 編集
+    let mut editing = use_signal(|| None::<Option<VehicleRepair>>);
+    let mut error_message = use_signal(String::new);
+
+    rsx! {
+        div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+            div { class: "flex items-center justify-between mb-6 pb-3 border-b-2 border-amber-400",
+                h3 { class: "text-xl font-bold", "修理履歴" }
+                if editing().is_none() {
+                    button {
+                        class: "px-3 py-1.5 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 text-sm font-semibold transition-colors",
+                        onclick: move |_| editing.set(Some(None)),
+                        "＋ 修理を追加"
+                    }
+                }
+            }
+
+            if let Some(initial) = editing() {
+                RepairForm {
+                    key: "{initial.as_ref().map(|r| r.id).unwrap_or(0)}",
+                    vehicle_id,
+                    initial,
+                    on_saved: move |_| {
+                        editing.set(None);
+                        repairs.restart();
+                    },
+                    on_cancel: move |_| editing.set(None),
+                }
+            }
+
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm mb-4", "{error_message}" }
+            }
+
+            match &*repairs.read_unchecked() {
+                Some(Ok(list)) if list.is_empty() => rsx! {
+                    p { class: "text-gray-500 text-center py-4", "修理履歴が登録されていません" }
+                },
+                Some(Ok(list)) => {
+                    let total: f64 = list.iter().filter_map(|r| r.cost.as_ref()).map(money::to_f64).sum();
+                    rsx! {
+                        p { class: "text-sm text-gray-600 mb-4", "修理費用合計: {format_yen(&total)}" }
+                        div { class: "relative border-l-2 border-gray-300 ml-3 space-y-6",
+                            for item in list.iter().cloned() {
+                                {
+                                    let id = item.id;
+                                    let repair_type = item.repair_type.clone().unwrap_or_else(|| "修理".to_string());
+                                    let edit_item = item.clone();
+                                    rsx! {
+                                        div { key: "{id}", class: "relative pl-6",
+                                            span { class: "absolute -left-[9px] top-1.5 w-4 h-4 rounded-full bg-gray-400 border-2 border-white" }
+                                            div { class: "flex items-start justify-between",
+                                                div {
+                                                    p { class: "text-sm text-gray-500", "{item.repair_date}" }
+                                                    p { class: "font-semibold text-gray-800", "{repair_type}" }
+                                                }
+                                                div { class: "flex gap-2 text-sm",
+                                                    button {
+                                                        class: "text-amber-700 hover:underline",
+                                                        onclick: move |_| editing.set(Some(Some(edit_item.clone()))),
+                                                        "編集"
+                                                    }
+                                                    button {
+                                                        class: "text-red-600 hover:underline",
+                                                        onclick: move |_| async move {
+                                                            match delete_vehicle_repair(id).await {
+                                                                Ok(_) => repairs.restart(),
+                                                                Err(e) => error_message.set(format!("削除に失敗しました: {}", e)),
+                                                            }
+                                                        },
+                                                        "削除"
+                                                    }
+                                                }
+                                            }
+                                            p { class: "mt-2 text-gray-700 whitespace-pre-wrap", "{item.repair_description}" }
+                                            div { class: "grid grid-cols-2 gap-2 mt-2 text-sm text-gray-700",
+                                                if let Some(location) = &item.repair_location {
+                                                    p { "修理先: {location}" }
+                                                }
+                                                if let Some(cost) = &item.cost {
+                                                    p { "費用: {format_yen(cost)}" }
+                                                }
+                                            }
+                                            if let Some(parts) = &item.parts_replaced {
+                                                p { class: "mt-2 text-sm text-gray-600", "交換部品: {parts}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(Err(e)) => rsx! {
+                    p { class: "text-red-500 text-center py-4", "修理履歴の読み込みに失敗しました: {e}" }
+                },
+                None => rsx! {
+                    div { class: "flex justify-center py-4",
+                        div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// 修理履歴の入力フォーム（新規・編集兼用）
+#[component]
+fn RepairForm(
+    vehicle_id: i32,
+    initial: Option<VehicleRepair>,
+    on_saved: EventHandler<()>,
+    on_cancel: EventHandler<()>,
+) -> Element {
+    let record_id = initial.as_ref().map(|r| r.id);
+    let mut repair_date = use_signal(|| initial.as_ref().map(|r| r.repair_date));
+    let mut repair_type = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|r| r.repair_type.clone())
+            .unwrap_or_default()
+    });
+    let mut repair_location = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|r| r.repair_location.clone())
+            .unwrap_or_default()
+    });
+    let mut repair_description = use_signal(|| {
+        initial
+            .as_ref()
+            .map(|r| r.repair_description.clone())
+            .unwrap_or_default()
+    });
+    let mut cost = use_signal(|| initial.as_ref().and_then(|r| r.cost.clone()));
+    let mut parts_replaced = use_signal(|| {
+        initial
+            .as_ref()
+            .and_then(|r| r.parts_replaced.clone())
+            .unwrap_or_default()
+    });
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    let handle_submit = move |_| async move {
+        let Some(date) = repair_date() else {
+            error_message.set("修理日を入力してください".to_string());
+            return;
+        };
+
+        is_submitting.set(true);
+        let result = match record_id {
+            Some(id) => update_vehicle_repair(UpdateVehicleRepair {
+                id,
+                vehicle_id,
+                repair_date: date,
+                repair_type: opt_string(repair_type()),
+                repair_location: opt_string(repair_location()),
+                repair_description: repair_description(),
+                cost: cost(),
+                parts_replaced: opt_string(parts_replaced()),
+            })
+            .await
+            .map(|_| ()),
+            None => create_vehicle_repair(CreateVehicleRepair {
+                vehicle_id,
+                repair_date: date,
+                repair_type: opt_string(repair_type()),
+                repair_location: opt_string(repair_location()),
+                repair_description: repair_description(),
+                cost: cost(),
+                parts_replaced: opt_string(parts_replaced()),
+            })
+            .await
+            .map(|_| ()),
+        };
+        is_submitting.set(false);
+
+        match result {
+            Ok(_) => on_saved.call(()),
+            Err(e) => error_message.set(format!("保存に失敗しました: {}", e)),
+        }
+    };
+
+    rsx! {
+        div { class: "mb-6 p-4 bg-amber-50 border border-amber-200 rounded-lg space-y-3",
+            div { class: "grid grid-cols-2 gap-3",
+                DateField {
+                    label: "修理日".to_string(),
+                    value: repair_date(),
+                    required: true,
+                    on_input: move |v: Option<NaiveDate>| repair_date.set(v),
+                }
+                InputLabelField { label: "修理種別".to_string(), required: false,
+                    select {
+                        class: INPUT_CLASS,
+                        value: "{repair_type}",
+                        onchange: move |evt| repair_type.set(evt.value()),
+                        option { value: "", "未設定" }
+                        for t in REPAIR_TYPES {
+                            option { value: "{t}", selected: repair_type() == *t, "{t}" }
+                        }
+                    }
+                }
+                TextField {
+                    label: "修理先".to_string(),
+                    value: repair_location(),
+                    on_input: move |v| repair_location.set(v),
+                }
+                NumberField {
+                    label: "費用（円）".to_string(),
+                    value: amount_text(cost().as_ref()),
+                    on_input: move |v: String| cost.set(opt_number(&v)),
+                }
+            }
+            InputLabelField { label: "修理内容".to_string(), required: true,
+                textarea {
+                    class: INPUT_CLASS,
+                    rows: "3",
+                    value: "{repair_description}",
+                    oninput: move |evt| repair_description.set(evt.value()),
+                }
+            }
+            TextField {
+                label: "交換部品".to_string(),
+                value: parts_replaced(),
+                placeholder: "例: ブレーキパッド、バッテリー".to_string(),
+                on_input: move |v| parts_replaced.set(v),
+            }
+            if !error_message().is_empty() {
+                p { class: "text-red-500 text-sm", "{error_message}" }
+            }
+            div { class: "flex justify-end gap-2",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_cancel.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting() || repair_description().trim().is_empty(),
+                    onclick: handle_submit,
+                    if record_id.is_some() { "更新" } else { "追加" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::modules::vehicles::models::VehicleSummary;
+use chrono::Local;
+use dioxus::prelude::*;
+
+#[component]
+pub fn VehicleCard(vehicle: VehicleSummary, on_click: EventHandler<MouseEvent>) -> Element {
+    let today = Local::now().date_naive();
+    // 車検期限の状態（期限切れ・30日以内を強調）
+    let (inspection_label, inspection_class) = match vehicle.inspection_expiration_date {
+        Some(date) if date < today => (
+            format!("車検 {} (期限切れ)", date),
+            "text-red-600 font-semibold",
+        ),
+        Some(date) if (date - today).num_days() <= 30 => (
+            format!("車検 {} (30日以内)", date),
+            "text-orange-600 font-semibold",
+        ),
+        Some(date) => (format!("車検 {}", date), "text-gray-600"),
+        None => ("車検 未設定".to_string(), "text-gray-400"),
+    };
+
+    let model = format!(
+        "{} {}",
+        vehicle.manufacturer_name.clone().unwrap_or_default(),
+        vehicle.model.clone().unwrap_or_default()
+    );
+    let office = vehicle
+        .office_name
+        .clone()
+        .unwrap_or_else(|| "営業所未設定".to_string());
+
+    rsx! {
+        div {
+            key: "{vehicle.id}",
+            class: "bg-white rounded-lg border border-gray-200 shadow-sm hover:shadow-md transition-all cursor-pointer p-4",
+            onclick: move |evt| on_click.call(evt),
+
+            div { class: "flex items-start justify-between mb-2",
+                div {
+                    p { class: "text-lg font-bold text-gray-800", "{vehicle.vehicle_number}" }
+                    if let Some(code) = &vehicle.vehicle_code {
+                        p { class: "text-xs text-gray-500", "{code}" }
+                    }
+                }
+                span { class: "inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-amber-100 text-amber-800",
+                    "{vehicle.vehicle_type_name}"
+                }
+            }
+            ul { class: "grid gap-0.5 text-xs",
+                li { class: "text-gray-700", "{model}" }
+                li { class: "text-gray-600", "{office} / {vehicle.ownership_type_name}" }
+                li { class: inspection_class, "{inspection_label}" }
+            }
+        }
+    }
+}
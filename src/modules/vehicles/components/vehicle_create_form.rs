@@ -0,0 +1,208 @@
+use crate::components::{opt_string, TextField};
+use crate::modules::vehicles::components::form_fields::SelectField;
+use crate::modules::vehicles::handlers::{
+    check_vehicle_number_available, create_vehicle, get_vehicle_masters,
+};
+use crate::modules::vehicles::models::{CreateVehicle, VehicleSummary};
+use crate::modules::vehicles::validation::{validate_vehicle_code, validate_vehicle_number};
+use dioxus::prelude::*;
+
+#[component]
+pub fn VehicleCreateForm(
+    mut vehicles_list: Resource<Result<Vec<VehicleSummary>, ServerFnError>>,
+    on_close: EventHandler<MouseEvent>,
+) -> Element {
+    let masters = use_resource(|| async move { get_vehicle_masters().await });
+
+    let mut vehicle_number = use_signal(String::new);
+    let mut vehicle_code = use_signal(String::new);
+    let mut company_id = use_signal(|| None::<i32>);
+    let mut office_id = use_signal(|| None::<i32>);
+    let mut vehicle_type_id = use_signal(|| None::<i32>);
+    let mut ownership_type_id = use_signal(|| None::<i32>);
+    let mut err_msg = use_signal(String::new);
+    let mut is_number_duplicate = use_signal(|| false);
+
+    // 会社が1社のみの場合は自動選択
+    use_effect(move || {
+        if let Some(Ok(m)) = &*masters.read() {
+            if company_id.peek().is_none() && m.companies.len() == 1 {
+                company_id.set(Some(m.companies[0].id));
+            }
+        }
+    });
+
+    let err_number = use_memo(move || {
+        let v = vehicle_number();
+        if v.is_empty() {
+            None
+        } else {
+            validate_vehicle_number(&v).err()
+        }
+    });
+    let err_code = use_memo(move || validate_vehicle_code(&vehicle_code()).err());
+
+    // 車両番号の重複チェック（同一会社内）
+    use_effect(move || {
+        let number = vehicle_number();
+        let Some(company) = company_id() else {
+            is_number_duplicate.set(false);
+            return;
+        };
+        if validate_vehicle_number(&number).is_err() {
+            is_number_duplicate.set(false);
+            return;
+        }
+        spawn(async move {
+            let available = check_vehicle_number_available(company, number, None)
+                .await
+                .unwrap_or(true);
+            is_number_duplicate.set(!available);
+        });
+    });
+
+    let is_valid = use_memo(move || {
+        !vehicle_number().is_empty()
+            && err_number().is_none()
+            && err_code().is_none()
+            && company_id().is_some()
+            && vehicle_type_id().is_some()
+            && ownership_type_id().is_some()
+            && !is_number_duplicate()
+    });
+
+    let handle_submit = move |evt: Event<FormData>| {
+        evt.prevent_default();
+        let (Some(company), Some(vehicle_type), Some(ownership_type)) =
+            (company_id(), vehicle_type_id(), ownership_type_id())
+        else {
+            return;
+        };
+        spawn(async move {
+            let data = CreateVehicle {
+                company_id: company,
+                office_id: office_id(),
+                vehicle_type_id: vehicle_type,
+                ownership_type_id: ownership_type,
+                vehicle_number: vehicle_number(),
+                vehicle_code: opt_string(vehicle_code()),
+            };
+            match create_vehicle(data).await {
+                Ok(_) => {
+                    vehicles_list.restart();
+                    vehicle_number.set(String::new());
+                    vehicle_code.set(String::new());
+                    err_msg.set(String::new());
+                }
+                Err(e) => err_msg.set(format!("車両の登録に失敗しました: {}", e)),
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "flex flex-col h-full bg-white",
+            // ヘッダー
+            div { class: "flex items-center justify-between h-14 px-4 border-b border-gray-200",
+                h3 { class: "text-lg font-semibold text-gray-800", "新規車両登録" }
+                button {
+                    class: "text-gray-500 hover:text-gray-700 transition-colors",
+                    onclick: move |evt| on_close.call(evt),
+                    "✕"
+                }
+            }
+
+            // フォーム
+            form {
+                id: "vehicle-create-form",
+                class: "flex-1 overflow-y-auto p-4 space-y-4",
+                onsubmit: handle_submit,
+
+                match &*masters.read_unchecked() {
+                    Some(Ok(m)) => rsx! {
+                        TextField {
+                            label: "車両番号".to_string(),
+                            value: vehicle_number(),
+                            required: true,
+                            placeholder: "例: 品川 100 あ 12-34".to_string(),
+                            on_input: move |v| vehicle_number.set(v),
+                        }
+                        if let Some(err) = err_number() {
+                            p { class: "text-red-500 text-sm -mt-2", "{err}" }
+                        }
+                        if is_number_duplicate() {
+                            p { class: "text-red-500 text-sm -mt-2", "この車両番号は既に登録されています" }
+                        }
+                        TextField {
+                            label: "管理コード".to_string(),
+                            value: vehicle_code(),
+                            placeholder: "例: TRK-001".to_string(),
+                            on_input: move |v| vehicle_code.set(v),
+                        }
+                        if let Some(err) = err_code() {
+                            p { class: "text-red-500 text-sm -mt-2", "{err}" }
+                        }
+                        if m.companies.len() > 1 {
+                            SelectField {
+                                label: "会社".to_string(),
+                                options: m.companies.clone(),
+                                value: company_id(),
+                                required: true,
+                                on_change: move |v| company_id.set(v),
+                            }
+                        }
+                        SelectField {
+                            label: "配属営業所".to_string(),
+                            options: m.offices.clone(),
+                            value: office_id(),
+                            on_change: move |v| office_id.set(v),
+                        }
+                        SelectField {
+                            label: "車両種別".to_string(),
+                            options: m.vehicle_types.clone(),
+                            value: vehicle_type_id(),
+                            required: true,
+                            on_change: move |v| vehicle_type_id.set(v),
+                        }
+                        SelectField {
+                            label: "所有形態".to_string(),
+                            options: m.ownership_types.clone(),
+                            value: ownership_type_id(),
+                            required: true,
+                            on_change: move |v| ownership_type_id.set(v),
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-red-500 text-sm", "マスタの読み込みに失敗しました: {e}" }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center py-4",
+                            div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+
+                if !err_msg().is_empty() {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded",
+                        "{err_msg}"
+                    }
+                }
+            }
+
+            // フッター
+            div { class: "flex justify-end gap-2 p-4 border-t border-gray-200",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |evt| on_close.call(evt),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    r#type: "submit",
+                    form: "vehicle-create-form",
+                    disabled: !is_valid(),
+                    "登録"
+                }
+            }
+        }
+    }
+}
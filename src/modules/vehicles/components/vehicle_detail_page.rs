@@ -0,0 +1,336 @@
+use crate::components::{format_yen, DetailField, Icon, IconType};
+use crate::modules::vehicles::components::{InspectionTimeline, RepairTimeline, VehicleEditForm};
+use crate::modules::vehicles::handlers::{delete_vehicle, get_vehicle, get_vehicle_masters};
+use crate::modules::vehicles::models::{MasterOption, Vehicle, VehicleMasters};
+use crate::Route;
+
+use chrono::NaiveDate;
+use dioxus::prelude::*;
+
+/// 日付の表示（未設定は空文字）
+fn date_text(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+#[component]
+pub fn VehicleDetailPage(id: i32) -> Element {
+    let mut vehicle_resource = use_resource(move || async move { get_vehicle(id).await });
+    let mut edit_mode = use_signal(|| false);
+    let mut show_delete_confirm = use_signal(|| false);
+    let mut is_deleting = use_signal(|| false);
+    let mut delete_error = use_signal(String::new);
+    let nav = navigator();
+
+    rsx! {
+        document::Title { "車両詳細" }
+        div { class: "flex h-full bg-gray-50",
+            div { class: "flex-1 flex flex-col overflow-hidden",
+                // ヘッダー
+                div { class: "flex items-center justify-between h-14 px-6 border-b border-gray-200 bg-white shadow-sm",
+                    div { class: "flex items-center gap-3",
+                        button {
+                            class: "p-2 hover:bg-gray-100 rounded-lg transition-colors",
+                            onclick: move |_| nav.go_back(),
+                            title: "一覧に戻る",
+                            Icon {
+                                icon_type: IconType::ArrowLeft,
+                                class: Some("size-5".to_string())
+                            }
+                        }
+                        h1 { class: "text-lg font-semibold text-gray-800", "車両詳細" }
+                    }
+                    div { class: "flex items-center gap-3",
+                        if !edit_mode() {
+                            button {
+                                class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm bg-red-500 text-white hover:bg-red-600",
+                                onclick: move |_| show_delete_confirm.set(true),
+                                "🗑 削除"
+                            }
+                        }
+                        button {
+                            class: "font-semibold py-2 px-6 rounded-lg transition-all shadow-sm",
+                            class: if edit_mode() {
+                                "bg-gray-200 text-gray-700 hover:bg-gray-300"
+                            } else {
+                                "bg-amber-400 text-gray-800 hover:bg-amber-500"
+                            },
+                            onclick: move |_| edit_mode.set(!edit_mode()),
+                            if edit_mode() { "✕ キャンセル" } else { "✎ 編集" }
+                        }
+                    }
+                }
+
+                // コンテンツ
+                div { class: "flex-1 overflow-auto",
+                    match &*vehicle_resource.read_unchecked() {
+                        Some(Ok(Some(vehicle))) => {
+                            if edit_mode() {
+                                rsx! {
+                                    VehicleEditForm {
+                                        vehicle: vehicle.clone(),
+                                        on_close: move |_| {
+                                            edit_mode.set(false);
+                                            vehicle_resource.restart();
+                                        },
+                                    }
+                                }
+                            } else {
+                                rsx! {
+                                    VehicleDetailView { vehicle: vehicle.clone() }
+                                }
+                            }
+                        },
+                        Some(Ok(None)) => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "車両が見つかりませんでした" }
+                                    p { class: "text-gray-600 text-sm", "指定されたIDの車両は存在しません。" }
+                                }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    p { class: "text-red-500 font-semibold text-lg mb-2", "エラーが発生しました" }
+                                    p { class: "text-gray-600 text-sm", "{e}" }
+                                }
+                            }
+                        },
+                        None => rsx! {
+                            div { class: "flex items-center justify-center p-12",
+                                div { class: "text-center",
+                                    div { class: "inline-block animate-spin rounded-full h-12 w-12 border-b-2 border-amber-500 mb-4" }
+                                    p { class: "text-gray-600", "読み込み中..." }
+                                }
+                            }
+                        },
+                    }
+                }
+
+                // 削除確認ダイアログ
+                if show_delete_confirm() {
+                    div {
+                        class: "fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50",
+                        onclick: move |_| {
+                            if !is_deleting() {
+                                show_delete_confirm.set(false);
+                            }
+                        },
+                        div {
+                            class: "bg-white rounded-xl shadow-2xl p-6 max-w-md w-full mx-4",
+                            onclick: move |e| e.stop_propagation(),
+                            div { class: "flex items-start gap-4 mb-6",
+                                div { class: "shrink-0 w-12 h-12 bg-red-100 rounded-full flex items-center justify-center",
+                                    svg {
+                                        class: "w-6 h-6 text-red-600",
+                                        view_box: "0 0 24 24",
+                                        fill: "none",
+                                        stroke: "currentColor",
+                                        stroke_width: "2",
+                                        path { d: "M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z" }
+                                    }
+                                }
+                                div { class: "flex-1",
+                                    h3 { class: "text-lg font-bold text-gray-900 mb-2", "車両を削除しますか？" }
+                                    p { class: "text-sm text-gray-600",
+                                        "この操作は取り消せません。点検・修理履歴も含めて削除されます。廃車の場合は編集画面でステータスを変更してください。"
+                                    }
+                                    if !delete_error().is_empty() {
+                                        p { class: "text-sm text-red-600 mt-2", "{delete_error}" }
+                                    }
+                                }
+                            }
+                            div { class: "flex gap-3 justify-end",
+                                button {
+                                    class: "px-4 py-2 text-gray-700 bg-gray-100 rounded-lg hover:bg-gray-200 transition-colors font-semibold",
+                                    disabled: is_deleting(),
+                                    onclick: move |_| show_delete_confirm.set(false),
+                                    "キャンセル"
+                                }
+                                button {
+                                    class: "px-4 py-2 text-white bg-red-600 rounded-lg hover:bg-red-700 transition-colors font-semibold disabled:opacity-50 disabled:cursor-not-allowed",
+                                    disabled: is_deleting(),
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            is_deleting.set(true);
+                                            match delete_vehicle(id).await {
+                                                Ok(_) => {
+                                                    nav.push(Route::VehiclesPage {});
+                                                }
+                                                Err(e) => {
+                                                    delete_error.set(format!("削除に失敗しました: {}", e));
+                                                    is_deleting.set(false);
+                                                }
+                                            }
+                                        });
+                                    },
+                                    if is_deleting() {
+                                        span { class: "flex items-center gap-2",
+                                            div { class: "inline-block animate-spin rounded-full h-4 w-4 border-b-2 border-white" }
+                                            "削除中..."
+                                        }
+                                    } else {
+                                        "削除する"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn VehicleDetailView(vehicle: Vehicle) -> Element {
+    let masters_resource = use_resource(|| async move { get_vehicle_masters().await });
+    let masters = match &*masters_resource.read_unchecked() {
+        Some(Ok(m)) => Some(m.clone()),
+        _ => None,
+    };
+    // マスタ名称（読み込み前・失敗時は空表示）
+    let name = |select: fn(&VehicleMasters) -> &Vec<MasterOption>, id: Option<i32>| {
+        masters
+            .as_ref()
+            .map(|m| VehicleMasters::name_of(select(m), id))
+            .unwrap_or_default()
+    };
+
+    let company_name = name(|m| &m.companies, Some(vehicle.company_id));
+    let office_name = name(|m| &m.offices, vehicle.office_id);
+    let vehicle_type_name = name(|m| &m.vehicle_types, Some(vehicle.vehicle_type_id));
+    let ownership_type_name = name(|m| &m.ownership_types, Some(vehicle.ownership_type_id));
+    let manufacturer_name = name(|m| &m.manufacturers, vehicle.manufacturer_id);
+    let lease_company_name = name(|m| &m.lease_companies, vehicle.lease_company_id);
+
+    rsx! {
+        div { class: "max-w-5xl mx-auto p-6 space-y-6",
+            // 基本情報セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "基本情報"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField { label: "車両番号".to_string(), value: vehicle.vehicle_number.clone() }
+                    DetailField {
+                        label: "管理コード".to_string(),
+                        value: vehicle.vehicle_code.clone().unwrap_or_default()
+                    }
+                    DetailField { label: "会社".to_string(), value: company_name }
+                    DetailField { label: "配属営業所".to_string(), value: office_name }
+                    DetailField { label: "車両種別".to_string(), value: vehicle_type_name }
+                    DetailField { label: "所有形態".to_string(), value: ownership_type_name }
+                    DetailField { label: "メーカー".to_string(), value: manufacturer_name }
+                    DetailField {
+                        label: "車種・型式".to_string(),
+                        value: vehicle.model.clone().unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "年式".to_string(),
+                        value: vehicle.year.map(|y| format!("{}年", y)).unwrap_or_default()
+                    }
+                    DetailField {
+                        label: "色".to_string(),
+                        value: vehicle.color.clone().unwrap_or_default()
+                    }
+                    div { class: "col-span-2",
+                        DetailField {
+                            label: "車台番号".to_string(),
+                            value: vehicle.chassis_number.clone().unwrap_or_default()
+                        }
+                    }
+                }
+            }
+
+            // 車検・保険セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "車検・保険"
+                }
+                div { class: "grid grid-cols-3 gap-4",
+                    DetailField {
+                        label: "車検有効期限".to_string(),
+                        value: date_text(vehicle.inspection_expiration_date)
+                    }
+                    DetailField {
+                        label: "自賠責保険有効期限".to_string(),
+                        value: date_text(vehicle.insurance_expiration_date)
+                    }
+                    DetailField {
+                        label: "任意保険有効期限".to_string(),
+                        value: date_text(vehicle.voluntary_insurance_expiration_date)
+                    }
+                }
+            }
+
+            // リース・購入セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "リース・購入"
+                }
+                div { class: "grid grid-cols-3 gap-4",
+                    DetailField { label: "リース会社".to_string(), value: lease_company_name }
+                    DetailField { label: "リース開始日".to_string(), value: date_text(vehicle.lease_start_date) }
+                    DetailField { label: "リース終了日".to_string(), value: date_text(vehicle.lease_end_date) }
+                    DetailField {
+                        label: "月額リース料".to_string(),
+                        value: vehicle.monthly_lease_fee.as_ref().map(format_yen).unwrap_or_default()
+                    }
+                    DetailField { label: "購入日".to_string(), value: date_text(vehicle.purchase_date) }
+                    DetailField {
+                        label: "購入金額".to_string(),
+                        value: vehicle.purchase_price.as_ref().map(format_yen).unwrap_or_default()
+                    }
+                }
+            }
+
+            // 稼働状況セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "稼働状況"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField {
+                        label: "ステータス".to_string(),
+                        value: if vehicle.is_active { "稼働中".to_string() } else { "廃車済み".to_string() }
+                    }
+                    DetailField { label: "廃車日".to_string(), value: date_text(vehicle.retirement_date) }
+                    div { class: "col-span-2",
+                        DetailField {
+                            label: "廃車理由".to_string(),
+                            value: vehicle.retirement_reason.clone().unwrap_or_default()
+                        }
+                    }
+                    div { class: "col-span-2",
+                        DetailField {
+                            label: "備考".to_string(),
+                            value: vehicle.notes.clone().unwrap_or_default()
+                        }
+                    }
+                }
+            }
+
+            InspectionTimeline { vehicle_id: vehicle.id }
+            RepairTimeline { vehicle_id: vehicle.id }
+
+            // メタ情報セクション
+            div { class: "bg-white rounded-xl shadow-sm border border-gray-200 p-6",
+                h3 { class: "text-xl font-bold mb-6 pb-3 border-b-2 border-amber-400",
+                    "メタ情報"
+                }
+                div { class: "grid grid-cols-2 gap-4",
+                    DetailField {
+                        label: "作成日時".to_string(),
+                        value: vehicle.created_at.format("%Y-%m-%d %H:%M:%S").to_string()
+                    }
+                    DetailField {
+                        label: "更新日時".to_string(),
+                        value: vehicle.updated_at.format("%Y-%m-%d %H:%M:%S").to_string()
+                    }
+                }
+            }
+        }
+    }
+}
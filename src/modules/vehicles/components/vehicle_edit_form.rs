@@ -0,0 +1,320 @@
+use crate::components::{
+    amount_text, opt_number, opt_string, DateField, InputLabelField, NumberField, TextField,
+    INPUT_CLASS,
+};
+use crate::modules::vehicles::components::form_fields::SelectField;
+use crate::modules::vehicles::handlers::{
+    check_vehicle_number_available, get_vehicle_masters, update_vehicle,
+};
+use crate::modules::vehicles::models::Vehicle;
+use crate::modules::vehicles::validation::{validate_vehicle_code, validate_vehicle_number};
+use dioxus::prelude::*;
+
+/// フォームセクション
+#[component]
+fn FormSection(title: String, children: Element) -> Element {
+    rsx! {
+        div { class: "bg-white rounded-lg shadow p-4 space-y-3",
+            h4 { class: "font-bold text-md border-b pb-2", "{title}" }
+            {children}
+        }
+    }
+}
+
+#[component]
+pub fn VehicleEditForm(vehicle: Vehicle, on_close: EventHandler<()>) -> Element {
+    let masters = use_resource(|| async move { get_vehicle_masters().await });
+    let mut data = use_signal(|| vehicle.clone());
+    let mut error_message = use_signal(String::new);
+    let mut is_submitting = use_signal(|| false);
+
+    // 入力値をまとめて更新するヘルパー
+    let mut edit = move |f: &dyn Fn(&mut Vehicle)| {
+        let mut v = data();
+        f(&mut v);
+        data.set(v);
+    };
+
+    let handle_submit = move |_| {
+        spawn(async move {
+            is_submitting.set(true);
+            error_message.set(String::new());
+            let v = data();
+
+            // バリデーション
+            let code = v.vehicle_code.clone().unwrap_or_default();
+            if let Err(e) = validate_vehicle_number(&v.vehicle_number)
+                .and_then(|_| validate_vehicle_code(&code))
+            {
+                error_message.set(e);
+                is_submitting.set(false);
+                return;
+            }
+
+            // 車両番号の重複チェック（自分以外）
+            match check_vehicle_number_available(v.company_id, v.vehicle_number.clone(), Some(v.id))
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    error_message.set("この車両番号は既に登録されています".to_string());
+                    is_submitting.set(false);
+                    return;
+                }
+                Err(e) => {
+                    error_message.set(format!("バリデーションエラー: {}", e));
+                    is_submitting.set(false);
+                    return;
+                }
+            }
+
+            match update_vehicle(v).await {
+                Ok(_) => {
+                    is_submitting.set(false);
+                    on_close.call(());
+                }
+                Err(e) => {
+                    error_message.set(format!("更新エラー: {}", e));
+                    is_submitting.set(false);
+                }
+            }
+        });
+    };
+
+    let v = data();
+
+    rsx! {
+        div { class: "flex flex-col h-full bg-gray-50",
+            // ヘッダー
+            div { class: "flex items-center justify-between h-14 px-4 border-b border-gray-300 bg-white",
+                h3 { class: "text-lg font-semibold", "車両編集" }
+                button {
+                    class: "text-gray-500 hover:text-gray-700 transition-colors",
+                    onclick: move |_| on_close.call(()),
+                    "✕"
+                }
+            }
+
+            div { class: "flex-1 overflow-y-auto p-4 space-y-4 max-w-5xl mx-auto w-full",
+                if !error_message().is_empty() {
+                    div { class: "bg-red-100 border border-red-400 text-red-700 px-4 py-3 rounded",
+                        "{error_message}"
+                    }
+                }
+
+                match &*masters.read_unchecked() {
+                    Some(Ok(m)) => rsx! {
+                        // 基本情報
+                        FormSection { title: "基本情報".to_string(),
+                            div { class: "grid grid-cols-2 gap-3",
+                                TextField {
+                                    label: "車両番号".to_string(),
+                                    value: v.vehicle_number.clone(),
+                                    required: true,
+                                    on_input: move |val: String| edit(&|x| x.vehicle_number = val.clone()),
+                                }
+                                TextField {
+                                    label: "管理コード".to_string(),
+                                    value: v.vehicle_code.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.vehicle_code = opt_string(val.clone())),
+                                }
+                                SelectField {
+                                    label: "会社".to_string(),
+                                    options: m.companies.clone(),
+                                    value: Some(v.company_id),
+                                    required: true,
+                                    on_change: move |val: Option<i32>| {
+                                        if let Some(id) = val {
+                                            edit(&|x| x.company_id = id);
+                                        }
+                                    },
+                                }
+                                SelectField {
+                                    label: "配属営業所".to_string(),
+                                    options: m.offices.clone(),
+                                    value: v.office_id,
+                                    on_change: move |val| edit(&|x| x.office_id = val),
+                                }
+                                SelectField {
+                                    label: "車両種別".to_string(),
+                                    options: m.vehicle_types.clone(),
+                                    value: Some(v.vehicle_type_id),
+                                    required: true,
+                                    on_change: move |val: Option<i32>| {
+                                        if let Some(id) = val {
+                                            edit(&|x| x.vehicle_type_id = id);
+                                        }
+                                    },
+                                }
+                                SelectField {
+                                    label: "所有形態".to_string(),
+                                    options: m.ownership_types.clone(),
+                                    value: Some(v.ownership_type_id),
+                                    required: true,
+                                    on_change: move |val: Option<i32>| {
+                                        if let Some(id) = val {
+                                            edit(&|x| x.ownership_type_id = id);
+                                        }
+                                    },
+                                }
+                                SelectField {
+                                    label: "メーカー".to_string(),
+                                    options: m.manufacturers.clone(),
+                                    value: v.manufacturer_id,
+                                    on_change: move |val| edit(&|x| x.manufacturer_id = val),
+                                }
+                                TextField {
+                                    label: "車種・型式".to_string(),
+                                    value: v.model.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.model = opt_string(val.clone())),
+                                }
+                                TextField {
+                                    label: "年式".to_string(),
+                                    value: v.year.map(|y| y.to_string()).unwrap_or_default(),
+                                    placeholder: "例: 2020".to_string(),
+                                    on_input: move |val: String| edit(&|x| x.year = opt_number(&val)),
+                                }
+                                TextField {
+                                    label: "色".to_string(),
+                                    value: v.color.clone().unwrap_or_default(),
+                                    on_input: move |val: String| edit(&|x| x.color = opt_string(val.clone())),
+                                }
+                                div { class: "col-span-2",
+                                    TextField {
+                                        label: "車台番号".to_string(),
+                                        value: v.chassis_number.clone().unwrap_or_default(),
+                                        on_input: move |val: String| edit(&|x| x.chassis_number = opt_string(val.clone())),
+                                    }
+                                }
+                            }
+                        }
+
+                        // 車検・保険
+                        FormSection { title: "車検・保険".to_string(),
+                            div { class: "grid grid-cols-3 gap-3",
+                                DateField {
+                                    label: "車検有効期限".to_string(),
+                                    value: v.inspection_expiration_date,
+                                    on_input: move |val| edit(&|x| x.inspection_expiration_date = val),
+                                }
+                                DateField {
+                                    label: "自賠責保険有効期限".to_string(),
+                                    value: v.insurance_expiration_date,
+                                    on_input: move |val| edit(&|x| x.insurance_expiration_date = val),
+                                }
+                                DateField {
+                                    label: "任意保険有効期限".to_string(),
+                                    value: v.voluntary_insurance_expiration_date,
+                                    on_input: move |val| edit(&|x| x.voluntary_insurance_expiration_date = val),
+                                }
+                            }
+                        }
+
+                        // リース・購入
+                        FormSection { title: "リース・購入".to_string(),
+                            div { class: "grid grid-cols-3 gap-3",
+                                SelectField {
+                                    label: "リース会社".to_string(),
+                                    options: m.lease_companies.clone(),
+                                    value: v.lease_company_id,
+                                    on_change: move |val| edit(&|x| x.lease_company_id = val),
+                                }
+                                DateField {
+                                    label: "リース開始日".to_string(),
+                                    value: v.lease_start_date,
+                                    on_input: move |val| edit(&|x| x.lease_start_date = val),
+                                }
+                                DateField {
+                                    label: "リース終了日".to_string(),
+                                    value: v.lease_end_date,
+                                    on_input: move |val| edit(&|x| x.lease_end_date = val),
+                                }
+                                NumberField {
+                                    label: "月額リース料（円）".to_string(),
+                                    value: amount_text(v.monthly_lease_fee.as_ref()),
+                                    on_input: move |val: String| edit(&|x| x.monthly_lease_fee = opt_number(&val)),
+                                }
+                                DateField {
+                                    label: "購入日".to_string(),
+                                    value: v.purchase_date,
+                                    on_input: move |val| edit(&|x| x.purchase_date = val),
+                                }
+                                NumberField {
+                                    label: "購入金額（円）".to_string(),
+                                    value: amount_text(v.purchase_price.as_ref()),
+                                    on_input: move |val: String| edit(&|x| x.purchase_price = opt_number(&val)),
+                                }
+                            }
+                        }
+
+                        // 稼働状況
+                        FormSection { title: "稼働状況".to_string(),
+                            div { class: "grid grid-cols-2 gap-3",
+                                InputLabelField { label: "ステータス".to_string(), required: false,
+                                    select {
+                                        class: INPUT_CLASS,
+                                        value: if v.is_active { "true" } else { "false" },
+                                        onchange: move |evt| {
+                                            let active = evt.value() == "true";
+                                            edit(&|x| x.is_active = active);
+                                        },
+                                        option { value: "true", "稼働中" }
+                                        option { value: "false", "廃車済み" }
+                                    }
+                                }
+                                DateField {
+                                    label: "廃車日".to_string(),
+                                    value: v.retirement_date,
+                                    on_input: move |val| edit(&|x| x.retirement_date = val),
+                                }
+                                div { class: "col-span-2",
+                                    TextField {
+                                        label: "廃車理由".to_string(),
+                                        value: v.retirement_reason.clone().unwrap_or_default(),
+                                        on_input: move |val: String| edit(&|x| x.retirement_reason = opt_string(val.clone())),
+                                    }
+                                }
+                                div { class: "col-span-2",
+                                    InputLabelField { label: "備考".to_string(), required: false,
+                                        textarea {
+                                            class: INPUT_CLASS,
+                                            rows: "3",
+                                            value: "{v.notes.clone().unwrap_or_default()}",
+                                            oninput: move |evt| {
+                                                let notes = opt_string(evt.value());
+                                                edit(&|x| x.notes = notes.clone());
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Some(Err(e)) => rsx! {
+                        p { class: "text-red-500 text-sm", "マスタの読み込みに失敗しました: {e}" }
+                    },
+                    None => rsx! {
+                        div { class: "flex justify-center py-4",
+                            div { class: "inline-block animate-spin rounded-full h-6 w-6 border-b-2 border-amber-500" }
+                        }
+                    },
+                }
+            }
+
+            // フッター
+            div { class: "flex justify-end gap-2 p-4 border-t border-gray-300 bg-white",
+                button {
+                    class: "px-4 py-2 border border-gray-300 rounded-lg hover:bg-gray-50 transition-colors",
+                    onclick: move |_| on_close.call(()),
+                    "キャンセル"
+                }
+                button {
+                    class: "px-4 py-2 bg-amber-400 text-gray-800 rounded-lg hover:bg-amber-500 font-semibold transition-colors disabled:opacity-50 disabled:cursor-not-allowed",
+                    disabled: is_submitting(),
+                    onclick: handle_submit,
+                    if is_submitting() { "保存中..." } else { "保存" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! 車両管理のハンドラー層（サーバー関数）
+
+use super::models::{
+    CreateVehicle, CreateVehicleInspection, CreateVehicleRepair, UpdateVehicleInspection,
+    UpdateVehicleRepair, Vehicle, VehicleInspection, VehicleMasters, VehicleRepair, VehicleSummary,
+};
+#[cfg(feature = "server")]
+use super::repository::VehicleRepository;
+use super::validation::{
+    validate_amount, validate_period, validate_vehicle_code, validate_vehicle_number,
+    validate_vehicle_year,
+};
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use crate::modules::auth::{require_permission, Permission};
+
+/// データベースエラーを`ServerFnError`に変換するヘルパー関数
+#[cfg(feature = "server")]
+fn db_error_to_server_error(error: sqlx::Error) -> ServerFnError {
+    let error_msg = error.to_string();
+
+    // 重複・制約エラーの場合、わかりやすいメッセージに変換
+    if error_msg.contains("uq_vehicles_company_number") {
+        ServerFnError::new("この車両番号は既に登録されています".to_string())
+    } else if error_msg.contains("vehicles_vehicle_code_key") {
+        ServerFnError::new("この管理コードは既に使用されています".to_string())
+    } else if error_msg.contains("chk_vehicle_inspection_dates") {
+        ServerFnError::new("次回点検予定日は点検日より後にしてください".to_string())
+    } else {
+        ServerFnError::new(error_msg)
+    }
+}
+
+/// 車両の全項目をバリデーション
+fn validate_vehicle(vehicle: &Vehicle) -> Result<(), ServerFnError> {
+    validate_vehicle_number(&vehicle.vehicle_number).map_err(ServerFnError::new)?;
+    validate_vehicle_code(vehicle.vehicle_code.as_deref().unwrap_or(""))
+        .map_err(ServerFnError::new)?;
+    if let Some(year) = vehicle.year {
+        validate_vehicle_year(year).map_err(ServerFnError::new)?;
+    }
+    validate_period(
+        vehicle.lease_start_date,
+        vehicle.lease_end_date,
+        "リース期間",
+    )
+    .map_err(ServerFnError::new)?;
+    validate_amount(vehicle.monthly_lease_fee.as_ref(), "月額リース料")
+        .map_err(ServerFnError::new)?;
+    validate_amount(vehicle.purchase_price.as_ref(), "購入金額").map_err(ServerFnError::new)?;
+    Ok(())
+}
+
+// ========================================
+// 車両
+// ========================================
+
+/// 全車両の一覧を取得
+#[server]
+pub async fn get_vehicles() -> Result<Vec<VehicleSummary>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::get_all_summaries()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// IDで車両を取得
+#[server]
+pub async fn get_vehicle(id: i32) -> Result<Option<Vehicle>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::get_by_id(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 車両入力フォーム用のマスタを取得
+#[server]
+pub async fn get_vehicle_masters() -> Result<VehicleMasters, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::get_masters()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 車両番号が使用可能かチェック
+#[server]
+pub async fn check_vehicle_number_available(
+    company_id: i32,
+    vehicle_number: String,
+    exclude_id: Option<i32>,
+) -> Result<bool, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::is_vehicle_number_available(company_id, &vehicle_number, exclude_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 車両の作成
+#[server]
+pub async fn create_vehicle(data: CreateVehicle) -> Result<Vehicle, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    validate_vehicle_number(&data.vehicle_number).map_err(ServerFnError::new)?;
+    validate_vehicle_code(data.vehicle_code.as_deref().unwrap_or(""))
+        .map_err(ServerFnError::new)?;
+
+    VehicleRepository::create(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 車両の全項目を更新
+#[server]
+pub async fn update_vehicle(vehicle: Vehicle) -> Result<Vehicle, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    validate_vehicle(&vehicle)?;
+
+    VehicleRepository::update(vehicle)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 車両の削除
+#[server]
+pub async fn delete_vehicle(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    VehicleRepository::delete(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+// ========================================
+// 点検履歴
+// ========================================
+
+/// 車両の点検履歴を取得
+#[server]
+pub async fn get_vehicle_inspections(
+    vehicle_id: i32,
+) -> Result<Vec<VehicleInspection>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::get_inspections(vehicle_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 点検履歴を作成
+#[server]
+pub async fn create_vehicle_inspection(
+    data: CreateVehicleInspection,
+) -> Result<VehicleInspection, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    if data.inspection_type.trim().is_empty() {
+        return Err(ServerFnError::new("点検種別を入力してください".to_string()));
+    }
+    validate_amount(data.cost.as_ref(), "費用").map_err(ServerFnError::new)?;
+
+    VehicleRepository::create_inspection(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 点検履歴を更新
+#[server]
+pub async fn update_vehicle_inspection(
+    data: UpdateVehicleInspection,
+) -> Result<VehicleInspection, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    if data.inspection_type.trim().is_empty() {
+        return Err(ServerFnError::new("点検種別を入力してください".to_string()));
+    }
+    validate_amount(data.cost.as_ref(), "費用").map_err(ServerFnError::new)?;
+
+    VehicleRepository::update_inspection(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 点検履歴を削除
+#[server]
+pub async fn delete_vehicle_inspection(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    VehicleRepository::delete_inspection(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+// ========================================
+// 修理履歴
+// ========================================
+
+/// 車両の修理履歴を取得
+#[server]
+pub async fn get_vehicle_repairs(vehicle_id: i32) -> Result<Vec<VehicleRepair>, ServerFnError> {
+    require_permission(Permission::View).await?;
+
+    VehicleRepository::get_repairs(vehicle_id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+/// 修理履歴を作成
+#[server]
+pub async fn create_vehicle_repair(
+    data: CreateVehicleRepair,
+) -> Result<VehicleRepair, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    if data.repair_description.trim().is_empty() {
+        return Err(ServerFnError::new("修理内容を入力してください".to_string()));
+    }
+    validate_amount(data.cost.as_ref(), "修理費用").map_err(ServerFnError::new)?;
+
+    VehicleRepository::create_repair(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 修理履歴を更新
+#[server]
+pub async fn update_vehicle_repair(
+    data: UpdateVehicleRepair,
+) -> Result<VehicleRepair, ServerFnError> {
+    require_permission(Permission::EditDriver).await?;
+
+    // バリデーション
+    if data.repair_description.trim().is_empty() {
+        return Err(ServerFnError::new("修理内容を入力してください".to_string()));
+    }
+    validate_amount(data.cost.as_ref(), "修理費用").map_err(ServerFnError::new)?;
+
+    VehicleRepository::update_repair(data)
+        .await
+        .map_err(db_error_to_server_error)
+}
+
+/// 修理履歴を削除
+#[server]
+pub async fn delete_vehicle_repair(id: i32) -> Result<(), ServerFnError> {
+    require_permission(Permission::Delete).await?;
+
+    VehicleRepository::delete_repair(id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))
+}
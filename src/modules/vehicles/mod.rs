@@ -0,0 +1,19 @@
+//! 車両管理モジュール
+//! 車両台帳と点検・修理履歴の管理
+
+mod components;
+pub mod handlers;
+pub mod models;
+mod page;
+
+#[cfg(feature = "server")]
+pub mod repository;
+mod validation;
+
+pub use components::VehicleDetailPage;
+pub use models::{
+    CreateVehicle, CreateVehicleInspection, CreateVehicleRepair, MasterOption,
+    UpdateVehicleInspection, UpdateVehicleRepair, Vehicle, VehicleInspection, VehicleMasters,
+    VehicleRepair, VehicleSummary,
+};
+pub use page::VehiclesPage;
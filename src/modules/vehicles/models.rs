@@ -0,0 +1,220 @@
+//! 車両管理のモデル定義
+
+#[cfg(feature = "server")]
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 車両（全項目）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct Vehicle {
+    pub id: i32,
+    pub company_id: i32,
+    pub office_id: Option<i32>,
+    pub vehicle_type_id: i32,
+    pub ownership_type_id: i32,
+    pub manufacturer_id: Option<i32>,
+    // 車両識別情報
+    pub vehicle_number: String,
+    pub vehicle_code: Option<String>,
+    // 車両基本情報
+    pub model: Option<String>,
+    pub year: Option<i32>,
+    pub color: Option<String>,
+    pub chassis_number: Option<String>,
+    // 車検・保険情報
+    pub inspection_expiration_date: Option<NaiveDate>,
+    pub insurance_expiration_date: Option<NaiveDate>,
+    pub voluntary_insurance_expiration_date: Option<NaiveDate>,
+    // リース情報
+    pub lease_company_id: Option<i32>,
+    pub lease_start_date: Option<NaiveDate>,
+    pub lease_end_date: Option<NaiveDate>,
+    #[cfg(feature = "server")]
+    pub monthly_lease_fee: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    #[serde(default, deserialize_with = "crate::modules::money::deserialize_opt")]
+    pub monthly_lease_fee: Option<f64>,
+    // 購入情報
+    pub purchase_date: Option<NaiveDate>,
+    #[cfg(feature = "server")]
+    pub purchase_price: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    #[serde(default, deserialize_with = "crate::modules::money::deserialize_opt")]
+    pub purchase_price: Option<f64>,
+    // 稼働状況
+    pub is_active: bool,
+    pub retirement_date: Option<NaiveDate>,
+    pub retirement_reason: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 車両一覧用（マスタ名称を結合済み）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct VehicleSummary {
+    pub id: i32,
+    pub vehicle_number: String,
+    pub vehicle_code: Option<String>,
+    pub model: Option<String>,
+    pub vehicle_type_name: String,
+    pub ownership_type_name: String,
+    pub manufacturer_name: Option<String>,
+    pub office_name: Option<String>,
+    pub inspection_expiration_date: Option<NaiveDate>,
+    pub is_active: bool,
+}
+
+/// 車両作成のリクエストモデル（詳細は作成後に詳細画面で編集）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVehicle {
+    pub company_id: i32,
+    pub office_id: Option<i32>,
+    pub vehicle_type_id: i32,
+    pub ownership_type_id: i32,
+    pub vehicle_number: String,
+    pub vehicle_code: Option<String>,
+}
+
+/// 車両点検履歴
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct VehicleInspection {
+    pub id: i32,
+    pub vehicle_id: i32,
+    pub inspection_type: String,
+    pub inspection_date: NaiveDate,
+    pub next_inspection_date: Option<NaiveDate>,
+    pub inspector_name: Option<String>,
+    pub inspection_location: Option<String>,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    #[serde(default, deserialize_with = "crate::modules::money::deserialize_opt")]
+    pub cost: Option<f64>,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 車両点検履歴作成用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVehicleInspection {
+    pub vehicle_id: i32,
+    pub inspection_type: String,
+    pub inspection_date: NaiveDate,
+    pub next_inspection_date: Option<NaiveDate>,
+    pub inspector_name: Option<String>,
+    pub inspection_location: Option<String>,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    pub cost: Option<f64>,
+    pub notes: Option<String>,
+}
+
+/// 車両点検履歴更新用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateVehicleInspection {
+    pub id: i32,
+    pub vehicle_id: i32,
+    pub inspection_type: String,
+    pub inspection_date: NaiveDate,
+    pub next_inspection_date: Option<NaiveDate>,
+    pub inspector_name: Option<String>,
+    pub inspection_location: Option<String>,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    pub cost: Option<f64>,
+    pub notes: Option<String>,
+}
+
+/// 車両修理履歴
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct VehicleRepair {
+    pub id: i32,
+    pub vehicle_id: i32,
+    pub repair_date: NaiveDate,
+    pub repair_type: Option<String>,
+    pub repair_location: Option<String>,
+    pub repair_description: String,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    #[serde(default, deserialize_with = "crate::modules::money::deserialize_opt")]
+    pub cost: Option<f64>,
+    pub parts_replaced: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 車両修理履歴作成用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVehicleRepair {
+    pub vehicle_id: i32,
+    pub repair_date: NaiveDate,
+    pub repair_type: Option<String>,
+    pub repair_location: Option<String>,
+    pub repair_description: String,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    pub cost: Option<f64>,
+    pub parts_replaced: Option<String>,
+}
+
+/// 車両修理履歴更新用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateVehicleRepair {
+    pub id: i32,
+    pub vehicle_id: i32,
+    pub repair_date: NaiveDate,
+    pub repair_type: Option<String>,
+    pub repair_location: Option<String>,
+    pub repair_description: String,
+    #[cfg(feature = "server")]
+    pub cost: Option<BigDecimal>,
+    #[cfg(not(feature = "server"))]
+    pub cost: Option<f64>,
+    pub parts_replaced: Option<String>,
+}
+
+/// 選択肢用のマスタ項目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(sqlx::FromRow))]
+pub struct MasterOption {
+    pub id: i32,
+    pub name: String,
+}
+
+/// 車両の入力フォームで使うマスタ一式
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VehicleMasters {
+    pub companies: Vec<MasterOption>,
+    pub offices: Vec<MasterOption>,
+    pub vehicle_types: Vec<MasterOption>,
+    pub manufacturers: Vec<MasterOption>,
+    pub ownership_types: Vec<MasterOption>,
+    /// リース会社（取引先種別が「リース会社」の取引先）
+    pub lease_companies: Vec<MasterOption>,
+}
+
+impl VehicleMasters {
+    /// IDから名称を引く（未設定・不明の場合は空文字）
+    pub fn name_of(options: &[MasterOption], id: Option<i32>) -> String {
+        id.and_then(|id| options.iter().find(|o| o.id == id))
+            .map(|o| o.name.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// 点検種別の候補（自由入力も可）
+pub const INSPECTION_TYPES: &[&str] = &["日常点検", "3ヶ月定期点検", "車検（12ヶ月定期点検）"];
+
+/// 修理種別の候補（自由入力も可）
+pub const REPAIR_TYPES: &[&str] = &["定期整備", "故障修理", "事故修理", "その他"];
@@ -0,0 +1,537 @@
+#[cfg(feature = "server")]
+use super::models::{
+    CreateVehicle, CreateVehicleInspection, CreateVehicleRepair, MasterOption,
+    UpdateVehicleInspection, UpdateVehicleRepair, Vehicle, VehicleInspection, VehicleMasters,
+    VehicleRepair, VehicleSummary,
+};
+#[cfg(feature = "server")]
+use crate::db;
+#[cfg(feature = "server")]
+use crate::modules::audit_log;
+#[cfg(feature = "server")]
+use crate::modules::clients::LEASE_COMPANY_TYPE_NAME;
+
+#[cfg(feature = "server")]
+pub struct VehicleRepository;
+
+#[cfg(feature = "server")]
+impl VehicleRepository {
+    /// データベースプールを取得するヘルパーメソッド
+    async fn pool() -> Result<&'static sqlx::PgPool, sqlx::Error> {
+        db::get_pool_async().await
+    }
+
+    // ========================================
+    // 車両
+    // ========================================
+
+    /// 全車両の一覧を取得（マスタ名称付き）
+    pub async fn get_all_summaries() -> Result<Vec<VehicleSummary>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            VehicleSummary,
+            r#"SELECT
+                v.id,
+                v.vehicle_number,
+                v.vehicle_code,
+                v.model,
+                vt.name AS vehicle_type_name,
+                ot.name AS ownership_type_name,
+                m.name AS "manufacturer_name?",
+                o.name AS "office_name?",
+                v.inspection_expiration_date,
+                v.is_active
+            FROM vehicles v
+            JOIN vehicle_types vt ON vt.id = v.vehicle_type_id
+            JOIN vehicle_ownership_types ot ON ot.id = v.ownership_type_id
+            LEFT JOIN vehicle_manufacturers m ON m.id = v.manufacturer_id
+            LEFT JOIN offices o ON o.id = v.office_id
+            ORDER BY v.vehicle_code NULLS LAST, v.vehicle_number"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで車両を取得
+    pub async fn get_by_id(id: i32) -> Result<Option<Vehicle>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            Vehicle,
+            r#"SELECT
+                id, company_id, office_id, vehicle_type_id, ownership_type_id, manufacturer_id,
+                vehicle_number, vehicle_code, model, year, color, chassis_number,
+                inspection_expiration_date, insurance_expiration_date,
+                voluntary_insurance_expiration_date,
+                lease_company_id, lease_start_date, lease_end_date,
+                monthly_lease_fee,
+                purchase_date, purchase_price,
+                is_active, retirement_date, retirement_reason, notes,
+                created_at, updated_at
+            FROM vehicles WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 車両番号が使用可能かチェック（同一会社内で重複不可）
+    pub async fn is_vehicle_number_available(
+        company_id: i32,
+        vehicle_number: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<bool, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM vehicles
+                WHERE company_id = $1 AND vehicle_number = $2
+                  AND ($3::INT IS NULL OR id <> $3)
+            ) AS "exists!""#,
+            company_id,
+            vehicle_number,
+            exclude_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(!exists)
+    }
+
+    /// 車両の作成
+    pub async fn create(data: CreateVehicle) -> Result<Vehicle, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let vehicle = sqlx::query_as!(
+            Vehicle,
+            r#"INSERT INTO vehicles
+               (company_id, office_id, vehicle_type_id, ownership_type_id,
+                vehicle_number, vehicle_code, is_active, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING
+                id, company_id, office_id, vehicle_type_id, ownership_type_id, manufacturer_id,
+                vehicle_number, vehicle_code, model, year, color, chassis_number,
+                inspection_expiration_date, insurance_expiration_date,
+                voluntary_insurance_expiration_date,
+                lease_company_id, lease_start_date, lease_end_date,
+                monthly_lease_fee,
+                purchase_date, purchase_price,
+                is_active, retirement_date, retirement_reason, notes,
+                created_at, updated_at"#,
+            data.company_id,
+            data.office_id,
+            data.vehicle_type_id,
+            data.ownership_type_id,
+            data.vehicle_number,
+            data.vehicle_code
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("vehicles", vehicle.id, None, &vehicle).await;
+
+        Ok(vehicle)
+    }
+
+    /// 車両の全項目を更新
+    pub async fn update(vehicle: Vehicle) -> Result<Vehicle, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(vehicle.id).await?;
+        let updated = sqlx::query_as!(
+            Vehicle,
+            r#"UPDATE vehicles SET
+                company_id = $2,
+                office_id = $3,
+                vehicle_type_id = $4,
+                ownership_type_id = $5,
+                manufacturer_id = $6,
+                vehicle_number = $7,
+                vehicle_code = $8,
+                model = $9,
+                year = $10,
+                color = $11,
+                chassis_number = $12,
+                inspection_expiration_date = $13,
+                insurance_expiration_date = $14,
+                voluntary_insurance_expiration_date = $15,
+                lease_company_id = $16,
+                lease_start_date = $17,
+                lease_end_date = $18,
+                monthly_lease_fee = $19,
+                purchase_date = $20,
+                purchase_price = $21,
+                is_active = $22,
+                retirement_date = $23,
+                retirement_reason = $24,
+                notes = $25,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = $1
+            RETURNING
+                id, company_id, office_id, vehicle_type_id, ownership_type_id, manufacturer_id,
+                vehicle_number, vehicle_code, model, year, color, chassis_number,
+                inspection_expiration_date, insurance_expiration_date,
+                voluntary_insurance_expiration_date,
+                lease_company_id, lease_start_date, lease_end_date,
+                monthly_lease_fee,
+                purchase_date, purchase_price,
+                is_active, retirement_date, retirement_reason, notes,
+                created_at, updated_at"#,
+            vehicle.id,
+            vehicle.company_id,
+            vehicle.office_id,
+            vehicle.vehicle_type_id,
+            vehicle.ownership_type_id,
+            vehicle.manufacturer_id,
+            vehicle.vehicle_number,
+            vehicle.vehicle_code,
+            vehicle.model,
+            vehicle.year,
+            vehicle.color,
+            vehicle.chassis_number,
+            vehicle.inspection_expiration_date,
+            vehicle.insurance_expiration_date,
+            vehicle.voluntary_insurance_expiration_date,
+            vehicle.lease_company_id,
+            vehicle.lease_start_date,
+            vehicle.lease_end_date,
+            vehicle.monthly_lease_fee,
+            vehicle.purchase_date,
+            vehicle.purchase_price,
+            vehicle.is_active,
+            vehicle.retirement_date,
+            vehicle.retirement_reason,
+            vehicle.notes
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update("vehicles", updated.id, None, old.as_ref(), &updated).await;
+
+        Ok(updated)
+    }
+
+    /// 車両の削除（点検・修理履歴も削除される）
+    pub async fn delete(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_by_id(id).await?;
+        sqlx::query!("DELETE FROM vehicles WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("vehicles", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    // ========================================
+    // マスタ
+    // ========================================
+
+    /// 入力フォーム用のマスタ一式を取得
+    pub async fn get_masters() -> Result<VehicleMasters, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let companies = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT id, name FROM companies WHERE is_active = TRUE ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let offices = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT id, name FROM offices WHERE is_active = TRUE ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let vehicle_types = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT id, name FROM vehicle_types ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let manufacturers = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT id, name FROM vehicle_manufacturers ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        let ownership_types = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT id, name FROM vehicle_ownership_types ORDER BY id"#
+        )
+        .fetch_all(pool)
+        .await?;
+        // 取引停止の会社も既存車両の表示用に含める
+        let lease_companies = sqlx::query_as!(
+            MasterOption,
+            r#"SELECT c.id, c.name
+               FROM clients c
+               JOIN client_types t ON t.id = c.client_type_id
+               WHERE t.name = $1
+               ORDER BY c.is_active DESC, c.name_kana NULLS LAST, c.name"#,
+            LEASE_COMPANY_TYPE_NAME
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(VehicleMasters {
+            companies,
+            offices,
+            vehicle_types,
+            manufacturers,
+            ownership_types,
+            lease_companies,
+        })
+    }
+
+    // ========================================
+    // 点検履歴
+    // ========================================
+
+    /// 車両の点検履歴を取得（新しい順）
+    pub async fn get_inspections(vehicle_id: i32) -> Result<Vec<VehicleInspection>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            VehicleInspection,
+            r#"SELECT id, vehicle_id, inspection_type, inspection_date, next_inspection_date,
+                      inspector_name, inspection_location, cost, notes,
+                      created_at, updated_at
+               FROM vehicle_inspection_history
+               WHERE vehicle_id = $1
+               ORDER BY inspection_date DESC, id DESC"#,
+            vehicle_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで点検履歴を取得
+    pub async fn get_inspection_by_id(id: i32) -> Result<Option<VehicleInspection>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            VehicleInspection,
+            r#"SELECT id, vehicle_id, inspection_type, inspection_date, next_inspection_date,
+                      inspector_name, inspection_location, cost, notes,
+                      created_at, updated_at
+               FROM vehicle_inspection_history
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 点検履歴を作成
+    pub async fn create_inspection(
+        data: CreateVehicleInspection,
+    ) -> Result<VehicleInspection, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let inspection = sqlx::query_as!(
+            VehicleInspection,
+            r#"INSERT INTO vehicle_inspection_history
+               (vehicle_id, inspection_type, inspection_date, next_inspection_date,
+                inspector_name, inspection_location, cost, notes, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8,
+                       CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, vehicle_id, inspection_type, inspection_date, next_inspection_date,
+                         inspector_name, inspection_location, cost, notes,
+                         created_at, updated_at"#,
+            data.vehicle_id,
+            data.inspection_type,
+            data.inspection_date,
+            data.next_inspection_date,
+            data.inspector_name,
+            data.inspection_location,
+            data.cost,
+            data.notes
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create(
+            "vehicle_inspection_history",
+            inspection.id,
+            None,
+            &inspection,
+        )
+        .await;
+
+        Ok(inspection)
+    }
+
+    /// 点検履歴を更新
+    pub async fn update_inspection(
+        data: UpdateVehicleInspection,
+    ) -> Result<VehicleInspection, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_inspection_by_id(data.id).await?;
+        let inspection = sqlx::query_as!(
+            VehicleInspection,
+            r#"UPDATE vehicle_inspection_history SET
+                inspection_type = $3,
+                inspection_date = $4,
+                next_inspection_date = $5,
+                inspector_name = $6,
+                inspection_location = $7,
+                cost = $8,
+                notes = $9,
+                updated_at = CURRENT_TIMESTAMP
+               WHERE id = $1 AND vehicle_id = $2
+               RETURNING id, vehicle_id, inspection_type, inspection_date, next_inspection_date,
+                         inspector_name, inspection_location, cost, notes,
+                         created_at, updated_at"#,
+            data.id,
+            data.vehicle_id,
+            data.inspection_type,
+            data.inspection_date,
+            data.next_inspection_date,
+            data.inspector_name,
+            data.inspection_location,
+            data.cost,
+            data.notes
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update(
+            "vehicle_inspection_history",
+            inspection.id,
+            None,
+            old.as_ref(),
+            &inspection,
+        )
+        .await;
+
+        Ok(inspection)
+    }
+
+    /// 点検履歴を削除
+    pub async fn delete_inspection(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_inspection_by_id(id).await?;
+        sqlx::query!("DELETE FROM vehicle_inspection_history WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("vehicle_inspection_history", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+
+    // ========================================
+    // 修理履歴
+    // ========================================
+
+    /// 車両の修理履歴を取得（新しい順）
+    pub async fn get_repairs(vehicle_id: i32) -> Result<Vec<VehicleRepair>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            VehicleRepair,
+            r#"SELECT id, vehicle_id, repair_date, repair_type, repair_location,
+                      repair_description, cost, parts_replaced,
+                      created_at, updated_at
+               FROM vehicle_repair_history
+               WHERE vehicle_id = $1
+               ORDER BY repair_date DESC, id DESC"#,
+            vehicle_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// IDで修理履歴を取得
+    pub async fn get_repair_by_id(id: i32) -> Result<Option<VehicleRepair>, sqlx::Error> {
+        let pool = Self::pool().await?;
+        sqlx::query_as!(
+            VehicleRepair,
+            r#"SELECT id, vehicle_id, repair_date, repair_type, repair_location,
+                      repair_description, cost, parts_replaced,
+                      created_at, updated_at
+               FROM vehicle_repair_history
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 修理履歴を作成
+    pub async fn create_repair(data: CreateVehicleRepair) -> Result<VehicleRepair, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let repair = sqlx::query_as!(
+            VehicleRepair,
+            r#"INSERT INTO vehicle_repair_history
+               (vehicle_id, repair_date, repair_type, repair_location, repair_description,
+                cost, parts_replaced, created_at, updated_at)
+               VALUES ($1, $2, $3, $4, $5, $6, $7,
+                       CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+               RETURNING id, vehicle_id, repair_date, repair_type, repair_location,
+                         repair_description, cost, parts_replaced,
+                         created_at, updated_at"#,
+            data.vehicle_id,
+            data.repair_date,
+            data.repair_type,
+            data.repair_location,
+            data.repair_description,
+            data.cost,
+            data.parts_replaced
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_create("vehicle_repair_history", repair.id, None, &repair).await;
+
+        Ok(repair)
+    }
+
+    /// 修理履歴を更新
+    pub async fn update_repair(data: UpdateVehicleRepair) -> Result<VehicleRepair, sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_repair_by_id(data.id).await?;
+        let repair = sqlx::query_as!(
+            VehicleRepair,
+            r#"UPDATE vehicle_repair_history SET
+                repair_date = $3,
+                repair_type = $4,
+                repair_location = $5,
+                repair_description = $6,
+                cost = $7,
+                parts_replaced = $8,
+                updated_at = CURRENT_TIMESTAMP
+               WHERE id = $1 AND vehicle_id = $2
+               RETURNING id, vehicle_id, repair_date, repair_type, repair_location,
+                         repair_description, cost, parts_replaced,
+                         created_at, updated_at"#,
+            data.id,
+            data.vehicle_id,
+            data.repair_date,
+            data.repair_type,
+            data.repair_location,
+            data.repair_description,
+            data.cost,
+            data.parts_replaced
+        )
+        .fetch_one(pool)
+        .await?;
+
+        audit_log::record_update(
+            "vehicle_repair_history",
+            repair.id,
+            None,
+            old.as_ref(),
+            &repair,
+        )
+        .await;
+
+        Ok(repair)
+    }
+
+    /// 修理履歴を削除
+    pub async fn delete_repair(id: i32) -> Result<(), sqlx::Error> {
+        let pool = Self::pool().await?;
+        let old = Self::get_repair_by_id(id).await?;
+        sqlx::query!("DELETE FROM vehicle_repair_history WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+
+        audit_log::record_delete("vehicle_repair_history", id, None, old.as_ref()).await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+
+/// 車両番号（ナンバープレート）のバリデーション
+///
+/// ルール:
+/// - 空文字列は不可
+/// - 前後の空白は不可
+/// - 50文字以内
+pub fn validate_vehicle_number(number: &str) -> Result<(), String> {
+    if number.is_empty() {
+        return Err("車両番号を入力してください".to_string());
+    }
+
+    if number != number.trim() {
+        return Err("車両番号の前後に空白を含めることはできません".to_string());
+    }
+
+    if number.chars().count() > 50 {
+        return Err("車両番号は50文字以内で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 社内管理コードのバリデーション
+///
+/// ルール:
+/// - 空文字列は可（未設定）
+/// - 半角大文字アルファベット(A-Z)、数字(0-9)、ハイフンのみ許可
+/// - 50文字以内
+pub fn validate_vehicle_code(code: &str) -> Result<(), String> {
+    if code.is_empty() {
+        return Ok(());
+    }
+
+    let is_valid = code
+        .chars()
+        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-');
+    if !is_valid {
+        return Err(
+            "管理コードは半角大文字アルファベット(A-Z)・数字(0-9)・ハイフンのみ使用できます"
+                .to_string(),
+        );
+    }
+
+    if code.len() > 50 {
+        return Err("管理コードは50文字以内で入力してください".to_string());
+    }
+
+    Ok(())
+}
+
+/// 年式のバリデーション
+pub fn validate_vehicle_year(year: i32) -> Result<(), String> {
+    if !(1950..=2100).contains(&year) {
+        return Err("年式は1950〜2100の範囲で入力してください".to_string());
+    }
+    Ok(())
+}
+
+/// 期間（開始日 < 終了日）のバリデーション
+pub fn validate_period(
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    label: &str,
+) -> Result<(), String> {
+    if let (Some(start), Some(end)) = (start, end) {
+        if start >= end {
+            return Err(format!("{}の終了日は開始日より後にしてください", label));
+        }
+    }
+    Ok(())
+}
+
+/// 金額のバリデーション（0以上）
+///
+/// 金額はサーバーでは BigDecimal、クライアントでは f64 のため、どちらも受け付ける（NaNは不可）
+pub fn validate_amount<T: PartialOrd + Default>(
+    amount: Option<&T>,
+    label: &str,
+) -> Result<(), String> {
+    if let Some(amount) = amount {
+        match amount.partial_cmp(&T::default()) {
+            Some(Ordering::Equal | Ordering::Greater) => {}
+            _ => return Err(format!("{}は0以上の数値で入力してください", label)),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vehicle_number() {
+        assert!(validate_vehicle_number("品川 100 あ 12-34").is_ok());
+        assert!(validate_vehicle_number("").is_err());
+        assert!(validate_vehicle_number(" 品川 100 あ 12-34").is_err());
+    }
+
+    #[test]
+    fn test_vehicle_code() {
+        assert!(validate_vehicle_code("").is_ok());
+        assert!(validate_vehicle_code("TRK-001").is_ok());
+        assert!(validate_vehicle_code("trk-001").is_err());
+        assert!(validate_vehicle_code("TRK 001").is_err());
+    }
+
+    #[test]
+    fn test_period() {
+        let start = NaiveDate::from_ymd_opt(2025, 4, 1);
+        let end = NaiveDate::from_ymd_opt(2030, 3, 31);
+        assert!(validate_period(start, end, "リース期間").is_ok());
+        assert!(validate_period(end, start, "リース期間").is_err());
+        assert!(validate_period(start, None, "リース期間").is_ok());
+    }
+
+    #[test]
+    fn test_amount() {
+        assert!(validate_amount(None::<&f64>, "月額リース料").is_ok());
+        assert!(validate_amount(Some(&85000.0), "月額リース料").is_ok());
+        assert!(validate_amount(Some(&0.0), "月額リース料").is_ok());
+        assert!(validate_amount(Some(&-1.0), "月額リース料").is_err());
+        assert!(validate_amount(Some(&f64::NAN), "月額リース料").is_err());
+    }
+}